@@ -0,0 +1,186 @@
+//! Support for `cargo creusot prove --check-cache`: run the solvers again on every goal of the
+//! proof cache (the `proof.json` files of `why3find`), and report the goals whose status changed.
+//!
+//! Goals are identified by the name of their module, which is derived from the stable path of the
+//! Rust item (as listed by `--list-obligations`), and by the name of the goal in this module.
+
+use crate::{
+    diff::{Results, load_proof_file},
+    why3find_wrapper::{ProveArgs, raw_prove},
+};
+use anyhow::{Context as _, Result, bail};
+use creusot_setup::Paths;
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+/// Check the cached results of the Coma files `args.files`, leaving the cache unchanged.
+pub fn check_cache(args: ProveArgs, paths: &Paths) -> Result<()> {
+    let mut coma_files = Vec::new();
+    for file in &args.files {
+        find_coma_files(file, &mut coma_files)?;
+    }
+
+    // `why3find` updates the `proof.json` files: save them to restore them afterwards.
+    let mut saved = Vec::new();
+    let mut cached = Results::new();
+    for coma in &coma_files {
+        let proof_file = proof_file(coma);
+        let contents = std::fs::read_to_string(&proof_file).ok();
+        if contents.is_some() {
+            load_proof_file(&proof_file, &mut cached)?;
+        }
+        saved.push((proof_file, contents));
+    }
+
+    let run = raw_prove(args, paths);
+
+    let mut fresh = Results::new();
+    for (proof_file, _) in &saved {
+        if proof_file.exists() {
+            load_proof_file(proof_file, &mut fresh)?;
+        }
+    }
+    for (proof_file, contents) in saved {
+        let restored = match contents {
+            Some(contents) => std::fs::write(&proof_file, contents),
+            None if proof_file.exists() => std::fs::remove_file(&proof_file),
+            None => Ok(()),
+        };
+        restored.with_context(|| format!("restoring {}", proof_file.display()))?;
+    }
+
+    let spans = module_spans(&coma_files)?;
+    let drifted = drifted(&cached, &fresh);
+    for drift in &drifted {
+        let span = spans.get(drift.modl).map_or("<unknown>", String::as_str);
+        eprintln!(
+            "error: {span}: {} {}: cached {}, now {}",
+            drift.modl,
+            drift.goal,
+            status(Some(drift.was_proved)),
+            status(drift.is_proved)
+        );
+    }
+
+    if !drifted.is_empty() {
+        bail!("{} goal(s) changed status since they were cached", drifted.len());
+    }
+    // The run can only fail without changing the status of a cached goal if it could not check
+    // the goals at all, or if some goals were not in the cache.
+    run
+}
+
+/// A cached goal whose status changed when running the solvers again.
+#[derive(Debug, PartialEq)]
+struct Drift<'a> {
+    modl: &'a str,
+    goal: &'a str,
+    was_proved: bool,
+    /// `None` if the goal is not in the fresh results anymore.
+    is_proved: Option<bool>,
+}
+
+fn drifted<'a>(cached: &'a Results, fresh: &Results) -> Vec<Drift<'a>> {
+    let mut drifted = Vec::new();
+    for (modl, goals) in cached {
+        for (goal, &was_proved) in goals {
+            let is_proved = fresh.get(modl).and_then(|goals| goals.get(goal)).copied();
+            if is_proved != Some(was_proved) {
+                drifted.push(Drift { modl, goal, was_proved, is_proved });
+            }
+        }
+    }
+    drifted
+}
+
+fn status(proved: Option<bool>) -> &'static str {
+    match proved {
+        Some(true) => "proved",
+        Some(false) => "unproved",
+        None => "missing",
+    }
+}
+
+pub(crate) fn find_coma_files(path: &Path, coma_files: &mut Vec<PathBuf>) -> Result<()> {
+    if path.is_dir() {
+        let mut entries =
+            std::fs::read_dir(path)?.map(|e| Ok(e?.path())).collect::<Result<Vec<_>>>()?;
+        entries.sort();
+        for entry in entries {
+            find_coma_files(&entry, coma_files)?;
+        }
+    } else if path.extension() == Some(OsStr::new("coma")) {
+        coma_files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// `why3find` stores the proofs of `foo.coma` in `foo/proof.json`.
+fn proof_file(coma: &Path) -> PathBuf {
+    coma.with_extension("").join("proof.json")
+}
+
+/// The location of each module, from the span attribute of its declaration:
+/// `module M_foo [#"src/lib.rs" 10 4 10 20]`.
+fn module_spans(coma_files: &[PathBuf]) -> Result<BTreeMap<String, String>> {
+    let mut spans = BTreeMap::new();
+    for coma in coma_files {
+        let contents =
+            std::fs::read_to_string(coma).with_context(|| format!("reading {}", coma.display()))?;
+        spans.extend(contents.lines().filter_map(module_span));
+    }
+    Ok(spans)
+}
+
+fn module_span(line: &str) -> Option<(String, String)> {
+    let decl = line.strip_prefix("module ")?;
+    let (name, rest) = decl.split_once(' ')?;
+    let (file, pos) = rest.strip_prefix("[#\"")?.split_once('"')?;
+    let mut pos = pos.split_whitespace();
+    let (line, col) = (pos.next()?, pos.next()?);
+    Some((name.to_string(), format!("{file}:{line}:{col}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(goals: &[(&str, &str, bool)]) -> Results {
+        let mut results = Results::new();
+        for &(modl, goal, proved) in goals {
+            results.entry(modl.to_string()).or_default().insert(goal.to_string(), proved);
+        }
+        results
+    }
+
+    #[test]
+    fn drift() {
+        let cached =
+            results(&[("M_f", "vc_f", true), ("M_g", "vc_g", false), ("M_h", "vc_h", true)]);
+        let fresh = results(&[("M_f", "vc_f", true), ("M_g", "vc_g", true)]);
+        assert_eq!(drifted(&cached, &fresh), [
+            Drift { modl: "M_g", goal: "vc_g", was_proved: false, is_proved: Some(true) },
+            Drift { modl: "M_h", goal: "vc_h", was_proved: true, is_proved: None },
+        ]);
+        // New goals are not reported: they were not cached.
+        assert_eq!(drifted(&fresh, &cached), [Drift {
+            modl: "M_g",
+            goal: "vc_g",
+            was_proved: true,
+            is_proved: Some(false)
+        }]);
+    }
+
+    #[test]
+    fn span_of_module() {
+        assert_eq!(
+            module_span("module M_foo [#\"src/lib.rs\" 10 4 10 20]"),
+            Some(("M_foo".to_string(), "src/lib.rs:10:4".to_string()))
+        );
+        assert_eq!(module_span("module M_foo"), None);
+        assert_eq!(module_span("  let rec foo [#\"src/lib.rs\" 10 4 10 20]"), None);
+    }
+}
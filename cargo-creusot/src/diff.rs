@@ -0,0 +1,123 @@
+use anyhow::{Context as _, Result, bail};
+use clap::*;
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+/// Arguments for `cargo creusot diff`.
+#[derive(Debug, Parser)]
+pub struct DiffArgs {
+    /// Proof results of the old revision: a `proof.json` file, or a directory (typically `verif/`)
+    /// where to look for them.
+    pub old: PathBuf,
+    /// Proof results of the new revision.
+    pub new: PathBuf,
+}
+
+/// Status of every goal, indexed by module then goal name.
+///
+/// Module names are derived from the stable paths of the Rust items (including the hash of
+/// impls), so they can be compared between two revisions.
+pub(crate) type Results = BTreeMap<String, BTreeMap<String, bool>>;
+
+/// Compare the proof results of two revisions, and fail if some goals are not proved anymore.
+pub fn diff(args: DiffArgs) -> Result<()> {
+    let old = load_results(&args.old)?;
+    let new = load_results(&args.new)?;
+
+    let mut newly_failing: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut newly_proved: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (modl, goals) in &new {
+        for (goal, &proved) in goals {
+            let was_proved = old.get(modl).and_then(|goals| goals.get(goal)).copied();
+            match (was_proved, proved) {
+                (Some(true) | None, false) => newly_failing.entry(modl).or_default().push(goal),
+                (Some(false), true) => newly_proved.entry(modl).or_default().push(goal),
+                _ => {}
+            }
+        }
+    }
+    let removed: BTreeSet<&str> =
+        old.keys().filter(|modl| !new.contains_key(*modl)).map(String::as_str).collect();
+
+    print_section("Newly failing", &newly_failing);
+    print_section("Newly proved", &newly_proved);
+    if !removed.is_empty() {
+        println!("Removed:");
+        for modl in &removed {
+            println!("  {modl}");
+        }
+    }
+
+    let failing = newly_failing.values().map(Vec::len).sum::<usize>();
+    if failing > 0 {
+        bail!("{failing} goal(s) are not proved anymore");
+    }
+    Ok(())
+}
+
+fn print_section(title: &str, items: &BTreeMap<&str, Vec<&str>>) {
+    if items.is_empty() {
+        return;
+    }
+    println!("{title}:");
+    for (modl, goals) in items {
+        println!("  {modl}");
+        for goal in goals {
+            println!("    {goal}");
+        }
+    }
+}
+
+fn load_results(path: &Path) -> Result<Results> {
+    let mut results = Results::new();
+    if path.is_dir() {
+        load_results_rec(path, &mut results)?;
+    } else {
+        load_proof_file(path, &mut results)?;
+    }
+    Ok(results)
+}
+
+fn load_results_rec(dir: &Path, results: &mut Results) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            load_results_rec(&path, results)?;
+        } else if path.file_name().is_some_and(|name| name == "proof.json") {
+            load_proof_file(&path, results)?;
+        }
+    }
+    Ok(())
+}
+
+/// Read a `proof.json` file produced by `why3find`.
+pub(crate) fn load_proof_file(path: &Path, results: &mut Results) -> Result<()> {
+    let contents =
+        std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let json: Value = serde_json::from_str(&contents)
+        .with_context(|| format!("{} is not a valid JSON file", path.display()))?;
+    let Some(proofs) = json.get("proofs").and_then(Value::as_object) else {
+        bail!("{} is not a why3find proof file", path.display())
+    };
+    for (modl, goals) in proofs {
+        let entry = results.entry(modl.clone()).or_default();
+        for (goal, proof) in goals.as_object().into_iter().flatten() {
+            entry.insert(goal.clone(), is_proved(proof));
+        }
+    }
+    Ok(())
+}
+
+/// A goal is proved if it was discharged by a prover, or by a tactic whose subgoals are all proved.
+fn is_proved(proof: &Value) -> bool {
+    if proof.get("prover").is_some() {
+        return true;
+    }
+    match proof.get("children").and_then(Value::as_array) {
+        Some(children) => proof.get("tactic").is_some() && children.iter().all(is_proved),
+        None => false,
+    }
+}
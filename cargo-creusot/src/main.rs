@@ -18,6 +18,9 @@ mod why3find_wrapper;
 use why3find_wrapper::*;
 mod new;
 use new::*;
+mod diff;
+use diff::*;
+mod check_cache;
 
 fn main() -> Result<()> {
     let cargs = CargoCreusotCmds::parse_from(std::env::args().skip(1));
@@ -34,6 +37,7 @@ fn main() -> Result<()> {
         Some(New(args)) => new(args),
         Some(Init(args)) => init(args),
         Some(Clean(args)) => clean(args),
+        Some(Diff(args)) => diff(args),
         Some(Why3(args)) => why3(args),
     }
 }
@@ -189,6 +193,8 @@ pub enum CargoCreusotSubCommand {
     Init(InitArgs),
     /// Clean dangling files in verif/
     Clean(CleanArgs),
+    /// Compare the proof results of two revisions
+    Diff(DiffArgs),
     /// Run Why3
     Why3(Why3Args),
 }
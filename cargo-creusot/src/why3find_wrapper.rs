@@ -1,10 +1,11 @@
 use anyhow::{Result, anyhow};
 use clap::*;
 use creusot_setup::{Paths, creusot_paths};
-use std::{ffi::OsStr, path::PathBuf, process::Command};
+use std::{ffi::OsStr, io::IsTerminal as _, path::PathBuf, process::Command};
 
 use crate::{
     OUTPUT_PREFIX,
+    check_cache::{check_cache, find_coma_files},
     why3_launcher::{self, Why3Mode},
 };
 
@@ -16,9 +17,16 @@ pub struct ProveArgs {
     /// Replay proofs only, no update.
     #[clap(long)]
     pub replay: bool,
+    /// Run the solvers again on the goals of the existing proofs, and report the goals whose
+    /// status changed. The existing proofs are not updated.
+    #[clap(long, conflicts_with = "replay")]
+    pub check_cache: bool,
     /// Generate Why3 sessions for why3 ide.
     #[clap(long)]
     pub why3session: bool,
+    /// Maximum number of solver processes running at the same time.
+    #[clap(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_parallel_solvers: Option<u32>,
     /// Files to prove; default to everything in `verif/`.
     pub files: Vec<PathBuf>,
 }
@@ -56,7 +64,52 @@ fn check_why3find_json_exists(root: &PathBuf) -> Result<()> {
     }
 }
 
-fn raw_prove(args: ProveArgs, paths: &Paths) -> Result<()> {
+/// The attribute of the modules given a solver resource limit by `#[creusot::solver(rlimit = N)]`,
+/// `#[creusot::backend(rlimit = N)]` or `--rlimit`, see `RLIMIT_ATTR` in `creusot`.
+const RLIMIT_ATTR: &str = "[@creusot:rlimit:";
+
+/// The modules of `coma_files` with a solver resource limit, with this limit.
+fn module_rlimits(coma_files: &[PathBuf]) -> Result<Vec<(String, String)>> {
+    let mut rlimits = Vec::new();
+    for coma in coma_files {
+        let contents = std::fs::read_to_string(coma)?;
+        for line in contents.lines() {
+            let Some(decl) = line.strip_prefix("module ") else { continue };
+            let Some((_, rlimit)) = decl.split_once(RLIMIT_ATTR) else { continue };
+            let Some((rlimit, _)) = rlimit.split_once(']') else { continue };
+            let name = decl.split_whitespace().next().unwrap_or_default();
+            rlimits.push((name.to_string(), rlimit.to_string()));
+        }
+    }
+    Ok(rlimits)
+}
+
+/// `why3find` gives the same time limit to every goal: warn that the resource limits of the
+/// modules are not applied, rather than dropping them silently.
+fn warn_rlimits(files: &[PathBuf]) -> Result<()> {
+    let mut coma_files = Vec::new();
+    for file in files {
+        find_coma_files(file, &mut coma_files)?;
+    }
+    let rlimits = module_rlimits(&coma_files)?;
+    if rlimits.is_empty() {
+        return Ok(());
+    }
+    if std::io::stderr().is_terminal() {
+        eprint!("\x1b[33mWarning\x1b[0m");
+    } else {
+        eprint!("Warning");
+    }
+    eprintln!(": why3find does not apply the solver resource limits of these modules:");
+    for (name, rlimit) in rlimits {
+        eprintln!("  {name} (rlimit = {rlimit})");
+    }
+    eprintln!("They are only applied when Creusot runs `why3 prove` itself");
+    Ok(())
+}
+
+pub(crate) fn raw_prove(args: ProveArgs, paths: &Paths) -> Result<()> {
+    warn_rlimits(&args.files)?;
     let mut why3find = Command::new(&paths.why3find);
     why3find.arg("prove");
     if args.ide.ide_on_fail {
@@ -69,6 +122,10 @@ fn raw_prove(args: ProveArgs, paths: &Paths) -> Result<()> {
     if args.why3session || args.ide.ide_always {
         why3find.arg("-s");
     }
+    // Goals are queued by why3find and dispatched to at most this many solvers at once.
+    if let Some(jobs) = args.max_parallel_solvers {
+        why3find.arg("-j").arg(jobs.to_string());
+    }
     why3find.args(&args.files);
     if let Some(why3_path) = paths.why3.parent() {
         let mut path = why3_path.to_path_buf().into_os_string();
@@ -102,6 +159,12 @@ pub fn why3find_prove(mut args: ProveArgs, root: &PathBuf) -> Result<()> {
     if args.files.is_empty() {
         args.files.push(root.join(OUTPUT_PREFIX));
     }
+    // Report results in a stable order, independently of the order of the command line.
+    args.files.sort();
+    args.files.dedup();
+    if args.check_cache {
+        return check_cache(args, &paths);
+    }
     // If the proof fails, we still want to run the IDE if `--ide-always` was set.
     let prove_result = raw_prove(args, &paths);
     if let Some(coma) = coma {
@@ -1,6 +1,7 @@
 use clap::*;
 use serde::{Deserialize, Serialize};
 use std::{error::Error, ffi::OsString, path::PathBuf};
+pub use why3::printer::Why3Version;
 
 #[derive(Debug, Parser, Serialize, Deserialize)]
 pub struct CommonOptions {
@@ -43,6 +44,126 @@ pub struct CommonOptions {
     /// Use `result` as the trigger of definition and specification axioms of logic/ghost/predicate functions
     #[clap(long, default_value_t = false, action = clap::ArgAction::Set)]
     pub simple_triggers: bool,
+    /// Print the location of every `assume!` and of every trusted `#[variant]` of an extern spec
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub report_assumptions: bool,
+    /// Consider that the given crate belongs to a closed set of crates (typically, a workspace
+    /// with no outside dependents): no crate but the ones already known can add implementations to
+    /// its traits. This lets Creusot resolve more trait calls, but is unsound if the assumption
+    /// does not hold. Can be passed several times.
+    #[clap(long = "closed-crate", value_name = "CRATE")]
+    pub closed_crates: Vec<String>,
+    /// Only verify the items defined in files changed since the given git revision, and the items
+    /// depending on them. Everything is verified if the changed files cannot be obtained from git.
+    #[clap(long = "verify-only-changed", visible_alias = "since", value_name = "REF")]
+    pub since: Option<String>,
+    /// Fail if verified code relies on an implementation from another crate whose refinement of
+    /// its trait specification was never checked.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub deny_trusted_external_impls: bool,
+    /// Fail if an item is trusted without being listed in the given file, which holds one item
+    /// path per line (`#` starts a comment)
+    #[clap(long, value_name = "FILE")]
+    pub trusted_allowlist: Option<PathBuf>,
+    /// List the proof obligations that would be sent to Why3, with their stable id, kind and
+    /// location, instead of writing them out.
+    #[clap(long, value_enum, value_name = "FORMAT", num_args = 0..=1, default_missing_value = "table")]
+    pub list_obligations: Option<ObligationsFormat>,
+    /// Reject contracts and refinement obligations whose quantifiers are nested deeper than the
+    /// given depth, which solvers are unlikely to handle.
+    #[clap(long, value_name = "DEPTH")]
+    pub max_quantifier_depth: Option<usize>,
+    /// Simplify the contracts and refinement obligations with the given pass before generating
+    /// Why3 code. Can be passed several times.
+    #[clap(long, value_enum, value_name = "PASS")]
+    pub simplify: Vec<SimplifyPass>,
+    /// Write the refinement obligations of all the trait impls of the crate to the given file, in
+    /// a deterministic format suitable for golden tests.
+    #[clap(long, value_name = "FILE")]
+    pub dump_refinements_all: Option<PathBuf>,
+    /// Write the graph of the refinements and laws of the trait impls of the crate to a file, in
+    /// the DOT format.
+    #[clap(long, value_name = "FILE")]
+    pub emit_refinement_graph: Option<PathBuf>,
+    /// Write the bounds assumed by the refinement obligation of each impl item of the crate to a
+    /// file, to find which ones are missing when a refinement fails.
+    #[clap(long, value_name = "FILE")]
+    pub dump_param_env: Option<PathBuf>,
+    /// Write the Coma module of each proof obligation, as given to Why3, to its own file in this
+    /// directory, named by the id of the obligation (see `--list-obligations`), to run the
+    /// solvers on one obligation by hand.
+    #[clap(long, value_name = "DIR")]
+    pub solver_log: Option<PathBuf>,
+    /// Default resource limit of the solvers for each proof obligation, which
+    /// `#[creusot::solver(rlimit = N)]` overrides for an item or an impl. It is applied by `why3
+    /// prove`; `cargo creusot prove` warns that `why3find` does not apply it.
+    #[clap(long, value_name = "N")]
+    pub rlimit: Option<u64>,
+    /// Print the time spent translating and proving each item, slowest first
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub stats: bool,
+    /// Only check that trait impls refine the specifications of their traits, without verifying
+    /// function bodies, and report the result for each impl item.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub only_refinements: bool,
+    /// Only elaborate and check the contracts and logic functions, without translating function
+    /// bodies or running Why3, to report the errors in specifications quickly.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub check_specs_only: bool,
+    /// Write an HTML page listing the proof obligations of each item with their status and
+    /// location, from the results of the run.
+    #[clap(long, value_name = "FILE")]
+    pub html_report: Option<PathBuf>,
+    /// Print, for each verified function, whether it cannot panic or the calls through which it
+    /// may panic. The report is an unverified over-approximation, computed from the contracts of
+    /// the called functions: the `#[panics_when]` conditions it shows are not checked.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub panic_report: bool,
+    /// Compare the obligations proved by the run with those of the given baseline file, and fail
+    /// unless every failed obligation is a known failure of the baseline.
+    #[clap(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+    /// With `--baseline`, write the obligations proved and failed by the run to the baseline file
+    /// instead of failing on regressions.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue, requires = "baseline")]
+    pub update_baseline: bool,
+    /// Check that each generated Coma module survives a round trip through the serialization of
+    /// its syntax tree, by comparing the Coma printed before and after. This catches the parts of
+    /// the syntax tree that are lost or altered when it is serialized.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub check_coma_round_trip: bool,
+    /// Stop at the first failed obligation: the first refinement or translation error, or the
+    /// first goal that Why3 does not prove.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue, overrides_with = "keep_going")]
+    pub fail_fast: bool,
+    /// Keep checking after a failed obligation, and report all of them (the default).
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue, overrides_with = "fail_fast")]
+    pub keep_going: bool,
+    /// Check that the precondition of each function is satisfiable, by proving that some arguments
+    /// satisfy it: a contradictory precondition makes the function verify vacuously. This runs the
+    /// solvers on an additional goal per function with a precondition.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub check_precondition_satisfiability: bool,
+    /// The release of Why3 that the generated code is written for. Older releases do not accept
+    /// named spans (`let%span`) or the `[@rewrite]` attribute of axioms, which are then written
+    /// in an equivalent form. Either `1.7` or `1.8`.
+    #[clap(long, value_name = "VERSION", default_value = "1.8")]
+    pub why3_version: Why3Version,
+    /// Reuse the results of the obligations already proved, recorded in this file, which is
+    /// created if needed. An obligation is identified by the contents of its verification
+    /// condition rather than by its item, so that identical obligations are only proved once.
+    #[clap(long, value_name = "FILE")]
+    pub vc_cache: Option<PathBuf>,
+    /// After proving the crate, list the proved obligations which may rely on trusted facts:
+    /// trusted laws, extern specs, or impls from other crates. The facts listed are those
+    /// available to the proof, since the solvers do not report which ones they use.
+    #[clap(long, default_value_t = false, action = clap::ArgAction::SetTrue)]
+    pub report_trust_dependencies: bool,
+    /// With `why3 prove`, run at most this many solver processes at the same time. The modules
+    /// proved one at a time (with `--only-refinements` or `--vc-cache`) are queued, and their
+    /// results are reported in the order of the modules, whatever the scheduling.
+    #[clap(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_parallel_solvers: Option<u32>,
 }
 
 #[derive(Debug, Parser, Serialize, Deserialize)]
@@ -100,6 +221,20 @@ impl CreusotArgs {
     }
 }
 
+#[derive(Debug, clap::ValueEnum, Clone, Copy, Deserialize, Serialize)]
+pub enum SimplifyPass {
+    /// Evaluate arithmetic and comparisons on literals
+    ConstFold,
+    /// Remove `true` and `false` from conjunctions, disjunctions, implications and quantifiers
+    TrivialLogic,
+}
+
+#[derive(Debug, clap::ValueEnum, Clone, Copy, Deserialize, Serialize)]
+pub enum ObligationsFormat {
+    Table,
+    Json,
+}
+
 #[derive(Debug, clap::ValueEnum, Clone, Deserialize, Serialize)]
 pub enum SpanMode {
     Relative,
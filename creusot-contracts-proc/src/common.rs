@@ -27,6 +27,36 @@ impl<'a> FilterAttrs<'a> for &'a [Attribute] {
     }
 }
 
+/// Attributes guarded by a `#[cfg_attr(predicate, attr1, attr2, ...)]`.
+///
+/// Contract clauses may be written under a `cfg_attr`, e.g.
+/// `#[cfg_attr(feature = "strict", ensures(...))]`: rustc expands it to the clause itself when
+/// the predicate holds, but the macros which look at the attributes of an item before this
+/// expansion must see through it.
+pub struct CfgAttr {
+    pub predicate: Meta,
+    pub attrs: Vec<Meta>,
+}
+
+impl CfgAttr {
+    pub fn parse(attr: &Attribute) -> Option<Self> {
+        if !attr.path().is_ident("cfg_attr") {
+            return None;
+        }
+        let metas =
+            attr.parse_args_with(punctuated::Punctuated::<Meta, Token![,]>::parse_terminated);
+        let mut metas = metas.ok()?.into_iter();
+        let predicate = metas.next()?;
+        Some(CfgAttr { predicate, attrs: metas.collect() })
+    }
+
+    /// Write back the (possibly modified) guarded attributes into `attr`.
+    pub fn store(self, attr: &mut Attribute) {
+        let CfgAttr { predicate, attrs } = self;
+        attr.meta = parse_quote! { cfg_attr(#predicate, #(#attrs),*) };
+    }
+}
+
 pub struct TraitItemSignature {
     pub attrs: Vec<Attribute>,
     pub defaultness: Option<Token![default]>,
@@ -112,6 +142,24 @@ impl Parse for ContractSubject {
     }
 }
 
+/// A `const` or `static` item, which may carry an `#[ensures]` on its value.
+pub struct ConstOrStatic(pub Item);
+
+impl Parse for ConstOrStatic {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        match input.parse()? {
+            item @ (Item::Const(_) | Item::Static(_)) => Ok(ConstOrStatic(item)),
+            item => Err(Error::new_spanned(item, "expected a `const` or `static` item")),
+        }
+    }
+}
+
+impl ToTokens for ConstOrStatic {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
 impl ToTokens for ContractSubject {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
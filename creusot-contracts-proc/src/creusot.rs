@@ -11,6 +11,7 @@ use syn::{
 
 mod derive;
 pub(crate) mod doc;
+mod effects;
 mod extern_spec;
 mod invariant;
 mod maintains;
@@ -88,7 +89,18 @@ fn sig_spec_item(tag: Ident, mut sig: Signature, p: Term) -> TokenStream {
     let req_body = req_body(&p);
     let attrs = spec_attrs(&tag);
     sig.ident = tag;
+    // The spec item is a default method of the trait, it must be a plain function returning `bool`
+    // whatever the qualifiers of the signature it is attached to.
+    sig.asyncness = None;
     sig.output = parse_quote! { -> bool };
+    // Unlike a declaration, a method with a body cannot take an unsized `Self` by value: the spec
+    // item is only meaningful for sized implementors anyway.
+    if takes_self_by_value(&sig) {
+        sig.generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::std::marker::Sized });
+    }
 
     quote! {
         #attrs
@@ -96,8 +108,29 @@ fn sig_spec_item(tag: Ident, mut sig: Signature, p: Term) -> TokenStream {
     }
 }
 
+fn takes_self_by_value(sig: &Signature) -> bool {
+    sig.inputs.iter().any(|arg| match arg {
+        FnArg::Receiver(r) => {
+            r.reference.is_none() && matches!(&*r.ty, Type::Path(p) if p.path.is_ident("Self"))
+        }
+        FnArg::Typed(p) => {
+            matches!(&*p.ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self"))
+        }
+    })
+}
+
 pub fn requires(attr: TS1, tokens: TS1) -> TS1 {
-    let documentation = document_spec("requires", doc::LogicBody::Some(attr.clone()));
+    precondition_clause("requires", attr, tokens)
+}
+
+pub fn panics_when(attr: TS1, tokens: TS1) -> TS1 {
+    precondition_clause("panics_when", attr, tokens)
+}
+
+/// Attach a clause over the arguments of a function, in the same way as `requires`.
+fn precondition_clause(clause: &str, attr: TS1, tokens: TS1) -> TS1 {
+    let documentation = document_spec(clause, doc::LogicBody::Some(attr.clone()));
+    let clause = Ident::new(clause, Span::call_site());
 
     let mut item = parse_macro_input!(tokens as ContractSubject);
     let term = parse_macro_input!(attr as Term);
@@ -113,7 +146,7 @@ pub fn requires(attr: TS1, tokens: TS1) -> TS1 {
             let requires_tokens = sig_spec_item(req_name, fn_or_meth.sig.clone(), term);
             TS1::from(quote! {
               #requires_tokens
-              #[creusot::clause::requires=#name_tag]
+              #[creusot::clause::#clause=#name_tag]
               #(#attrs)*
               #documentation
               #fn_or_meth
@@ -127,7 +160,7 @@ pub fn requires(attr: TS1, tokens: TS1) -> TS1 {
                 b.stmts.insert(0, Stmt::Item(Item::Verbatim(requires_tokens)))
             }
             TS1::from(quote! {
-              #[creusot::clause::requires=#name_tag]
+              #[creusot::clause::#clause=#name_tag]
               #(#attrs)*
               #documentation
               #f
@@ -138,7 +171,7 @@ pub fn requires(attr: TS1, tokens: TS1) -> TS1 {
             let body = &clos.body;
             *clos.body = parse_quote!({let res = #body; #requires_tokens res});
             TS1::from(quote! {
-              #[creusot::clause::requires=#name_tag]
+              #[creusot::clause::#clause=#name_tag]
               #clos
             })
         }
@@ -148,6 +181,11 @@ pub fn requires(attr: TS1, tokens: TS1) -> TS1 {
 pub fn ensures(attr: TS1, tokens: TS1) -> TS1 {
     let documentation = document_spec("ensures", doc::LogicBody::Some(attr.clone()));
 
+    if let Ok(item) = syn::parse::<ConstOrStatic>(tokens.clone()) {
+        let term = parse_macro_input!(attr as Term);
+        return TS1::from(const_ensures(item, term, documentation));
+    }
+
     let mut item = parse_macro_input!(tokens as ContractSubject);
     let term = parse_macro_input!(attr as Term);
     item.mark_unused();
@@ -213,6 +251,92 @@ pub fn ensures(attr: TS1, tokens: TS1) -> TS1 {
     }
 }
 
+/// `#[impls_ensure]` on a trait method: a postcondition that every implementation of the method
+/// must satisfy, but which is not part of the contract of the trait method itself.
+pub fn impls_ensure(attr: TS1, tokens: TS1) -> TS1 {
+    let documentation = document_spec("impls_ensure", doc::LogicBody::Some(attr.clone()));
+
+    let mut item = parse_macro_input!(tokens as ContractSubject);
+    let term = parse_macro_input!(attr as Term);
+    item.mark_unused();
+
+    let ens_name = generate_unique_ident(&item.name());
+    let name_tag = format!("{}", quote! { #ens_name });
+
+    match item {
+        ContractSubject::FnOrMethod(mut s) if s.is_trait_signature() => {
+            let attrs = std::mem::take(&mut s.attrs);
+            let result = match s.sig.output {
+                ReturnType::Default => parse_quote! { result : () },
+                ReturnType::Type(_, ref ty) => parse_quote! { result : #ty },
+            };
+
+            let mut sig = s.sig.clone();
+            sig.inputs.push(result);
+            let ensures_tokens = sig_spec_item(ens_name, sig, term);
+            TS1::from(quote! {
+              #ensures_tokens
+              #[creusot::clause::impls_ensure=#name_tag]
+              #(#attrs)*
+              #documentation
+              #s
+            })
+        }
+        _ => Error::new(
+            Span::call_site(),
+            "`impls_ensure` can only be used on a trait method without a default implementation",
+        )
+        .into_compile_error()
+        .into(),
+    }
+}
+
+/// `#[ensures]` on a `const` or `static` item, including the associated constants of impls.
+///
+/// The postcondition is checked by a hidden function returning the initializer of the item, so
+/// that `result` denotes its value. This function is nested in the initializer, where it can be
+/// declared whether the item belongs to a module or to an impl: as a consequence, the initializer
+/// cannot refer to `Self` or to the generic parameters of an impl. The other `#[ensures]` of the
+/// item are moved to the function, which gives the obligation a stable name.
+fn const_ensures(item: ConstOrStatic, term: Term, documentation: TokenStream) -> TokenStream {
+    let mut item = item.0;
+    let (attrs, ident, ty, expr) = match &mut item {
+        Item::Const(c) => (&mut c.attrs, c.ident.clone(), (*c.ty).clone(), &mut c.expr),
+        Item::Static(s) => {
+            if let StaticMutability::Mut(m) = s.mutability {
+                return Error::new(m.span, "`ensures` cannot be used on a `static mut`")
+                    .into_compile_error();
+            }
+            (&mut s.attrs, s.ident.clone(), (*s.ty).clone(), &mut s.expr)
+        }
+        _ => unreachable!(),
+    };
+    let (spec_attrs, other_attrs): (Vec<_>, Vec<_>) = std::mem::take(attrs)
+        .into_iter()
+        .partition(|attr| attr.path().segments.last().map_or(false, |s| s.ident == "ensures"));
+
+    let ens_name = generate_unique_ident(&ident.to_string());
+    let name_tag = format!("{}", quote! { #ens_name });
+    let ensures_tokens = fn_spec_item(ens_name, Some(parse_quote! { result: #ty }), term);
+    let fn_name = Ident::new(&format!("{}_ensures", ident), ident.span());
+    let value = expr.clone();
+    **expr = parse_quote! {{
+        #(#spec_attrs)*
+        #[creusot::clause::ensures=#name_tag]
+        #[allow(non_snake_case, dead_code)]
+        fn #fn_name() -> #ty {
+            #ensures_tokens
+            #value
+        }
+        #value
+    }};
+    *attrs = other_attrs;
+    quote! {
+        #documentation
+        #item
+    }
+}
+
 pub fn variant(attr: TS1, tokens: TS1) -> TS1 {
     invariant::desugar_variant(attr.into(), tokens.into())
         .unwrap_or_else(|e| e.to_compile_error())
@@ -232,6 +356,20 @@ impl Parse for Assertion {
 }
 
 pub fn proof_assert(assertion: TS1) -> TS1 {
+    spec_assertion(assertion, quote! {})
+}
+
+pub fn unsafe_requires(assertion: TS1) -> TS1 {
+    spec_assertion(assertion, quote! { #[creusot::spec::unsafe_requires] })
+}
+
+pub fn assume(assertion: TS1) -> TS1 {
+    spec_assertion(assertion, quote! { #[creusot::spec::assume] })
+}
+
+/// The expansion of `proof_assert!` and its variants, which are told apart by the attributes
+/// `attrs` of the closure.
+fn spec_assertion(assertion: TS1, attrs: TokenStream) -> TS1 {
     let assert = parse_macro_input!(assertion as Assertion);
     let assert_body = pretyping::encode_block(&assert.0).unwrap_or_else(|e| e.into_tokens());
 
@@ -242,6 +380,7 @@ pub fn proof_assert(assertion: TS1) -> TS1 {
                 #[creusot::no_translate]
                 #[creusot::spec]
                 #[creusot::spec::assert]
+                #attrs
                 #[allow(unused_braces)]
                 || -> bool #assert_body
             };
@@ -249,6 +388,48 @@ pub fn proof_assert(assertion: TS1) -> TS1 {
     })
 }
 
+/// The arguments of `use_lemma!`: the path of a lemma, followed by the terms it is applied to.
+struct LemmaUse {
+    lemma: Path,
+    args: Vec<Term>,
+}
+
+impl Parse for LemmaUse {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let lemma = input.parse()?;
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            let _: Token![,] = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            args.push(input.parse()?);
+        }
+        Ok(LemmaUse { lemma, args })
+    }
+}
+
+pub fn use_lemma(tokens: TS1) -> TS1 {
+    let LemmaUse { lemma, args } = parse_macro_input!(tokens as LemmaUse);
+    let span = lemma.span();
+    // This equality is trivial, but once asserted it keeps the application of the lemma in the
+    // context of the following goals, where it instantiates the specification of the lemma.
+    let application: Term = parse_quote_spanned! {span=> #lemma(#(#args),*) == () };
+    let use_body = pretyping::encode_term(&application).unwrap_or_else(|e| e.into_tokens());
+
+    TS1::from(quote_spanned! {span=>
+        {
+            #[allow(let_underscore_drop)]
+            let _ = {
+                #[creusot::no_translate]
+                #[creusot::spec]
+                #[creusot::spec::assert]
+                || -> bool { #use_body }
+            };
+        }
+    })
+}
+
 pub fn snapshot(assertion: TS1) -> TS1 {
     let assert = parse_macro_input!(assertion as Assertion);
     let assert_body = pretyping::encode_block(&assert.0).unwrap_or_else(|e| e.into_tokens());
@@ -299,8 +480,24 @@ pub fn terminates(_: TS1, tokens: TS1) -> TS1 {
     .into()
 }
 
-pub fn pure(_: TS1, tokens: TS1) -> TS1 {
-    let documentation = document_spec("pure", doc::LogicBody::None);
+pub fn pure(kind: TS1, tokens: TS1) -> TS1 {
+    let is_logic = if kind.is_empty() {
+        false
+    } else {
+        let t = parse_macro_input!(kind as Ident);
+        if t == "logic" {
+            true
+        } else {
+            return syn::Error::new(
+                t.span(),
+                "unsupported modifier. The only supported modifier at the moment is `logic`",
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+    let documentation =
+        document_spec(if is_logic { "pure(logic)" } else { "pure" }, doc::LogicBody::None);
     let item = tokens.clone();
     let item = parse_macro_input!(item as ContractSubject);
     let is_closure = match item {
@@ -316,12 +513,23 @@ pub fn pure(_: TS1, tokens: TS1) -> TS1 {
                 false
             }
         }
+        ContractSubject::Closure(clos) if is_logic => {
+            return syn::Error::new(clos.span(), "closures cannot be `pure(logic)`")
+                .into_compile_error()
+                .into();
+        }
         ContractSubject::Closure(_) => true,
     };
     let Attributes { attrs, rest } = syn::parse(tokens).unwrap();
+    let logic_attrs = if is_logic {
+        quote! { #[creusot::decl::pure_logic] }
+    } else {
+        quote! {}
+    };
     let mut result = quote! {
         #[creusot::clause::no_panic]
         #[creusot::clause::terminates]
+        #logic_attrs
         #(#attrs)*
         #documentation
         #rest
@@ -500,6 +708,14 @@ pub fn law(_: TS1, tokens: TS1) -> TS1 {
     })
 }
 
+pub fn forall_impls(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::forall_impls]
+        #tokens
+    })
+}
+
 pub fn predicate(prophetic: TS1, tokens: TS1) -> TS1 {
     let prophetic = if prophetic.is_empty() {
         None
@@ -625,6 +841,28 @@ pub fn extern_spec(tokens: TS1) -> TS1 {
     })
 }
 
+/// The arguments of `#[ensures_if(cond, post)]`.
+struct EnsuresIf {
+    cond: Term,
+    _comma: Token![,],
+    post: Term,
+}
+
+impl Parse for EnsuresIf {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        Ok(EnsuresIf { cond: input.parse()?, _comma: input.parse()?, post: input.parse()? })
+    }
+}
+
+pub fn ensures_if(attr: TS1, tokens: TS1) -> TS1 {
+    let EnsuresIf { cond, post, .. } = parse_macro_input!(attr as EnsuresIf);
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[::creusot_contracts::ensures((#cond) ==> (#post))]
+        #tokens
+    })
+}
+
 pub fn maintains(attr: TS1, body: TS1) -> TS1 {
     let tokens = maintains::maintains_impl(attr, body);
 
@@ -634,6 +872,16 @@ pub fn maintains(attr: TS1, body: TS1) -> TS1 {
     }
 }
 
+pub fn reads(attr: TS1, body: TS1) -> TS1 {
+    effects::effects_impl(effects::Effect::Reads, attr, body)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+pub fn writes(attr: TS1, body: TS1) -> TS1 {
+    effects::effects_impl(effects::Effect::Writes, attr, body)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
 pub fn invariant(invariant: TS1, tokens: TS1) -> TS1 {
     invariant::desugar_invariant(invariant.into(), tokens.into())
         .unwrap_or_else(|e| e.to_compile_error())
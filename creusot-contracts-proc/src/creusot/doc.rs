@@ -19,8 +19,8 @@ pub(crate) enum LogicBody {
 pub(crate) fn document_spec(spec_name: &str, spec_body: LogicBody) -> TokenStream {
     let spec_color = match spec_name {
         "requires" => "Tomato",
-        "ensures" => "DodgerBlue",
-        "terminates" | "pure" | "logic" | "logic(prophetic)" | "law" => "Violet",
+        "ensures" | "impls_ensure" => "DodgerBlue",
+        "terminates" | "pure" | "pure(logic)" | "logic" | "logic(prophetic)" | "law" => "Violet",
         _ => "LightGray",
     };
     let styled_spec_name = format!(
@@ -0,0 +1,85 @@
+// Implementation of the `reads` and `writes` macros.
+
+use crate::common::ContractSubject;
+use proc_macro::TokenStream as TS1;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parser, Result},
+    punctuated::Punctuated,
+    token::Comma,
+    *,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    /// The listed mutable borrows are left unchanged.
+    Reads,
+    /// The mutable borrows which are not listed are left unchanged.
+    Writes,
+}
+
+impl Effect {
+    fn name(self) -> &'static str {
+        match self {
+            Effect::Reads => "reads",
+            Effect::Writes => "writes",
+        }
+    }
+}
+
+/// The parameters of `sig` which are mutable borrows, including the receiver.
+fn mutable_params(sig: &Signature) -> Vec<Ident> {
+    sig.inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Receiver(recv) => match &*recv.ty {
+                Type::Reference(TypeReference { mutability: Some(_), .. }) => {
+                    Some(Ident::new("self", recv.self_token.span))
+                }
+                _ => None,
+            },
+            FnArg::Typed(PatType { pat, ty, .. }) => match (&**pat, &**ty) {
+                (
+                    Pat::Ident(PatIdent { ident, .. }),
+                    Type::Reference(TypeReference { mutability: Some(_), .. }),
+                ) => Some(ident.clone()),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+/// Desugar `#[writes(a, b)]` (resp. `#[reads(a, b)]`) into `#[ensures(^c == *c)]` for every
+/// other mutable borrow `c` of the signature (resp. for `a` and `b`).
+pub fn effects_impl(effect: Effect, attr: TS1, body: TS1) -> Result<TS1> {
+    let listed = Punctuated::<Ident, Comma>::parse_terminated_with(Ident::parse_any).parse(attr)?;
+    let subject: ContractSubject = parse(body.clone())?;
+    let ContractSubject::FnOrMethod(f) = subject else {
+        return Err(Error::new(
+            proc_macro2::Span::call_site(),
+            format!("`{}` can only be used on functions", effect.name()),
+        ));
+    };
+
+    let params = mutable_params(&f.sig);
+    for name in &listed {
+        if !params.contains(name) {
+            return Err(Error::new(
+                name.span(),
+                format!("`{name}` is not a parameter of this function of type `&mut _`"),
+            ));
+        }
+    }
+
+    let unchanged =
+        params.iter().filter(|p| listed.iter().any(|l| l == *p) == (effect == Effect::Reads));
+    let frames = unchanged.map(|p| quote! { #[::creusot_contracts::ensures(^#p == *#p)] });
+    let body = TokenStream::from(body);
+    Ok(quote! {
+        #(#frames)*
+        #body
+    }
+    .into())
+}
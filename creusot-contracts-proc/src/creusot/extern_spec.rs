@@ -1,4 +1,7 @@
-use crate::creusot::{doc::DocItemName, generate_unique_ident};
+use crate::{
+    common::CfgAttr,
+    creusot::{doc::DocItemName, generate_unique_ident},
+};
 use pearlite_syn::term::*;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote, quote_spanned};
@@ -284,14 +287,26 @@ impl syn::visit_mut::VisitMut for SelfEscape {
 
 fn escape_self_in_contracts(attrs: &mut Vec<Attribute>) -> Result<()> {
     for attr in attrs {
-        if let Some(id) = attr.path().get_ident() {
-            if id == "ensures" || id == "requires" {
-                if let Meta::List(l) = &mut attr.meta {
-                    let tokens = std::mem::take(&mut l.tokens);
-                    let mut term: Term = syn::parse2(tokens)?;
-                    escape_self_in_term(&mut term);
-                    l.tokens = term.into_token_stream();
-                }
+        if let Some(mut cfg_attr) = CfgAttr::parse(attr) {
+            for meta in &mut cfg_attr.attrs {
+                escape_self_in_contract(meta)?;
+            }
+            cfg_attr.store(attr);
+        } else {
+            escape_self_in_contract(&mut attr.meta)?;
+        }
+    }
+    Ok(())
+}
+
+fn escape_self_in_contract(meta: &mut Meta) -> Result<()> {
+    if let Some(id) = meta.path().get_ident() {
+        if id == "ensures" || id == "requires" || id == "variant" {
+            if let Meta::List(l) = meta {
+                let tokens = std::mem::take(&mut l.tokens);
+                let mut term: Term = syn::parse2(tokens)?;
+                escape_self_in_term(&mut term);
+                l.tokens = term.into_token_stream();
             }
         }
     }
@@ -2,8 +2,15 @@ use crate::creusot::pretyping;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote, quote_spanned};
 use syn::{
-    AttrStyle, Attribute, Block, Error, Expr, ExprForLoop, ExprLoop, ExprWhile, Ident, ItemFn,
-    Meta, Result, Stmt, Token, parse_quote_spanned, spanned::Spanned, token::Brace,
+    AttrStyle, Attribute, BinOp, Block, Error, Expr, ExprClosure, ExprContinue, ExprForLoop,
+    ExprLit, ExprLoop, ExprRange, ExprWhile, Ident, Item, ItemFn, Lifetime, Lit, LitInt, Meta,
+    RangeLimits, Result, Stmt, Token,
+    parse::{ParseStream, Parser},
+    parse_quote_spanned,
+    spanned::Spanned,
+    token::Brace,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -17,6 +24,8 @@ use InvariantKind::*;
 enum Tag {
     Invariant(InvariantKind),
     Variant,
+    /// A variant inferred from the shape of the loop, only checked when the loop must terminate.
+    InferredVariant,
 }
 
 // Represents both invariants and variants
@@ -43,10 +52,15 @@ impl ToTokens for Invariant {
                   ||{ #term }
                 }
             }
-            Tag::Variant => quote_spanned! {span=>
-              #[creusot::spec::variant::loop_]
-              ||{ ::creusot_contracts::__stubs::variant_check(#term) }
-            },
+            Tag::Variant | Tag::InferredVariant => {
+                let inferred = matches!(self.tag, Tag::InferredVariant)
+                    .then(|| quote_spanned! {span=> #[creusot::spec::variant::inferred] });
+                quote_spanned! {span=>
+                  #[creusot::spec::variant::loop_]
+                  #inferred
+                  ||{ ::creusot_contracts::__stubs::variant_check(#term) }
+                }
+            }
         };
         tokens.extend(quote_spanned! {span=>
             #[allow(let_underscore_drop)]
@@ -64,11 +78,25 @@ pub fn desugar_invariant(invariant0: TokenStream, expr: TokenStream) -> Result<T
 
 fn desugar(tag: Tag, invariant0: TokenStream, expr: TokenStream) -> Result<TokenStream> {
     let expr: Expr = syn::parse2(expr)?;
-    let invariants = |attrs| filter_invariants(tag, invariant0, attrs);
+    let invariants = |label: Option<&Lifetime>, body: &mut Block, attrs: &mut Vec<Attribute>| {
+        if let Some(label) = label {
+            hoist_labeled_invariants(label, body, attrs);
+        }
+        filter_invariants(tag, invariant0, label, attrs)
+    };
     match expr {
-        Expr::ForLoop(mut expr) => Ok(desugar_for(invariants(&mut expr.attrs)?, expr)),
-        Expr::While(mut expr) => Ok(desugar_while(invariants(&mut expr.attrs)?, expr)),
-        Expr::Loop(mut expr) => Ok(desugar_loop(invariants(&mut expr.attrs)?, expr)),
+        Expr::ForLoop(mut expr) => {
+            let label = expr.label.as_ref().map(|l| &l.name);
+            Ok(desugar_for(invariants(label, &mut expr.body, &mut expr.attrs)?, expr))
+        }
+        Expr::While(mut expr) => {
+            let label = expr.label.as_ref().map(|l| &l.name);
+            Ok(desugar_while(invariants(label, &mut expr.body, &mut expr.attrs)?, expr))
+        }
+        Expr::Loop(mut expr) => {
+            let label = expr.label.as_ref().map(|l| &l.name);
+            Ok(desugar_loop(invariants(label, &mut expr.body, &mut expr.attrs)?, expr))
+        }
         _ => {
             return Err(Error::new_spanned(
                 expr,
@@ -78,13 +106,78 @@ fn desugar(tag: Tag, invariant0: TokenStream, expr: TokenStream) -> Result<Token
     }
 }
 
+/// Split the label of an invariant `'label: P` of an enclosing loop.
+fn split_label(tokens: TokenStream) -> Result<(Option<Lifetime>, TokenStream)> {
+    let parser = |input: ParseStream| {
+        let mut label = None;
+        if input.peek(Lifetime) && input.peek2(Token![:]) {
+            label = Some(input.parse()?);
+            input.parse::<Token![:]>()?;
+        }
+        Ok((label, input.parse()?))
+    };
+    parser.parse2(tokens)
+}
+
+/// Move the invariants `#[invariant('label: P)]` written on the loops nested in `body` to
+/// `attrs`, the attributes of the loop labeled `'label`.
+fn hoist_labeled_invariants(label: &Lifetime, body: &mut Block, attrs: &mut Vec<Attribute>) {
+    struct Hoist<'a> {
+        label: &'a Lifetime,
+        hoisted: &'a mut Vec<Attribute>,
+    }
+
+    impl VisitMut for Hoist<'_> {
+        fn visit_expr_mut(&mut self, expr: &mut Expr) {
+            let attrs = match expr {
+                Expr::ForLoop(l) => Some(&mut l.attrs),
+                Expr::While(l) => Some(&mut l.attrs),
+                Expr::Loop(l) => Some(&mut l.attrs),
+                _ => None,
+            };
+            if let Some(attrs) = attrs {
+                let label = self.label;
+                self.hoisted.extend(attrs.extract_if(0.., |attr| match &attr.meta {
+                    Meta::List(l) if attr.path().is_ident("invariant") => {
+                        matches!(split_label(l.tokens.clone()), Ok((Some(l), _)) if l == *label)
+                    }
+                    _ => false,
+                }));
+            }
+            visit_mut::visit_expr_mut(self, expr)
+        }
+
+        // Labels are not visible in closures and nested items.
+        fn visit_expr_closure_mut(&mut self, _: &mut ExprClosure) {}
+        fn visit_item_mut(&mut self, _: &mut Item) {}
+    }
+
+    Hoist { label, hoisted: attrs }.visit_block_mut(body)
+}
+
 // Set the expl before pushing the invariant into the vector
 fn parse_push_invariant(
     invariants: &mut Vec<Invariant>,
     tag: Tag,
+    loop_label: Option<&Lifetime>,
     term: TokenStream,
 ) -> Result<()> {
     let span = term.span();
+    let (label, term) = match tag {
+        Tag::Invariant(_) => split_label(term)?,
+        Tag::Variant | Tag::InferredVariant => (None, term),
+    };
+    match label {
+        Some(label) if Some(&label) != loop_label => {
+            return Err(Error::new(
+                label.span(),
+                format!(
+                    "`{label}` is not the label of this loop, or of an enclosing loop with invariants"
+                ),
+            ));
+        }
+        _ => (),
+    }
     let term = syn::parse2(term)?;
     invariants.push(Invariant { tag, span, term });
     Ok(())
@@ -93,11 +186,12 @@ fn parse_push_invariant(
 fn filter_invariants(
     tag: Tag,
     invariant: TokenStream,
+    label: Option<&Lifetime>,
     attrs: &mut Vec<Attribute>,
 ) -> Result<Vec<Invariant>> {
     let mut n_invariants = if let Tag::Variant = &tag { 0 } else { 1 };
     let mut invariants = Vec::new();
-    parse_push_invariant(&mut invariants, tag, invariant)?;
+    parse_push_invariant(&mut invariants, tag, label, invariant)?;
 
     let attrs = attrs.extract_if(0.., |attr| {
         attr.path().get_ident().is_some_and(|i| i == "invariant" || i == "variant")
@@ -110,7 +204,7 @@ fn filter_invariants(
             Tag::Variant
         };
         if let Meta::List(l) = attr.meta {
-            parse_push_invariant(&mut invariants, i, l.tokens)?;
+            parse_push_invariant(&mut invariants, i, label, l.tokens)?;
         } else {
             return Err(Error::new_spanned(attr, "expected #[invariant(...)]"));
         }
@@ -145,10 +239,87 @@ fn while_to_loop(w: ExprWhile) -> ExprLoop {
     }
 }
 
-fn desugar_while(invariants: Vec<Invariant>, w: ExprWhile) -> TokenStream {
+fn desugar_while(mut invariants: Vec<Invariant>, w: ExprWhile) -> TokenStream {
+    if !has_variant(&invariants) {
+        invariants.extend(infer_counter_variant(&w));
+    }
     desugar_loop(invariants, while_to_loop(w))
 }
 
+fn has_variant(invariants: &[Invariant]) -> bool {
+    invariants.iter().any(|i| matches!(i.tag, Tag::Variant))
+}
+
+/// Infer the variant of a `while` loop with an obvious counter: `while i < n { ...; i += 1 }`
+/// decreases `n - i`, and `while i > n { ...; i -= 1 }` decreases `i - n`.
+///
+/// This is conservative: `i` and `n` must be variables (or `n` an integer literal), the step
+/// must be the last statement of the body, and the body must not `continue`. The decrease is
+/// still proved, which fails if the body also modifies `i` or `n`.
+fn infer_counter_variant(w: &ExprWhile) -> Option<Invariant> {
+    let span = w.while_token.span;
+    let Expr::Binary(cond) = &*w.cond else { return None };
+    let Some(Stmt::Expr(Expr::Binary(step), _)) = w.body.stmts.last() else { return None };
+    let increasing = match (&cond.op, &step.op) {
+        (BinOp::Lt(_), BinOp::AddAssign(_)) => true,
+        (BinOp::Gt(_), BinOp::SubAssign(_)) => false,
+        _ => return None,
+    };
+    let counter = as_variable(&cond.left)?;
+    if as_variable(&step.left)? != counter
+        || !int_literal(&step.right).is_some_and(|one| one.base10_digits() == "1")
+    {
+        return None;
+    }
+    let mut continues = FindContinue(false);
+    continues.visit_block(&w.body);
+    if continues.0 {
+        return None;
+    }
+    let bound = match int_literal(&cond.right) {
+        Some(n) => n.to_token_stream(),
+        None => {
+            let bound = as_variable(&cond.right)?;
+            quote_spanned! {span=> #bound@ }
+        }
+    };
+    let term = if increasing {
+        parse_quote_spanned! {span=> #bound - #counter@ }
+    } else {
+        parse_quote_spanned! {span=> #counter@ - #bound }
+    };
+    Some(Invariant { tag: Tag::InferredVariant, span, term })
+}
+
+/// `Some(x)` if the expression is the local variable `x`.
+fn as_variable(e: &Expr) -> Option<&Ident> {
+    match e {
+        Expr::Path(p) if p.qself.is_none() && p.attrs.is_empty() => p.path.get_ident(),
+        _ => None,
+    }
+}
+
+/// `Some(n)` if the expression is an unsuffixed integer literal, which is then an `Int` in
+/// pearlite.
+fn int_literal(e: &Expr) -> Option<&LitInt> {
+    match e {
+        Expr::Lit(ExprLit { lit: Lit::Int(n), .. }) if n.suffix().is_empty() => Some(n),
+        _ => None,
+    }
+}
+
+/// Find a `continue`, which would skip the step of the counter.
+struct FindContinue(bool);
+
+impl Visit<'_> for FindContinue {
+    fn visit_expr_continue(&mut self, _: &ExprContinue) {
+        self.0 = true;
+    }
+
+    fn visit_expr_closure(&mut self, _: &ExprClosure) {}
+    fn visit_item(&mut self, _: &Item) {}
+}
+
 fn desugar_loop(invariants: Vec<Invariant>, mut l: ExprLoop) -> TokenStream {
     let span = l.loop_token.span;
     l.body.stmts.insert(0, Stmt::Expr(Expr::Verbatim(quote! { #(#invariants)* }), None));
@@ -194,6 +365,48 @@ fn desugar_for(mut invariants: Vec<Invariant>, f: ExprForLoop) -> TokenStream {
 
     let elem = Ident::new("__creusot_proc_iter_elem", proc_macro::Span::def_site().into());
 
+    let is_range = matches!(
+        &*iter,
+        Expr::Range(ExprRange {
+            start: Some(_),
+            end: Some(_),
+            limits: RangeLimits::HalfOpen(_),
+            ..
+        })
+    );
+
+    // A loop over a literal range `a..b` terminates: each iteration decreases its length.
+    // The decrease follows from the spec of `Range::next`, which creusot-contracts only
+    // provides with its `nightly` feature: without it, the variant cannot be proved.
+    if is_range && !has_variant(&invariants) {
+        invariants.push(Invariant {
+            tag: Tag::InferredVariant,
+            span: for_span,
+            term: parse_quote_spanned! {for_span=> ::creusot_contracts::std::iter::range_len(#it) },
+        });
+    }
+
+    // When iterating over a literal range `a..b`, the bounds of the element follow from the
+    // `produces` invariant: assume them so that the body can use them without restating them.
+    // This adds no goal, and the assumption is not reported by `--report-assumptions`.
+    let bounds = if is_range {
+        quote_spanned! {for_span=>
+            #[allow(let_underscore_drop)]
+            let _ = {
+                #[creusot::no_translate]
+                #[creusot::spec]
+                #[creusot::spec::assert]
+                #[creusot::spec::assume]
+                #[creusot::spec::assume::range_bounds]
+                || -> bool {
+                    ::creusot_contracts::std::iter::range_contains(#iter_old.inner(), #elem)
+                }
+            };
+        }
+    } else {
+        TokenStream::new()
+    };
+
     // Note: the type of `produced` is not determined from its definition alone.
     // We expect:
     // ```
@@ -216,6 +429,7 @@ fn desugar_for(mut invariants: Vec<Invariant>, f: ExprForLoop) -> TokenStream {
                 Some(#elem) => {
                     #[allow(unused_assignments)]
                     #produced = snapshot! { #produced.inner().concat(::creusot_contracts::logic::Seq::singleton(#elem)) };
+                    #bounds
                     let #pat = #elem;
                     #body
                 },
@@ -1,4 +1,4 @@
-use crate::common::ContractSubject;
+use crate::common::{CfgAttr, ConstOrStatic, ContractSubject};
 use proc_macro::TokenStream as TS1;
 use quote::ToTokens as _;
 use syn::visit_mut::VisitMut;
@@ -13,6 +13,18 @@ pub fn proof_assert(_: TS1) -> TS1 {
     TS1::new()
 }
 
+pub fn unsafe_requires(_: TS1) -> TS1 {
+    TS1::new()
+}
+
+pub fn assume(_: TS1) -> TS1 {
+    TS1::new()
+}
+
+pub fn use_lemma(_: TS1) -> TS1 {
+    TS1::new()
+}
+
 pub fn snapshot(_: TS1) -> TS1 {
     quote::quote! { ::creusot_contracts::snapshot::Snapshot::from_fn(|| std::process::abort()) }
         .into()
@@ -36,12 +48,27 @@ pub fn requires(_: TS1, tokens: TS1) -> TS1 {
     TS1::from(item.into_token_stream())
 }
 
+pub fn panics_when(attr: TS1, tokens: TS1) -> TS1 {
+    requires(attr, tokens)
+}
+
 pub fn ensures(_: TS1, tokens: TS1) -> TS1 {
+    if syn::parse::<ConstOrStatic>(tokens.clone()).is_ok() {
+        return tokens;
+    }
     let mut item = syn::parse_macro_input!(tokens as ContractSubject);
     delete_invariants(&mut item);
     TS1::from(item.into_token_stream())
 }
 
+pub fn ensures_if(attr: TS1, tokens: TS1) -> TS1 {
+    ensures(attr, tokens)
+}
+
+pub fn impls_ensure(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
 pub fn variant(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
@@ -70,6 +97,10 @@ pub fn law(_: TS1, _: TS1) -> TS1 {
     TS1::new()
 }
 
+pub fn forall_impls(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
 pub fn trusted(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
@@ -82,6 +113,14 @@ pub fn maintains(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
 
+pub fn reads(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+pub fn writes(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
 pub fn open(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
@@ -144,12 +183,25 @@ fn delete_invariants_attrs(attrs: &mut Vec<syn::Attribute>) {
     });
 }
 
+fn is_contract(meta: &syn::Meta) -> bool {
+    if let syn::Meta::List(meta) = meta {
+        is("requires", &meta.path) || is("ensures", &meta.path)
+    } else {
+        false
+    }
+}
+
 fn delete_contracts(attrs: &mut Vec<syn::Attribute>) {
-    attrs.retain(|attr| {
-        if let syn::Meta::List(meta) = &attr.meta {
-            !is("requires", &meta.path) && !is("ensures", &meta.path)
-        } else {
+    attrs.retain_mut(|attr| {
+        if let Some(mut cfg_attr) = CfgAttr::parse(attr) {
+            cfg_attr.attrs.retain(|meta| !is_contract(meta));
+            if cfg_attr.attrs.is_empty() {
+                return false;
+            }
+            cfg_attr.store(attr);
             true
+        } else {
+            !is_contract(&attr.meta)
         }
     });
 }
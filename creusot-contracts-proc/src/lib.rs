@@ -45,7 +45,10 @@ macro_rules! proc_macro_attributes {
 
 proc_macro_attributes! {
     requires
+    panics_when
     ensures
+    ensures_if
+    impls_ensure
     invariant
     variant
     terminates
@@ -54,10 +57,13 @@ proc_macro_attributes! {
     trusted
     predicate
     law
+    forall_impls
     open
     open_inv_result
     bitwise_proof
     maintains
+    reads
+    writes
 }
 
 macro_rules! proc_macros {
@@ -72,6 +78,9 @@ macro_rules! proc_macros {
 
 proc_macros! {
     proof_assert
+    unsafe_requires
+    assume
+    use_lemma
     snapshot
     ghost
     pearlite
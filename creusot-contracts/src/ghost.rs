@@ -243,3 +243,29 @@ impl<T, U> Ghost<(T, U)> {
         (Ghost::conjure(), Ghost::conjure())
     }
 }
+
+/// Increment the ghost counter `c`, a place of type `Ghost<Int>`.
+///
+/// A ghost counter counts events of the execution, e.g. the comparisons done by an algorithm,
+/// so that the contract can bound them:
+///
+/// ```
+/// # use creusot_contracts::*;
+/// #[ensures(*result.1 == 2)]
+/// fn compare_twice(x: u32, y: u32) -> (bool, Ghost<Int>) {
+///     let mut cmp = ghost!(0int);
+///     let lt = x < y;
+///     ghost_incr!(cmp);
+///     let gt = x > y;
+///     ghost_incr!(cmp);
+///     (!lt && !gt, cmp)
+/// }
+/// ```
+///
+/// This is a shorthand for `ghost! { *c = *c + 1int }`.
+#[macro_export]
+macro_rules! ghost_incr {
+    ($c:expr) => {
+        creusot_contracts::ghost! { *$c = *$c + 1int }
+    };
+}
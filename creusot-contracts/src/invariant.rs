@@ -47,6 +47,39 @@ use crate::*;
 ///
 /// This does not prevent the type to additionnaly implement the `Invariant` trait.
 ///
+/// ## Recursive invariants
+///
+/// An invariant may depend on the invariant of the substructures of the value, e.g. for a tree.
+/// A recursive invariant needs a `#[variant]`, a measure decreasing at each recursive call, which
+/// proves that it is well-defined on finite structures:
+///
+/// ```
+/// # use creusot_contracts::*;
+/// enum Tree {
+///     Leaf,
+///     Node(Box<Tree>, u32, Box<Tree>),
+/// }
+/// # impl Tree {
+/// #     #[logic] #[open] #[ensures(result >= 0)]
+/// #     fn size(self) -> Int { pearlite! { match self { Tree::Leaf => 0, Tree::Node(l, _, r) => 1 + l.size() + r.size() } } }
+/// #     #[logic] #[open]
+/// #     fn below(self, v: u32) -> bool { pearlite! { match self { Tree::Leaf => true, Tree::Node(_, w, _) => w < v } } }
+/// # }
+/// impl Invariant for Tree {
+///     #[predicate]
+///     #[open]
+///     #[variant(self.size())]
+///     fn invariant(self) -> bool {
+///         pearlite! {
+///             match self {
+///                 Tree::Leaf => true,
+///                 Tree::Node(l, v, r) => l.below(v) && (*l).invariant() && (*r).invariant(),
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Mutable borrows
 ///
 /// For mutable borrows, the invariant is the conjunction of the invariants of the current
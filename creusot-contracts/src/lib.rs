@@ -79,8 +79,57 @@ pub mod macros {
     /// #[ensures(result@ == 1)]
     /// fn foo() -> i32 { 1 }
     /// ```
+    ///
+    /// On a `const` or `static` item, `result` is the value of the item, and Creusot checks that
+    /// its initializer satisfies the postcondition. This includes the associated constants of
+    /// impls, as long as their initializer does not refer to `Self` or to the generic parameters
+    /// of the impl:
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// #[ensures(result@ > 0)]
+    /// const SIZE: usize = 16;
+    ///
+    /// struct Buffer;
+    ///
+    /// impl Buffer {
+    ///     #[ensures(result@ >= SIZE@)]
+    ///     const CAPACITY: usize = 2 * SIZE;
+    /// }
+    /// ```
     pub use base_macros::ensures;
 
+    /// A conditional post-condition of a function or trait item
+    ///
+    /// `#[ensures_if(cond, post)]` is `#[ensures(cond ==> post)]`: the function only guarantees
+    /// `post` when `cond` holds. As in [`ensures`], the arguments in `cond` denote their values
+    /// when the function is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// #[ensures_if(x@ > 0, result@ > 1)]
+    /// fn succ(x: u32) -> u32 { x.saturating_add(1) }
+    /// ```
+    pub use base_macros::ensures_if;
+
+    /// A post-condition that every implementation of a trait method must satisfy
+    ///
+    /// Unlike [`ensures`], the condition is not part of the contract of the trait method: callers
+    /// of the method cannot use it, but Creusot checks that each impl of the trait guarantees it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// trait Encode {
+    ///     #[impls_ensure(result@.len() > 0)]
+    ///     fn encode(&self) -> Vec<u8>;
+    /// }
+    /// ```
+    pub use base_macros::impls_ensure;
+
     /// Create a new [`Snapshot`](crate::Snapshot) object.
     ///
     /// The inside of `snapshot` may look like Rust code, but it is in fact
@@ -172,8 +221,46 @@ pub mod macros {
     /// - `str::to_string`
     /// - `<&[T]>::into_vec`
     /// - `Deque::push_front`, `Deque::push_back`, `Deque::with_capacity`
+    ///
+    /// # `pure(logic)`
+    ///
+    /// In an [`extern_spec!`], `#[pure(logic)]` additionally models the external function as
+    /// a logic function, which can then be called in specifications as well as in code. Its
+    /// `ensures` clauses become axioms characterizing the logic function, under its `requires`
+    /// clauses. The external function is trusted to agree with this model, and cannot take
+    /// mutable references.
+    ///
+    /// ```ignore
+    /// extern_spec! {
+    ///     mod mylib {
+    ///         #[pure(logic)]
+    ///         #[requires(x@ <= 1_000_000)]
+    ///         #[ensures(result@ * result@ <= x@ && x@ < (result@ + 1) * (result@ + 1))]
+    ///         fn isqrt(x: u32) -> u32;
+    ///     }
+    /// }
+    /// ```
     pub use base_macros::pure;
 
+    /// Declare when a function that is not [`pure`] may panic
+    ///
+    /// The condition is a [pearlite](https://creusot-rs.github.io/creusot/guide/pearlite)
+    /// term over the arguments of the function. Panics of the body itself are always proof
+    /// obligations: it may only panic in the non-`pure` functions it calls, such as `Vec::push`.
+    /// Creusot does not check the condition: it is shown by `--panic-report`, which warns about
+    /// the functions that may panic without declaring `panics_when`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// #[panics_when(v@.len() == usize::MAX@)]
+    /// fn push_zero(v: &mut Vec<u32>) {
+    ///     v.push(0)
+    /// }
+    /// ```
+    pub use base_macros::panics_when;
+
     /// A loop invariant
     ///
     /// The inside of a `invariant` may look like Rust code, but it is in fact
@@ -203,6 +290,14 @@ pub mod macros {
     /// trait item is used in a function
     pub use base_macros::law;
 
+    /// Declares that a [`law`] holds for every implementation of its trait.
+    ///
+    /// The law must have a default body. Instead of being proved once for all the types
+    /// implementing the trait, it is proved separately for each impl which does not override it.
+    /// This is only sound if no other impls can be added later, so it is only accepted in binary
+    /// crates, or in crates declared closed with `--closed-crate`.
+    pub use base_macros::forall_impls;
+
     /// Declare a function as being a logical function
     ///
     /// This declaration must be pure and total. It cannot be called from Rust programs,
@@ -261,6 +356,65 @@ pub mod macros {
     /// ```
     pub use base_macros::proof_assert;
 
+    /// Declares the safety condition of an `unsafe` block
+    ///
+    /// The condition is proved where the macro is used, which must be inside an `unsafe`
+    /// block, and is then a hypothesis for the unsafe operations that follow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// #[requires(i@ < v@.len())]
+    /// fn get(v: &[u32], i: usize) -> u32 {
+    ///     unsafe {
+    ///         unsafe_requires!(i@ < v@.len());
+    ///         *v.get_unchecked(i)
+    ///     }
+    /// }
+    /// ```
+    pub use base_macros::unsafe_requires;
+
+    /// Inserts a *logical* assumption into the code
+    ///
+    /// The proposition is not proved: it is taken as a hypothesis for the rest of
+    /// the function. This is unsound if the proposition does not actually hold, so
+    /// every use is recorded and can be listed with `--report-assumptions`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// fn f(x: u32) {
+    ///     assume!(x@ < 100);
+    ///     proof_assert!(x@ < 1000);
+    /// }
+    /// ```
+    pub use base_macros::assume;
+
+    /// Applies a lemma to some arguments
+    ///
+    /// A lemma is a [`logic`] function returning `()`, whose contract is the statement of the
+    /// lemma and whose body is its proof. The lemma is proved once, where it is defined;
+    /// `use_lemma!(lemma, args...)` then brings its conclusion for `args` into scope as a
+    /// hypothesis for the rest of the function, provided its preconditions hold there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// #[logic]
+    /// #[requires(0 <= x)]
+    /// #[ensures(x <= 2 * x)]
+    /// fn le_double(x: Int) {}
+    ///
+    /// fn f(x: u32) {
+    ///     use_lemma!(le_double, x@);
+    ///     proof_assert!(x@ <= 2 * x@);
+    /// }
+    /// ```
+    pub use base_macros::use_lemma;
+
     /// Instructs Creusot to ignore the body of a declaration, assuming any contract the declaration has is
     /// valid.
     ///
@@ -335,6 +489,29 @@ pub mod macros {
     /// `mut` replaced by `*` in the `requires` and `^` in the ensures.
     pub use base_macros::maintains;
 
+    /// Declares the mutable borrows that a function may modify.
+    ///
+    /// Every parameter of type `&mut _` which is not listed is left unchanged by the function:
+    /// `#[writes(a)]` on a function with parameters `a: &mut A, b: &mut B` adds the postcondition
+    /// `^b == *b`. This is especially useful for `#[trusted]` functions, whose contract is
+    /// assumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// #[trusted]
+    /// #[writes(x)]
+    /// fn set(x: &mut Ghost<Int>, y: &mut Ghost<Int>) { }
+    /// ```
+    pub use base_macros::writes;
+
+    /// Declares mutable borrows that a function only reads: they are left unchanged.
+    ///
+    /// This is the dual of [`writes`]: `#[reads(b)]` on a function with a parameter `b: &mut B`
+    /// adds the postcondition `^b == *b`.
+    pub use base_macros::reads;
+
     /// Allows the body of a logical definition to be made visible to provers
     ///
     /// By default, bodies are *opaque*: they are only visible to definitions in the same
@@ -401,7 +578,7 @@ mod base_prelude {
     pub use crate::{
         fn_pure::FnPure,
         ghost::Ghost,
-        logic::{Int, OrdLogic, Seq, ops::IndexLogic as _},
+        logic::{FloatLogic, Int, OrdLogic, Seq, ops::IndexLogic as _},
         model::{DeepModel, View},
         resolve::*,
         snapshot::Snapshot,
@@ -424,14 +601,19 @@ mod base_prelude {
     // Export extension traits anonymously
     pub use crate::std::{
         char::CharExt as _,
-        iter::{SkipExt as _, TakeExt as _},
-        ops::{FnExt as _, FnMutExt as _, FnOnceExt as _, RangeInclusiveExt as _},
+        iter::{Appended as _, SkipExt as _, StepByExt as _, TakeExt as _},
+        ops::{
+            FnExt as _, FnMutExt as _, FnOnceExt as _, RangeBoundsExt as _, RangeExt as _,
+            RangeInclusiveExt as _,
+        },
+        option::OptionExt as _,
         ptr::PointerExt as _,
         slice::SliceExt as _,
+        vec::{DrainExt as _, VecExt as _},
     };
 
     #[cfg(creusot)]
-    pub use crate::util::such_that;
+    pub use crate::util::{fresh, such_that};
 }
 pub mod prelude {
     pub use crate::{base_prelude::*, macros::*};
@@ -4,19 +4,27 @@
 
 #![cfg_attr(not(creusot), allow(unused_imports))]
 
+mod float;
 mod fmap;
 pub mod fset;
+mod history;
 mod int;
 mod mapping;
 pub mod ops;
 pub mod ord;
+mod popcount;
 pub mod seq;
 mod set;
+mod tokens;
 
+pub use float::FloatLogic;
 pub use fmap::FMap;
 pub use fset::FSet;
+pub use history::History;
 pub use int::Int;
 pub use mapping::Mapping;
 pub use ord::OrdLogic;
+pub use popcount::Popcount;
 pub use seq::Seq;
 pub use set::Set;
+pub use tokens::Tokens;
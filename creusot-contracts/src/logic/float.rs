@@ -0,0 +1,244 @@
+use crate::{
+    logic::ops::{AddLogic, DivLogic, MulLogic, NegLogic, SubLogic},
+    *,
+};
+
+/// Logical operations on IEEE-754 floating-point numbers.
+///
+/// The operations of [`f32`] and [`f64`] are modeled precisely, rounding to the nearest (ties to
+/// even) as Rust does. In logic, `+`, `-`, `*` and `/` are these rounded operations, so that
+/// `result == a + b` states exactly the result of the program addition.
+///
+/// Note that in pearlite, `==` is the logical equality: `NaN == NaN` holds, and `0.0 == -0.0`
+/// does not. Use [`eq_log`](Self::eq_log) for the IEEE equality of Rust's `==`.
+///
+/// The comparison operators are not available in pearlite since floats are not totally ordered:
+/// use the methods of this trait instead.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::*;
+/// #[ensures(a.is_nan_log() || b.is_nan_log() ==> result.is_nan_log())]
+/// #[ensures(result == a + b)]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+/// ```
+pub trait FloatLogic: Sized {
+    /// Is `self` a NaN?
+    #[predicate]
+    fn is_nan_log(self) -> bool;
+
+    /// Is `self` positive or negative infinity?
+    #[predicate]
+    fn is_infinite_log(self) -> bool;
+
+    /// Is `self` neither infinite nor NaN?
+    #[predicate]
+    fn is_finite_log(self) -> bool;
+
+    /// Is `self` either `+0.0` or `-0.0`?
+    #[predicate]
+    fn is_zero_log(self) -> bool;
+
+    /// Is `self` positive, including `+0.0` and positive infinity?
+    ///
+    /// This is false for NaN, regardless of its sign bit.
+    #[predicate]
+    fn is_positive_log(self) -> bool;
+
+    /// Is `self` negative, including `-0.0` and negative infinity?
+    ///
+    /// This is false for NaN, regardless of its sign bit.
+    #[predicate]
+    fn is_negative_log(self) -> bool;
+
+    /// IEEE equality, as computed by `==` in programs.
+    ///
+    /// This is false if one of the operands is NaN, and `+0.0` is equal to `-0.0`.
+    #[predicate]
+    fn eq_log(self, other: Self) -> bool;
+
+    /// IEEE `<`, which is false if one of the operands is NaN.
+    #[predicate]
+    fn lt_log(self, other: Self) -> bool;
+
+    /// IEEE `<=`, which is false if one of the operands is NaN.
+    #[predicate]
+    fn le_log(self, other: Self) -> bool;
+
+    /// IEEE `>`, which is false if one of the operands is NaN.
+    #[predicate]
+    fn gt_log(self, other: Self) -> bool;
+
+    /// IEEE `>=`, which is false if one of the operands is NaN.
+    #[predicate]
+    fn ge_log(self, other: Self) -> bool;
+}
+
+macro_rules! float_logic_impl {
+    ($t:ty, $module:literal) => {
+        impl FloatLogic for $t {
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".is_nan")]
+            fn is_nan_log(self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".is_infinite")]
+            fn is_infinite_log(self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".is_finite")]
+            fn is_finite_log(self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".is_zero")]
+            fn is_zero_log(self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".is_positive")]
+            fn is_positive_log(self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".is_negative")]
+            fn is_negative_log(self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".eq")]
+            #[allow(unused_variables)]
+            fn eq_log(self, other: Self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".lt")]
+            #[allow(unused_variables)]
+            fn lt_log(self, other: Self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".le")]
+            #[allow(unused_variables)]
+            fn le_log(self, other: Self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".gt")]
+            #[allow(unused_variables)]
+            fn gt_log(self, other: Self) -> bool {
+                dead
+            }
+
+            #[trusted]
+            #[predicate]
+            #[creusot::builtins = concat!($module, ".ge")]
+            #[allow(unused_variables)]
+            fn ge_log(self, other: Self) -> bool {
+                dead
+            }
+        }
+
+        impl AddLogic for $t {
+            type Output = Self;
+            #[logic]
+            #[trusted]
+            #[creusot::no_translate]
+            #[creusot::builtins = concat!($module, ".add")]
+            #[allow(unused_variables)]
+            fn add(self, other: Self) -> Self {
+                dead
+            }
+        }
+
+        impl SubLogic for $t {
+            type Output = Self;
+            #[logic]
+            #[trusted]
+            #[creusot::no_translate]
+            #[creusot::builtins = concat!($module, ".sub")]
+            #[allow(unused_variables)]
+            fn sub(self, other: Self) -> Self {
+                dead
+            }
+        }
+
+        impl MulLogic for $t {
+            type Output = Self;
+            #[logic]
+            #[trusted]
+            #[creusot::no_translate]
+            #[creusot::builtins = concat!($module, ".mul")]
+            #[allow(unused_variables)]
+            fn mul(self, other: Self) -> Self {
+                dead
+            }
+        }
+
+        impl DivLogic for $t {
+            type Output = Self;
+            #[logic]
+            #[trusted]
+            #[creusot::no_translate]
+            #[creusot::builtins = concat!($module, ".div")]
+            #[allow(unused_variables)]
+            fn div(self, other: Self) -> Self {
+                dead
+            }
+        }
+
+        impl NegLogic for $t {
+            type Output = Self;
+            #[logic]
+            #[trusted]
+            #[creusot::no_translate]
+            #[creusot::builtins = concat!($module, ".neg")]
+            fn neg(self) -> Self {
+                dead
+            }
+        }
+
+        extern_spec! {
+            impl $t {
+                #[pure]
+                #[ensures(result == self.is_nan_log())]
+                fn is_nan(self) -> bool;
+
+                #[pure]
+                #[ensures(result == self.is_infinite_log())]
+                fn is_infinite(self) -> bool;
+
+                #[pure]
+                #[ensures(result == self.is_finite_log())]
+                fn is_finite(self) -> bool;
+            }
+        }
+    };
+}
+
+float_logic_impl!(f32, "creusot.float.Float32");
+float_logic_impl!(f64, "creusot.float.Float64");
@@ -0,0 +1,68 @@
+use crate::{logic::Seq, *};
+
+/// A ghost log of events.
+///
+/// A function that records events takes a `&mut Ghost<History<E>>`: its contract can then refer
+/// to the sequence of events recorded during the call with [`appended`](Self::appended).
+/// Since events cannot be removed, the history at the end of the call always extends the history
+/// at the beginning.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, logic::History};
+/// #[ensures((**log).appended(*^log) == Seq::singleton(1i32).push_back(2i32))]
+/// fn two_events(log: &mut Ghost<History<i32>>) {
+///     ghost! {
+///         log.push(1i32);
+///         log.push(2i32);
+///     };
+/// }
+/// ```
+#[trusted]
+pub struct History<E>(std::marker::PhantomData<E>);
+
+impl<E> View for History<E> {
+    type ViewTy = Seq<E>;
+
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    #[ensures(result.len() >= 0)]
+    fn view(self) -> Seq<E> {
+        dead
+    }
+}
+
+impl<E> History<E> {
+    /// Create a new, empty history.
+    #[trusted]
+    #[pure]
+    #[ensures(result@ == Seq::EMPTY)]
+    pub fn new() -> Ghost<Self> {
+        Ghost::conjure()
+    }
+
+    /// Record a new event.
+    #[trusted]
+    #[pure]
+    #[ensures((^self)@ == self@.push_back(e))]
+    #[allow(unused_variables)]
+    pub fn push(&mut self, e: E) {
+        panic!()
+    }
+
+    /// `fin` was obtained from `self` by recording more events.
+    #[predicate]
+    #[open]
+    pub fn extended_by(self, fin: Self) -> bool {
+        pearlite! { self@.len() <= fin@.len() && fin@.subsequence(0, self@.len()) == self@ }
+    }
+
+    /// The events recorded since `self` to get to `fin`.
+    #[logic]
+    #[open]
+    pub fn appended(self, fin: Self) -> Seq<E> {
+        pearlite! { fin@.subsequence(self@.len(), fin@.len()) }
+    }
+}
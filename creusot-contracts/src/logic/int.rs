@@ -62,6 +62,9 @@ impl Int {
 
     /// Compute `self^p`.
     ///
+    /// This is Why3's `int.Power.power`, whose axioms give `b.pow(0) == 1` and
+    /// `b.pow(n + 1) == b * b.pow(n)` for `n >= 0`.
+    ///
     /// # Example
     ///
     /// ```
@@ -140,6 +143,23 @@ impl Int {
         dead
     }
 
+    /// Compute the absolute value of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// proof_assert!((-5).abs() == 5);
+    /// proof_assert!(3.abs() == 3);
+    /// ```
+    #[logic]
+    #[open]
+    #[ensures(result >= 0)]
+    #[ensures(result == self || result == -self)]
+    pub fn abs(self) -> Int {
+        if self < 0 { -self } else { self }
+    }
+
     /// Compute the absolute difference of `self` and `x`.
     ///
     /// # Example
@@ -155,6 +175,27 @@ impl Int {
     pub fn abs_diff(self, other: Int) -> Int {
         if self < other { other - self } else { self - other }
     }
+
+    /// Compute the number of bits set to `1` in the binary representation of `self`.
+    ///
+    /// This is only meaningful for non-negative integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// proof_assert!(0.popcount() == 0);
+    /// proof_assert!(5.popcount() == 2);
+    /// ```
+    #[logic]
+    #[open]
+    #[variant(self)]
+    #[requires(self >= 0)]
+    #[ensures(0 <= result && result <= self)]
+    #[ensures((result == 0) == (self == 0))]
+    pub fn popcount(self) -> Int {
+        if self == 0 { 0 } else { self % 2 + (self / 2).popcount() }
+    }
 }
 
 impl AddLogic for Int {
@@ -193,12 +234,24 @@ impl MulLogic for Int {
     }
 }
 
+/// Division and remainder, as computed by `/` and `%`, round toward zero, like the operators of
+/// Rust's machine integers: `self == (self / d) * d + self % d`, and the remainder has the sign of
+/// `self`.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::*;
+/// proof_assert!(7 / 2 == 3 && 7 % 2 == 1);
+/// proof_assert!((-7) / 2 == -3 && (-7) % 2 == -1);
+/// ```
 impl DivLogic for Int {
     type Output = Self;
     #[logic]
     #[trusted]
-    #[creusot::no_translate]
-    #[creusot::builtins = "mach.int.Int.div"]
+    #[ensures(result == int_div(self, other))]
+    #[ensures(other != 0 ==> self == result * other + int_rem(self, other))]
+    #[ensures(self >= 0 && other > 0 ==> 0 <= result && result <= self)]
     #[allow(unused_variables)]
     fn div(self, other: Self) -> Self {
         dead
@@ -209,14 +262,35 @@ impl RemLogic for Int {
     type Output = Self;
     #[logic]
     #[trusted]
-    #[creusot::no_translate]
-    #[creusot::builtins = "mach.int.Int.mod"]
+    #[ensures(result == int_rem(self, other))]
+    #[ensures(other != 0 ==> self == int_div(self, other) * other + result)]
+    #[ensures(other != 0 ==> result.abs() < other.abs())]
+    #[ensures(other != 0 && self >= 0 ==> result >= 0)]
+    #[ensures(other != 0 && self <= 0 ==> result <= 0)]
     #[allow(unused_variables)]
     fn rem(self, other: Self) -> Self {
         dead
     }
 }
 
+// The contract of a builtin is not emitted: `/` and `%` wrap these, so that their laws are
+// available wherever the operators are used.
+#[logic]
+#[trusted]
+#[creusot::builtins = "mach.int.Int.div"]
+#[allow(unused_variables)]
+fn int_div(a: Int, b: Int) -> Int {
+    dead
+}
+
+#[logic]
+#[trusted]
+#[creusot::builtins = "mach.int.Int.mod"]
+#[allow(unused_variables)]
+fn int_rem(a: Int, b: Int) -> Int {
+    dead
+}
+
 impl NegLogic for Int {
     type Output = Self;
     #[logic]
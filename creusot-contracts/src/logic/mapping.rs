@@ -49,6 +49,40 @@ impl<A: ?Sized, B: ?Sized> Mapping<A, B> {
     pub fn cst(b: B) -> Self {
         dead
     }
+
+    /// After `set(a, b)`, looking up `a` gives `b`.
+    #[law]
+    #[open(self)]
+    #[ensures(self.set(a, b).get(a) == b)]
+    pub fn set_get_same(self, a: A, b: B)
+    where
+        A: Sized,
+        B: Sized,
+    {
+    }
+
+    /// `set(a, b)` leaves the values associated with keys other than `a` unchanged.
+    #[law]
+    #[open(self)]
+    #[requires(a != a2)]
+    #[ensures(self.set(a, b).get(a2) == self.get(a2))]
+    pub fn set_get_other(self, a: A, a2: A, b: B)
+    where
+        A: Sized,
+        B: Sized,
+    {
+    }
+
+    /// Every key of a constant mapping is associated with its value.
+    #[law]
+    #[open(self)]
+    #[ensures(Self::cst(b).get(a) == b)]
+    pub fn cst_get(a: A, b: B)
+    where
+        A: Sized,
+        B: Sized,
+    {
+    }
 }
 
 impl<A: ?Sized, B> IndexLogic<A> for Mapping<A, B> {
@@ -0,0 +1,45 @@
+use crate::*;
+
+/// Logical model of the number of bits set to `1` in an unsigned machine integer.
+///
+/// The logical definition goes through [`Int::popcount`], so it is available
+/// regardless of whether the function is proved in bitwise mode.
+pub trait Popcount: View<ViewTy = Int> + Sized {
+    /// The number of bits set to `1` in `self`.
+    #[logic]
+    fn popcount(self) -> Int;
+
+    #[law]
+    #[ensures(0 <= x.popcount() && x.popcount() <= x@)]
+    fn popcount_bounds(x: Self);
+
+    #[law]
+    #[ensures((x.popcount() == 0) == (x@ == 0))]
+    fn popcount_zero(x: Self);
+}
+
+macro_rules! popcount_impl {
+    ($($t:ty)*) => {
+        $(
+            impl Popcount for $t {
+                #[logic]
+                #[open]
+                fn popcount(self) -> Int {
+                    pearlite! { self@.popcount() }
+                }
+
+                #[law]
+                #[open]
+                #[ensures(0 <= x.popcount() && x.popcount() <= x@)]
+                fn popcount_bounds(x: Self) {}
+
+                #[law]
+                #[open]
+                #[ensures((x.popcount() == 0) == (x@ == 0))]
+                fn popcount_zero(x: Self) {}
+            }
+        )*
+    };
+}
+
+popcount_impl!(u8 u16 u32 u64 u128 usize);
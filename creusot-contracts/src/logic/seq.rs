@@ -1,6 +1,6 @@
 use crate::{
     invariant::*,
-    logic::{Mapping, ops::IndexLogic},
+    logic::{FSet, Mapping, ops::IndexLogic},
     *,
 };
 
@@ -316,6 +316,29 @@ impl<T: ?Sized> Seq<T> {
         dead
     }
 
+    /// Reversing a sequence preserves its length.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s: Self> #![trigger s.reverse()] s.reverse().len() == s.len())]
+    pub fn reverse_len() {}
+
+    /// The element at index `i` of the reverse of `s` is the one at index `s.len() - 1 - i` of `s`.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s: Self, i: Int> #![trigger s.reverse()[i]]
+        0 <= i && i < s.len() ==> s.reverse()[i] == s[s.len() - 1 - i])]
+    pub fn reverse_index()
+    where
+        T: Sized,
+    {
+    }
+
+    /// Reversing a sequence twice gives back the sequence.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s: Self> #![trigger s.reverse().reverse()] s.reverse().reverse() == s)]
+    pub fn reverse_reverse() {}
+
     /// Returns `true` if `other` is a permutation of `self`.
     #[predicate]
     #[open]
@@ -352,6 +375,79 @@ impl<T: ?Sized> Seq<T> {
         dead
     }
 
+    /// `permutation_of` is reflexive.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s: Self> #![trigger s.permutation_of(s)] s.permutation_of(s))]
+    pub fn permutation_of_refl() {}
+
+    /// `permutation_of` is symmetric.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s1: Self, s2: Self> #![trigger s1.permutation_of(s2)]
+        s1.permutation_of(s2) ==> s2.permutation_of(s1))]
+    pub fn permutation_of_sym() {}
+
+    /// `permutation_of` is transitive.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s1: Self, s2: Self, s3: Self>
+        #![trigger s1.permutation_of(s2), s2.permutation_of(s3)]
+        s1.permutation_of(s2) && s2.permutation_of(s3) ==> s1.permutation_of(s3))]
+    pub fn permutation_of_trans() {}
+
+    /// Swapping two elements of a sequence gives a permutation of it.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s1: Self, s2: Self, i: Int, j: Int> #![trigger s1.exchange(s2, i, j)]
+        s1.exchange(s2, i, j) ==> s1.permutation_of(s2))]
+    pub fn exchange_permutation_of() {}
+
+    /// The number of occurrences of `x` in `s`, between `start` (included) and `end` (excluded).
+    #[trusted]
+    #[logic]
+    #[creusot::builtins = "seq.Occ.occ"]
+    #[allow(unused_variables)]
+    pub fn occ(x: T, s: Self, start: Int, end: Int) -> Int
+    where
+        T: Sized,
+    {
+        dead
+    }
+
+    /// The number of occurrences of `x` in `self`.
+    #[logic]
+    #[open]
+    pub fn count(self, x: T) -> Int
+    where
+        T: Sized,
+    {
+        Self::occ(x, self, 0, self.len())
+    }
+
+    /// Appending `y` to a sequence adds one occurrence of `y`, and none of the other elements.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s: Self, x: T, y: T> #![trigger s.push_back(y).count(x)]
+        s.push_back(y).count(x) == s.count(x) + if x == y { 1 } else { 0 })]
+    pub fn count_push_back()
+    where
+        T: Sized,
+    {
+    }
+
+    /// Two sequences are permutations of each other if and only if they contain every element
+    /// the same number of times.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<s1: Self, s2: Self> #![trigger s1.permutation_of(s2)]
+        s1.permutation_of(s2) == (s1.len() == s2.len() && forall<x: T> s1.count(x) == s2.count(x)))]
+    pub fn permutation_of_count()
+    where
+        T: Sized,
+    {
+    }
+
     /// Returns `true` if there is an index `i` such that `self[i] == x`.
     #[open]
     #[predicate]
@@ -362,6 +458,76 @@ impl<T: ?Sized> Seq<T> {
         pearlite! { exists<i : Int> 0 <= i &&  i < self.len() && self[i] == x }
     }
 
+    /// Returns `true` if every element of `self` satisfies `p`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// let s = snapshot!(Seq::create(3, |i| 2 * i));
+    /// proof_assert!(s.all(|x: Int| x % 2 == 0));
+    /// ```
+    #[open]
+    #[predicate]
+    pub fn all(self, p: Mapping<T, bool>) -> bool
+    where
+        T: Sized,
+    {
+        pearlite! { forall<i: Int> 0 <= i && i < self.len() ==> p.get(self[i]) }
+    }
+
+    /// Returns `true` if some element of `self` satisfies `p`.
+    #[open]
+    #[predicate]
+    pub fn any(self, p: Mapping<T, bool>) -> bool
+    where
+        T: Sized,
+    {
+        pearlite! { exists<i: Int> 0 <= i && i < self.len() && p.get(self[i]) }
+    }
+
+    #[open]
+    #[logic]
+    #[ensures(forall<p: Mapping<T, bool>> Seq::<T>::EMPTY.all(p))]
+    #[ensures(forall<s: Seq<T>, x: T, p: Mapping<T, bool>> #![trigger s.push_back(x).all(p)]
+        s.push_back(x).all(p) == (s.all(p) && p.get(x)))]
+    #[ensures(forall<a: Seq<T>, b: Seq<T>, p: Mapping<T, bool>> #![trigger a.concat(b).all(p)]
+        a.concat(b).all(p) == (a.all(p) && b.all(p)))]
+    pub fn all_laws()
+    where
+        T: Sized,
+    {
+        proof_assert! { forall<s: Seq<T>, x: T> s.push_back(x)[s.len()] == x };
+    }
+
+    #[open]
+    #[logic]
+    #[ensures(forall<s: Seq<T>, p: Mapping<T, bool>> #![trigger s.any(p)]
+        s.any(p) == !s.all(|x| !p.get(x)))]
+    pub fn any_not_all()
+    where
+        T: Sized,
+    {
+    }
+
+    /// Returns the set of the elements of `self`.
+    #[open]
+    #[logic]
+    #[variant(self.len())]
+    #[ensures(forall<x: T> result.contains(x) == self.contains(x))]
+    pub fn to_set(self) -> FSet<T>
+    where
+        T: Sized,
+    {
+        pearlite! {
+            if self.len() == 0 {
+                FSet::empty()
+            } else {
+                self.tail().to_set().insert(self[0])
+            }
+        }
+    }
+
     /// Returns `true` if `self` is sorted between `start` and `end`.
     #[open]
     #[predicate]
@@ -408,6 +574,70 @@ impl<T: ?Sized> Seq<Seq<T>> {
     }
 }
 
+impl<T: DeepModel<DeepModelTy = Int>> Seq<T> {
+    /// The sum of the elements of the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// let s = snapshot!(seq![1, 2, 3]);
+    /// proof_assert!(s.sum() == 6);
+    /// ```
+    #[logic]
+    #[open]
+    #[variant(self.len())]
+    pub fn sum(self) -> Int {
+        pearlite! {
+            if self.len() == 0 {
+                0
+            } else {
+                self.subsequence(0, self.len() - 1).sum() + self[self.len() - 1].deep_model()
+            }
+        }
+    }
+
+    /// The product of the elements of the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use creusot_contracts::*;
+    /// let s = snapshot!(seq![2, 3, 4]);
+    /// proof_assert!(s.product() == 24);
+    /// ```
+    #[logic]
+    #[open]
+    #[variant(self.len())]
+    pub fn product(self) -> Int {
+        pearlite! {
+            if self.len() == 0 {
+                1
+            } else {
+                self.subsequence(0, self.len() - 1).product() * self[self.len() - 1].deep_model()
+            }
+        }
+    }
+
+    #[logic]
+    #[open]
+    #[ensures(Seq::<T>::EMPTY.sum() == 0)]
+    #[ensures(forall<s: Seq<T>, x: T> #![trigger s.push_back(x).sum()]
+        s.push_back(x).sum() == s.sum() + x.deep_model())]
+    pub fn sum_push_back() {
+        proof_assert! { forall<s: Seq<T>, x: T> s.push_back(x).subsequence(0, s.len()) == s }
+    }
+
+    #[logic]
+    #[open]
+    #[ensures(Seq::<T>::EMPTY.product() == 1)]
+    #[ensures(forall<s: Seq<T>, x: T> #![trigger s.push_back(x).product()]
+        s.push_back(x).product() == s.product() * x.deep_model())]
+    pub fn product_push_back() {
+        proof_assert! { forall<s: Seq<T>, x: T> s.push_back(x).subsequence(0, s.len()) == s }
+    }
+}
+
 impl<T: ?Sized> Seq<&T> {
     /// Convert `Seq<&T>` to `Seq<T>`.
     ///
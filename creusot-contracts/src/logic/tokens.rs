@@ -0,0 +1,94 @@
+use crate::{logic::Int, *};
+
+/// A ghost amount of some resource.
+///
+/// `Tokens` cannot be copied or cloned: the only ways to get new tokens are to create them with
+/// [`new`](Self::new), or to [`split`](Self::split) them off existing ones. Splitting and
+/// [`join`](Self::join)ing preserve the total amount, and [`consume`](Self::consume) requires
+/// that there is something left to consume. As a result, a token cannot be used twice.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, logic::Tokens};
+/// let mut t = ghost!(Tokens::new(2int));
+/// ghost! {
+///     let u = t.split(1int);
+///     t.consume();
+///     u.consume_all();
+///     proof_assert!(t@ == 0);
+/// };
+/// ```
+#[trusted]
+pub struct Tokens(std::marker::PhantomData<()>);
+
+impl View for Tokens {
+    type ViewTy = Int;
+
+    /// The amount of tokens, which is never negative.
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    #[ensures(result >= 0)]
+    fn view(self) -> Int {
+        dead
+    }
+}
+
+impl Tokens {
+    /// Create `n` tokens.
+    ///
+    /// This function can only be called in `ghost!` code.
+    #[trusted]
+    #[pure]
+    #[requires(n >= 0)]
+    #[ensures(result@ == n)]
+    #[allow(unused_variables)]
+    pub fn new(n: Int) -> Self {
+        panic!()
+    }
+
+    /// Take `n` tokens out of `self`.
+    ///
+    /// This function can only be called in `ghost!` code.
+    #[trusted]
+    #[pure]
+    #[requires(0 <= n && n <= self@)]
+    #[ensures(result@ == n)]
+    #[ensures((^self)@ == self@ - n)]
+    #[allow(unused_variables)]
+    pub fn split(&mut self, n: Int) -> Self {
+        panic!()
+    }
+
+    /// Put the tokens of `other` back into `self`.
+    ///
+    /// This function can only be called in `ghost!` code.
+    #[trusted]
+    #[pure]
+    #[ensures((^self)@ == self@ + other@)]
+    #[allow(unused_variables)]
+    pub fn join(&mut self, other: Self) {
+        panic!()
+    }
+
+    /// Use up one token.
+    ///
+    /// This function can only be called in `ghost!` code.
+    #[trusted]
+    #[pure]
+    #[requires(self@ >= 1)]
+    #[ensures((^self)@ == self@ - 1)]
+    pub fn consume(&mut self) {
+        panic!()
+    }
+
+    /// Use up all the tokens at once.
+    ///
+    /// This function can only be called in `ghost!` code.
+    #[trusted]
+    #[pure]
+    pub fn consume_all(self) {
+        panic!()
+    }
+}
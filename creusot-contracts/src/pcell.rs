@@ -2,6 +2,13 @@
 //!
 //! This allows a form of interior mutability, using [ghost](mod@crate::ghost) code to keep
 //! track of the logical value.
+//!
+//! Shared references are modeled by their value, so the value of a standard
+//! [`Cell`](::std::cell::Cell) is not known after it changes through `&self`: only a property of
+//! all its values can be, see [`PredCell`](crate::std::cell::PredCell). With `PCell`, the current
+//! value lives in the ghost [`PCellOwn`] instead, and accessing it mutably requires
+//! `&mut PCellOwn`: two overlapping mutable borrows of the same cell, which would make a
+//! `RefCell` panic, cannot be written.
 
 #[cfg(creusot)]
 use crate::util::SizedW;
@@ -3,6 +3,7 @@ pub use ::std::*;
 pub mod array;
 pub mod borrow;
 pub mod boxed;
+pub mod cell;
 pub mod clone;
 pub mod collections {
     pub mod hash_map;
@@ -14,14 +15,17 @@ pub mod convert;
 pub mod default;
 pub mod deque;
 pub mod fmt;
+pub mod hash;
 pub mod hint;
 pub mod io;
 pub mod iter;
+pub mod marker;
 pub mod mem;
 pub mod num;
 pub mod ops;
 pub mod option;
 pub mod panicking;
+pub mod process;
 pub mod ptr;
 pub mod rc;
 pub mod result;
@@ -16,24 +16,29 @@ impl<T, const N: usize> View for [T; N] {
 
     #[logic]
     #[trusted]
-    #[cfg_attr(target_pointer_width = "16", creusot::builtins = "creusot.slice.Slice16.id")]
-    #[cfg_attr(target_pointer_width = "32", creusot::builtins = "creusot.slice.Slice32.id")]
-    #[cfg_attr(target_pointer_width = "64", creusot::builtins = "creusot.slice.Slice64.id")]
-    // TODO:
-    // #[ensures(result.len() == N@)]
-    // Warning: #[ensures] and #[trusted] are incompatible, so this might require
+    #[ensures(result.len() == N@)]
+    #[ensures(result == array_model(self))]
     fn view(self) -> Self::ViewTy {
         dead
     }
 }
 
+// The contract of a builtin is not emitted: the view of arrays wraps it, like the one of slices.
+#[logic]
+#[trusted]
+#[cfg_attr(target_pointer_width = "16", creusot::builtins = "creusot.slice.Slice16.id")]
+#[cfg_attr(target_pointer_width = "32", creusot::builtins = "creusot.slice.Slice32.id")]
+#[cfg_attr(target_pointer_width = "64", creusot::builtins = "creusot.slice.Slice64.id")]
+fn array_model<T, const N: usize>(_: [T; N]) -> Seq<T> {
+    dead
+}
+
 impl<T: DeepModel, const N: usize> DeepModel for [T; N] {
     type DeepModelTy = Seq<T::DeepModelTy>;
 
     #[logic]
     #[trusted]
-    // TODO
-    // #[ensures(result.len() == N@)]
+    #[ensures(result.len() == N@)]
     #[ensures(self.view().len() == result.len())]
     #[ensures(forall<i: _> 0 <= i && i < result.len() ==> result[i] == self[i].deep_model())]
     fn deep_model(self) -> Self::DeepModelTy {
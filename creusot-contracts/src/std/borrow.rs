@@ -1,5 +1,5 @@
 use crate::*;
-use ::std::borrow::Borrow;
+use ::std::borrow::{Borrow, ToOwned};
 
 // "In particular Eq, Ord and Hash must be equivalent for borrowed and owned values:
 // x.borrow() == y.borrow() should give the same result as x == y."
@@ -20,3 +20,20 @@ extern_spec! {
         }
     }
 }
+
+/// The law relating [`ToOwned`] and [`Borrow`]: borrowing the result of `to_owned` gives back a
+/// value with the same view, `x.to_owned().borrow()@ == x@`.
+///
+/// It does not hold for every type, e.g. with the blanket `impl<T: Clone> ToOwned for T` and a
+/// `Clone` impl changing the view. It is thus a law of each local implementation of `ToOwned`,
+/// proved using the specifications of its `to_owned` method and of the `borrow` method of its
+/// `Owned` type, and available when an item of this implementation is used. It cannot be called.
+#[trusted]
+#[logic]
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_borrow_to_owned_consistent")]
+#[ensures(forall<o: &T::Owned, b: &T>
+    T::to_owned.postcondition((x,), *o) && <T::Owned as Borrow<T>>::borrow.postcondition((o,), b)
+        ==> b@ == x@
+)]
+#[allow(unused_variables, dead_code)]
+pub(crate) fn borrow_to_owned_consistent<T: ToOwned + View + ?Sized>(x: &T) {}
@@ -0,0 +1,215 @@
+use crate::*;
+pub use ::std::cell::*;
+use ::std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+// Shared references are modeled by their value, so the contents of a `Cell` cannot be known after
+// they change through `&self`. The only property of the values of a `Cell` is their type
+// invariant, which `new` and `set` require and `get` ensures. See `PredCell` for cells with a
+// stronger property.
+extern_spec! {
+    mod std {
+        mod cell {
+            impl<T> Cell<T> {
+                #[pure]
+                fn new(value: T) -> Cell<T>;
+
+                #[pure]
+                fn get(&self) -> T
+                where
+                    T: Copy;
+
+                #[pure]
+                fn set(&self, val: T);
+
+                #[pure]
+                fn replace(&self, val: T) -> T;
+
+                #[pure]
+                fn into_inner(self) -> T;
+
+                #[pure]
+                fn get_mut(&mut self) -> &mut T;
+            }
+
+            // Like for `Cell`, the contents of a `RefCell` are not known after they change
+            // through `&self`, and neither is its borrow flag. The contents are known through
+            // a guard while it is alive, and the borrow flag in the `panics_when` clauses.
+            impl<T> RefCell<T> {
+                #[pure]
+                fn new(value: T) -> RefCell<T>;
+
+                #[panics_when(self.borrow_state() == BorrowState::Writing)]
+                fn borrow(&self) -> Ref<'_, T>;
+
+                #[panics_when(self.borrow_state() != BorrowState::Unused)]
+                fn borrow_mut(&self) -> RefMut<'_, T>;
+
+                #[pure]
+                fn try_borrow(&self) -> Result<Ref<'_, T>, BorrowError>;
+
+                #[pure]
+                fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, BorrowMutError>;
+
+                #[panics_when(self.borrow_state() != BorrowState::Unused)]
+                fn replace(&self, t: T) -> T;
+
+                #[pure]
+                fn into_inner(self) -> T;
+
+                #[pure]
+                fn get_mut(&mut self) -> &mut T;
+            }
+
+            impl<'b, T> Deref for Ref<'b, T> {
+                #[pure]
+                #[ensures(*result == (*self)@)]
+                fn deref(&self) -> &T;
+            }
+
+            impl<'b, T> Deref for RefMut<'b, T> {
+                #[pure]
+                #[ensures(*result == (*self)@)]
+                fn deref(&self) -> &T;
+            }
+
+            impl<'b, T> DerefMut for RefMut<'b, T> {
+                #[pure]
+                #[ensures(*result == (*self)@)]
+                #[ensures(^result == (^self)@)]
+                fn deref_mut(&mut self) -> &mut T;
+            }
+        }
+    }
+}
+
+/// The state of the dynamic borrow flag of a [`RefCell`].
+pub enum BorrowState {
+    /// The cell is not borrowed.
+    Unused,
+    /// The cell is borrowed by one or more [`Ref`].
+    Reading,
+    /// The cell is borrowed by a [`RefMut`].
+    Writing,
+}
+
+pub trait RefCellExt: Sized {
+    /// The state of the borrow flag of the cell.
+    ///
+    /// The borrow flag changes through shared references, like the contents of the cell, so it
+    /// is ghost state that Creusot does not track: `borrow_state` has no properties, and can only
+    /// be used to declare when a function may panic with [`panics_when`]. For example,
+    /// [`RefCell::borrow_mut`] panics when the state is not [`BorrowState::Unused`].
+    #[logic]
+    fn borrow_state(self) -> BorrowState;
+}
+
+impl<T> RefCellExt for RefCell<T> {
+    #[trusted]
+    #[logic]
+    #[open(self)]
+    fn borrow_state(self) -> BorrowState {
+        dead
+    }
+}
+
+/// The value borrowed by the guard.
+///
+/// It does not change while the guard is alive, since the cell cannot be borrowed mutably.
+impl<'b, T> View for Ref<'b, T> {
+    type ViewTy = T;
+
+    #[logic]
+    #[trusted]
+    fn view(self) -> T {
+        dead
+    }
+}
+
+/// The value borrowed by the guard.
+///
+/// It only changes through the guard, which has unique access to the contents of the cell.
+impl<'b, T> View for RefMut<'b, T> {
+    type ViewTy = T;
+
+    #[logic]
+    #[trusted]
+    fn view(self) -> T {
+        dead
+    }
+}
+
+/// The property of the values of a [`PredCell`].
+pub trait CellInv<T> {
+    #[predicate]
+    fn cell_inv(x: T) -> bool;
+}
+
+/// A [`Cell`] whose values always satisfy the predicate `I`.
+///
+/// Like for a [`Cell`], the value of a `PredCell` is not known after it is changed through a
+/// shared reference. Instead, every value it holds satisfies [`I::cell_inv`](CellInv::cell_inv):
+/// storing a value requires it, and reading one ensures it.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{std::cell::*, *};
+/// struct Even;
+///
+/// impl CellInv<u32> for Even {
+///     #[predicate]
+///     #[open]
+///     fn cell_inv(x: u32) -> bool {
+///         pearlite! { x@ % 2 == 0 }
+///     }
+/// }
+///
+/// let c: PredCell<u32, Even> = PredCell::new(0);
+/// let r = &c;
+/// r.set(4);
+/// let x = r.get();
+/// proof_assert!(x@ % 2 == 0);
+/// ```
+pub struct PredCell<T, I> {
+    inner: Cell<T>,
+    inv: PhantomData<I>,
+}
+
+impl<T, I: CellInv<T>> PredCell<T, I> {
+    #[trusted]
+    #[requires(I::cell_inv(value))]
+    pub fn new(value: T) -> Self {
+        PredCell { inner: Cell::new(value), inv: PhantomData }
+    }
+
+    #[trusted]
+    #[ensures(I::cell_inv(result))]
+    pub fn get(&self) -> T
+    where
+        T: Copy,
+    {
+        self.inner.get()
+    }
+
+    #[trusted]
+    #[requires(I::cell_inv(value))]
+    pub fn set(&self, value: T) {
+        self.inner.set(value)
+    }
+
+    #[trusted]
+    #[requires(I::cell_inv(value))]
+    #[ensures(I::cell_inv(result))]
+    pub fn replace(&self, value: T) -> T {
+        self.inner.replace(value)
+    }
+
+    #[trusted]
+    #[ensures(I::cell_inv(result))]
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
@@ -1,5 +1,7 @@
 use crate::*;
 pub use ::std::char::*;
+#[cfg(creusot)]
+use ::std::convert::TryFrom;
 
 impl View for char {
     type ViewTy = Int;
@@ -27,6 +29,144 @@ extern_spec! {
     }
 }
 
+/// Is `n` a Unicode scalar value, i.e. the model of a `char`?
+///
+/// The scalar values are the code points, except the surrogates `0xD800..0xE000`.
+#[logic]
+#[open]
+pub fn is_scalar_value(n: Int) -> bool {
+    pearlite! { 0 <= n && n <= 0x10FFFF && !(0xD800 <= n && n < 0xE000) }
+}
+
+/// The model of a `char` is a Unicode scalar value.
+#[trusted]
+#[logic]
+#[open]
+#[ensures(forall<c: char> is_scalar_value(c@))]
+pub fn char_is_scalar_value() {}
+
+/// The value of `c` as a digit in base 36: `0-9`, then `a-z` or `A-Z` for `10-35`.
+#[logic]
+#[open]
+pub fn digit_value(c: char) -> Option<Int> {
+    pearlite! {
+        if 48 <= c@ && c@ <= 57 {
+            Some(c@ - 48)
+        } else if 97 <= c@ && c@ <= 122 {
+            Some(c@ - 87)
+        } else if 65 <= c@ && c@ <= 90 {
+            Some(c@ - 55)
+        } else {
+            None
+        }
+    }
+}
+
+extern_spec! {
+    impl char {
+        #[pure]
+        #[ensures(match result {
+            Some(c) => c@ == i@,
+            None => !is_scalar_value(i@),
+        })]
+        fn from_u32(i: u32) -> Option<char>;
+
+        #[pure]
+        #[requires(is_scalar_value(i@))]
+        #[ensures(result@ == i@)]
+        unsafe fn from_u32_unchecked(i: u32) -> char;
+
+        #[pure]
+        #[requires(2 <= radix@ && radix@ <= 36)]
+        #[ensures(match result {
+            Some(d) => digit_value(self) == Some(d@) && d@ < radix@,
+            None => forall<d: Int> digit_value(self) == Some(d) ==> radix@ <= d,
+        })]
+        fn to_digit(self, radix: u32) -> Option<u32>;
+
+        #[pure]
+        #[requires(2 <= radix@ && radix@ <= 36)]
+        #[ensures(result == exists<d: Int> digit_value(self) == Some(d) && d < radix@)]
+        fn is_digit(self, radix: u32) -> bool;
+
+        #[pure]
+        #[requires(2 <= radix@ && radix@ <= 36)]
+        #[ensures(match result {
+            Some(c) => digit_value(c) == Some(num@) && !(65 <= c@ && c@ <= 90),
+            None => radix@ <= num@,
+        })]
+        fn from_digit(num: u32, radix: u32) -> Option<char>;
+
+        #[pure]
+        #[ensures(result == (self@ < 128))]
+        fn is_ascii(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (48 <= self@ && self@ <= 57))]
+        fn is_ascii_digit(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (65 <= self@ && self@ <= 90))]
+        fn is_ascii_uppercase(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (97 <= self@ && self@ <= 122))]
+        fn is_ascii_lowercase(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (65 <= self@ && self@ <= 90 || 97 <= self@ && self@ <= 122))]
+        fn is_ascii_alphabetic(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (digit_value(*self) != None))]
+        fn is_ascii_alphanumeric(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == exists<d: Int> digit_value(*self) == Some(d) && d < 16)]
+        fn is_ascii_hexdigit(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (self@ == 9 || self@ == 10 || self@ == 12 || self@ == 13 || self@ == 32))]
+        fn is_ascii_whitespace(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == (self@ < 32 || self@ == 127))]
+        fn is_ascii_control(&self) -> bool;
+
+        #[pure]
+        #[ensures(if 97 <= self@ && self@ <= 122 { result@ == self@ - 32 } else { result == *self })]
+        fn to_ascii_uppercase(&self) -> char;
+
+        #[pure]
+        #[ensures(if 65 <= self@ && self@ <= 90 { result@ == self@ + 32 } else { result == *self })]
+        fn to_ascii_lowercase(&self) -> char;
+
+        #[pure]
+        #[ensures(result@ == self.to_utf8().len())]
+        fn len_utf8(self) -> usize;
+    }
+}
+
+extern_spec! {
+    impl TryFrom<u32> for char {
+        #[pure]
+        #[ensures(match result {
+            Ok(c) => c@ == i@,
+            Err(_) => !is_scalar_value(i@),
+        })]
+        fn try_from(i: u32) -> Result<char, CharTryFromError>;
+    }
+
+    impl TryFrom<char> for u8 {
+        #[pure]
+        #[ensures(match result {
+            Ok(b) => b@ == c@,
+            Err(_) => c@ >= 256,
+        })]
+        fn try_from(c: char) -> Result<u8, TryFromCharError>;
+    }
+}
+
 /// Extra methods for `char`
 pub trait CharExt {
     #[logic]
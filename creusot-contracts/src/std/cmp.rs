@@ -129,6 +129,32 @@ extern_spec! {
     }
 }
 
+/// The laws of [`Ord::cmp`]: it is a total order, and [`PartialOrd::partial_cmp`] agrees with it.
+///
+/// They follow from the specifications of `Ord` and `PartialOrd` when the [`OrdLogic`]
+/// implementation of the deep model is lawful. They are also laws of each local implementation of
+/// `Ord`, proved using the specifications of its `cmp` and `partial_cmp` methods, so that an
+/// implementation of `cmp` which is not a total order is reported at its impl. It cannot be called.
+#[trusted]
+#[logic]
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_ord_cmp_laws")]
+#[ensures(forall<o: Ordering> T::cmp.postcondition((x, x), o) ==> o == Ordering::Equal)]
+#[ensures(forall<y: &T, o: Ordering, p: Ordering>
+    T::cmp.postcondition((x, y), o) && T::cmp.postcondition((y, x), p) ==>
+        (o == Ordering::Less) == (p == Ordering::Greater)
+            && (o == Ordering::Equal) == (p == Ordering::Equal)
+)]
+#[ensures(forall<y: &T, z: &T, o: Ordering, p: Ordering>
+    T::cmp.postcondition((x, y), o) && T::cmp.postcondition((y, z), o)
+        && T::cmp.postcondition((x, z), p) ==> p == o
+)]
+#[ensures(forall<y: &T, o: Ordering, p: Option<Ordering>>
+    T::cmp.postcondition((x, y), o) && <T as PartialOrd>::partial_cmp.postcondition((x, y), p)
+        ==> p == Some(o)
+)]
+#[allow(unused_variables, dead_code)]
+pub(crate) fn ord_cmp_laws<T: Ord + ?Sized>(x: &T) {}
+
 impl<T: DeepModel> DeepModel for Reverse<T> {
     type DeepModelTy = Reverse<T::DeepModelTy>;
 
@@ -4,9 +4,11 @@ use crate::{
     *,
 };
 use ::std::{
+    borrow::Borrow,
     collections::hash_map::*,
     default::Default,
     hash::{BuildHasher, Hash},
+    ops::Index,
 };
 
 impl<K: DeepModel, V, S> View for HashMap<K, V, S> {
@@ -37,6 +39,17 @@ extern_spec! {
         }
     }
 
+    impl<K, Q: ?Sized, V, S> Index<&Q> for HashMap<K, V, S>
+    where
+        K: Eq + Hash + Borrow<Q> + DeepModel,
+        Q: Eq + Hash + DeepModel<DeepModelTy = K::DeepModelTy> + IsKey<K>,
+        S: BuildHasher,
+    {
+        #[requires(self@.contains(key.deep_model()))]
+        #[ensures(*result == self@[key.deep_model()])]
+        fn index(&self, key: &Q) -> &V;
+    }
+
     impl<K: DeepModel, V, S> IntoIterator for HashMap<K, V, S> {
         #[ensures(self@ == result@)]
         fn into_iter(self) -> IntoIter<K, V>;
@@ -55,6 +68,21 @@ extern_spec! {
     }
 }
 
+/// `Q: IsKey<K>` holds only for `Q = K`.
+///
+/// Indexing a [`HashMap`] by `&Q` is only specified for the key type itself: for another `Q`,
+/// the map finds the key using the `Eq` and `Hash` impls of `Q` and the `Borrow<Q>` impl of the
+/// keys, whose agreement with the deep models is not checked for the impls of other crates.
+pub trait IsKey<K: ?Sized>: sealed::Sealed<K> {}
+
+impl<K: ?Sized> IsKey<K> for K {}
+
+mod sealed {
+    pub trait Sealed<K: ?Sized> {}
+
+    impl<K: ?Sized> Sealed<K> for K {}
+}
+
 impl<K: DeepModel, V> View for IntoIter<K, V> {
     type ViewTy = FMap<K::DeepModelTy, V>;
 
@@ -1,6 +1,121 @@
 use crate::*;
 pub use ::std::convert::*;
 
+/// A conversion from `T` that does not lose information.
+///
+/// When a type implements both `From<T>` and `RoundTrip<T>`, Creusot checks that the contract of
+/// its `from` method determines the result to be [`from_logic(value)`](RoundTrip::from_logic).
+/// The [`round_trip`](RoundTrip::round_trip) law then states that the conversion can be undone.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::convert::RoundTrip};
+/// pub struct Wide(pub u16);
+///
+/// impl From<u8> for Wide {
+///     #[ensures(result.0@ == value@)]
+///     fn from(value: u8) -> Self {
+///         Wide(value as u16)
+///     }
+/// }
+///
+/// impl RoundTrip<u8> for Wide {
+///     #[logic]
+///     #[open]
+///     fn from_logic(value: u8) -> Self {
+///         Wide(value as u16)
+///     }
+///
+///     #[logic]
+///     #[open]
+///     fn back(self) -> u8 {
+///         self.0 as u8
+///     }
+///
+///     #[law]
+///     #[open(self)]
+///     #[ensures(Self::from_logic(value).back() == value)]
+///     fn round_trip(value: u8) {}
+/// }
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_round_trip")]
+pub trait RoundTrip<T>: Sized {
+    /// The value that `from` computes.
+    #[logic]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_round_trip_from_logic")]
+    fn from_logic(value: T) -> Self;
+
+    /// The inverse of [`from_logic`](RoundTrip::from_logic).
+    #[logic]
+    fn back(self) -> T;
+
+    #[law]
+    #[ensures(Self::from_logic(value).back() == value)]
+    fn round_trip(value: T);
+}
+
+/// A specification of a fallible conversion from `T`: when it succeeds, and what it returns.
+///
+/// When a type implements both `TryFrom<T>` and `TryFromSpec<T>`, Creusot checks that the
+/// contract of its `try_from` method implies [`returns(value, result)`](TryFromSpec::returns):
+/// the conversion fails exactly when [`fails`](TryFromSpec::fails) holds, and otherwise returns
+/// a result in relation with `value` by [`converts`](TryFromSpec::converts).
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::convert::TryFromSpec};
+/// # use std::convert::TryFrom;
+/// pub struct Percent(pub u8);
+///
+/// impl TryFrom<u8> for Percent {
+///     type Error = ();
+///
+///     #[ensures(match result { Ok(p) => p.0 == value, Err(_) => value@ > 100 })]
+///     fn try_from(value: u8) -> Result<Self, ()> {
+///         if value <= 100 { Ok(Percent(value)) } else { Err(()) }
+///     }
+/// }
+///
+/// impl TryFromSpec<u8> for Percent {
+///     #[predicate]
+///     #[open]
+///     fn fails(value: u8) -> bool {
+///         pearlite! { value@ > 100 }
+///     }
+///
+///     #[predicate]
+///     #[open]
+///     fn converts(value: u8, result: Self) -> bool {
+///         result.0 == value
+///     }
+/// }
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_try_from_spec")]
+pub trait TryFromSpec<T>: TryFrom<T> {
+    /// Does the conversion of `value` fail?
+    #[predicate]
+    fn fails(value: T) -> bool;
+
+    /// Is `result` a possible result of the successful conversion of `value`?
+    #[predicate]
+    fn converts(value: T, result: Self) -> bool;
+
+    /// `result` is the result of the conversion of `value`.
+    #[predicate]
+    #[open]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_try_from_spec_returns")]
+    fn returns(value: T, result: Result<Self, Self::Error>) -> bool {
+        pearlite! {
+            match result {
+                Ok(r) => !Self::fails(value) && Self::converts(value, r),
+                Err(_) => Self::fails(value),
+            }
+        }
+    }
+}
+
 extern_spec! {
     mod std {
         mod convert {
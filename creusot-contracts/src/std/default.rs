@@ -4,6 +4,41 @@ pub use ::std::default::*;
 #[cfg(creusot)]
 pub use creusot_contracts_proc::Default;
 
+/// A specification of the value built by [`Default::default`].
+///
+/// When a type implements both `Default` and `DefaultSpec`, Creusot checks that the contract of
+/// its `default` method implies [`is_default`](DefaultSpec::is_default) for the result. In any
+/// case, the result of `default` must satisfy the type invariant of the type.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::default::DefaultSpec};
+/// pub struct Counter(pub u32);
+///
+/// impl Default for Counter {
+///     #[ensures(result.0@ == 0)]
+///     fn default() -> Self {
+///         Counter(0)
+///     }
+/// }
+///
+/// impl DefaultSpec for Counter {
+///     #[predicate]
+///     #[open]
+///     fn is_default(self) -> bool {
+///         pearlite! { self.0@ == 0 }
+///     }
+/// }
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_default_spec")]
+pub trait DefaultSpec: Default {
+    /// Is `self` a value that `default` may return?
+    #[predicate]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_default_spec_is_default")]
+    fn is_default(self) -> bool;
+}
+
 extern_spec! {
     mod std {
         mod default {
@@ -71,6 +71,14 @@ impl<T> Resolve for VecDeque<T> {
     fn resolve_coherence(&self) {}
 }
 
+impl<T> FromIterator<T> for VecDeque<T> {
+    #[predicate]
+    #[open]
+    fn from_iter_post(prod: Seq<T>, res: Self) -> bool {
+        pearlite! { prod == res@ }
+    }
+}
+
 extern_spec! {
     mod std {
         mod collections {
@@ -1,11 +1,77 @@
 use crate::*;
 #[cfg(creusot)]
-use ::std::fmt::{Arguments, Debug, Formatter};
+use ::std::fmt::{Arguments, Debug, Write};
+use ::std::fmt::{Display, Formatter};
+
+/// The model of a [`Formatter`] is the sequence of characters written to it so far.
+///
+/// The output is only specified for successful writes: after an error, the formatter may have
+/// received any prefix of the data.
+impl View for Formatter<'_> {
+    type ViewTy = Seq<char>;
+
+    #[logic]
+    #[trusted]
+    fn view(self) -> Self::ViewTy {
+        dead
+    }
+}
+
+/// A specification of the text written by [`Display::fmt`].
+///
+/// When a type implements both `Display` and `DisplaySpec`, Creusot checks that the contract of
+/// its `fmt` method implies [`writes`](DisplaySpec::writes): if it succeeds, it appends
+/// [`displayed`](DisplaySpec::displayed) to the formatter.
+///
+/// This contract is only known to the callers of `fmt`: `to_string` and `format!` go through the
+/// formatting machinery of the standard library, which has no specification.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::fmt::DisplaySpec};
+/// use std::fmt::{self, Display, Formatter};
+///
+/// pub struct Name(pub String);
+///
+/// impl Display for Name {
+///     #[ensures(self.writes(f, result))]
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         f.write_str(&self.0)
+///     }
+/// }
+///
+/// impl DisplaySpec for Name {
+///     #[logic]
+///     #[open]
+///     fn displayed(&self) -> Seq<char> {
+///         pearlite! { self.0@ }
+///     }
+/// }
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_display_spec")]
+pub trait DisplaySpec: Display {
+    /// The characters written when displaying `self`.
+    #[logic]
+    fn displayed(&self) -> Seq<char>;
+
+    /// `result` is the result of displaying `self` in `f`: if it is a success, the characters
+    /// [`displayed`](DisplaySpec::displayed) are appended to the formatter.
+    #[predicate(prophetic)]
+    #[open]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_display_spec_writes")]
+    fn writes(&self, f: &mut Formatter<'_>, result: ::std::fmt::Result) -> bool {
+        pearlite! { result.is_ok() ==> (^f)@ == f@.concat(self.displayed()) }
+    }
+}
 
 extern_spec! {
     mod core {
         mod fmt {
             impl<'a> Formatter<'a> {
+                #[ensures(result.is_ok() ==> (^self)@ == self@.concat(data@))]
+                fn write_str(&mut self, data: &str) -> ::std::fmt::Result;
+
                 #[requires(true)]
                 fn debug_struct_field1_finish<'b>(
                     &'b mut self,
@@ -18,6 +84,16 @@ extern_spec! {
     }
 }
 
+extern_spec! {
+    impl<'a> Write for Formatter<'a> {
+        #[ensures(result.is_ok() ==> (^self)@ == self@.push_back(c))]
+        fn write_char(&mut self, c: char) -> ::std::fmt::Result;
+
+        #[ensures(result.is_ok() ==> (^self)@ == self@.concat(s@))]
+        fn write_str(&mut self, s: &str) -> ::std::fmt::Result;
+    }
+}
+
 extern_spec! {
     mod core {
         mod fmt {
@@ -0,0 +1,45 @@
+use crate::*;
+pub use ::std::hash::*;
+
+/// Logical model of a [`Hash`] implementation.
+///
+/// [`hash_log`](HashLogic::hash_log) is the data that [`Hash::hash`] feeds to the hasher: two
+/// values with the same `hash_log` have the same hash.
+///
+/// The law [`hash_eq_consistent`](HashLogic::hash_eq_consistent) states that values with the same
+/// deep model, such as values equal for the specification of [`PartialEq`], have the same hash.
+/// Each impl proves it.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{std::hash::HashLogic, *};
+/// #[derive(PartialEq, Eq, Hash, DeepModel)]
+/// struct Key(u32);
+///
+/// impl HashLogic for Key {
+///     type HashTy = Int;
+///
+///     #[logic]
+///     #[open]
+///     fn hash_log(self) -> Int {
+///         pearlite! { self.0@ }
+///     }
+///
+///     #[law]
+///     #[open(self)]
+///     #[ensures(a.deep_model() == b.deep_model() ==> a.hash_log() == b.hash_log())]
+///     fn hash_eq_consistent(a: Self, b: Self) {}
+/// }
+/// ```
+pub trait HashLogic: Hash + DeepModel {
+    type HashTy;
+
+    #[logic]
+    fn hash_log(self) -> Self::HashTy;
+
+    /// The law relating [`PartialEq`] and [`Hash`]: `a == b ==> hash(a) == hash(b)`.
+    #[law]
+    #[ensures(a.deep_model() == b.deep_model() ==> a.hash_log() == b.hash_log())]
+    fn hash_eq_consistent(a: Self, b: Self);
+}
@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{invariant::inv, *};
 pub use ::std::iter::*;
 
 mod cloned;
@@ -15,6 +15,7 @@ mod range;
 mod repeat;
 mod rev;
 mod skip;
+mod step_by;
 mod take;
 mod zip;
 
@@ -26,8 +27,10 @@ pub use filter_map::FilterMapExt;
 pub use fuse::FusedIterator;
 pub use map::MapExt;
 pub use map_inv::MapInv;
+pub use range::{range_contains, range_len};
 pub use rev::RevExt;
 pub use skip::SkipExt;
+pub use step_by::StepByExt;
 pub use take::TakeExt;
 pub use zip::ZipExt;
 
@@ -63,6 +66,53 @@ pub trait Iterator: ::std::iter::Iterator {
     {
         MapInv { iter: self, func, produced: snapshot! {Seq::EMPTY} }
     }
+
+    /// Like [`fold`](::std::iter::Iterator::fold), with an invariant `inv` relating the items
+    /// produced so far to the accumulator: `inv[prod][acc]`.
+    ///
+    /// The invariant must hold initially, and be preserved by every call of `func` on an item
+    /// produced by the iterator. It then holds for the result and all the items of the iterator.
+    #[requires(inv[Seq::EMPTY][init])]
+    #[requires(forall<prod: Seq<Self::Item>, i: Self, e: Self::Item, i2: Self, f: F, acc: B>
+        self.produces(prod, i) && i.produces(Seq::singleton(e), i2)
+        && func.hist_inv(f) && inv[prod][acc]
+        ==> f.precondition((acc, e)))]
+    #[requires(forall<prod: Seq<Self::Item>, i: Self, e: Self::Item, i2: Self, f: &mut F, acc: B, res: B>
+        self.produces(prod, i) && i.produces(Seq::singleton(e), i2)
+        && func.hist_inv(*f) && inv[prod][acc] && (*f).postcondition_mut((acc, e), ^f, res)
+        ==> inv[prod.push_back(e)][res])]
+    #[ensures(exists<done: &mut Self, prod: Seq<Self::Item>>
+        resolve(&^done) && done.completed() && self.produces(prod, *done) && inv[prod][result])]
+    fn fold_inv<B, F>(
+        mut self,
+        init: B,
+        inv: Snapshot<logic::Mapping<Seq<Self::Item>, logic::Mapping<B, bool>>>,
+        mut func: F,
+    ) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let old_self = snapshot! { self };
+        let old_func = snapshot! { func };
+        let mut prod: Snapshot<Seq<Self::Item>> = snapshot! { Seq::EMPTY };
+        let mut acc = init;
+        // `inv` is the parameter: the type invariants are named by their path.
+        #[invariant(crate::invariant::inv(self) && crate::invariant::inv(func))]
+        #[invariant(crate::invariant::inv(acc))]
+        #[invariant(old_self.produces(*prod, self))]
+        #[invariant(old_func.hist_inv(func))]
+        #[invariant(inv[*prod][acc])]
+        loop {
+            match self.next() {
+                Some(e) => {
+                    prod = snapshot! { prod.push_back(e) };
+                    acc = func(acc, e);
+                }
+                None => return acc,
+            }
+        }
+    }
 }
 
 pub trait FromIterator<A>: ::std::iter::FromIterator<A> {
@@ -70,6 +120,92 @@ pub trait FromIterator<A>: ::std::iter::FromIterator<A> {
     fn from_iter_post(prod: Seq<A>, res: Self) -> bool;
 }
 
+/// A collection whose view is the sequence of its items, to which
+/// [`extend`](::std::iter::Extend::extend) appends the items of the iterator.
+///
+/// When a type implements both `Extend<A>` and `ExtendSpec<A>`, Creusot checks that the contract
+/// of its `extend` method implies [`appended`](Appended::appended) for the collection before and
+/// after the call.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::iter::{Appended, ExtendSpec}};
+/// pub struct Stack(pub Vec<u32>);
+///
+/// impl View for Stack {
+///     type ViewTy = Seq<u32>;
+///
+///     #[logic]
+///     #[open]
+///     fn view(self) -> Seq<u32> {
+///         pearlite! { self.0@ }
+///     }
+/// }
+///
+/// impl Extend<u32> for Stack {
+///     #[trusted]
+///     #[ensures((*self).appended(iter, ^self))]
+///     fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+///         self.0.extend(iter)
+///     }
+/// }
+///
+/// impl ExtendSpec<u32> for Stack {}
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_extend_spec")]
+pub trait ExtendSpec<A>: ::std::iter::Extend<A> + View<ViewTy = Seq<A>> {}
+
+/// The specification of [`extend`](::std::iter::Extend::extend) for the types implementing
+/// [`ExtendSpec`].
+pub trait Appended<A>: View<ViewTy = Seq<A>> + Sized {
+    /// The view of `result` is the view of `self` followed by the items produced by iterating
+    /// over `iter` until its end.
+    ///
+    /// Its definition, [`appended_def`](Appended::appended_def), only holds when the iterator of
+    /// `I` implements [`Iterator`]. Unlike the definition, this predicate can be used in the
+    /// contract of a generic `extend` method, whose iterator type has no such bound.
+    #[predicate(prophetic)]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_appended")]
+    fn appended<I: IntoIterator<Item = A>>(self, iter: I, result: Self) -> bool;
+
+    #[law]
+    #[ensures(self.appended(iter, result) ==
+        exists<start: I::IntoIter, done: &mut I::IntoIter, prod: Seq<A>>
+            inv(start) && inv(done) && inv(prod) &&
+            I::into_iter.postcondition((iter,), start) &&
+            done.completed() && start.produces(prod, *done) && result@ == self@.concat(prod)
+    )]
+    fn appended_def<I>(self, iter: I, result: Self)
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: Iterator;
+}
+
+impl<C: View<ViewTy = Seq<A>>, A> Appended<A> for C {
+    #[predicate(prophetic)]
+    #[trusted]
+    #[allow(unused_variables)]
+    fn appended<I: IntoIterator<Item = A>>(self, iter: I, result: Self) -> bool {
+        dead
+    }
+
+    #[law]
+    #[trusted]
+    #[ensures(self.appended(iter, result) ==
+        exists<start: I::IntoIter, done: &mut I::IntoIter, prod: Seq<A>>
+            inv(start) && inv(done) && inv(prod) &&
+            I::into_iter.postcondition((iter,), start) &&
+            done.completed() && start.produces(prod, *done) && result@ == self@.concat(prod)
+    )]
+    fn appended_def<I>(self, iter: I, result: Self)
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: Iterator,
+    {
+    }
+}
+
 pub trait DoubleEndedIterator: ::std::iter::DoubleEndedIterator + Iterator {
     #[predicate(prophetic)]
     fn produces_back(self, visited: Seq<Self::Item>, o: Self) -> bool;
@@ -105,6 +241,11 @@ extern_spec! {
                 #[ensures(result.iter() == self && result.n() == n@)]
                 fn take(self, n: usize) -> Take<Self>;
 
+                #[pure]
+                #[requires(step@ != 0)]
+                #[ensures(result.iter() == self && result.step() == step@ && result.first_take())]
+                fn step_by(self, step: usize) -> StepBy<Self>;
+
                 #[pure]
                 #[ensures(result.iter() == self)]
                 fn cloned<'a, T>(self) -> Cloned<Self>
@@ -207,6 +348,7 @@ extern_spec! {
     }
 
     impl<I: Iterator> IntoIterator for I {
+        #[pure]
         #[ensures(result == self)]
         fn into_iter(self) -> I;
     }
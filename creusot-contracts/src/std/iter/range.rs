@@ -70,6 +70,50 @@ impl<Idx: DeepModel<DeepModelTy = Int> + Step> DoubleEndedIterator for Range<Idx
     fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
 
+// Stepping through a range terminates, so that `for` loops over ranges can be used in
+// `#[terminates]` functions.
+//
+// This spec needs the unstable trait `Step`, so it only exists with the `nightly` feature.
+// Without it, `next` has no postcondition: the variant inferred for `for i in a..b` (see
+// `range_len`) cannot be proved, and such loops need the `nightly` feature to be verified.
+#[cfg(feature = "nightly")]
+extern_spec! {
+    mod std {
+        mod ops {
+            impl<Idx: DeepModel<DeepModelTy = Int> + Step> Iterator for Range<Idx> {
+                #[pure]
+                #[ensures(match result {
+                    None => self.completed(),
+                    Some(v) => (*self).produces(Seq::singleton(v), ^self)
+                })]
+                fn next(&mut self) -> Option<Idx>;
+            }
+        }
+    }
+}
+
+/// The number of elements left in the range `r`, which may be negative if it is empty.
+///
+/// This is the variant inferred for the loops `for i in a..b`. Proving that it decreases
+/// relies on the spec of `Range::next`, which is only available with the `nightly` feature.
+#[logic]
+#[open]
+pub fn range_len<Idx: DeepModel<DeepModelTy = Int>>(r: Range<Idx>) -> Int {
+    pearlite! { r.end.deep_model() - r.start.deep_model() }
+}
+
+/// Whether `item` lies in the range `r`.
+///
+/// In the loops `for i in a..b`, this is assumed of each element `i`: it follows from the
+/// `produces` invariant of the loop, by the model of `Range` as an iterator above.
+#[logic]
+#[open]
+pub fn range_contains<Idx: DeepModel<DeepModelTy = Int>>(r: Range<Idx>, item: Idx) -> bool {
+    pearlite! {
+        r.start.deep_model() <= item.deep_model() && item.deep_model() < r.end.deep_model()
+    }
+}
+
 #[logic]
 #[open]
 #[ensures(r.is_empty_log() == (result == 0))]
@@ -143,6 +187,8 @@ impl<Idx: DeepModel<DeepModelTy = Int> + Step> DoubleEndedIterator for RangeIncl
 }
 
 /// Dummy impls that don't use the unstable trait Step
+///
+/// They give no spec to `next`, so loops over ranges cannot be proved to terminate.
 #[cfg(not(feature = "nightly"))]
 macro_rules! impl_range {
     ( $( $ty:tt ),+ ) => {
@@ -0,0 +1,117 @@
+use crate::{resolve::structural_resolve, std::iter::StepBy, *};
+
+pub trait StepByExt<I> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn step(self) -> Int;
+
+    /// Whether the next element is the next one of the underlying iterator: this is the case
+    /// before the first call to `next`. Otherwise, `step() - 1` elements are skipped first.
+    #[logic]
+    fn first_take(self) -> bool;
+
+    /// The number of elements of the underlying iterator skipped before the next element.
+    #[logic]
+    fn skipped(self) -> Int;
+}
+
+impl<I> StepByExt<I> for StepBy<I> {
+    #[logic]
+    #[trusted]
+    #[ensures(inv(self) ==> inv(result))]
+    fn iter(self) -> I {
+        dead
+    }
+
+    #[logic]
+    #[trusted]
+    #[ensures(result >= 1 && result <= usize::MAX@)]
+    fn step(self) -> Int {
+        dead
+    }
+
+    #[logic]
+    #[trusted]
+    fn first_take(self) -> bool {
+        dead
+    }
+
+    #[logic]
+    #[open]
+    fn skipped(self) -> Int {
+        pearlite! { if self.first_take() { 0 } else { self.step() - 1 } }
+    }
+}
+
+impl<I> Resolve for StepBy<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            resolve(&self.iter())
+        }
+    }
+
+    #[trusted]
+    #[logic(prophetic)]
+    #[requires(structural_resolve(self))]
+    #[ensures((*self).resolve())]
+    fn resolve_coherence(&self) {}
+}
+
+impl<I: Iterator> Iterator for StepBy<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (^self).step() == (*self).step() &&
+            exists<s: Seq<Self::Item>, i: &mut I>
+                   s.len() <= (*self).skipped()
+                && self.iter().produces(s, *i)
+                && (forall<i: Int> 0 <= i && i < s.len() ==> resolve(&s[i]))
+                && i.completed()
+                && ^i == (^self).iter()
+        }
+    }
+
+    // The elements produced are those of the underlying iterator at the indices
+    // `skipped() + j * step()`, the others are dropped.
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            o.step() == self.step() && !o.first_take() && visited.len() > 0 &&
+            exists<s: Seq<Self::Item>>
+                   s.len() == self.skipped() + (visited.len() - 1) * self.step() + 1
+                && self.iter().produces(s, o.iter())
+                && (forall<j: Int> 0 <= j && j < visited.len() ==>
+                        visited[j] == s[self.skipped() + j * self.step()])
+                && (forall<i: Int> 0 <= i && i < s.len() ==>
+                        (exists<j: Int> 0 <= j && j < visited.len() && i == self.skipped() + j * self.step())
+                        || resolve(&s[i]))
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    // Trusted: the solvers fail to prove it from the definition of `produces`. The witness for `ac`
+    // is the concatenation `s_ab.concat(s_bc)` of the witnesses for `ab` and `bc` (the case where
+    // either is empty is trivial), and since `b` is not at its first call,
+    // `skipped(b) == step - 1`. The element `j` of `bc` is then at index
+    // `skipped(a) + (ab.len() + j) * step` of the concatenation, which needs distributing a
+    // product of two variables, and the dropped elements of `s_bc` need the witness
+    // `ab.len() + j` of the existential. A proof would state both facts as auxiliary lemmas.
+    #[law]
+    #[trusted]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
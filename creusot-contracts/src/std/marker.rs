@@ -0,0 +1,23 @@
+use crate::*;
+pub use ::std::marker::*;
+
+/// `PhantomData` holds no value, so its model is `()`, whatever its parameter.
+impl<T: ?Sized> DeepModel for PhantomData<T> {
+    type DeepModelTy = ();
+
+    #[logic]
+    #[open]
+    fn deep_model(self) -> Self::DeepModelTy {
+        pearlite! { () }
+    }
+}
+
+impl<T: ?Sized> View for PhantomData<T> {
+    type ViewTy = ();
+
+    #[logic]
+    #[open]
+    fn view(self) -> Self::ViewTy {
+        pearlite! { () }
+    }
+}
@@ -28,8 +28,9 @@ extern_spec! {
             #[ensures(resolve(&_x))]
             fn drop<T>(_x: T) {}
 
+            // The destructor of `t` is not run, so it is not resolved: e.g. a forgotten `Drain`
+            // does not give back the tail of its vector.
             #[pure]
-            #[ensures(resolve(&t))]
             fn forget<T>(t: T) {}
         }
     }
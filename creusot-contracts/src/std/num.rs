@@ -1,7 +1,12 @@
 use crate::{
-    logic::ops::{AddLogic, MulLogic, NegLogic, SubLogic},
+    logic::{
+        Popcount,
+        ops::{AddLogic, MulLogic, NegLogic, SubLogic},
+    },
     *,
 };
+#[cfg(creusot)]
+use ::std::convert::TryFrom;
 pub use ::std::num::*;
 
 macro_rules! mach_int {
@@ -120,6 +125,31 @@ macro_rules! spec_type {
                 #[pure]
                 #[ensures(result == -self)]
                 fn wrapping_neg(self) -> $type;
+
+                #[allow(dead_code)]
+                #[pure]
+                // Returns `None` iff the negation is out of range
+                #[ensures((result == None) == (-self@ < $type::MIN@ || -self@ > $type::MAX@))]
+                // Else, returns the negation
+                #[ensures(forall<r: $type> result == Some(r) ==> r@ == -self@)]
+                fn checked_neg(self) -> Option<$type>;
+
+                #[allow(dead_code)]
+                #[pure]
+                // Panics if the result is out of range
+                #[requires($type::MIN@ <= self@.pow(exp@) && self@.pow(exp@) <= $type::MAX@)]
+                #[ensures(result@ == self@.pow(exp@))]
+                fn pow(self, exp: u32) -> $type;
+
+                #[allow(dead_code)]
+                #[pure]
+                // Returns `None` iff the result is out of range
+                #[ensures(
+                    (result == None) == (self@.pow(exp@) < $type::MIN@ || self@.pow(exp@) > $type::MAX@)
+                )]
+                // Else, returns the power
+                #[ensures(forall<r: $type> result == Some(r) ==> r@ == self@.pow(exp@))]
+                fn checked_pow(self, exp: u32) -> Option<$type>;
             }
         }
 
@@ -167,6 +197,14 @@ macro_rules! spec_type {
                 // Overflow only occurs when computing `$type::MIN / -1`
                 #[ensures(result.1 == (self@ == $type::MIN@ && rhs@ == -1))]
                 fn overflowing_div(self, rhs: $type) -> ($type, bool);
+
+                #[allow(dead_code)]
+                #[pure]
+                // Returns `None` iff the divisor is zero or the division overflows
+                #[ensures((result == None) == (rhs@ == 0 || (self@ == $type::MIN@ && rhs@ == -1)))]
+                // Else, returns the remainder of the division
+                #[ensures(forall<r: $type> result == Some(r) ==> r@ == self@ % rhs@)]
+                fn checked_rem(self, rhs: $type) -> Option<$type>;
             }
         }
     };
@@ -267,6 +305,55 @@ macro_rules! spec_abs_diff {
     };
 }
 
+/// Adds specifications for the abs operation on the given signed integer type
+macro_rules! spec_abs {
+    ($type:ty) => {
+        extern_spec! {
+            impl $type {
+                #[allow(dead_code)]
+                #[pure]
+                #[requires(self@ != $type::MIN@)]
+                #[ensures(result@ == self@.abs())]
+                fn abs(self) -> $type;
+            }
+        }
+    };
+}
+
+/// Adds specifications for the count_ones operation on the given unsigned integer type
+macro_rules! spec_count_ones {
+    ($type:ty) => {
+        extern_spec! {
+            impl $type {
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result@ == self.popcount())]
+                fn count_ones(self) -> u32;
+            }
+        }
+    };
+}
+
+/// Adds specifications for the fallible conversions to `$target` from each of the given integer
+/// types: the conversion succeeds iff the value is in the range of `$target`.
+macro_rules! spec_try_from {
+    ($target:ty : $($source:ty)*) => {
+        $(
+            extern_spec! {
+                impl TryFrom<$source> for $target {
+                    #[allow(dead_code)]
+                    #[pure]
+                    #[ensures(match result {
+                        Ok(r) => r@ == value@,
+                        Err(_) => value@ < $target::MIN@ || value@ > $target::MAX@,
+                    })]
+                    fn try_from(value: $source) -> Result<$target, TryFromIntError>;
+                }
+            }
+        )*
+    };
+}
+
 spec_type!(u8);
 spec_type!(u16);
 spec_type!(u32);
@@ -287,3 +374,30 @@ spec_abs_diff!(u32, i32);
 spec_abs_diff!(u64, i64);
 spec_abs_diff!(u128, i128);
 spec_abs_diff!(usize, isize);
+
+spec_abs!(i8);
+spec_abs!(i16);
+spec_abs!(i32);
+spec_abs!(i64);
+spec_abs!(i128);
+spec_abs!(isize);
+
+spec_count_ones!(u8);
+spec_count_ones!(u16);
+spec_count_ones!(u32);
+spec_count_ones!(u64);
+spec_count_ones!(u128);
+spec_count_ones!(usize);
+
+// The conversions between fixed-width integers that are not lossless, for which there is thus no
+// `From` impl.
+spec_try_from!(u8: u16 u32 u64 u128 i8 i16 i32 i64 i128);
+spec_try_from!(u16: u32 u64 u128 i8 i16 i32 i64 i128);
+spec_try_from!(u32: u64 u128 i8 i16 i32 i64 i128);
+spec_try_from!(u64: u128 i8 i16 i32 i64 i128);
+spec_try_from!(u128: i8 i16 i32 i64 i128);
+spec_try_from!(i8: u8 u16 u32 u64 u128 i16 i32 i64 i128);
+spec_try_from!(i16: u16 u32 u64 u128 i32 i64 i128);
+spec_try_from!(i32: u32 u64 u128 i64 i128);
+spec_try_from!(i64: u64 u128 i128);
+spec_try_from!(i128: u128);
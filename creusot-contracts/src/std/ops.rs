@@ -8,6 +8,157 @@ pub use ::std::ops::*;
 // method is an exception being used both as a logic function and as a program
 // function. See #1235.
 
+/// A specification of the target of a smart pointer, for [`Deref::deref`] and
+/// [`DerefMut::deref_mut`].
+///
+/// When a type implements both `Deref` and `DerefSpec`, Creusot checks that the contract of its
+/// `deref` method implies `*result == (*self).target()`. If it also implements `DerefMut`, the
+/// contract of `deref_mut` must imply `*result == (*self).target()` and
+/// `^result == (^self).target()`: the changes made through the returned reference are changes of
+/// the target of the pointer.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::ops::{Deref, DerefSpec}};
+/// pub struct Wrapper(pub u32);
+///
+/// impl Deref for Wrapper {
+///     type Target = u32;
+///
+///     #[ensures(*result == self.0)]
+///     fn deref(&self) -> &u32 {
+///         &self.0
+///     }
+/// }
+///
+/// impl DerefSpec for Wrapper {
+///     #[logic]
+///     #[open]
+///     fn target(self) -> u32 {
+///         self.0
+///     }
+/// }
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_deref_spec")]
+pub trait DerefSpec: Deref {
+    /// The value that the pointer `self` points to.
+    #[logic]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_deref_spec_target")]
+    fn target(self) -> Self::Target
+    where
+        Self::Target: Sized;
+}
+
+/// A logical model of [`Add::add`], to state the algebraic laws of an addition.
+///
+/// When a type implements both `Add` and `AddSpec`, Creusot checks that the contract of its
+/// `add` method implies `result == self.add_logic(rhs)`. The laws are then stated on
+/// `add_logic`, by implementing [`AddCommutative`], [`AddAssociative`] or [`AddIdentity`]: each law
+/// is an obligation of the implementation.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::{*, std::ops::{Add, AddCommutative, AddSpec}};
+/// #[derive(Clone, Copy)]
+/// pub struct Max(pub u32);
+///
+/// impl Add for Max {
+///     type Output = Max;
+///
+///     #[ensures(result == self.add_logic(rhs))]
+///     fn add(self, rhs: Self) -> Self {
+///         if self.0 >= rhs.0 { self } else { rhs }
+///     }
+/// }
+///
+/// impl AddSpec for Max {
+///     #[logic]
+///     #[open]
+///     fn add_logic(self, rhs: Self) -> Self {
+///         pearlite! { if self.0@ >= rhs.0@ { self } else { rhs } }
+///     }
+/// }
+///
+/// impl AddCommutative for Max {
+///     #[law]
+///     #[open(self)]
+///     #[ensures(a.add_logic(b) == b.add_logic(a))]
+///     fn add_commutative(a: Self, b: Self) {}
+/// }
+/// ```
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_add_spec")]
+pub trait AddSpec: Add<Output = Self> + Sized {
+    /// The sum of `self` and `rhs`.
+    #[logic]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_add_spec_add_logic")]
+    fn add_logic(self, rhs: Self) -> Self;
+}
+
+/// The addition of the type is commutative.
+pub trait AddCommutative: AddSpec {
+    #[law]
+    #[ensures(a.add_logic(b) == b.add_logic(a))]
+    fn add_commutative(a: Self, b: Self);
+}
+
+/// The addition of the type is associative.
+pub trait AddAssociative: AddSpec {
+    #[law]
+    #[ensures(a.add_logic(b).add_logic(c) == a.add_logic(b.add_logic(c)))]
+    fn add_associative(a: Self, b: Self, c: Self);
+}
+
+/// The addition of the type has an identity element.
+pub trait AddIdentity: AddSpec {
+    /// The identity element of the addition.
+    #[logic]
+    fn zero() -> Self;
+
+    #[law]
+    #[ensures(a.add_logic(Self::zero()) == a && Self::zero().add_logic(a) == a)]
+    fn add_identity(a: Self);
+}
+
+/// A logical model of [`Mul::mul`], to state the algebraic laws of a multiplication.
+///
+/// This is the counterpart of [`AddSpec`] for [`Mul`]: the contract of `mul` must imply
+/// `result == self.mul_logic(rhs)`, and the laws are stated by implementing [`MulCommutative`],
+/// [`MulAssociative`] or [`MulIdentity`].
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_mul_spec")]
+pub trait MulSpec: Mul<Output = Self> + Sized {
+    /// The product of `self` and `rhs`.
+    #[logic]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_mul_spec_mul_logic")]
+    fn mul_logic(self, rhs: Self) -> Self;
+}
+
+/// The multiplication of the type is commutative.
+pub trait MulCommutative: MulSpec {
+    #[law]
+    #[ensures(a.mul_logic(b) == b.mul_logic(a))]
+    fn mul_commutative(a: Self, b: Self);
+}
+
+/// The multiplication of the type is associative.
+pub trait MulAssociative: MulSpec {
+    #[law]
+    #[ensures(a.mul_logic(b).mul_logic(c) == a.mul_logic(b.mul_logic(c)))]
+    fn mul_associative(a: Self, b: Self, c: Self);
+}
+
+/// The multiplication of the type has an identity element.
+pub trait MulIdentity: MulSpec {
+    /// The identity element of the multiplication.
+    #[logic]
+    fn one() -> Self;
+
+    #[law]
+    #[ensures(a.mul_logic(Self::one()) == a && Self::one().mul_logic(a) == a)]
+    fn mul_identity(a: Self);
+}
+
 /// `FnOnceExt` is an extension trait for the `FnOnce` trait, used for
 /// adding a specification to closures. It should not be used directly.
 #[cfg(feature = "nightly")]
@@ -74,6 +225,111 @@ pub trait FnExt<Args: Tuple>: FnMutExt<Args> {
     fn fn_hist_inv(self, res_state: Self);
 }
 
+/// The call of a closure `f` to some arguments, built by [`call_spec!`](crate::call_spec), to
+/// refer to the specification of `f` in a contract.
+#[cfg(feature = "nightly")]
+pub struct CallSpec<F, Args> {
+    pub f: F,
+    pub args: Args,
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, F: FnExt<Args>> CallSpec<F, Args> {
+    /// The precondition of `f` holds for the arguments.
+    #[predicate(prophetic)]
+    #[open]
+    pub fn pre(self) -> bool {
+        pearlite! { self.f.precondition(self.args) }
+    }
+
+    /// The postcondition of `f` holds for the arguments and `result`.
+    #[predicate]
+    #[open]
+    pub fn holds(self, result: <F as FnOnceExt<Args>>::Output) -> bool {
+        pearlite! { self.f.postcondition(self.args, result) }
+    }
+}
+
+/// The call of the closure `f` to the arguments `a, b, ...` in a specification, written
+/// `call_spec!(f, a, b, ...)`.
+///
+/// This gives access to the specification of a closure received as argument, e.g. in the
+/// contract of a function applying it:
+///
+/// ```ignore
+/// #[requires(call_spec!(f, x).pre())]
+/// #[ensures(call_spec!(f, x).holds(result))]
+/// fn apply<F: Fn(u32) -> u32>(f: F, x: u32) -> u32 { f(x) }
+/// ```
+///
+/// As for [`seq!`](crate::seq), the arguments are Rust expressions.
+#[macro_export]
+macro_rules! call_spec {
+    ($f:expr $(, $args:expr)* $(,)?) => {
+        creusot_contracts::std::ops::CallSpec { f: $f, args: ($($args,)*) }
+    };
+}
+
+/// The current value of the variable `var` captured by the closure `f`.
+///
+/// This gives access to the state of a closure between its calls, e.g. in a loop invariant:
+///
+/// ```ignore
+/// let mut count = 0u32;
+/// let mut c = #[requires(count@ < 100)] #[ensures(count@ == (old(count)@ + 1))] || count += 1;
+/// #[invariant(captured(c, count)@ == i@)]
+/// while i < 100 { c(); i += 1 }
+/// ```
+///
+/// The closure must be defined in the current crate, and `var` must be a variable that it
+/// captures, which is then seen through the reference held by the closure if it is captured by
+/// reference.
+#[cfg(feature = "nightly")]
+#[logic]
+#[open]
+#[allow(unused_variables)]
+#[rustc_diagnostic_item = "closure_captured"]
+pub fn captured<F, T>(f: F, var: T) -> T {
+    var /* Dummy */
+}
+
+/// The function pointer to `f`, as created by the coercion `let p: fn(u32) -> u32 = f;`.
+///
+/// Creusot translates such coercions into calls to this function: a function pointer is an
+/// opaque value, specified by [`FnExt`] like a closure received as argument. Applying it, with
+/// `call_spec!(p, x)` in a specification or with `p(x)` in a program, follows the contract of
+/// `f`. Two pointers are equal if they are the same value; nothing is known about the equality of
+/// pointers created separately, even to the same function.
+///
+/// It should not be called directly.
+#[cfg(feature = "nightly")]
+#[trusted]
+#[allow(unused_variables)]
+#[rustc_diagnostic_item = "fn_ptr_of"]
+#[ensures(forall<args: Args> result.precondition(args) == f.precondition(args))]
+#[ensures(forall<args: Args, res: <F as FnOnce<Args>>::Output>
+    result.postcondition(args, res) == f.postcondition(args, res))]
+pub fn fn_ptr_of<Args: Tuple, F: Fn<Args>, P: Fn<Args, Output = F::Output>>(f: F) -> P {
+    panic!()
+}
+
+/// The pointer to a trait object created by an unsizing coercion, e.g. `let p: &dyn Tr = &x;`.
+///
+/// Creusot translates such coercions into calls to this function: `T` is the type of the pointer
+/// to the concrete value, and `U` the type of the pointer to the trait object. The trait object is
+/// opaque: methods called on it follow the contracts of its trait, which the method of the
+/// concrete type refines. Like for any call, the type invariant of `x` must hold, and the one of
+/// the trait object is then known to hold.
+///
+/// It should not be called directly.
+#[cfg(feature = "nightly")]
+#[trusted]
+#[allow(unused_variables)]
+#[rustc_diagnostic_item = "creusot_unsize"]
+pub fn unsize<T, U>(x: T) -> U {
+    panic!()
+}
+
 #[cfg(feature = "nightly")]
 impl<Args: Tuple, F: FnOnce<Args>> FnOnceExt<Args> for F {
     type Output = <Self as FnOnce<Args>>::Output;
@@ -188,6 +444,47 @@ extern_spec! {
     }
 }
 
+/// Logical operations on [`Range`].
+pub trait RangeExt<Idx> {
+    /// Whether `item` lies in the range, as tested by [`Range::contains`].
+    #[logic]
+    fn contains_log(self, item: Idx) -> bool
+    where
+        Idx: DeepModel,
+        Idx::DeepModelTy: OrdLogic;
+}
+
+impl<Idx> RangeExt<Idx> for Range<Idx> {
+    #[logic]
+    #[open]
+    fn contains_log(self, item: Idx) -> bool
+    where
+        Idx: DeepModel,
+        Idx::DeepModelTy: OrdLogic,
+    {
+        pearlite! {
+            self.start.deep_model() <= item.deep_model() && item.deep_model() < self.end.deep_model()
+        }
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod ops {
+            impl<Idx : PartialOrd<Idx> + DeepModel> Range<Idx>
+            where Idx::DeepModelTy: OrdLogic
+            {
+                #[pure]
+                #[ensures(result == (self.start.deep_model() <= item.deep_model() && item.deep_model() < self.end.deep_model()))]
+                fn contains<U>(&self, item: &U) -> bool
+                where
+                    Idx: PartialOrd<U>,
+                    U: ?Sized + PartialOrd<Idx> + DeepModel<DeepModelTy = Idx::DeepModelTy>;
+            }
+        }
+    }
+}
+
 pub trait RangeInclusiveExt<Idx> {
     #[logic]
     fn start_log(self) -> Idx;
@@ -200,6 +497,13 @@ pub trait RangeInclusiveExt<Idx> {
     where
         Idx: DeepModel,
         Idx::DeepModelTy: OrdLogic;
+
+    /// Whether `item` lies in the range, as tested by [`RangeInclusive::contains`].
+    #[logic]
+    fn contains_log(self, item: Idx) -> bool
+    where
+        Idx: DeepModel,
+        Idx::DeepModelTy: OrdLogic;
 }
 
 impl<Idx> RangeInclusiveExt<Idx> for RangeInclusive<Idx> {
@@ -225,6 +529,20 @@ impl<Idx> RangeInclusiveExt<Idx> for RangeInclusive<Idx> {
     {
         dead
     }
+
+    #[logic]
+    #[open]
+    fn contains_log(self, item: Idx) -> bool
+    where
+        Idx: DeepModel,
+        Idx::DeepModelTy: OrdLogic,
+    {
+        pearlite! {
+            !self.is_empty_log()
+            && self.start_log().deep_model() <= item.deep_model()
+            && item.deep_model() <= self.end_log().deep_model()
+        }
+    }
 }
 
 extern_spec! {
@@ -249,11 +567,102 @@ extern_spec! {
             {
                 #[ensures(result == self.is_empty_log())]
                 fn is_empty(&self) -> bool;
+
+                #[pure]
+                #[ensures(result == (
+                    !self.is_empty_log()
+                    && self.start_log().deep_model() <= item.deep_model()
+                    && item.deep_model() <= self.end_log().deep_model()
+                ))]
+                fn contains<U>(&self, item: &U) -> bool
+                where
+                    Idx: PartialOrd<U>,
+                    U: ?Sized + PartialOrd<Idx> + DeepModel<DeepModelTy = Idx::DeepModelTy>;
             }
         }
     }
 }
 
+/// The indices covered by a range of `usize`, as given to [`Vec::drain`]: the range
+/// `start_index(len)..end_index(len)` of a sequence of length `len`.
+pub trait RangeBoundsExt {
+    #[logic]
+    fn start_index(self, len: Int) -> Int;
+
+    #[logic]
+    fn end_index(self, len: Int) -> Int;
+}
+
+impl RangeBoundsExt for Range<usize> {
+    #[logic]
+    #[open]
+    fn start_index(self, _len: Int) -> Int {
+        pearlite! { self.start@ }
+    }
+
+    #[logic]
+    #[open]
+    fn end_index(self, _len: Int) -> Int {
+        pearlite! { self.end@ }
+    }
+}
+
+impl RangeBoundsExt for RangeFrom<usize> {
+    #[logic]
+    #[open]
+    fn start_index(self, _len: Int) -> Int {
+        pearlite! { self.start@ }
+    }
+
+    #[logic]
+    #[open]
+    fn end_index(self, len: Int) -> Int {
+        len
+    }
+}
+
+impl RangeBoundsExt for RangeTo<usize> {
+    #[logic]
+    #[open]
+    fn start_index(self, _len: Int) -> Int {
+        pearlite! { 0 }
+    }
+
+    #[logic]
+    #[open]
+    fn end_index(self, _len: Int) -> Int {
+        pearlite! { self.end@ }
+    }
+}
+
+impl RangeBoundsExt for RangeToInclusive<usize> {
+    #[logic]
+    #[open]
+    fn start_index(self, _len: Int) -> Int {
+        pearlite! { 0 }
+    }
+
+    #[logic]
+    #[open]
+    fn end_index(self, _len: Int) -> Int {
+        pearlite! { self.end@ + 1 }
+    }
+}
+
+impl RangeBoundsExt for RangeFull {
+    #[logic]
+    #[open]
+    fn start_index(self, _len: Int) -> Int {
+        pearlite! { 0 }
+    }
+
+    #[logic]
+    #[open]
+    fn end_index(self, len: Int) -> Int {
+        len
+    }
+}
+
 extern_spec! {
     mod std {
         mod ops {
@@ -322,6 +731,11 @@ extern_spec! {
             }
 
             impl<T, E, F: From<E>> FromResidual<Result<Infallible, E>> for Result<T, F> {
+                // The error is propagated by `?` through `F::from`, which must be callable on it.
+                #[requires(match residual {
+                    Err(residual) => F::from.precondition((residual,)),
+                    _ => true,
+                })]
                 #[ensures(match (result, residual) {
                    (Err(result), Err(residual)) => F::from.postcondition((residual,), result),
                     _ => false,
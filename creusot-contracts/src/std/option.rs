@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{logic::Mapping, *};
 use ::std::cmp::Ordering;
 pub use ::std::option::*;
 
@@ -37,14 +37,9 @@ extern_spec! {
     mod std {
         mod option {
             impl<T> Option<T> {
-                #[pure]
+                #[pure(logic)]
                 #[ensures(result == (*self != None))]
-                fn is_some(&self) -> bool {
-                    match self {
-                        None => false,
-                        Some(_) => true,
-                    }
-                }
+                fn is_some(&self) -> bool;
 
                 #[requires(match self {
                     None => true,
@@ -61,14 +56,9 @@ extern_spec! {
                     }
                 }
 
-                #[pure]
+                #[pure(logic)]
                 #[ensures(result == (*self == None))]
-                fn is_none(&self) -> bool {
-                    match self {
-                        None => true,
-                        Some(_) => false,
-                    }
-                }
+                fn is_none(&self) -> bool;
 
                 #[pure]
                 #[ensures(*self == None ==> result == None)]
@@ -145,6 +135,7 @@ extern_spec! {
                 #[pure]
                 #[ensures(self == None ==> result == default)]
                 #[ensures(self == None || (self == Some(result) && resolve(&default)))]
+                #[ensures(result == self.unwrap_or_logic(default))]
                 fn unwrap_or(self, default: T) -> T {
                     match self {
                         None => default,
@@ -195,6 +186,10 @@ extern_spec! {
                     None => result == None,
                     Some(t) => exists<r: _> result == Some(r) && f.postcondition_once((t,), r),
                 })]
+                #[ensures(forall<m: Mapping<T, U>>
+                    (forall<t: T, r: U> f.postcondition_once((t,), r) ==> m.get(t) == r) ==>
+                    result == self.map_logic(m)
+                )]
                 fn map<U, F>(self, f: F) -> Option<U>
                 where
                     F: FnOnce(T) -> U {
@@ -301,6 +296,10 @@ extern_spec! {
                     None => result == None,
                     Some(t) => f.postcondition_once((t,), result),
                 })]
+                #[ensures(forall<m: Mapping<T, Option<U>>>
+                    (forall<t: T, r: Option<U>> f.postcondition_once((t,), r) ==> m.get(t) == r) ==>
+                    result == self.and_then_logic(m)
+                )]
                 fn and_then<U, F>(self, f: F) -> Option<U>
                 where
                     F: FnOnce(T) -> Option<U> {
@@ -598,6 +597,87 @@ impl<T: OrdLogic> OrdLogic for Option<T> {
     ord_laws_impl! {}
 }
 
+/// Logical counterparts of the combinators of `Option`.
+///
+/// The extern specs of `map` and `and_then` relate their result to these functions, for a mapping
+/// which agrees with every possible result of the closure (e.g. a closure whose postcondition
+/// determines its result).
+///
+/// The accessors `is_some` and `is_none` are logic functions themselves, see their `#[pure(logic)]`
+/// extern specs.
+pub trait OptionExt<T> {
+    #[logic]
+    fn map_logic<U>(self, f: Mapping<T, U>) -> Option<U>;
+
+    #[logic]
+    fn and_then_logic<U>(self, f: Mapping<T, Option<U>>) -> Option<U>;
+
+    #[logic]
+    fn unwrap_or_logic(self, default: T) -> T;
+
+    #[law]
+    #[ensures(Some(x).map_logic(f) == Some(f.get(x)))]
+    #[ensures(None.map_logic(f) == None)]
+    fn map_logic_def<U>(x: T, f: Mapping<T, U>);
+
+    #[law]
+    #[ensures(Some(x).and_then_logic(f) == f.get(x))]
+    #[ensures(None.and_then_logic(f) == None)]
+    fn and_then_logic_def<U>(x: T, f: Mapping<T, Option<U>>);
+
+    #[law]
+    #[ensures(Some(x).unwrap_or_logic(default) == x)]
+    #[ensures(None.unwrap_or_logic(default) == default)]
+    fn unwrap_or_logic_def(x: T, default: T);
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[logic]
+    #[open]
+    fn map_logic<U>(self, f: Mapping<T, U>) -> Option<U> {
+        match self {
+            None => None,
+            Some(x) => Some(f.get(x)),
+        }
+    }
+
+    #[logic]
+    #[open]
+    fn and_then_logic<U>(self, f: Mapping<T, Option<U>>) -> Option<U> {
+        match self {
+            None => None,
+            Some(x) => f.get(x),
+        }
+    }
+
+    #[logic]
+    #[open]
+    fn unwrap_or_logic(self, default: T) -> T {
+        match self {
+            None => default,
+            Some(x) => x,
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(Some(x).map_logic(f) == Some(f.get(x)))]
+    #[ensures(None.map_logic(f) == None)]
+    fn map_logic_def<U>(x: T, f: Mapping<T, U>) {}
+
+    #[law]
+    #[open(self)]
+    #[ensures(Some(x).and_then_logic(f) == f.get(x))]
+    #[ensures(None.and_then_logic(f) == None)]
+    fn and_then_logic_def<U>(x: T, f: Mapping<T, Option<U>>) {}
+
+    #[law]
+    #[open(self)]
+    #[ensures(Some(x).unwrap_or_logic(default) == x)]
+    #[ensures(None.unwrap_or_logic(default) == default)]
+    fn unwrap_or_logic_def(x: T, default: T) {}
+}
+
 impl<T> View for IntoIter<T> {
     type ViewTy = Option<T>;
 
@@ -0,0 +1,14 @@
+use crate::*;
+pub use ::std::process::*;
+
+extern_spec! {
+    mod std {
+        mod process {
+            #[creusot::diverges]
+            fn exit(code: i32) -> !;
+
+            #[creusot::diverges]
+            fn abort() -> !;
+        }
+    }
+}
@@ -42,6 +42,7 @@ impl<T: ?Sized> DeepModel for *const T {
 pub trait PointerExt<T: ?Sized>: Sized {
     /// _logical_ address of the pointer
     #[logic]
+    #[cfg_attr(creusot, rustc_diagnostic_item = "creusot_addr_logic")]
     fn addr_logic(self) -> usize;
 
     #[logic]
@@ -1,7 +1,7 @@
 use crate::*;
 #[cfg(feature = "nightly")]
 use ::std::alloc::Allocator;
-use ::std::rc::Rc;
+use ::std::{ops::Deref, rc::Rc};
 
 #[cfg(feature = "nightly")]
 impl<T: DeepModel, A: Allocator> DeepModel for Rc<T, A> {
@@ -35,6 +35,18 @@ extern_spec! {
                 #[ensures(*result == (*self)@)]
                 fn as_ref(&self) -> &T;
             }
+
+            impl<T, A: Allocator> Deref for Rc<T, A> {
+                #[pure]
+                #[ensures(*result == (*self)@)]
+                fn deref(&self) -> &T;
+            }
+
+            // All the clones of a `Rc` share the same pointee, and thus the same view.
+            impl<T, A: Allocator + Clone> Clone for Rc<T, A> {
+                #[ensures(result@ == (*self)@)]
+                fn clone(&self) -> Self;
+            }
         }
     }
 }
@@ -19,11 +19,11 @@ extern_spec! {
     mod std {
         mod result {
             impl<T, E> Result<T, E> {
-                #[pure]
+                #[pure(logic)]
                 #[ensures(result == exists<t: T> *self == Ok(t))]
                 fn is_ok(&self) -> bool;
 
-                #[pure]
+                #[pure(logic)]
                 #[ensures(result == exists<e: E> *self == Err(e))]
                 fn is_err(&self) -> bool;
 
@@ -244,10 +244,24 @@ extern_spec! {
         #[ensures((^self)@.exchange(self@, i@, j@))]
         fn swap(&mut self, i: usize, j: usize);
 
+        #[pure]
+        #[ensures((^self)@ == self@.reverse())]
+        fn reverse(&mut self);
+
         #[ensures(ix.in_bounds(self@) ==> exists<r: _> result == Some(r) && ix.has_value(self_@, *r))]
         #[ensures(ix.in_bounds(self@) || result == None)]
         fn get<I : SliceIndex<[T]>>(&self, ix: I) -> Option<&<I as ::std::slice::SliceIndex<[T]>>::Output>;
 
+        #[pure]
+        #[requires(mid@ <= self@.len())]
+        #[ensures({
+            let (l, r) = result;
+            l@ == self@.subsequence(0, mid@) &&
+            r@ == self@.subsequence(mid@, self@.len()) &&
+            l@.concat(r@) == self@
+        })]
+        fn split_at(&self, mid: usize) -> (&[T], &[T]);
+
         #[pure]
         #[requires(mid@ <= self@.len())]
         #[ensures({
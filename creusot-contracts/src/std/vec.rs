@@ -1,14 +1,17 @@
 use crate::{
     invariant::*,
+    logic::Mapping,
     resolve::structural_resolve,
     std::{
-        ops::{Deref, DerefMut, Index, IndexMut},
+        ops::{Deref, DerefMut, Index, IndexMut, RangeBounds, RangeBoundsExt},
         slice::SliceIndex,
     },
     *,
 };
 #[cfg(feature = "nightly")]
 use ::std::alloc::Allocator;
+#[cfg(creusot)]
+use ::std::convert::TryFrom;
 pub use ::std::vec::*;
 
 #[cfg(feature = "nightly")]
@@ -23,6 +26,26 @@ impl<T, A: Allocator> View for Vec<T, A> {
     }
 }
 
+/// Logical operations on [`Vec`].
+pub trait VecExt {
+    /// The capacity of the vector, as returned by [`Vec::capacity`].
+    ///
+    /// Only the guarantees of the standard library are specified: the capacity is at least the
+    /// length, and it grows by an unspecified amount when elements are added.
+    #[logic]
+    fn capacity_log(self) -> Int;
+}
+
+#[cfg(feature = "nightly")]
+impl<T, A: Allocator> VecExt for Vec<T, A> {
+    #[logic]
+    #[trusted]
+    #[ensures(self@.len() <= result && result <= usize::MAX@)]
+    fn capacity_log(self) -> Int {
+        dead
+    }
+}
+
 #[cfg(feature = "nightly")]
 impl<T: DeepModel, A: Allocator> DeepModel for Vec<T, A> {
     type DeepModelTy = Seq<T::DeepModelTy>;
@@ -73,6 +96,7 @@ extern_spec! {
 
                 #[terminates] // can OOM
                 #[ensures(result@.len() == 0)]
+                #[ensures(result.capacity_log() >= capacity@)]
                 fn with_capacity(capacity: usize) -> Vec<T>;
             }
             impl<T, A : Allocator> Vec<T, A> {
@@ -82,6 +106,7 @@ extern_spec! {
 
                 #[terminates] // can OOM
                 #[ensures((^self)@ == self@.push_back(v))]
+                #[ensures((^self).capacity_log() >= (*self).capacity_log())]
                 fn push(&mut self, v: T);
 
                 #[pure]
@@ -91,6 +116,7 @@ extern_spec! {
                         self@ == (^self)@.push_back(t),
                     None => *self == ^self && self@.len() == 0
                 })]
+                #[ensures((^self).capacity_log() == (*self).capacity_log())]
                 fn pop(&mut self) -> Option<T>;
 
                 #[pure]
@@ -108,15 +134,17 @@ extern_spec! {
                 fn insert(&mut self, index: usize, element: T);
 
                 #[pure]
-                #[ensures(result@ >= self@.len())]
+                #[ensures(result@ == (*self).capacity_log())]
                 fn capacity(&self) -> usize;
 
                 #[terminates] // can OOM
                 #[ensures((^self)@ == self@)]
+                #[ensures((^self).capacity_log() >= self@.len() + additional@)]
                 fn reserve(&mut self, additional: usize);
 
                 #[terminates] // can OOM
                 #[ensures((^self)@ == self@)]
+                #[ensures((^self).capacity_log() >= self@.len() + additional@)]
                 fn reserve_exact(&mut self, additional: usize);
 
                 #[pure]
@@ -129,7 +157,46 @@ extern_spec! {
 
                 #[pure]
                 #[ensures((^self)@.len() == 0)]
+                #[ensures((^self).capacity_log() == (*self).capacity_log())]
                 fn clear(&mut self);
+
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.concat(other@))]
+                #[ensures((^other)@.len() == 0)]
+                #[ensures((^other).capacity_log() == (*other).capacity_log())]
+                fn append(&mut self, other: &mut Vec<T, A>);
+
+                // The elements kept are those for which `f` returns `true`, in the same order:
+                // `m` maps the indices of the result to those of the original vector. As for
+                // `Iterator::filter`, `f` must not change its state, and its result must be
+                // determined by its postcondition.
+                #[requires(forall<g: F> f.hist_inv(g) ==> f == g)]
+                #[requires(forall<e: &T> f.precondition((e,)))]
+                #[requires(forall<e: &T, g: F>
+                    !(f.postcondition_mut((e,), g, true) && f.postcondition_mut((e,), g, false)))]
+                #[ensures(exists<m: Mapping<Int, Int>>
+                    (forall<i: Int> 0 <= i && i < (^self)@.len() ==>
+                        0 <= m.get(i) && m.get(i) < self@.len() && (^self)[i] == self[m.get(i)]) &&
+                    (forall<i: Int, j: Int> 0 <= i && i < j && j < (^self)@.len() ==> m.get(i) < m.get(j)) &&
+                    (forall<k: Int> 0 <= k && k < self@.len() ==>
+                        (exists<i: Int> 0 <= i && i < (^self)@.len() && m.get(i) == k)
+                        == f.postcondition_mut((&self[k],), f, true))
+                )]
+                fn retain<F>(&mut self, f: F)
+                where
+                    F: FnMut(&T) -> bool;
+
+                // The vector is only shortened when the iterator is dropped, see `DrainExt`.
+                #[pure]
+                #[requires(range.start_index(self@.len()) <= range.end_index(self@.len()))]
+                #[requires(range.end_index(self@.len()) <= self@.len())]
+                #[ensures(result@ == self@.subsequence(range.start_index(self@.len()), range.end_index(self@.len())))]
+                #[ensures(result.kept() == self@.subsequence(0, range.start_index(self@.len()))
+                    .concat(self@.subsequence(range.end_index(self@.len()), self@.len())))]
+                #[ensures(result.vec_final() == (^self)@)]
+                fn drain<R>(&mut self, range: R) -> Drain<'_, T, A>
+                where
+                    R: RangeBounds<usize> + RangeBoundsExt;
             }
 
             impl<T, A : Allocator> Extend<T> for Vec<T, A> {
@@ -195,6 +262,17 @@ extern_spec! {
         fn into_iter(self) -> std::slice::IterMut<'a, T>;
     }
 
+    // An array can be built by pushing its elements to a vector, whose view describes the
+    // initialized prefix, and converting it once it has `N` elements.
+    impl<T, A: Allocator, const N: usize> TryFrom<Vec<T, A>> for [T; N] {
+        #[pure]
+        #[ensures(match result {
+            Ok(a) => a@ == vec@,
+            Err(v) => v@ == vec@ && vec@.len() != N@,
+        })]
+        fn try_from(vec: Vec<T, A>) -> Result<[T; N], Vec<T, A>>;
+    }
+
     impl<T> Default for Vec<T> {
         #[ensures(result@ == Seq::EMPTY)]
         fn default() -> Vec<T>;
@@ -256,6 +334,65 @@ impl<T, A: Allocator> Iterator for std::vec::IntoIter<T, A> {
     fn produces_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
 }
 
+#[cfg(feature = "nightly")]
+impl<T, A: Allocator> View for Drain<'_, T, A> {
+    type ViewTy = Seq<T>;
+
+    #[logic]
+    #[trusted]
+    fn view(self) -> Self::ViewTy {
+        dead
+    }
+}
+
+/// The model of the vector a [`Drain`] removes elements from.
+///
+/// The elements are only removed from the vector when the `Drain` is dropped, which resolves
+/// it: until then, the vector is borrowed by the `Drain`. If the `Drain` is leaked instead, e.g.
+/// with `mem::forget`, the vector is left with the elements before the drained range.
+pub trait DrainExt<T> {
+    /// The elements left in the vector once the `Drain` is dropped.
+    #[logic]
+    fn kept(self) -> Seq<T>;
+
+    /// The final view of the vector.
+    #[logic(prophetic)]
+    fn vec_final(self) -> Seq<T>;
+}
+
+#[cfg(feature = "nightly")]
+impl<T, A: Allocator> DrainExt<T> for Drain<'_, T, A> {
+    #[logic]
+    #[trusted]
+    fn kept(self) -> Seq<T> {
+        dead
+    }
+
+    #[logic(prophetic)]
+    #[trusted]
+    fn vec_final(self) -> Seq<T> {
+        dead
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, A: Allocator> Resolve for Drain<'_, T, A> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.vec_final() == self.kept()
+                && forall<i: Int> 0 <= i && i < self@.len() ==> resolve(&self@[i])
+        }
+    }
+
+    #[trusted]
+    #[logic(prophetic)]
+    #[requires(structural_resolve(self))]
+    #[ensures((*self).resolve())]
+    fn resolve_coherence(&self) {}
+}
+
 impl<T> FromIterator<T> for Vec<T> {
     #[predicate]
     #[open]
@@ -282,4 +419,9 @@ mod impls {
     }
     impl<T> Resolve for std::vec::IntoIter<T> {}
     impl<T> Iterator for std::vec::IntoIter<T> {}
+    impl<T> View for Drain<'_, T> {
+        type ViewTy = Seq<T>;
+    }
+    impl<T> DrainExt<T> for Drain<'_, T> {}
+    impl<T> Resolve for Drain<'_, T> {}
 }
@@ -36,6 +36,39 @@ pub fn such_that<T>(p: crate::logic::Mapping<T, bool>) -> T {
     dead
 }
 
+/// States that `x` is *fresh*: it does not alias any input of the function.
+///
+/// In the postcondition of a function, `fresh(x)` is replaced by a condition that depends on the
+/// type of `x`:
+/// - If `x` is a raw pointer, its [address](crate::std::ptr::PointerExt::addr_logic) differs
+///   from the address of each input of the function that is a raw pointer. Pointers stored in
+///   other inputs, e.g. in a struct or behind a reference, are not compared.
+/// - Owned values and mutable borrows are unaliased by Rust's ownership discipline: a function
+///   returning such a value gives its caller unique access to it, so `fresh(x)` is `true`.
+/// - Shared references cannot be fresh, since they are modeled by the value they point to: using
+///   `fresh` on a shared reference is an error.
+///
+/// An impl of a trait method whose contract `ensures(fresh(result))` must preserve it like any
+/// other postcondition. Outside of a postcondition, `fresh(x)` is an opaque predicate.
+///
+/// # Example
+///
+/// ```
+/// # use creusot_contracts::*;
+/// #[trusted]
+/// #[ensures(fresh(result))]
+/// fn alloc(hint: *const u8) -> *mut u8 {
+///     Box::into_raw(Box::new(0))
+/// }
+/// ```
+#[trusted]
+#[predicate]
+#[allow(unused_variables)]
+#[cfg_attr(creusot, rustc_diagnostic_item = "creusot_fresh")]
+pub fn fresh<T>(x: T) -> bool {
+    dead
+}
+
 /// Indicates unreachable code.
 ///
 /// This function indicates a logical branch that should be impossible to reach.
@@ -70,6 +70,44 @@ impl CreusotArgsExt for CreusotArgs {
             monolithic: self.options.monolithic,
             prefix: Vec::new(), // to be set in callbacks::ToWhy::set_output_dir
             simple_triggers: self.options.simple_triggers,
+            report_assumptions: self.options.report_assumptions,
+            closed_crates: self.options.closed_crates,
+            since: self.options.since,
+            deny_trusted_external_impls: self.options.deny_trusted_external_impls,
+            trusted_allowlist: self.options.trusted_allowlist,
+            list_obligations: self.options.list_obligations.map(|format| match format {
+                ObligationsFormat::Table => options::ObligationsFormat::Table,
+                ObligationsFormat::Json => options::ObligationsFormat::Json,
+            }),
+            max_quantifier_depth: self.options.max_quantifier_depth,
+            simplify: self
+                .options
+                .simplify
+                .into_iter()
+                .map(|pass| match pass {
+                    SimplifyPass::ConstFold => options::SimplifyPass::ConstFold,
+                    SimplifyPass::TrivialLogic => options::SimplifyPass::TrivialLogic,
+                })
+                .collect(),
+            dump_refinements_all: self.options.dump_refinements_all,
+            emit_refinement_graph: self.options.emit_refinement_graph,
+            dump_param_env: self.options.dump_param_env,
+            solver_log: self.options.solver_log,
+            rlimit: self.options.rlimit,
+            stats: self.options.stats,
+            only_refinements: self.options.only_refinements,
+            check_specs_only: self.options.check_specs_only,
+            html_report: self.options.html_report,
+            panic_report: self.options.panic_report,
+            baseline: self.options.baseline,
+            update_baseline: self.options.update_baseline,
+            check_coma_round_trip: self.options.check_coma_round_trip,
+            fail_fast: self.options.fail_fast,
+            check_precondition_satisfiability: self.options.check_precondition_satisfiability,
+            why3_version: self.options.why3_version,
+            vc_cache: self.options.vc_cache,
+            report_trust_dependencies: self.options.report_trust_dependencies,
+            max_parallel_solvers: self.options.max_parallel_solvers,
             why3_cmd: match self.subcommand {
                 Some(cmd @ LegacyCreusotSubCommand::Why3 { .. }) => {
                     Some(why3_command(self.why3_path, self.why3_config_file, cmd))
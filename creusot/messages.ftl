@@ -1,3 +1,7 @@
 creusot_contractless_external_function =
     calling external function `{$name}` with no contract will yield an impossible precondition
-    .label = function called here
\ No newline at end of file
+    .label = function called here
+
+creusot_vacuous_postcondition =
+    this postcondition is always true
+    .label = a stronger property was probably intended here
\ No newline at end of file
@@ -1,21 +1,35 @@
+use indexmap::{IndexMap, IndexSet};
+use petgraph::{algo::tarjan_scc, graphmap::DiGraphMap};
 use rustc_hir::{def::DefKind, def_id::DefId};
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{GenericArg, GenericArgsRef, TyCtxt, TyKind, TypeVisitableExt};
 use rustc_span::Span;
 use why3::declaration::Attribute;
 
 use crate::{
-    contracts_items::{is_resolve_function, is_spec, is_trusted},
-    ctx::{ItemType, TranslatedItem, TranslationCtx},
+    baseline::{Baseline, load_baseline},
+    changes::Changes,
+    contracts_items::{
+        OverflowMode, is_law, is_resolve_function, is_spec, is_trusted, is_verify_monomorphized,
+        solver_rlimit,
+    },
+    ctx::{BodyId, ItemType, TranslatedItem, TranslationCtx},
     error::CannotFetchThir,
+    html_report::Report,
     naming::ModulePath,
+    obligations::ObligationKind,
     options::SpanMode,
-    run_why3::SpanMap,
+    run_why3::{OVERFLOW_ATTR, PROVER_ATTR, RLIMIT_ATTR, SpanMap},
+    stats::Stats,
+    translation::fmir,
+    trust_report::TrustDependencies,
     util::path_of_span,
 };
 use std::{
-    cell::RefCell,
+    cell::{OnceCell, RefCell},
+    collections::HashMap,
     ops::{Deref, DerefMut},
     path::PathBuf,
+    time::Instant,
 };
 
 pub(crate) mod clone_map;
@@ -35,8 +49,21 @@ pub(crate) mod wto;
 
 pub struct Why3Generator<'tcx> {
     pub ctx: TranslationCtx<'tcx>,
-    functions: Vec<TranslatedItem>,
+    functions: Vec<(DefId, TranslatedItem)>,
     pub(crate) span_map: RefCell<SpanMap>,
+    /// The changes to verify, with `--verify-only-changed`.
+    changes: Option<Changes>,
+    /// The time spent on each item, with `--stats`.
+    pub(crate) stats: Option<RefCell<Stats>>,
+    /// The results of the obligations, with `--html-report`, `--baseline` or
+    /// `--report-trust-dependencies`.
+    pub(crate) report: Option<RefCell<Report>>,
+    /// The baseline to compare the results with, with `--baseline`.
+    pub(crate) baseline: Option<Baseline>,
+    /// The dependencies of each item, with `--report-trust-dependencies`.
+    pub(crate) trust: Option<RefCell<TrustDependencies>>,
+    /// The groups of local program functions calling each other, see `is_recursive_call`.
+    recursion_groups: OnceCell<HashMap<DefId, usize>>,
 }
 
 impl<'tcx> Deref for Why3Generator<'tcx> {
@@ -55,40 +82,230 @@ impl<'tcx> DerefMut for Why3Generator<'tcx> {
 
 impl<'tcx> Why3Generator<'tcx> {
     pub fn new(ctx: TranslationCtx<'tcx>) -> Self {
-        Why3Generator { ctx, functions: Default::default(), span_map: Default::default() }
+        let changes = ctx.opts.since.as_deref().and_then(|rev| {
+            let changes = Changes::since(rev);
+            if changes.is_none() {
+                ctx.dcx().warn(format!(
+                    "could not get the files changed since `{rev}` from git, verifying everything"
+                ));
+            }
+            changes
+        });
+        let stats = ctx.opts.stats.then(Default::default);
+        let report = (ctx.opts.html_report.is_some()
+            || ctx.opts.baseline.is_some()
+            || ctx.opts.report_trust_dependencies)
+            .then(Default::default);
+        let trust = ctx.opts.report_trust_dependencies.then(Default::default);
+        let baseline = ctx.opts.baseline.as_deref().map(|path| load_baseline(&ctx, path));
+        Why3Generator {
+            ctx,
+            functions: Default::default(),
+            span_map: Default::default(),
+            changes,
+            stats,
+            report,
+            baseline,
+            trust,
+            recursion_groups: Default::default(),
+        }
     }
 
     pub(crate) fn translate(&mut self, def_id: DefId) -> Result<(), CannotFetchThir> {
         debug!("translating {:?}", def_id);
 
-        match self.item_type(def_id) {
+        let start = Instant::now();
+        let kind = match self.item_type(def_id) {
             ItemType::Impl if self.tcx.impl_trait_ref(def_id).is_some() => {
                 let modls = traits::lower_impl(self, def_id);
-                self.functions.push(TranslatedItem::Impl { modls });
+                self.functions.push((def_id, TranslatedItem::Impl { modls }));
+                ObligationKind::Refinement
             }
             ItemType::Predicate { .. } if is_resolve_function(self.tcx, def_id) => {
-                self.functions.push(TranslatedItem::Logic { proof_modl: None });
+                self.functions.push((def_id, TranslatedItem::Logic { proof_modl: None }));
+                ObligationKind::Logic
             }
             ItemType::Logic { .. } | ItemType::Predicate { .. } => {
                 let proof_modl = logic::translate_logic_or_predicate(self, def_id)?;
-                self.functions.push(TranslatedItem::Logic { proof_modl });
+                self.functions.push((def_id, TranslatedItem::Logic { proof_modl }));
+                if is_law(self.tcx, def_id) { ObligationKind::Law } else { ObligationKind::Logic }
             }
             ItemType::Program => {
                 let modl = program::translate_function(self, def_id);
-                self.functions.push(TranslatedItem::Program { modl });
+                self.functions.push((def_id, TranslatedItem::Program { modl }));
+                if self.opts.check_precondition_satisfiability
+                    && let Some(modl) = program::precondition_vacuity(self, def_id)
+                {
+                    self.functions.push((def_id, TranslatedItem::Vacuity { modl }));
+                }
+                ObligationKind::Body
             }
             ItemType::Field | ItemType::Variant => unreachable!(),
             ItemType::Unsupported(dk) => self.crash_and_error(
                 self.tcx.def_span(def_id),
                 &format!("unsupported definition kind {:?} {:?}", def_id, dk),
             ),
-            _ => (),
+            _ => return Ok(()),
+        };
+        if let Some(stats) = &self.stats
+            && let Some(local_id) = def_id.as_local()
+        {
+            let span = self.tcx.source_span(local_id);
+            stats.borrow_mut().record_translation(def_id, kind, span, start.elapsed());
         }
         Ok(())
     }
 
-    pub(crate) fn modules(&mut self) -> impl Iterator<Item = TranslatedItem> + '_ {
-        self.functions.drain(..)
+    /// Translate the functions with `#[creusot::verify_monomorphized]` for each of the concrete
+    /// instantiations they are used with in the translated program functions: called, or used as
+    /// values, e.g. turned into function pointers or passed to higher-order functions, whose
+    /// types are then function item types.
+    pub(crate) fn translate_monomorphizations(&mut self) {
+        let mut uses = Vec::new();
+        for (def_id, item) in &self.functions {
+            let TranslatedItem::Program { .. } = item else { continue };
+            let Some(local_id) = def_id.as_local() else { continue };
+            if !self.has_body(*def_id) || is_trusted_item(self.tcx, *def_id) {
+                continue;
+            }
+            for block in self.fmir_body(BodyId::new(local_id, None)).blocks.values() {
+                for stmt in &block.stmts {
+                    let fmir::Statement::Call(_, callee, subst, _, span) = stmt else { continue };
+                    uses.push((*callee, *subst, *span));
+                    uses.extend(
+                        subst.iter().flat_map(fn_items).map(|(f, subst)| (f, subst, *span)),
+                    );
+                }
+            }
+        }
+
+        let mut instances: IndexMap<DefId, IndexSet<GenericArgsRef<'tcx>>> = IndexMap::new();
+        for (callee, subst, span) in uses {
+            if !callee.is_local()
+                || !is_verify_monomorphized(self.tcx, callee)
+                || !self.generics_of(callee).requires_monomorphization(self.tcx)
+            {
+                continue;
+            }
+            if subst.has_param() {
+                let msg = format!(
+                    "`{}` is verified for each of its instantiations, and cannot be used with generic arguments",
+                    self.def_path_str(callee)
+                );
+                self.error(span, &msg).emit();
+                continue;
+            }
+            instances.entry(callee).or_default().insert(self.tcx.erase_regions(subst));
+        }
+
+        for (def_id, substs) in instances {
+            for (i, subst) in substs.into_iter().enumerate() {
+                let suffix = format!("__mono{i}");
+                let modl = program::translate_monomorphized(self, def_id, subst, &suffix);
+                self.functions.push((def_id, TranslatedItem::Program { modl }));
+            }
+        }
+    }
+
+    /// Is the call of `callee` in the body of `caller` recursive: is it a call of `caller` itself,
+    /// or of a local program function which calls `caller` back, directly or not?
+    ///
+    /// The first query of a call to another local function builds the call graph of all the
+    /// local program functions, from their bodies, and its strongly connected components: this is
+    /// done once per crate, even if no function is mutually recursive.
+    pub(crate) fn is_recursive_call(&self, caller: DefId, callee: DefId) -> bool {
+        if caller == callee {
+            return true;
+        }
+        if !callee.is_local() {
+            return false;
+        }
+        let groups = self.recursion_groups.get_or_init(|| {
+            let mut graph = DiGraphMap::new();
+            for local_id in self.hir().body_owners() {
+                let def_id = local_id.to_def_id();
+                if !matches!(self.item_type(def_id), ItemType::Program)
+                    || !self.has_body(def_id)
+                    || is_trusted_item(self.tcx, def_id)
+                {
+                    continue;
+                }
+                graph.add_node(def_id);
+                for block in self.fmir_body(BodyId::new(local_id, None)).blocks.values() {
+                    for stmt in &block.stmts {
+                        if let fmir::Statement::Call(_, callee, _, _, _) = stmt
+                            && callee.is_local()
+                        {
+                            graph.add_edge(def_id, *callee, ());
+                        }
+                    }
+                }
+            }
+            let mut groups = HashMap::new();
+            for (i, scc) in tarjan_scc(&graph).into_iter().enumerate() {
+                groups.extend(scc.into_iter().map(|def_id| (def_id, i)));
+            }
+            groups
+        });
+        groups.get(&caller).is_some_and(|g| groups.get(&callee) == Some(g))
+    }
+
+    /// The local program functions whose body was translated, and must thus be verified.
+    pub(crate) fn verified_program_functions(&self) -> Vec<DefId> {
+        self.functions
+            .iter()
+            .filter(|(def_id, item)| {
+                matches!(item, TranslatedItem::Program { .. })
+                    && def_id.is_local()
+                    && self.has_body(*def_id)
+                    && !is_trusted_item(self.tcx, *def_id)
+            })
+            .map(|(def_id, _)| *def_id)
+            .collect()
+    }
+
+    /// The translated items to output, with the item they come from.
+    pub(crate) fn modules(&mut self) -> impl Iterator<Item = (DefId, TranslatedItem)> + '_ {
+        let functions = std::mem::take(&mut self.functions);
+        functions.into_iter().filter(move |(def_id, _)| self.is_affected(*def_id))
+    }
+
+    /// Is `def_id` affected by the changes to verify? This is always true without
+    /// `--verify-only-changed`.
+    fn is_affected(&self, def_id: DefId) -> bool {
+        let Some(changes) = &self.changes else { return true };
+        changes.is_affected(self.tcx, def_id)
+            || (matches!(self.def_kind(def_id), DefKind::Impl { .. })
+                && self
+                    .associated_item_def_ids(def_id)
+                    .iter()
+                    .any(|&id| changes.is_affected(self.tcx, id)))
+    }
+
+    /// Does a change touch the impl `impl_id`, or the specification of the trait it implements?
+    /// If not, its refinement obligations need not be generated with `--verify-only-changed`.
+    pub(crate) fn impl_touched(&self, impl_id: DefId) -> bool {
+        let Some(changes) = &self.changes else { return true };
+        let trait_id = self.trait_id_of_impl(impl_id);
+        [Some(impl_id), trait_id]
+            .into_iter()
+            .flatten()
+            .flat_map(|id| {
+                std::iter::once(id).chain(self.associated_item_def_ids(id).iter().copied())
+            })
+            .any(|id| changes.is_changed(self.tcx, id))
+    }
+
+    /// Record the dependencies of the translation of `self_id`, to find the items affected by the
+    /// changes to verify, and the trusted facts that it relies on.
+    pub(crate) fn record_dependencies(&self, self_id: DefId, deps: impl Iterator<Item = DefId>) {
+        let deps: Vec<_> = deps.collect();
+        if let Some(changes) = &self.changes {
+            changes.record_dependencies(self.tcx, self_id, deps.iter().copied())
+        }
+        if let Some(trust) = &self.trust {
+            trust.borrow_mut().record(self_id, deps.into_iter())
+        }
     }
 
     fn is_logical(&self, item: DefId) -> bool {
@@ -141,6 +358,40 @@ impl<'tcx> Why3Generator<'tcx> {
         Some(Attribute::Span(filename, lo.line, lo.col_display, hi.line, hi.col_display))
     }
 
+    /// The attributes of the modules of the proof obligations of `def_id`, with its location, its
+    /// solver resource limit, the solver of its module and its overflow mode.
+    pub(crate) fn module_attrs(&self, def_id: DefId) -> Vec<Attribute> {
+        let mut attrs: Vec<_> = self.span_attr(self.def_span(def_id)).into_iter().collect();
+        if let Some(rlimit) = self.solver_rlimit(def_id) {
+            attrs.push(Attribute::Attr(format!("{RLIMIT_ATTR}{rlimit}")));
+        }
+        if let Some(prover) = self.module_backend_config(def_id).prover {
+            attrs.push(Attribute::Attr(format!("{PROVER_ATTR}{prover}")));
+        }
+        let overflow = self.overflow_mode(def_id);
+        if overflow != OverflowMode::Check {
+            attrs.push(Attribute::Attr(format!("{OVERFLOW_ATTR}{}", overflow.as_str())));
+        }
+        attrs
+    }
+
+    /// The resource limit given by `#[creusot::solver(rlimit = N)]` on `def_id` or on the impl
+    /// containing it, or else by `#[creusot::backend(rlimit = N)]` on its module, or else by
+    /// `--rlimit`.
+    fn solver_rlimit(&self, def_id: DefId) -> Option<u64> {
+        solver_rlimit(self.tcx, def_id)
+            .or_else(|| {
+                let parent = self.opt_parent(def_id)?;
+                if matches!(self.def_kind(parent), DefKind::Impl { .. }) {
+                    solver_rlimit(self.tcx, parent)
+                } else {
+                    None
+                }
+            })
+            .or_else(|| self.module_backend_config(def_id).rlimit)
+            .or(self.opts.rlimit)
+    }
+
     pub fn display_impl_of(&self, def_id: DefId) -> Option<String> {
         let tcx = self.ctx.tcx;
         let mut id = def_id;
@@ -190,3 +441,11 @@ pub fn is_trusted_item(tcx: TyCtxt, mut def_id: DefId) -> bool {
 
     false
 }
+
+/// The function items appearing in the type `arg`.
+fn fn_items<'tcx>(arg: GenericArg<'tcx>) -> impl Iterator<Item = (DefId, GenericArgsRef<'tcx>)> {
+    arg.walk().filter_map(|arg| match arg.as_type()?.kind() {
+        &TyKind::FnDef(def_id, subst) => Some((def_id, subst)),
+        _ => None,
+    })
+}
@@ -3,10 +3,9 @@ use std::cell::RefCell;
 
 use crate::{
     backend::{Why3Generator, clone_map::elaborator::Expander, dependency::Dependency},
-    contracts_items::{get_builtin, get_inv_function, is_bitwise},
+    contracts_items::{get_builtin, get_inv_function},
     ctx::*,
     options::SpanMode,
-    translation::traits::TraitResolved,
     util::{erased_identity_for_item, path_of_span},
 };
 use elaborator::Strength;
@@ -22,7 +21,8 @@ use rustc_macros::{TypeFoldable, TypeVisitable};
 use rustc_middle::{
     mir::Promoted,
     ty::{
-        self, GenericArgsRef, List, Ty, TyCtxt, TyKind, TypeFoldable, TypeVisitableExt, TypingEnv,
+        self, EarlyBinder, GenericArgsRef, List, Ty, TyCtxt, TyKind, TypeFoldable,
+        TypeVisitableExt, TypingEnv,
     },
 };
 use rustc_span::Span;
@@ -133,6 +133,10 @@ pub(crate) trait Namer<'tcx> {
         self.dependency(Dependency::Promoted(def_id, prom)).ident()
     }
 
+    fn const_param(&self, param: ty::ParamConst) -> Ident {
+        self.dependency(Dependency::ConstParam(param)).ident()
+    }
+
     fn normalize<T: TypeFoldable<TyCtxt<'tcx>>>(&self, ctx: &TranslationCtx<'tcx>, ty: T) -> T;
 
     fn import_prelude_module(&self, module: PreMod) {
@@ -190,12 +194,14 @@ pub(crate) trait Namer<'tcx> {
         self.raw_dependency(dep.erase_regions(self.tcx()))
     }
 
-    fn resolve_dependency(&self, dep: Dependency<'tcx>) -> Dependency<'tcx> {
-        let ctx = self.tcx();
+    fn resolve_dependency(
+        &self,
+        ctx: &TranslationCtx<'tcx>,
+        dep: Dependency<'tcx>,
+    ) -> Dependency<'tcx> {
         if let Dependency::Item(def, args) = dep {
-            let (def, args) = TraitResolved::resolve_item(ctx, self.typing_env(), def, args)
-                .to_opt(def, args)
-                .unwrap();
+            let (def, args) =
+                ctx.resolve_item(self.typing_env(), def, args).to_opt(def, args).unwrap();
             Dependency::Item(def, args)
         } else {
             dep
@@ -209,6 +215,12 @@ pub(crate) trait Namer<'tcx> {
     fn span(&self, span: Span) -> Option<Attribute>;
 
     fn bitwise_mode(&self) -> bool;
+
+    /// The concrete instantiation of the item being translated, when it is verified for each of
+    /// its instantiations (see `#[creusot::verify_monomorphized]`).
+    fn monomorphization(&self) -> Option<GenericArgsRef<'tcx>> {
+        None
+    }
 }
 
 impl<'tcx> Namer<'tcx> for CloneNames<'tcx> {
@@ -265,7 +277,11 @@ impl<'tcx> CloneNames<'tcx> {
 
 impl<'tcx> Namer<'tcx> for Dependencies<'tcx> {
     fn normalize<T: TypeFoldable<TyCtxt<'tcx>>>(&self, ctx: &TranslationCtx<'tcx>, ty: T) -> T {
-        self.tcx().normalize_erasing_regions(ctx.typing_env(self.self_id), ty)
+        if self.monomorphized {
+            self.instantiate(ty)
+        } else {
+            self.tcx().normalize_erasing_regions(ctx.typing_env(self.self_id), ty)
+        }
     }
 
     fn tcx(&self) -> TyCtxt<'tcx> {
@@ -277,6 +293,7 @@ impl<'tcx> Namer<'tcx> for Dependencies<'tcx> {
     }
 
     fn raw_dependency(&self, key: Dependency<'tcx>) -> &Kind {
+        let key = if self.monomorphized { self.instantiate(key) } else { key };
         self.dep_set.borrow_mut().insert(key);
         self.names.raw_dependency(key)
     }
@@ -288,6 +305,10 @@ impl<'tcx> Namer<'tcx> for Dependencies<'tcx> {
     fn bitwise_mode(&self) -> bool {
         self.names.bitwise_mode()
     }
+
+    fn monomorphization(&self) -> Option<GenericArgsRef<'tcx>> {
+        self.monomorphized.then_some(self.self_subst)
+    }
 }
 
 pub(crate) struct Dependencies<'tcx> {
@@ -299,6 +320,8 @@ pub(crate) struct Dependencies<'tcx> {
 
     pub(crate) self_id: DefId,
     pub(crate) self_subst: GenericArgsRef<'tcx>,
+    /// Is `self_subst` a concrete instantiation of `self_id`, rather than its identity?
+    monomorphized: bool,
 }
 
 pub(crate) struct CloneNames<'tcx> {
@@ -365,24 +388,60 @@ impl Kind {
 
 impl<'tcx> Dependencies<'tcx> {
     pub(crate) fn new(ctx: &TranslationCtx<'tcx>, self_id: DefId) -> Self {
-        let bw = is_bitwise(ctx.tcx, self_id);
+        let bw = ctx.bitwise_mode(self_id);
         let names =
             CloneNames::new(ctx.tcx, ctx.typing_env(self_id), ctx.opts.span_mode.clone(), bw);
         debug!("cloning self: {:?}", self_id);
         let self_subst = erased_identity_for_item(ctx.tcx, self_id);
-        let deps =
-            Dependencies { tcx: ctx.tcx, self_id, self_subst, names, dep_set: Default::default() };
+        let deps = Dependencies {
+            tcx: ctx.tcx,
+            self_id,
+            self_subst,
+            names,
+            dep_set: Default::default(),
+            monomorphized: false,
+        };
+
+        let node = Dependency::Item(self_id, self_subst);
+        deps.names.dependency(node);
+        deps
+    }
+
+    /// The dependencies of `self_id` instantiated with the concrete `self_subst`: every type and
+    /// item used by `self_id` is instantiated, and trait items are resolved to their impl.
+    pub(crate) fn new_monomorphized(
+        ctx: &TranslationCtx<'tcx>,
+        self_id: DefId,
+        self_subst: GenericArgsRef<'tcx>,
+    ) -> Self {
+        let bw = ctx.bitwise_mode(self_id);
+        let typing_env = TypingEnv::fully_monomorphized();
+        let names = CloneNames::new(ctx.tcx, typing_env, ctx.opts.span_mode.clone(), bw);
+        let self_subst = ctx.tcx.erase_regions(self_subst);
+        let deps = Dependencies {
+            tcx: ctx.tcx,
+            self_id,
+            self_subst,
+            names,
+            dep_set: Default::default(),
+            monomorphized: true,
+        };
 
         let node = Dependency::Item(self_id, self_subst);
         deps.names.dependency(node);
         deps
     }
 
+    fn instantiate<T: TypeFoldable<TyCtxt<'tcx>>>(&self, t: T) -> T {
+        let t = EarlyBinder::bind(t).instantiate(self.tcx, self.self_subst);
+        self.tcx.normalize_erasing_regions(TypingEnv::fully_monomorphized(), t)
+    }
+
     pub(crate) fn provide_deps(mut self, ctx: &Why3Generator<'tcx>) -> Vec<Decl> {
         trace!("emitting dependencies for {:?}", self.self_id);
         let mut decls = Vec::new();
 
-        let typing_env = ctx.typing_env(self.self_id);
+        let typing_env = self.names.typing_env;
 
         let self_node = Dependency::Item(self.self_id, self.self_subst);
         let graph = Expander::new(
@@ -395,6 +454,10 @@ impl<'tcx> Dependencies<'tcx> {
 
         // Update the clone graph with any new entries.
         let (graph, mut bodies) = graph.update_graph(ctx);
+        ctx.record_dependencies(
+            self.self_id,
+            graph.nodes().filter_map(|n| n.did()).map(|(id, _)| id),
+        );
 
         for scc in petgraph::algo::tarjan_scc(&graph).into_iter() {
             if scc.iter().any(|node| node == &self_node) {
@@ -21,9 +21,9 @@ use crate::{
         ty_inv::InvariantElaborator,
     },
     contracts_items::{
-        get_builtin, get_fn_impl_postcond, get_fn_mut_impl_hist_inv, get_fn_mut_impl_postcond,
-        get_fn_once_impl_postcond, get_fn_once_impl_precond, get_resolve_method,
-        is_fn_impl_postcond, is_fn_mut_impl_hist_inv, is_fn_mut_impl_postcond,
+        disabled_laws, get_builtin, get_fn_impl_postcond, get_fn_mut_impl_hist_inv,
+        get_fn_mut_impl_postcond, get_fn_once_impl_postcond, get_fn_once_impl_precond,
+        get_resolve_method, is_fn_impl_postcond, is_fn_mut_impl_hist_inv, is_fn_mut_impl_postcond,
         is_fn_once_impl_postcond, is_fn_once_impl_precond, is_inv_function, is_predicate,
         is_resolve_function, is_structural_resolve,
     },
@@ -33,7 +33,7 @@ use crate::{
         constant::from_ty_const,
         pearlite::{Pattern, QuantKind, SmallRenaming, Term, TermKind, Trigger, normalize},
         specification::Condition,
-        traits::TraitResolved,
+        traits::{TraitResolved, law_applies},
     },
 };
 use petgraph::graphmap::DiGraphMap;
@@ -43,11 +43,11 @@ use rustc_middle::ty::{
     Const, GenericArg, GenericArgsRef, TraitRef, Ty, TyCtxt, TyKind, TypeFoldable, TypingEnv,
     UnevaluatedConst,
 };
-use rustc_span::{DUMMY_SP, Span};
+use rustc_span::{DUMMY_SP, Span, Symbol};
 use rustc_type_ir::{ClosureKind, ConstKind, EarlyBinder};
 use why3::{
     Ident,
-    declaration::{Attribute, Axiom, Decl, DeclKind, LogicDecl, Signature, TyDecl, Use},
+    declaration::{Attribute, Axiom, Constant, Decl, DeclKind, LogicDecl, Signature, TyDecl, Use},
 };
 
 /// Weak dependencies are allowed to form cycles in the graph, but strong ones cannot,
@@ -133,9 +133,9 @@ impl DepElab for ProgramElab {
         {
             let mut pre_sig = EarlyBinder::bind(ctx.sig(def_id).clone())
                 .instantiate(ctx.tcx, subst)
-                .normalize(ctx.tcx, typing_env);
+                .normalize(ctx, typing_env);
 
-            if let TraitResolved::UnknownFound = TraitResolved::resolve_item(ctx.tcx, typing_env, def_id, subst)
+            if let TraitResolved::UnknownFound(_) = ctx.resolve_item(typing_env, def_id, subst)
                 // These conditions are important to make sure the Fn trait familly is implemented
                 && ctx.fn_sig(def_id).skip_binder().is_fn_trait_compatible()
                 && ctx.codegen_fn_attrs(def_id).target_features.is_empty()
@@ -168,7 +168,14 @@ impl DepElab for ProgramElab {
                 let expl_post = format!("expl:{} ensures", fn_name);
                 pre_sig.contract.ensures = vec![Condition { term: post, expl: expl_post }]
             } else {
-                pre_sig.add_type_invariant_spec(ctx, def_id, typing_env)
+                pre_sig.add_type_invariant_spec(ctx, def_id, typing_env);
+                // The precondition of a recursive call is checked at the call, see
+                // `program::recursive_call_preconditions`.
+                if let Some((caller, _)) = elab.self_key.did()
+                    && ctx.is_recursive_call(caller, def_id)
+                {
+                    pre_sig.contract.requires.clear();
+                }
             }
 
             let return_ident = Ident::fresh_local("return");
@@ -232,21 +239,22 @@ impl DepElab for LogicElab {
         let typing_env = elab.typing_env;
         let pre_sig = EarlyBinder::bind(ctx.sig(def_id).clone())
             .instantiate(ctx.tcx, subst)
-            .normalize(ctx.tcx, typing_env);
+            .normalize(ctx, typing_env);
         let bound: Box<[Ident]> = pre_sig.inputs.iter().map(|(ident, _, _)| ident.0).collect();
 
-        let trait_resol = TraitResolved::resolve_item(ctx.tcx, typing_env, def_id, subst);
+        let trait_resol = ctx.resolve_item(typing_env, def_id, subst);
         assert_matches!(
             trait_resol,
             TraitResolved::NotATraitItem
             | TraitResolved::Instance(..) // The default impl is known to be the final instance
-            | TraitResolved::UnknownFound // Unresolved trait method
+            | TraitResolved::UnknownFound(_) // Unresolved trait method
         );
         // The other case are impossible, because that would mean we are  not guaranteed to have an instance
 
-        let opaque = matches!(trait_resol, TraitResolved::UnknownFound)
+        let opaque = matches!(trait_resol, TraitResolved::UnknownFound(_))
             || !ctx.is_transparent_from(def_id, elab.self_key.did().unwrap().0)
-            || is_trusted_item(ctx.tcx, def_id);
+            || is_trusted_item(ctx.tcx, def_id)
+            || ctx.is_logic_extern(def_id);
 
         let names = elab.namer(dep);
         let name = names.dependency(dep).ident();
@@ -464,6 +472,15 @@ impl<'a, 'tcx> Expander<'a, 'tcx> {
                 vec![eliminator(ctx, &self.namer(dep), def_id, subst)]
             }
             Dependency::Promoted(_, _) => ProgramElab::expand(self, ctx, dep),
+            Dependency::ConstParam(param) => {
+                let ty = param.find_ty_from_env(self.typing_env.param_env);
+                let names = self.namer(dep);
+                vec![Decl::ConstantDecl(Constant {
+                    name: names.dependency(dep).ident(),
+                    type_: translate_ty(ctx, &names, DUMMY_SP, ty),
+                    body: None,
+                })]
+            }
         };
 
         self.dep_bodies.insert(dep, decls);
@@ -516,11 +533,59 @@ fn expand_laws<'tcx>(
         return;
     }
 
+    let disabled = disabled_laws(ctx.tcx, self_did);
     for law in ctx.laws(item_container) {
-        let law_dep = elab.namer(dep).resolve_dependency(Dependency::Item(*law, item_subst));
+        if !law_applies(ctx.tcx, *law, item_subst, elab.typing_env)
+            || disabled.iter().any(|path| names_item(ctx.tcx, *law, path))
+        {
+            continue;
+        }
+        let law_dep = elab.namer(dep).resolve_dependency(ctx, Dependency::Item(*law, item_subst));
         // We add a weak dep from `dep` to make sure it appears close to the triggering item
         elab.expansion_queue.push_back((dep, Strength::Weak, law_dep));
     }
+
+    // The laws relating the trait of a local impl to other traits are proved for this impl only,
+    // see `cross_trait_laws`: they are not laws of the trait.
+    if item_container.is_local() && ctx.tcx.trait_id_of_impl(item_container).is_some() {
+        let impl_subst = item_subst.truncate_to(ctx.tcx, ctx.generics_of(item_container));
+        let self_ty = ctx.type_of(item_container).instantiate_identity();
+        for (law, law_subst) in ctx.cross_trait_laws(item_container, self_ty) {
+            let law_subst = EarlyBinder::bind(law_subst).instantiate(ctx.tcx, impl_subst);
+            if !law_applies(ctx.tcx, law, law_subst, elab.typing_env)
+                || disabled.iter().any(|path| names_item(ctx.tcx, law, path))
+            {
+                continue;
+            }
+            let law_dep = elab.namer(dep).resolve_dependency(ctx, Dependency::Item(law, law_subst));
+            elab.expansion_queue.push_back((dep, Strength::Weak, law_dep));
+        }
+    }
+}
+
+/// Does `path` name the item `def_id`? The path is matched from its end, so it may omit the
+/// leading modules, and the segment for an impl is the name of its self type: `concat_assoc`,
+/// `Seq::concat_assoc` and `logic::seq::Seq::concat_assoc` all name `Seq::concat_assoc`.
+fn names_item(tcx: TyCtxt, def_id: DefId, path: &[Symbol]) -> bool {
+    let mut current = Some(def_id);
+    for &segment in path.iter().rev() {
+        let Some(def_id) = current else { return false };
+        let name = if def_id.is_crate_root() {
+            Some(tcx.crate_name(def_id.krate))
+        } else if let DefKind::Impl { .. } = tcx.def_kind(def_id) {
+            tcx.type_of(def_id)
+                .instantiate_identity()
+                .ty_adt_def()
+                .map(|adt| tcx.item_name(adt.did()))
+        } else {
+            tcx.opt_item_name(def_id)
+        };
+        if name != Some(segment) {
+            return false;
+        }
+        current = tcx.opt_parent(def_id);
+    }
+    true
 }
 
 fn val(mut sig: Signature, kind: DeclKind) -> Vec<Decl> {
@@ -549,20 +614,22 @@ fn resolve_term<'tcx>(
     let trait_meth_id = get_resolve_method(ctx.tcx);
     let sig = ctx.sig(def_id).clone();
     let mut pre_sig = EarlyBinder::bind(sig).instantiate(ctx.tcx, subst);
-    pre_sig = pre_sig.normalize(ctx.tcx, typing_env);
+    pre_sig = pre_sig.normalize(ctx, typing_env);
 
     let arg = Term::var(pre_sig.inputs[0].0, pre_sig.inputs[0].2);
 
     if let &TyKind::Closure(def_id, subst) = subst[0].as_type().unwrap().kind() {
         Some(closure_resolve(ctx, def_id, subst, bound))
     } else {
-        match TraitResolved::resolve_item(ctx.tcx, typing_env, trait_meth_id, subst) {
+        match ctx.resolve_item(typing_env, trait_meth_id, subst) {
             TraitResolved::NotATraitItem => unreachable!(),
             TraitResolved::Instance(meth_did, meth_substs) => {
                 // We know the instance => body points to it
                 Some(Term::call(ctx.tcx, typing_env, meth_did, meth_substs, [arg]))
             }
-            TraitResolved::UnknownFound | TraitResolved::UnknownNotFound => {
+            TraitResolved::UnknownFound(_)
+            | TraitResolved::UnknownNotFound
+            | TraitResolved::Ambiguous => {
                 // We don't know the instance => body is opaque
                 None
             }
@@ -652,10 +719,10 @@ fn fn_once_postcond_term<'tcx>(
             ))
         }
         &TyKind::FnDef(mut did, mut subst) => {
-            match TraitResolved::resolve_item(ctx.tcx, typing_env, did, subst) {
+            match ctx.resolve_item(typing_env, did, subst) {
                 TraitResolved::NotATraitItem => (),
                 TraitResolved::Instance(did_i, subst_i) => (did, subst) = (did_i, subst_i),
-                TraitResolved::UnknownFound => return None,
+                TraitResolved::UnknownFound(_) | TraitResolved::Ambiguous => return None,
                 TraitResolved::UnknownNotFound | TraitResolved::NoInstance => unreachable!(),
             }
             post_fndef(ctx, typing_env, did, subst, args, res)
@@ -759,10 +826,10 @@ fn fn_mut_postcond_term<'tcx>(
             ]))
         }
         &TyKind::FnDef(mut did, mut subst) => {
-            match TraitResolved::resolve_item(ctx.tcx, typing_env, did, subst) {
+            match ctx.resolve_item(typing_env, did, subst) {
                 TraitResolved::NotATraitItem => (),
                 TraitResolved::Instance(did_i, subst_i) => (did, subst) = (did_i, subst_i),
-                TraitResolved::UnknownFound => return None,
+                TraitResolved::UnknownFound(_) | TraitResolved::Ambiguous => return None,
                 TraitResolved::UnknownNotFound | TraitResolved::NoInstance => unreachable!(),
             }
             post_fndef(ctx, typing_env, did, subst, args, res)
@@ -835,10 +902,10 @@ fn fn_postcond_term<'tcx>(
             ]))
         }
         &TyKind::FnDef(mut did, mut subst) => {
-            match TraitResolved::resolve_item(ctx.tcx, typing_env, did, subst) {
+            match ctx.resolve_item(typing_env, did, subst) {
                 TraitResolved::NotATraitItem => (),
                 TraitResolved::Instance(did_i, subst_i) => (did, subst) = (did_i, subst_i),
-                TraitResolved::UnknownFound => return None,
+                TraitResolved::UnknownFound(_) | TraitResolved::Ambiguous => return None,
                 TraitResolved::UnknownNotFound | TraitResolved::NoInstance => unreachable!(),
             }
             post_fndef(ctx, typing_env, did, subst, args, res)
@@ -864,7 +931,7 @@ fn post_fndef<'tcx>(
 
     let mut sig = EarlyBinder::bind(ctx.sig(did).clone())
         .instantiate(ctx.tcx, subst)
-        .normalize(ctx.tcx, typing_env);
+        .normalize(ctx, typing_env);
     sig.add_type_invariant_spec(ctx, did, typing_env);
     let mut post = sig.contract.ensures_conj(ctx.tcx);
     post.subst(&HashMap::from([(name::result(), res.kind)]));
@@ -911,10 +978,10 @@ fn fn_once_precond_term<'tcx>(
             ]))
         }
         &TyKind::FnDef(mut did, mut subst) => {
-            match TraitResolved::resolve_item(ctx.tcx, typing_env, did, subst) {
+            match ctx.resolve_item(typing_env, did, subst) {
                 TraitResolved::NotATraitItem => (),
                 TraitResolved::Instance(did_i, subst_i) => (did, subst) = (did_i, subst_i),
-                TraitResolved::UnknownFound => return None,
+                TraitResolved::UnknownFound(_) | TraitResolved::Ambiguous => return None,
                 TraitResolved::UnknownNotFound | TraitResolved::NoInstance => unreachable!(),
             }
             pre_fndef(ctx, typing_env, did, subst, args)
@@ -953,7 +1020,7 @@ fn pre_fndef<'tcx>(
     }
     let mut sig = EarlyBinder::bind(ctx.sig(did).clone())
         .instantiate(ctx.tcx, subst)
-        .normalize(ctx.tcx, typing_env);
+        .normalize(ctx, typing_env);
     sig.add_type_invariant_spec(ctx, did, typing_env);
     let pre = sig.contract.requires_conj(ctx.tcx);
     let pattern = Pattern::tuple(
@@ -1025,6 +1092,7 @@ fn term<'tcx>(
     match dep {
         Dependency::Item(def_id, subst) => {
             if matches!(ctx.item_type(def_id), ItemType::Constant) {
+                let (def_id, subst) = ctx.resolve_assoc_const(typing_env, def_id, subst)?;
                 let ct = UnevaluatedConst::new(def_id, subst);
                 let constant = Const::new(ctx.tcx, ConstKind::Unevaluated(ct));
                 let ty = ctx.type_of(def_id).instantiate(ctx.tcx, subst);
@@ -1049,11 +1117,8 @@ fn term<'tcx>(
                 fn_mut_hist_inv_term(ctx, typing_env, subst, bound)
             } else {
                 let term = ctx.term_fail_fast(def_id).unwrap().rename(bound);
-                let term = normalize(
-                    ctx.tcx,
-                    typing_env,
-                    EarlyBinder::bind(term).instantiate(ctx.tcx, subst),
-                );
+                let term =
+                    normalize(ctx, typing_env, EarlyBinder::bind(term).instantiate(ctx.tcx, subst));
                 Some(term)
             }
         }
@@ -73,7 +73,7 @@ pub(crate) fn closure_hist_inv<'tcx>(
         }
     }
 
-    normalize(ctx.tcx, ctx.typing_env(def_id.into()), hist_inv).span(ctx.def_span(def_id))
+    normalize(ctx, ctx.typing_env(def_id.into()), hist_inv).span(ctx.def_span(def_id))
 }
 
 pub(crate) fn closure_pre<'tcx>(
@@ -130,7 +130,7 @@ pub(crate) fn closure_pre<'tcx>(
         args.ty,
     );
     pre = Term::let_(pattern, args, pre).span(ctx.def_span(def_id));
-    normalize(ctx.tcx, ctx.typing_env(def_id.into()), pre)
+    normalize(ctx, ctx.typing_env(def_id.into()), pre)
 }
 
 pub(crate) fn closure_post<'tcx>(
@@ -304,7 +304,7 @@ pub(crate) fn closure_post<'tcx>(
         args.ty,
     );
     post = Term::let_(pattern, args, post).span(ctx.def_span(def_id));
-    normalize(ctx.tcx, typing_env, post)
+    normalize(ctx, typing_env, post)
 }
 
 pub(crate) fn closure_resolve<'tcx>(
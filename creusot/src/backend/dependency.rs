@@ -5,7 +5,7 @@ use rustc_hir::{
 use rustc_macros::{TypeFoldable, TypeVisitable};
 use rustc_middle::{
     mir::Promoted,
-    ty::{GenericArgsRef, List, Ty, TyCtxt, TyKind},
+    ty::{GenericArgsRef, List, ParamConst, Ty, TyCtxt, TyKind},
 };
 use rustc_span::Symbol;
 use rustc_target::abi::FieldIdx;
@@ -32,6 +32,8 @@ pub(crate) enum Dependency<'tcx> {
     PreMod(PreMod),
     Eliminator(DefId, GenericArgsRef<'tcx>),
     Promoted(LocalDefId, Promoted),
+    /// A const generic parameter, declared as an abstract constant.
+    ConstParam(ParamConst),
 }
 
 impl<'tcx> Dependency<'tcx> {
@@ -114,6 +116,9 @@ impl<'tcx> Dependency<'tcx> {
                         .unwrap_or_else(|| tcx.def_path_str(did.to_def_id()))
                 ))
             ))),
+            Dependency::ConstParam(p) => Some(Symbol::intern(&value_name(
+                &p.name.as_str().replace(|c: char| !(c.is_ascii_alphanumeric() || c == '_'), "_"),
+            ))),
             Dependency::PreMod(_) => None,
         }
     }
@@ -3,7 +3,7 @@ use crate::{
         CannotFetchThir, Why3Generator, is_trusted_item, logic::vcgen::wp,
         signature::lower_logic_sig, term::lower_pure, ty::translate_ty,
     },
-    contracts_items::get_builtin,
+    contracts_items::{get_builtin, is_forall_impls},
     ctx::*,
     naming::name,
     translated_item::FileModule,
@@ -23,7 +23,7 @@ pub(crate) fn translate_logic_or_predicate(
     def_id: DefId,
 ) -> Result<Option<FileModule>, CannotFetchThir> {
     let mut names = Dependencies::new(ctx, def_id);
-    let pre_sig = ctx.sig(def_id).clone().normalize(ctx.tcx, ctx.typing_env(def_id));
+    let pre_sig = ctx.sig(def_id).clone().normalize(ctx, ctx.typing_env(def_id));
 
     if pre_sig.contract.is_empty() {
         return Ok(None);
@@ -41,6 +41,11 @@ pub(crate) fn translate_logic_or_predicate(
         return Ok(None);
     }
 
+    // A `#[forall_impls]` law is proved at each impl of its trait instead, see `translate_impl`.
+    if is_forall_impls(ctx.tcx, def_id) {
+        return Ok(None);
+    }
+
     let mut body_decls = Vec::new();
 
     let args = pre_sig.inputs.clone();
@@ -109,7 +114,7 @@ pub(crate) fn translate_logic_or_predicate(
     let mut decls = names.provide_deps(ctx);
     decls.extend(body_decls);
 
-    let attrs = ctx.span_attr(ctx.def_span(def_id)).into_iter().collect();
+    let attrs = ctx.module_attrs(def_id);
     let meta = ctx.display_impl_of(def_id);
     let path = ctx.module_path(def_id);
     let name = path.why3_ident();
@@ -287,6 +287,7 @@ impl<'tcx> VCGen<'_, 'tcx> {
                 _ => self.ctx.crash_and_error(t.span, "unsupported cast"),
             },
             TermKind::Coerce { arg } => self.build_wp(arg, k),
+            TermKind::Const(_) => k(self.lower_pure(t)),
             // Items are just global names so
             // VC(i, Q) = Q(i)
             TermKind::Item(id, sub) => {
@@ -309,9 +310,20 @@ impl<'tcx> VCGen<'_, 'tcx> {
             TermKind::Call { id, subst, args } => self.build_wp_slice(args, &|args| {
                 let pre_sig = EarlyBinder::bind(self.ctx.sig(*id).clone())
                     .instantiate(self.ctx.tcx, subst)
-                    .normalize(self.ctx.tcx, self.typing_env);
+                    .normalize(self.ctx, self.typing_env);
 
                 let variant = pre_sig.contract.variant.clone();
+                let call = Exp::Var(self.names.item(*id, subst)).app(args.clone());
+                let call_subst = pre_sig
+                    .inputs
+                    .iter()
+                    .zip(args.clone())
+                    .map(|((nm, _, _), arg)| (nm.0, arg))
+                    .chain(std::iter::once((name::result(), call.clone())))
+                    .collect();
+                let mut contract = lower_contract(self.ctx, self.names, pre_sig.contract);
+                contract.subst(&call_subst);
+
                 let variant = if *id == self.self_id {
                     let subst = self.ctx.normalize_erasing_regions(self.typing_env, *subst);
                     let subst_id = erased_identity_for_item(self.ctx.tcx, *id);
@@ -326,21 +338,19 @@ impl<'tcx> VCGen<'_, 'tcx> {
                     } else {
                         Exp::mk_false()
                     }
+                } else if self.ctx.mutually_recursive(self.self_id, *id) {
+                    // Both functions have a variant, see `validate_terminates`: the one of the
+                    // callee, instantiated with the arguments of the call, must decrease.
+                    let variant = variant.unwrap();
+                    self.build_decrease(
+                        contract.variant.clone().unwrap(),
+                        variant.ty,
+                        variant.span,
+                    )?
                 } else {
                     Exp::mk_true()
                 };
 
-                let call = Exp::Var(self.names.item(*id, subst)).app(args.clone());
-                let call_subst = pre_sig
-                    .inputs
-                    .iter()
-                    .zip(args)
-                    .map(|((nm, _, _), arg)| (nm.0, arg))
-                    .chain(std::iter::once((name::result(), call.clone())))
-                    .collect();
-                let mut contract = lower_contract(self.ctx, self.names, pre_sig.contract);
-                contract.subst(&call_subst);
-
                 let post = contract
                     .requires_conj_labelled()
                     .log_and(variant)
@@ -622,9 +632,20 @@ impl<'tcx> VCGen<'_, 'tcx> {
     ) -> Result<Exp, VCError<'tcx>> {
         let subst: HashMap<Ident, Exp> =
             self.args_names.iter().cloned().zip(call_args.iter().cloned()).collect();
-        let orig_variant = self.variant.clone().unwrap();
-        let mut rec_var_exp = orig_variant.clone();
+        let mut rec_var_exp = self.variant.clone().unwrap();
         rec_var_exp.subst(&subst);
+        self.build_decrease(rec_var_exp, variant_ty, span)
+    }
+
+    // Generates the expression checking that `rec_var_exp`, the variant at a (mutually)
+    // recursive call, decreases with respect to the variant at entry.
+    fn build_decrease(
+        &self,
+        rec_var_exp: Exp,
+        variant_ty: Ty<'tcx>,
+        span: Span,
+    ) -> Result<Exp, VCError<'tcx>> {
+        let orig_variant = self.variant.clone().unwrap();
         if is_int(self.ctx.tcx, variant_ty) {
             self.names.import_prelude_module(PreMod::Int);
             let orig_variant = orig_variant.boxed();
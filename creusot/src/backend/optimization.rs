@@ -68,7 +68,10 @@ impl<'a, 'tcx> LocalUsage<'a, 'tcx> {
 
     fn visit_block(&mut self, b: &Block<'tcx>) {
         b.invariants.iter().for_each(|t| self.visit_term(&t.body));
-        b.variant.iter().for_each(|t| self.visit_term(t));
+        b.variant.iter().for_each(|v| {
+            self.visit_term(&v.term);
+            self.read_many(v.old)
+        });
         b.stmts.iter().for_each(|s| self.visit_statement(s));
         self.visit_terminator(&b.terminator);
     }
@@ -24,15 +24,19 @@ use crate::{
         },
         wto::{Component, weak_topological_order},
     },
+    contracts_items::{OverflowMode, is_inline_contract, is_verify_monomorphized},
     ctx::{BodyId, Dependencies},
-    naming::name,
+    naming::{ModulePath, name},
+    run_why3::SATISFIABILITY_EXPL,
     translated_item::FileModule,
     translation::{
         fmir::{
             Block, Body, BorrowKind, Branches, LocalDecls, Operand, Place, RValue, Statement,
             Terminator, TrivialInv,
         },
-        pearlite::Pattern,
+        pearlite::{Pattern, Term as PTerm},
+        specification::PreSignature,
+        traits::TraitResolved,
     },
 };
 use indexmap::IndexMap;
@@ -44,17 +48,17 @@ use rustc_hir::{
 };
 use rustc_middle::{
     mir::{BasicBlock, BinOp, ProjectionElem, START_BLOCK, UnOp, tcx::PlaceTy},
-    ty::{AdtDef, GenericArgsRef, Ty, TyCtxt, TyKind},
+    ty::{AdtDef, EarlyBinder, GenericArgsRef, Ty, TyCtxt, TyKind, TypingEnv},
 };
-use rustc_span::DUMMY_SP;
+use rustc_span::{DUMMY_SP, Span};
 use rustc_target::abi::VariantIdx;
 use rustc_type_ir::{IntTy, UintTy};
 use std::{collections::HashMap, fmt::Debug, iter::once};
 use why3::{
     Ident, Name,
     coma::{Arg, Defn, Expr, IsRef, Param, Prototype, Term, Var},
-    declaration::{Attribute, Condition, Contract, Decl, Meta, MetaArg, MetaIdent, Module},
-    exp::{Binder, Constant, Exp, Pattern as WPattern},
+    declaration::{Attribute, Condition, Contract, Decl, Goal, Meta, MetaArg, MetaIdent, Module},
+    exp::{BinOp as WBinOp, Binder, Constant, Exp, Pattern as WPattern, UnOp as WUnOp},
     ty::Type,
 };
 
@@ -65,6 +69,76 @@ pub(crate) fn translate_function(ctx: &Why3Generator, def_id: DefId) -> Option<F
         return None;
     }
 
+    // The function is verified for each of its instantiations instead, see
+    // `Why3Generator::translate_monomorphizations`.
+    if is_verify_monomorphized(ctx.tcx, def_id)
+        && ctx.generics_of(def_id).requires_monomorphization(ctx.tcx)
+    {
+        if ctx.effective_visibilities(()).is_exported(def_id.expect_local()) {
+            let msg = "a function verified for each of its instantiations cannot be exported, as other crates could instantiate it differently";
+            ctx.error(ctx.def_span(def_id), msg).emit();
+        }
+        return None;
+    }
+
+    function_module(ctx, names, ctx.module_path(def_id))
+}
+
+/// The module of `--check-precondition-satisfiability` for `def_id`, if it has a precondition: its
+/// goal states that some arguments satisfy the precondition, so that proving it shows that the
+/// function is not verified vacuously.
+pub(crate) fn precondition_vacuity(ctx: &Why3Generator, def_id: DefId) -> Option<FileModule> {
+    if !def_id.is_local() || !ctx.has_body(def_id) || is_trusted_item(ctx.tcx, def_id) {
+        return None;
+    }
+    let typing_env = ctx.typing_env(def_id);
+    let mut sig = ctx.sig(def_id).clone().normalize(ctx, typing_env);
+    if sig.contract.requires.is_empty() {
+        return None;
+    }
+    // The invariants of the arguments are also assumed by the body.
+    sig.add_type_invariant_spec(ctx, def_id, typing_env);
+
+    let span = ctx.def_span(def_id);
+    let satisfiable = sig.contract.requires_conj(ctx.tcx).span(span);
+    let satisfiable =
+        sig.inputs.iter().rfold(satisfiable, |acc, &(id, _, ty)| acc.exists((id, ty)).span(span));
+
+    let names = Dependencies::new(ctx, def_id);
+    let goal = lower_pure(ctx, &names, &satisfiable)
+        .with_attr(Attribute::Attr(format!("expl:{SATISFIABILITY_EXPL}")));
+    let mut decls = names.provide_deps(ctx);
+    decls.push(Decl::Goal(Goal { name: Ident::fresh(ctx.crate_name(), "satisfiable"), goal }));
+
+    let mut path = ctx.module_path(def_id);
+    path.add_suffix("__vacuity");
+    let attrs = ctx.module_attrs(def_id);
+    let meta = ctx.display_impl_of(def_id);
+    let name = path.why3_ident();
+    Some(FileModule { path, modl: Module { name, decls: decls.into(), attrs, meta } })
+}
+
+/// Translate the body of `def_id`, instantiated with the concrete `subst`.
+///
+/// The module is named after the function, with the suffix `suffix`.
+pub(crate) fn translate_monomorphized<'tcx>(
+    ctx: &Why3Generator<'tcx>,
+    def_id: DefId,
+    subst: GenericArgsRef<'tcx>,
+    suffix: &str,
+) -> Option<FileModule> {
+    let names = Dependencies::new_monomorphized(ctx, def_id, subst);
+    let mut path = ctx.module_path(def_id);
+    path.add_suffix(suffix);
+    function_module(ctx, names, path)
+}
+
+fn function_module(
+    ctx: &Why3Generator,
+    names: Dependencies,
+    path: ModulePath,
+) -> Option<FileModule> {
+    let def_id = names.self_id;
     let name = names.item_ident(names.self_id, names.self_subst);
     let body = Decl::Coma(to_why(ctx, &names, name, BodyId::new(def_id.expect_local(), None)));
 
@@ -75,9 +149,8 @@ pub(crate) fn translate_function(ctx: &Why3Generator, def_id: DefId) -> Option<F
     }));
     decls.push(body);
 
-    let attrs = ctx.span_attr(ctx.def_span(def_id)).into_iter().collect();
+    let attrs = ctx.module_attrs(def_id);
     let meta = ctx.display_impl_of(def_id);
-    let path = ctx.module_path(def_id);
     let name = path.why3_ident();
     Some(FileModule { path, modl: Module { name, decls: decls.into(), attrs, meta } })
 }
@@ -165,14 +238,29 @@ pub(crate) fn to_why<'tcx, N: Namer<'tcx>>(
     let blocks: Box<[Defn]> = wto
         .into_iter()
         .map(|c| {
-            component_to_defn(&mut body, ctx, names, body_id.def_id, &block_idents, inner_return, c)
+            component_to_defn(
+                &mut body,
+                ctx,
+                names,
+                body_id.def_id,
+                &block_idents,
+                inner_return,
+                &[],
+                c,
+            )
         })
         .collect();
 
     let (mut sig, contract, return_ty) = if body_id.promoted.is_none() {
         let def_id = body_id.def_id();
-        let typing_env = ctx.typing_env(def_id);
-        let mut pre_sig = ctx.sig(def_id).clone().normalize(ctx.tcx, typing_env);
+        let (pre_sig, typing_env) = match names.monomorphization() {
+            Some(subst) => (
+                EarlyBinder::bind(ctx.sig(def_id).clone()).instantiate(ctx.tcx, subst),
+                TypingEnv::fully_monomorphized(),
+            ),
+            None => (ctx.sig(def_id).clone(), ctx.typing_env(def_id)),
+        };
+        let mut pre_sig = pre_sig.normalize(ctx, typing_env);
         pre_sig.add_type_invariant_spec(ctx, def_id, typing_env);
         lower_program_sig(ctx, names, name, pre_sig, def_id, outer_return)
     } else {
@@ -265,24 +353,57 @@ fn component_to_defn<'tcx, N: Namer<'tcx>>(
     def_id: LocalDefId,
     block_idents: &IndexMap<BasicBlock, Ident>,
     return_ident: Ident,
+    loop_variants: &[(BasicBlock, Exp, Ident)],
     c: Component<BasicBlock>,
 ) -> Defn {
-    let mut lower =
-        LoweringState { ctx, names, locals: &body.locals, def_id, block_idents, return_ident };
     let (head, tl) = match c {
         Component::Vertex(v) => {
             let block = body.blocks.shift_remove(&v).unwrap();
+            let mut lower = LoweringState {
+                ctx,
+                names,
+                locals: &body.locals,
+                def_id,
+                block_idents,
+                return_ident,
+                loop_variants,
+            };
             return block.into_why(&mut lower, v);
         }
         Component::Component(v, tls) => (v, tls),
     };
 
     let block = body.blocks.shift_remove(&head).unwrap();
+    // The jumps back to the head of the loop, in its body, check that the variant decreased.
+    let mut loop_variants = loop_variants.to_vec();
+    if let Some(variant) = &block.variant {
+        loop_variants.push((head, lower_pure(ctx, names, &variant.term), variant.old));
+    }
+    let mut lower = LoweringState {
+        ctx,
+        names,
+        locals: &body.locals,
+        def_id,
+        block_idents,
+        return_ident,
+        loop_variants: &loop_variants,
+    };
     let mut block = block.into_why(&mut lower, head);
 
     let defns = tl
         .into_iter()
-        .map(|id| component_to_defn(body, ctx, names, def_id, block_idents, return_ident, id))
+        .map(|id| {
+            component_to_defn(
+                body,
+                ctx,
+                names,
+                def_id,
+                block_idents,
+                return_ident,
+                &loop_variants,
+                id,
+            )
+        })
         .collect();
 
     if !block.body.is_guarded() {
@@ -305,6 +426,9 @@ pub(crate) struct LoweringState<'a, 'tcx, N: Namer<'tcx>> {
     pub(super) def_id: LocalDefId,
     block_idents: &'a IndexMap<BasicBlock, Ident>,
     return_ident: Ident,
+    /// The headers of the enclosing loops with a variant, with the variant and the local holding
+    /// its value at the start of the iteration.
+    loop_variants: &'a [(BasicBlock, Exp, Ident)],
 }
 
 impl<'tcx, N: Namer<'tcx>> LoweringState<'_, 'tcx, N> {
@@ -312,6 +436,36 @@ impl<'tcx, N: Namer<'tcx>> LoweringState<'_, 'tcx, N> {
         translate_ty(self.ctx, self.names, DUMMY_SP, ty)
     }
 
+    /// Jump to `bb`. Going back to the head of an enclosing loop with a variant ends an
+    /// iteration: the variant must have decreased since the start of the iteration.
+    fn goto(&self, bb: BasicBlock) -> Expr {
+        let goto = Expr::var(self.block_idents[&bb]);
+        let Some((_, variant, old)) = self.loop_variants.iter().find(|(head, ..)| *head == bb)
+        else {
+            return goto;
+        };
+        self.names.import_prelude_module(PreMod::Int);
+        let old = Exp::var(*old).boxed();
+        let decrease = Exp::BinaryOp(WBinOp::Le, Exp::int(0).boxed(), old.clone())
+            .log_and(Exp::BinaryOp(WBinOp::Lt, variant.clone().boxed(), old));
+        Expr::assert(decrease.with_attr(Attribute::Attr("expl:loop variant".into())), goto)
+    }
+
+    /// How the integer arithmetic of the body is modeled.
+    fn overflow_mode(&self) -> OverflowMode {
+        self.ctx.overflow_mode(self.def_id.to_def_id())
+    }
+
+    /// The mathematical integer represented by `exp`, of the machine integer type `ty`.
+    fn to_int(&self, ty: Ty<'tcx>, exp: Exp) -> Exp {
+        let conv = match ty.kind() {
+            TyKind::Int(ity) => self.names.in_pre(ity_to_prelude(self.ctx.tcx, *ity), "to_int"),
+            TyKind::Uint(uty) => self.names.in_pre(uty_to_prelude(self.ctx.tcx, *uty), "t'int"),
+            _ => unreachable!("non-integer type {ty:?}"),
+        };
+        Exp::qvar(conv).app([exp])
+    }
+
     fn assignment(&self, lhs: &Place<'tcx>, rhs: Term, istmts: &mut Vec<IntermediateStmt>) {
         create_assign_inner(self, lhs, rhs, istmts)
     }
@@ -416,11 +570,31 @@ impl<'tcx> RValue<'tcx> {
                     Offset => unimplemented!("pointer offsets are unsupported"),
                 };
 
+                let int_op = match op {
+                    Add => Some(WBinOp::Add),
+                    Sub => Some(WBinOp::Sub),
+                    Mul => Some(WBinOp::Mul),
+                    _ => None,
+                };
+
                 let fname = lower.names.in_pre(prelude, opname);
                 let args = [l.into_why(lower, istmts), r];
 
                 if logic {
                     Exp::qvar(fname).app(args)
+                } else if let Some(int_op) = int_op
+                    && l_ty.is_integral()
+                    && lower.overflow_mode() != OverflowMode::Check
+                {
+                    let res = Exp::qvar(fname).app(args.clone());
+                    if lower.overflow_mode() == OverflowMode::Assume {
+                        let [a, b] = args.map(|e| lower.to_int(l_ty, e));
+                        let exact = Exp::BinaryOp(int_op, Box::new(a), Box::new(b));
+                        istmts.push(IntermediateStmt::Assume(
+                            lower.to_int(l_ty, res.clone()).eq(exact),
+                        ));
+                    }
+                    res
                 } else {
                     let ret_ident = Ident::fresh_local("_ret");
                     istmts.push(IntermediateStmt::call(
@@ -457,6 +631,18 @@ impl<'tcx> RValue<'tcx> {
                 };
 
                 let neg = lower.names.in_pre(prelude, "neg");
+                let mode = lower.overflow_mode();
+                if ty.is_integral() && mode != OverflowMode::Check {
+                    let arg = arg.into_why(lower, istmts);
+                    let res = Exp::qvar(neg).app([arg.clone()]);
+                    if mode == OverflowMode::Assume {
+                        let exact = Exp::UnaryOp(WUnOp::Neg, Box::new(lower.to_int(ty, arg)));
+                        istmts.push(IntermediateStmt::Assume(
+                            lower.to_int(ty, res.clone()).eq(exact),
+                        ));
+                    }
+                    return res;
+                }
                 let ret_ident = Ident::fresh_local("_ret");
                 let arg = Arg::Term(arg.into_why(lower, istmts));
                 istmts.push(IntermediateStmt::call(ret_ident, lower.ty(ty), Name::Global(neg), [
@@ -517,6 +703,11 @@ impl<'tcx> RValue<'tcx> {
                                     if lower.names.bitwise_mode() { "to_BV256" } else { "t'int" };
                                 lower.names.in_pre(uty_to_prelude(lower.ctx.tcx, *ity), fct_name)
                             }
+                            TyKind::Char => {
+                                let fct_name =
+                                    if lower.names.bitwise_mode() { "to_BV256" } else { "to_int" };
+                                lower.names.in_pre(PreMod::Char, fct_name)
+                            }
                             _ => lower.ctx.crash_and_error(
                                 DUMMY_SP,
                                 &format!("casts {:?} are currently unsupported", source.kind()),
@@ -711,7 +902,7 @@ impl<'tcx> Terminator<'tcx> {
     ) -> (Vec<IntermediateStmt>, Expr) {
         let mut istmts = vec![];
         let exp = match self {
-            Terminator::Goto(bb) => Expr::var(*lower.block_idents.get(&bb).unwrap()),
+            Terminator::Goto(bb) => lower.goto(bb),
             Terminator::Switch(switch, branches) => {
                 let ty = switch.ty(lower.ctx.tcx, lower.locals);
                 let discr = switch.into_why(lower, &mut istmts);
@@ -740,7 +931,7 @@ impl<'tcx> Branches<'tcx> {
         discr: Exp,
         discr_ty: &Ty<'tcx>,
     ) -> Expr {
-        let LoweringState { ctx, names, block_idents, .. } = *lower;
+        let LoweringState { ctx, names, .. } = *lower;
         match self {
             Branches::Int(brs, def) => {
                 let TyKind::Int(ity) = discr_ty.kind() else {
@@ -761,12 +952,12 @@ impl<'tcx> Branches<'tcx> {
                         } else {
                             Exp::Const(Constant::Int(val, Some(why_ty)))
                         };
-                        (e, mk_goto(block_idents, tgt))
+                        (e, lower.goto(tgt))
                     }),
                 );
                 let brs = brs.chain([Defn::simple(
                     Ident::fresh_local("default"),
-                    mk_goto(block_idents, def).black_box(),
+                    lower.goto(def).black_box(),
                 )]);
                 Expr::Defn(Expr::Any.boxed(), false, brs.collect())
             }
@@ -782,13 +973,10 @@ impl<'tcx> Branches<'tcx> {
                         let why_ty =
                             Type::qconstructor(names.in_pre(uty_to_prelude(ctx.tcx, *uty), "t"));
                         let e = Exp::Const(Constant::Uint(val, Some(why_ty)));
-                        (e, mk_goto(block_idents, tgt))
+                        (e, lower.goto(tgt))
                     }),
                 )
-                .chain([Defn::simple(
-                    Ident::fresh_local("default"),
-                    mk_goto(block_idents, def).black_box(),
-                )])
+                .chain([Defn::simple(Ident::fresh_local("default"), lower.goto(def).black_box())])
                 .collect();
                 Expr::Defn(Expr::Any.boxed(), false, brs)
             }
@@ -798,8 +986,8 @@ impl<'tcx> Branches<'tcx> {
             }
             Branches::Bool(f, t) => {
                 let brs = mk_switch_branches(discr, vec![
-                    (Exp::mk_false(), mk_goto(block_idents, f)),
-                    (Exp::mk_true(), mk_goto(block_idents, t)),
+                    (Exp::mk_false(), lower.goto(f)),
+                    (Exp::mk_true(), lower.goto(t)),
                 ]);
 
                 Expr::Defn(Expr::Any.boxed(), false, brs.collect())
@@ -808,10 +996,6 @@ impl<'tcx> Branches<'tcx> {
     }
 }
 
-fn mk_goto(block_idents: &IndexMap<BasicBlock, Ident>, bb: BasicBlock) -> Expr {
-    Expr::var(*block_idents.get(&bb).unwrap())
-}
-
 fn mk_adt_switch<'tcx, N: Namer<'tcx>>(
     lower: &LoweringState<'_, 'tcx, N>,
     adt: AdtDef<'tcx>,
@@ -822,7 +1006,7 @@ fn mk_adt_switch<'tcx, N: Namer<'tcx>>(
 ) -> Box<[Defn]> {
     assert!(adt.is_enum());
 
-    let LoweringState { ctx, names, block_idents, .. } = *lower;
+    let LoweringState { ctx, names, .. } = *lower;
     let mut brch = brch.into_iter().peekable();
 
     let res = adt
@@ -852,7 +1036,7 @@ fn mk_adt_switch<'tcx, N: Namer<'tcx>>(
 
             let cons = names.constructor(var.def_id, subst);
             let body = Exp::var(cons).app(ids);
-            let body = Expr::assert(discr.clone().eq(body), mk_goto(block_idents, tgt).black_box());
+            let body = Expr::assert(discr.clone().eq(body), lower.goto(tgt).black_box());
             let name = Ident::fresh_local(format!("br{}", ix.as_usize()));
 
             Defn { prototype: Prototype { name, params: params.into(), attrs: vec![] }, body }
@@ -1100,8 +1284,64 @@ impl<'tcx> Statement<'tcx> {
                 let rhs = e.into_why(lower, lhs.ty(lower.ctx.tcx, lower.locals), &mut istmts);
                 lower.assignment(&lhs, rhs, &mut istmts);
             }
-            Statement::Call(dest, fun_id, subst, args, _) => {
+            Statement::Call(dest, fun_id, subst, args, _) if lower.ctx.is_logic_extern(fun_id) => {
+                // A call of a `pure(logic)` function is the application of its logical model, once
+                // its precondition is checked.
+                let args: Box<[_]> =
+                    args.into_iter().map(|a| a.into_why(lower, &mut istmts)).collect();
+                let tcx = lower.ctx.tcx;
+                let typing_env = lower.ctx.typing_env(lower.def_id.to_def_id());
+                let pre_sig = EarlyBinder::bind(lower.ctx.sig(fun_id).clone())
+                    .instantiate(tcx, subst)
+                    .normalize(lower.ctx, typing_env);
+                let arg_subst: HashMap<_, _> = pre_sig
+                    .inputs
+                    .iter()
+                    .map(|(id, _, _)| id.0)
+                    .zip(args.iter().cloned())
+                    .collect();
+                for cond in pre_sig.contract.requires {
+                    let mut pre = lower_pure(lower.ctx, lower.names, &cond.term);
+                    pre.subst(&arg_subst);
+                    istmts
+                        .push(IntermediateStmt::Assert(pre.with_attr(Attribute::Attr(cond.expl))));
+                }
+                let call = Exp::Var(lower.names.item(fun_id, subst)).app(args);
+                lower.assignment(&dest, call, &mut istmts);
+            }
+            Statement::Call(dest, fun_id, subst, args, _)
+                if let Some(sig) = inlined_contract(lower, fun_id, subst) =>
+            {
+                // The precondition of the impl is checked, and its postcondition holds of an
+                // arbitrary result.
+                let args: Box<[_]> =
+                    args.into_iter().map(|a| a.into_why(lower, &mut istmts)).collect();
+                let mut arg_subst: HashMap<_, _> =
+                    sig.inputs.iter().map(|(id, _, _)| id.0).zip(args.iter().cloned()).collect();
+                for cond in sig.contract.requires {
+                    let mut pre = lower_pure(lower.ctx, lower.names, &cond.term);
+                    pre.subst(&arg_subst);
+                    istmts
+                        .push(IntermediateStmt::Assert(pre.with_attr(Attribute::Attr(cond.expl))));
+                }
+                let ty = lower.ty(dest.ty(lower.ctx.tcx, lower.locals));
+                let ret_ident = Ident::fresh_local("_ret");
+                istmts.push(IntermediateStmt::Any(ret_ident, ty));
+                arg_subst.insert(name::result(), Exp::var(ret_ident));
+                for cond in sig.contract.ensures {
+                    let mut post = lower_pure(lower.ctx, lower.names, &cond.term);
+                    post.subst(&arg_subst);
+                    istmts.push(IntermediateStmt::Assume(post));
+                }
+                lower.assignment(&dest, Exp::var(ret_ident), &mut istmts);
+            }
+            Statement::Call(dest, fun_id, subst, args, span) => {
                 let (fun_qname, args) = func_call_to_why3(lower, fun_id, subst, args, &mut istmts);
+                if !lower.ctx.is_closure_like(fun_id)
+                    && lower.ctx.is_recursive_call(lower.def_id.to_def_id(), fun_id)
+                {
+                    recursive_call_preconditions(lower, fun_id, subst, &args, span, &mut istmts);
+                }
                 let ty = dest.ty(lower.ctx.tcx, lower.locals);
                 let ty = lower.ty(ty);
                 let ret_ident = Ident::fresh_local("_ret");
@@ -1212,6 +1452,90 @@ fn pattern_of_place<'tcx>(
     pat
 }
 
+/// Check the precondition of a recursive call at the call itself, so that a failure is reported
+/// as a recursive call violating the precondition, at the location of the call. The precondition
+/// includes the type invariants of the arguments: the declaration of the callee in the module of
+/// the caller has no precondition (see `ProgramElab`), so that it is only checked here.
+fn recursive_call_preconditions<'tcx, N: Namer<'tcx>>(
+    lower: &LoweringState<'_, 'tcx, N>,
+    id: DefId,
+    subst: GenericArgsRef<'tcx>,
+    args: &[Arg],
+    span: Span,
+    istmts: &mut Vec<IntermediateStmt>,
+) {
+    let tcx = lower.ctx.tcx;
+    let typing_env = lower.ctx.typing_env(lower.def_id.to_def_id());
+    let mut pre_sig = EarlyBinder::bind(lower.ctx.sig(id).clone())
+        .instantiate(tcx, subst)
+        .normalize(lower.ctx, typing_env);
+    pre_sig.add_type_invariant_spec(lower.ctx, id, typing_env);
+    let arg_subst: HashMap<_, _> = pre_sig
+        .inputs
+        .iter()
+        .zip(args)
+        .filter_map(|((name, _, _), arg)| match arg {
+            Arg::Term(exp) => Some((name.0, exp.clone())),
+            _ => None,
+        })
+        .collect();
+    let span_attr = lower.names.span(span);
+    for cond in pre_sig.contract.requires {
+        let mut pre = lower_pure(lower.ctx, lower.names, &cond.term);
+        pre.subst(&arg_subst);
+        let expl = cond.expl.replacen("expl:", "expl:recursive call: ", 1);
+        pre = pre.with_attr(Attribute::Attr(expl));
+        if let Some(attr) = span_attr.clone() {
+            pre = pre.with_attr(attr);
+        }
+        istmts.push(IntermediateStmt::Assert(pre));
+    }
+}
+
+/// The largest number of clauses of a contract which is inlined by
+/// `#[creusot::inline_contract]`.
+const INLINED_CONTRACT_MAX_CLAUSES: usize = 4;
+
+/// The contract of the impl item that a call of `fun_id` resolves to, if it is inlined at the call
+/// site instead of calling the impl item.
+///
+/// A contract is inlined in a function with the attribute `#[creusot::inline_contract]`, when the
+/// call resolves to a concrete impl (not through a bound of the generic parameters), and the
+/// contract of the impl is small: it has at most [`INLINED_CONTRACT_MAX_CLAUSES`] clauses, without
+/// quantifiers. The contract then gives the solvers its facts directly at the call site.
+fn inlined_contract<'tcx, N: Namer<'tcx>>(
+    lower: &LoweringState<'_, 'tcx, N>,
+    fun_id: DefId,
+    subst: GenericArgsRef<'tcx>,
+) -> Option<PreSignature<'tcx>> {
+    let ctx = lower.ctx;
+    let def_id = lower.def_id.to_def_id();
+    if !is_inline_contract(ctx.tcx, def_id) {
+        return None;
+    }
+    let typing_env = ctx.typing_env(def_id);
+    let TraitResolved::Instance(impl_id, impl_subst) = ctx.resolve_item(typing_env, fun_id, subst)
+    else {
+        return None;
+    };
+    if ctx.is_closure_like(impl_id) || ctx.is_recursive_call(def_id, impl_id) {
+        return None;
+    }
+    let mut sig = EarlyBinder::bind(ctx.sig(impl_id).clone())
+        .instantiate(ctx.tcx, impl_subst)
+        .normalize(ctx, typing_env);
+    let contract = &sig.contract;
+    if contract.requires.len() + contract.ensures.len() > INLINED_CONTRACT_MAX_CLAUSES
+        || contract.variant.is_some()
+        || contract.terms().any(|term| term.quantifier_depth() > 0)
+    {
+        return None;
+    }
+    // The invariants of the arguments and the result are part of the contract of a call.
+    sig.add_type_invariant_spec(ctx, impl_id, typing_env);
+    Some(sig)
+}
+
 fn func_call_to_why3<'tcx, N: Namer<'tcx>>(
     lower: &mut LoweringState<'_, 'tcx, N>,
     id: DefId,
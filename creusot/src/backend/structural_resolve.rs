@@ -21,6 +21,7 @@ pub fn structural_resolve<'tcx>(
         TyKind::Adt(adt, args) if adt.is_box() => {
             Some(resolve_of(ctx, typing_env, subject.coerce(args.type_at(0))))
         }
+        TyKind::Adt(adt, _) if adt.is_phantom_data() => Some(Term::true_(ctx.tcx)),
         TyKind::Adt(adt, _) if is_trusted(ctx.tcx, adt.did()) => None,
         TyKind::Adt(adt, _) if is_snap_ty(ctx.tcx, adt.did()) => Some(Term::true_(ctx.tcx)),
         TyKind::Adt(adt, _) if get_builtin(ctx.tcx, adt.did()).is_some() => {
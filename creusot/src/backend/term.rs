@@ -7,6 +7,7 @@ use crate::{
     ctx::*,
     naming::name,
     translation::{
+        constant::from_ty_const,
         pearlite::{
             BinOp, Literal, Pattern, PatternKind, QuantKind, Term, TermKind, Trigger, UnOp,
         },
@@ -15,7 +16,7 @@ use crate::{
 };
 use rustc_ast::Mutability;
 use rustc_hir::def::DefKind;
-use rustc_middle::ty::{Ty, TyKind};
+use rustc_middle::ty::{ConstKind, Ty, TyKind};
 use rustc_span::DUMMY_SP;
 use rustc_type_ir::{IntTy, UintTy};
 use why3::{
@@ -82,7 +83,7 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
                     let qname = self.names.in_pre(prelude_kind, fct_name);
                     Exp::qvar(qname).app([self.lower_term(arg)])
                 }
-                TyKind::Int(_) | TyKind::Uint(_) => {
+                TyKind::Int(_) | TyKind::Uint(_) | TyKind::Char => {
                     // to
                     let (to_fct_name, to_prelude_kind) = match arg.ty.kind() {
                         TyKind::Int(ity) => (
@@ -93,6 +94,10 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
                             if self.names.bitwise_mode() { "to_BV256" } else { "t'int" },
                             uty_to_prelude(self.ctx.tcx, *ity),
                         ),
+                        TyKind::Char => (
+                            if self.names.bitwise_mode() { "to_BV256" } else { "to_int" },
+                            PreMod::Char,
+                        ),
                         _ => self.ctx.crash_and_error(
                             DUMMY_SP,
                             &format!("casts {:?} are currently unsupported", arg.ty.kind()),
@@ -122,7 +127,7 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
                 }
                 _ => self.ctx.crash_and_error(
                     DUMMY_SP,
-                    "casting from a type other than booleans and integers is not supported",
+                    "casting from a type other than booleans, integers and chars is not supported",
                 ),
             },
             TermKind::Coerce { arg } => self.lower_term(arg),
@@ -143,6 +148,14 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
                     item
                 }
             }
+            TermKind::Const(c) => match c.kind() {
+                ConstKind::Param(param) => Exp::var(self.names.const_param(param)),
+                _ => {
+                    let typing_env = self.names.typing_env();
+                    let value = from_ty_const(&self.ctx.ctx, *c, term.ty, typing_env, term.span);
+                    self.lower_term(&value)
+                }
+            },
             TermKind::Var(v) => Exp::var(v.0),
             TermKind::Binary { op, box lhs, box rhs } => {
                 let lhs = self.lower_term(lhs);
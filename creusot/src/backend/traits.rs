@@ -2,10 +2,13 @@ use crate::{
     backend::{Why3Generator, clone_map::Dependencies, is_trusted_item, term::lower_pure},
     contracts_items::is_snapshot_deref,
     ctx::FileModule,
+    naming::ModulePath,
+    translation::traits::Refinement,
 };
-use rustc_hir::def_id::DefId;
+use rustc_hir::{def::DefKind, def_id::DefId};
+use std::collections::HashMap;
 use why3::{
-    Ident,
+    Ident, Symbol,
     declaration::{Decl, Goal, Module},
 };
 
@@ -14,7 +17,15 @@ pub(crate) fn lower_impl<'tcx>(ctx: &Why3Generator<'tcx>, def_id: DefId) -> Vec<
         return vec![];
     }
 
-    let data = ctx.trait_impl(def_id).clone();
+    let data = match ctx.trait_impl(def_id) {
+        Ok(data) => data.clone(),
+        Err(errors) => {
+            for error in errors {
+                error.emit(ctx.tcx);
+            }
+            return vec![];
+        }
+    };
     let mut res = vec![];
 
     for refn in &data.refinements {
@@ -34,13 +45,42 @@ pub(crate) fn lower_impl<'tcx>(ctx: &Why3Generator<'tcx>, def_id: DefId) -> Vec<
         let refines = Ident::fresh(ctx.crate_name(), "refines");
         decls.push(Decl::Goal(Goal { name: refines, goal }));
 
-        let attrs = ctx.span_attr(ctx.def_span(impl_did)).into_iter().collect();
+        let attrs = ctx.module_attrs(impl_did);
         let meta = ctx.display_impl_of(impl_did);
-        let mut path = ctx.module_path(impl_did);
-        path.add_suffix("__refines");
+        let path = refinement_module_path(ctx, refn);
         let name = path.why3_ident();
         res.push(FileModule { path, modl: Module { name, decls: decls.into(), attrs, meta } })
     }
 
     res
 }
+
+/// The path of the module holding the refinement obligation `refn`.
+fn refinement_module_path(ctx: &Why3Generator, refn: &Refinement) -> ModulePath {
+    let impl_did = refn.impl_.0;
+    let mut path = ctx.module_path(impl_did);
+    // The laws proved for an impl (`#[forall_impls]` laws, or laws relating several traits)
+    // are all attached to the impl itself.
+    if matches!(ctx.def_kind(impl_did), DefKind::Impl { .. }) {
+        path.add_suffix(&format!("__{}", ctx.item_name(refn.trait_.0)));
+    }
+    // The agreement of a specialized item with the item of the base impl that it overrides.
+    if matches!(ctx.def_kind(ctx.parent(refn.trait_.0)), DefKind::Impl { .. }) {
+        path.add_suffix("__base");
+    }
+    path.add_suffix("__refines");
+    path
+}
+
+/// The impl items of the local trait impls, by name of the module holding their refinement
+/// obligation.
+pub(crate) fn refinement_modules(ctx: &Why3Generator) -> HashMap<Symbol, DefId> {
+    let mut modules = HashMap::new();
+    for impl_id in ctx.all_local_trait_impls(()).values().flatten() {
+        let Ok(data) = ctx.trait_impl(impl_id.to_def_id()) else { continue };
+        for refn in &data.refinements {
+            modules.insert(refinement_module_path(ctx, refn).why3_ident(), refn.impl_.0);
+        }
+    }
+    modules
+}
@@ -4,13 +4,14 @@ use crate::{
         get_inv_function, get_invariant_method, is_ignore_structural_inv, is_trusted,
         is_tyinv_trivial_if_param_trivial,
     },
+    ctx::TranslationCtx,
     naming::variable_name,
     translation::{
         pearlite::{Ident, Literal, Pattern, Term, TermKind, Trigger},
         traits::TraitResolved,
     },
 };
-use rustc_middle::ty::{GenericArg, Ty, TyCtxt, TyKind, TypingEnv};
+use rustc_middle::ty::{GenericArg, Ty, TyKind, TypingEnv};
 use rustc_span::{DUMMY_SP, Span};
 use rustc_target::abi::VariantIdx;
 use std::collections::HashSet;
@@ -24,20 +25,21 @@ use std::collections::HashSet;
 ///
 /// - `span`: used for error message
 pub(crate) fn is_tyinv_trivial<'tcx>(
-    tcx: TyCtxt<'tcx>,
+    ctx: &TranslationCtx<'tcx>,
     typing_env: TypingEnv<'tcx>,
     ty: Ty<'tcx>,
     span: Span,
 ) -> bool {
+    let tcx = ctx.tcx;
     // we cannot use a TypeWalker as it does not visit ADT field types
     let mut visited_tys = HashSet::new();
     let mut stack = vec![ty];
     while let Some(ty) = stack.pop() {
-        if !visited_tys.insert(ty) {
+        if !visited_tys.insert(ty) || is_phantom_data(ty) {
             continue;
         }
 
-        let user_inv = resolve_user_inv(tcx, ty, typing_env);
+        let user_inv = resolve_user_inv(ctx, ty, typing_env);
         match user_inv {
             TraitResolved::NotATraitItem => unreachable!(),
             TraitResolved::NoInstance => (),
@@ -65,7 +67,9 @@ pub(crate) fn is_tyinv_trivial<'tcx>(
             TyKind::Adt(_, substs) => stack.extend(substs.types()),
 
             TyKind::Closure(_, subst) => stack.extend(subst.as_closure().upvar_tys()),
-            TyKind::Never | TyKind::Param(_) | TyKind::Alias(_, _) => return false,
+            TyKind::Never | TyKind::Param(_) | TyKind::Alias(_, _) | TyKind::Dynamic(..) => {
+                return false;
+            }
             TyKind::Bool
             | TyKind::Char
             | TyKind::Int(_)
@@ -101,7 +105,7 @@ impl<'a, 'tcx> InvariantElaborator<'a, 'tcx> {
         let lhs = Term::call(self.ctx.tcx, self.typing_env, inv_id, subst, [subject.clone()]);
         let trig = Box::new([Trigger(Box::new([lhs.clone()]))]);
 
-        if is_tyinv_trivial(self.ctx.tcx, self.typing_env, ty, span) {
+        if is_tyinv_trivial(self.ctx, self.typing_env, ty, span) {
             self.rewrite = true;
             return Some(
                 lhs.eq(self.ctx.tcx, Term::true_(self.ctx.tcx)).forall_trig((x_ident, ty), trig),
@@ -109,28 +113,33 @@ impl<'a, 'tcx> InvariantElaborator<'a, 'tcx> {
         }
 
         let mut use_imples = false;
+        // A user invariant with a measure is recursive: it must not be unfolded as a rewrite rule,
+        // which would not terminate on a structure whose shape is unknown.
+        let mut measured = false;
 
         let mut rhs = Term::true_(self.ctx.tcx);
 
-        match resolve_user_inv(self.ctx.tcx, ty, self.typing_env) {
+        match resolve_user_inv(self.ctx, ty, self.typing_env) {
             TraitResolved::NotATraitItem => unreachable!(),
             TraitResolved::Instance(uinv_did, uinv_subst) => {
+                measured = self.ctx.sig(uinv_did).contract.variant.is_some();
                 rhs = rhs.conj(Term::call(self.ctx.tcx, self.typing_env, uinv_did, uinv_subst, [
                     subject.clone(),
                 ]))
             }
-            TraitResolved::UnknownFound => {
+            TraitResolved::UnknownFound(_) => {
                 let trait_item_did = get_invariant_method(self.ctx.tcx);
                 let subst = self.ctx.tcx.mk_args(&[GenericArg::from(ty)]);
                 rhs = rhs.conj(Term::call(self.ctx.tcx, self.typing_env, trait_item_did, subst, [
                     subject.clone(),
                 ]))
             }
-            TraitResolved::UnknownNotFound => use_imples = true,
+            TraitResolved::UnknownNotFound | TraitResolved::Ambiguous => use_imples = true,
             TraitResolved::NoInstance => (),
         }
 
-        if matches!(ty.kind(), TyKind::Alias(..) | TyKind::Param(_)) {
+        // The invariant of a trait object is that of its unknown underlying type.
+        if matches!(ty.kind(), TyKind::Alias(..) | TyKind::Param(_) | TyKind::Dynamic(..)) {
             use_imples = true
         } else {
             rhs = rhs.conj(self.structural_invariant(subject, ty))
@@ -142,7 +151,7 @@ impl<'a, 'tcx> InvariantElaborator<'a, 'tcx> {
             }
             Term::implies(lhs, rhs)
         } else {
-            self.rewrite = true;
+            self.rewrite = !measured;
             lhs.eq(self.ctx.tcx, rhs)
         };
 
@@ -151,7 +160,7 @@ impl<'a, 'tcx> InvariantElaborator<'a, 'tcx> {
 
     fn structural_invariant(&mut self, term: Term<'tcx>, ty: Ty<'tcx>) -> Term<'tcx> {
         if let TraitResolved::Instance(uinv_did, _) =
-            resolve_user_inv(self.ctx.tcx, ty, self.typing_env)
+            resolve_user_inv(self.ctx, ty, self.typing_env)
             && is_ignore_structural_inv(self.ctx.tcx, uinv_did)
         {
             return Term::true_(self.ctx.tcx);
@@ -235,6 +244,9 @@ impl<'a, 'tcx> InvariantElaborator<'a, 'tcx> {
                     };
 
                     let field_ty = field_def.ty(self.ctx.tcx, substs);
+                    if is_phantom_data(field_ty) {
+                        return Pattern::wildcard(field_ty);
+                    }
 
                     let f_exp = self.mk_inv_call(Term::var(field_name, field_ty));
                     exp = Some(exp.take().unwrap().conj(f_exp));
@@ -253,15 +265,20 @@ impl<'a, 'tcx> InvariantElaborator<'a, 'tcx> {
     }
 }
 
+/// `PhantomData` holds no value: it is logically absent, whatever its parameter, and in
+/// particular has no invariant and nothing to resolve.
+pub(crate) fn is_phantom_data(ty: Ty) -> bool {
+    matches!(ty.kind(), TyKind::Adt(def, _) if def.is_phantom_data())
+}
+
 fn resolve_user_inv<'tcx>(
-    tcx: TyCtxt<'tcx>,
+    ctx: &TranslationCtx<'tcx>,
     ty: Ty<'tcx>,
     typing_env: TypingEnv<'tcx>,
 ) -> TraitResolved<'tcx> {
-    TraitResolved::resolve_item(
-        tcx,
+    ctx.resolve_item(
         typing_env,
-        get_invariant_method(tcx),
-        tcx.mk_args(&[GenericArg::from(ty)]),
+        get_invariant_method(ctx.tcx),
+        ctx.mk_args(&[GenericArg::from(ty)]),
     )
 }
@@ -0,0 +1,112 @@
+//! Support for `--baseline`: compare the obligations proved by the run with those of a baseline
+//! file, to gate changes on the absence of regressions.
+//!
+//! An obligation is identified by the name of the module of its item, as for
+//! `--list-obligations`, which is derived from the stable path of the item. It is proved if all
+//! the goals located in the item are. The baseline records the obligations that were proved and
+//! those that were known to fail. Only the failures of the latter are tolerated and reported as
+//! warnings: a failure is a regression if the obligation is proved in the baseline, and a new
+//! failure if it has no entry in the baseline, e.g. for an item added since then, and both make
+//! the run fail. The obligations of the baseline which are not in the run (e.g. removed items)
+//! are ignored.
+
+use crate::{backend::Why3Generator, ctx::TranslationCtx, html_report::Report};
+use rustc_hir::def_id::DefId;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, io::ErrorKind, path::Path};
+
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct Baseline {
+    proved: BTreeSet<String>,
+    #[serde(default)]
+    failing: BTreeSet<String>,
+}
+
+pub(crate) fn load_baseline(ctx: &TranslationCtx, path: &Path) -> Baseline {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+            ctx.dcx().fatal(format!("invalid baseline {}: {err}", path.display()))
+        }),
+        // The baseline is created by the first update.
+        Err(err) if err.kind() == ErrorKind::NotFound && ctx.opts.update_baseline => {
+            Baseline::default()
+        }
+        Err(err) => {
+            ctx.dcx().fatal(format!("could not read the baseline {}: {err}", path.display()))
+        }
+    }
+}
+
+fn obligation_id(why3: &Why3Generator, def_id: DefId) -> String {
+    why3.module_path(def_id).why3_ident().to_string()
+}
+
+impl Baseline {
+    /// Is a failure of the obligation of `def_id` tolerated by the baseline? All failures are
+    /// while updating it.
+    pub(crate) fn tolerates(&self, why3: &Why3Generator, def_id: DefId) -> bool {
+        why3.opts.update_baseline || self.failing.contains(&obligation_id(why3, def_id))
+    }
+}
+
+pub(crate) fn check_baseline(
+    why3: &Why3Generator,
+    baseline: &Baseline,
+    report: &Report,
+    path: &Path,
+) {
+    let mut proved = BTreeSet::new();
+    let mut failing = BTreeSet::new();
+    let mut regressions = 0;
+    let mut new_failures = 0;
+    for (def_id, result) in report.item_results() {
+        let id = obligation_id(why3, def_id);
+        match result {
+            Some(true) => {
+                proved.insert(id);
+                continue;
+            }
+            Some(false) => {}
+            None => continue,
+        }
+        let span = why3.def_span(def_id);
+        let item = why3.def_path_str(def_id);
+        if baseline.proved.contains(&id) {
+            regressions += 1;
+            if !why3.opts.update_baseline {
+                why3.error(
+                    span,
+                    &format!("regression: `{item}` is proved in the baseline {}", path.display()),
+                )
+                .emit();
+            }
+        } else if !baseline.failing.contains(&id) {
+            new_failures += 1;
+            if !why3.opts.update_baseline {
+                why3.error(
+                    span,
+                    &format!(
+                        "new failure: `{item}` has no entry in the baseline {}",
+                        path.display()
+                    ),
+                )
+                .emit();
+            }
+        } else {
+            why3.dcx().span_note(span, format!("`{item}` is a known failure of the baseline"));
+        }
+        failing.insert(id);
+    }
+    why3.dcx().note(format!(
+        "{} obligation(s) proved, {} known failure(s), {regressions} regression(s), {new_failures} new failure(s)",
+        proved.len(),
+        failing.len() - regressions - new_failures,
+    ));
+
+    if why3.opts.update_baseline {
+        let json = serde_json::to_string_pretty(&Baseline { proved, failing }).unwrap();
+        if let Err(err) = std::fs::write(path, json + "\n") {
+            why3.dcx().fatal(format!("could not write the baseline {}: {err}", path.display()));
+        }
+    }
+}
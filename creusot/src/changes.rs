@@ -0,0 +1,123 @@
+//! Support for `--verify-only-changed`: only verify the items affected by the changes made since
+//! a git revision.
+//!
+//! An item is affected if it is defined in a changed file, or if its translation depends on an
+//! affected item (e.g. it calls a function whose contract changed).
+//!
+//! The items of other crates are changed if they are defined in a changed file, e.g. for a path
+//! dependency in the same repository, or if the entry of their package in a `Cargo.lock` changed,
+//! e.g. after a version bump. Packages are matched with crates by name, so a package whose library
+//! has another name is not seen to change, nor a path dependency outside of the repository.
+
+use rustc_hir::def_id::DefId;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::FileName;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    process::Command,
+};
+use toml::Table;
+
+pub(crate) struct Changes {
+    /// Canonical paths of the changed files.
+    files: HashSet<PathBuf>,
+    /// Packages whose entry in a `Cargo.lock` changed, as crate names, see [`changed_packages`].
+    packages: HashSet<String>,
+    /// Cache of the source files already looked up in `files`.
+    source_files: RefCell<HashMap<FileName, bool>>,
+    /// Items whose translation depends on an item defined in a changed file.
+    dependents: RefCell<HashSet<DefId>>,
+}
+
+impl Changes {
+    /// Ask git for the files changed since `rev`, including uncommitted and untracked files.
+    ///
+    /// Returns `None` if git is not available, or if we are not in a git repository.
+    pub(crate) fn since(rev: &str) -> Option<Self> {
+        let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+        let changed = git(&["diff", "--name-only", rev, "--"])?;
+        let untracked = git(&["ls-files", "--others", "--exclude-standard", "--full-name"])?;
+        let mut packages = HashSet::new();
+        for lock in changed.lines().chain(untracked.lines()).filter(|f| f.ends_with("Cargo.lock")) {
+            // The lockfile did not exist at `rev` if `git show` fails, or was deleted since.
+            let old = git(&["show", &format!("{rev}:{lock}")]).unwrap_or_default();
+            let new = std::fs::read_to_string(root.join(lock)).unwrap_or_default();
+            packages.extend(changed_packages(&old, &new));
+        }
+        let files = changed
+            .lines()
+            .chain(untracked.lines())
+            // Deleted files cannot be canonicalized, but they do not define any item either.
+            .filter_map(|file| root.join(file).canonicalize().ok())
+            .collect();
+        Some(Changes {
+            files,
+            packages,
+            source_files: Default::default(),
+            dependents: Default::default(),
+        })
+    }
+
+    /// Is `def_id` defined in one of the changed files, or in a crate whose package changed?
+    pub(crate) fn is_changed(&self, tcx: TyCtxt, def_id: DefId) -> bool {
+        if !def_id.is_local() && self.packages.contains(tcx.crate_name(def_id.krate).as_str()) {
+            return true;
+        }
+        let file = tcx.sess.source_map().lookup_source_file(tcx.def_span(def_id).lo());
+        *self.source_files.borrow_mut().entry(file.name.clone()).or_insert_with(|| {
+            let FileName::Real(path) = &file.name else { return false };
+            path.local_path()
+                .and_then(|path| path.canonicalize().ok())
+                .is_some_and(|path| self.files.contains(&path))
+        })
+    }
+
+    /// Record that the translation of `self_id` depends on `deps`.
+    pub(crate) fn record_dependencies(
+        &self,
+        tcx: TyCtxt,
+        self_id: DefId,
+        mut deps: impl Iterator<Item = DefId>,
+    ) {
+        if deps.any(|dep| self.is_changed(tcx, dep)) {
+            self.dependents.borrow_mut().insert(self_id);
+        }
+    }
+
+    /// Should the translation of `def_id` be verified?
+    pub(crate) fn is_affected(&self, tcx: TyCtxt, def_id: DefId) -> bool {
+        self.is_changed(tcx, def_id) || self.dependents.borrow().contains(&def_id)
+    }
+}
+
+/// The packages whose entry differs between the lockfiles `old` and `new`: added, removed, or with
+/// another version, source or checksum. They are named as crates, with `-` replaced by `_`.
+fn changed_packages(old: &str, new: &str) -> HashSet<String> {
+    let entries = |lock: &str| {
+        let mut entries: HashMap<String, Vec<String>> = HashMap::new();
+        let lock: Table = lock.parse().unwrap_or_default();
+        let packages = lock.get("package").and_then(|p| p.as_array()).into_iter().flatten();
+        for package in packages {
+            let field = |name| package.get(name).and_then(|v| v.as_str()).unwrap_or_default();
+            let entry = format!("{} {} {}", field("version"), field("source"), field("checksum"));
+            entries.entry(field("name").replace('-', "_")).or_default().push(entry);
+        }
+        entries.values_mut().for_each(|versions| versions.sort());
+        entries
+    };
+    let (old, new) = (entries(old), entries(new));
+    old.keys().chain(new.keys()).filter(|name| old.get(*name) != new.get(*name)).cloned().collect()
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+#[cfg(test)]
+mod tests;
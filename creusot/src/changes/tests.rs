@@ -0,0 +1,50 @@
+use super::changed_packages;
+use std::collections::HashSet;
+
+const LOCK: &str = r#"
+version = 4
+
+[[package]]
+name = "serde-json"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "aaaa"
+
+[[package]]
+name = "itertools"
+version = "0.13.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "bbbb"
+
+[[package]]
+name = "local"
+version = "0.1.0"
+"#;
+
+#[test]
+fn same_lockfile() {
+    assert!(changed_packages(LOCK, LOCK).is_empty());
+}
+
+// A version bump changes the package, named as a crate.
+#[test]
+fn version_bump() {
+    let bumped = LOCK.replace("\"1.0.0\"", "\"1.0.1\"").replace("aaaa", "cccc");
+    assert_eq!(changed_packages(LOCK, &bumped), HashSet::from(["serde_json".to_string()]));
+}
+
+// All the packages of a new lockfile are changed.
+#[test]
+fn new_lockfile() {
+    let all = ["serde_json", "itertools", "local"].map(String::from);
+    assert_eq!(changed_packages("", LOCK), HashSet::from(all));
+}
+
+// Another version of a package is added next to the existing one.
+#[test]
+fn added_version() {
+    let added = format!(
+        "{LOCK}\n[[package]]\nname = \"itertools\"\nversion = \"0.14.0\"\nchecksum = \"dddd\"\n"
+    );
+    assert_eq!(changed_packages(LOCK, &added), HashSet::from(["itertools".to_string()]));
+}
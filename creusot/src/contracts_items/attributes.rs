@@ -1,6 +1,6 @@
 //! Defines all the internal creusot attributes.
 
-use rustc_ast::Param;
+use rustc_ast::{LitKind, Param};
 use rustc_hir::{AttrArgs, Attribute, def_id::DefId};
 use rustc_middle::ty::TyCtxt;
 use rustc_span::Symbol;
@@ -43,16 +43,23 @@ attribute_functions! {
     [creusot::spec::invariant]               => is_invariant
     [creusot::spec::variant]                 => is_variant
     [creusot::spec::variant::loop_]          => is_loop_variant
+    [creusot::spec::variant::inferred]       => is_inferred_variant
     [creusot::before_loop]                   => is_before_loop
     [creusot::spec::assert]                  => is_assertion
+    [creusot::spec::assume]                  => is_assumption
+    [creusot::spec::assume::range_bounds]    => is_range_bounds
+    [creusot::spec::unsafe_requires]         => is_unsafe_requires
     [creusot::spec::snapshot]                => is_snapshot_closure
     [creusot::decl::logic]                   => is_logic
     [creusot::decl::logic::prophetic]        => is_prophetic
     [creusot::decl::predicate]               => is_predicate
+    [creusot::decl::pure_logic]              => is_pure_logic
     [creusot::decl::trusted]                 => is_trusted
     [creusot::decl::law]                     => is_law
+    [creusot::decl::forall_impls]            => is_forall_impls
     not [creusot::decl::no_trigger]          => should_replace_trigger
     [creusot::decl::open_inv_result]         => is_open_inv_result
+    [creusot::maintains_invariant]           => is_maintains_invariant
     [creusot::extern_spec]                   => is_extern_spec
     [creusot::trusted_ignore_structural_inv] => is_ignore_structural_inv
     [creusot::trusted_is_tyinv_trivial_if_param_trivial] => is_tyinv_trivial_if_param_trivial
@@ -60,6 +67,10 @@ attribute_functions! {
     [creusot::clause::terminates]            => is_terminates
     [creusot::clause::no_panic]              => is_no_panic
     [creusot::bitwise]                       => is_bitwise
+    [creusot::diverges]                      => is_diverges
+    [creusot::verify_monomorphized]          => is_verify_monomorphized
+    [creusot::inline_contract]               => is_inline_contract
+    [creusot::opaque]                        => is_opaque
 }
 
 pub fn get_invariant_expl(tcx: TyCtxt, def_id: DefId) -> Option<String> {
@@ -99,6 +110,190 @@ pub(crate) fn why3_attrs(tcx: TyCtxt, def_id: DefId) -> Vec<WAttribute> {
         .collect()
 }
 
+/// Get `N` in `#[creusot::solver(rlimit = N)]`: the resource limit of the solvers for the proof
+/// obligations of `def_id`.
+pub(crate) fn solver_rlimit(tcx: TyCtxt, def_id: DefId) -> Option<u64> {
+    let attr = get_attr(tcx, tcx.get_attrs_unchecked(def_id), &["creusot", "solver"])?;
+    let invalid = || -> ! {
+        tcx.dcx().span_fatal(
+            attr.span,
+            "Attribute `creusot::solver` should be of the form `creusot::solver(rlimit = N)`."
+                .to_string(),
+        )
+    };
+    let [item] = &attr.meta_item_list().unwrap_or_else(|| invalid())[..] else { invalid() };
+    match item.name_value_literal() {
+        Some((name, lit)) if name.as_str() == "rlimit" => match lit.kind {
+            LitKind::Int(n, _) => Some(u64::try_from(n.get()).unwrap_or_else(|_| invalid())),
+            _ => invalid(),
+        },
+        _ => invalid(),
+    }
+}
+
+/// Get the paths in `#[creusot::disable_law(path::to::law)]`: the laws which must not be made
+/// available to the proof obligations of `def_id`.
+pub(crate) fn disabled_laws(tcx: TyCtxt, def_id: DefId) -> Vec<Vec<Symbol>> {
+    let mut paths = Vec::new();
+    for attr in get_attrs(tcx.get_attrs_unchecked(def_id), &["creusot", "disable_law"]) {
+        let invalid = || -> ! {
+            tcx.dcx().span_fatal(
+                attr.span,
+                "Attribute `creusot::disable_law` should be of the form \
+                 `creusot::disable_law(path::to::law)`."
+                    .to_string(),
+            )
+        };
+        for item in attr.meta_item_list().unwrap_or_else(|| invalid()) {
+            match item.meta_item() {
+                Some(meta) if meta.is_word() => {
+                    paths.push(meta.path.segments.iter().map(|seg| seg.ident.name).collect())
+                }
+                _ => invalid(),
+            }
+        }
+    }
+    paths
+}
+
+/// How the type invariants at the boundary of a function are handled, as given by
+/// `#[creusot::invariant_mode(...)]`.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct InvariantMode {
+    /// `open_args`: the invariants of the arguments are neither required from the callers nor
+    /// assumed in the body, as if every parameter had `#[creusot::open_inv]`.
+    pub(crate) open_args: bool,
+    /// `open_result`: the invariant of the result is neither proved by the body nor given to the
+    /// callers, as with `#[open_inv_result]`.
+    pub(crate) open_result: bool,
+}
+
+pub(crate) fn invariant_mode(tcx: TyCtxt, def_id: DefId) -> InvariantMode {
+    let mut mode = InvariantMode::default();
+    let Some(attr) = get_attr(tcx, tcx.get_attrs_unchecked(def_id), &["creusot", "invariant_mode"])
+    else {
+        return mode;
+    };
+    let invalid = || -> ! {
+        tcx.dcx().span_fatal(
+            attr.span,
+            "Attribute `creusot::invariant_mode` should be of the form \
+             `creusot::invariant_mode(open_args, open_result)`, with at least one of the modes."
+                .to_string(),
+        )
+    };
+    let items = attr.meta_item_list().unwrap_or_else(|| invalid());
+    if items.is_empty() {
+        invalid()
+    }
+    for item in items {
+        match item.meta_item() {
+            Some(meta) if meta.is_word() && meta.has_name(Symbol::intern("open_args")) => {
+                mode.open_args = true
+            }
+            Some(meta) if meta.is_word() && meta.has_name(Symbol::intern("open_result")) => {
+                mode.open_result = true
+            }
+            _ => invalid(),
+        }
+    }
+    mode
+}
+
+/// The backend options given by `#[creusot::backend(...)]` on a module, for the proof obligations
+/// of all the items it contains. Unset options are inherited from the enclosing modules.
+#[derive(Clone, Default)]
+pub(crate) struct BackendConfig {
+    /// `encoding = "bitvector"` or `encoding = "int"`: whether machine integers are encoded as
+    /// bitvectors, as with `#[bitwise_proof]`.
+    pub(crate) bitwise: Option<bool>,
+    /// `prover = "..."`: the solver used for the proof obligations, replacing the ones of the
+    /// Why3 command.
+    pub(crate) prover: Option<Symbol>,
+    /// `rlimit = N`: the resource limit of the solver, as with `#[creusot::solver(rlimit = N)]`.
+    pub(crate) rlimit: Option<u64>,
+    /// `overflow = "check" | "wrap" | "assume"`: how the arithmetic operators of machine integers
+    /// are modeled.
+    pub(crate) overflow: Option<OverflowMode>,
+}
+
+/// How the arithmetic operators `+`, `-`, `*` and unary `-` of machine integers are modeled.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum OverflowMode {
+    /// An overflow is an error: each operation generates the proof obligation that it does not
+    /// overflow.
+    #[default]
+    Check,
+    /// The operations wrap around, as in release builds, like `wrapping_add`.
+    Wrap,
+    /// The operations are trusted not to overflow: the executions which overflow are ignored.
+    Assume,
+}
+
+impl OverflowMode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            OverflowMode::Check => "check",
+            OverflowMode::Wrap => "wrap",
+            OverflowMode::Assume => "assume",
+        }
+    }
+}
+
+impl BackendConfig {
+    /// Fill the options unset in `self` with the ones of `outer`.
+    pub(crate) fn inherit(&mut self, outer: &BackendConfig) {
+        self.bitwise = self.bitwise.or(outer.bitwise);
+        self.prover = self.prover.or(outer.prover);
+        self.rlimit = self.rlimit.or(outer.rlimit);
+        self.overflow = self.overflow.or(outer.overflow);
+    }
+}
+
+pub(crate) fn backend_config(tcx: TyCtxt, def_id: DefId) -> Option<BackendConfig> {
+    let attr = get_attr(tcx, tcx.get_attrs_unchecked(def_id), &["creusot", "backend"])?;
+    let invalid = || -> ! {
+        tcx.dcx().span_fatal(
+            attr.span,
+            "Attribute `creusot::backend` should be of the form \
+             `creusot::backend(encoding = \"bitvector\" | \"int\", prover = \"...\", rlimit = N, \
+             overflow = \"check\" | \"wrap\" | \"assume\")`, with at least one of the options."
+                .to_string(),
+        )
+    };
+    let items = attr.meta_item_list().unwrap_or_else(|| invalid());
+    if items.is_empty() {
+        invalid()
+    }
+    let mut config = BackendConfig::default();
+    for item in items {
+        let Some((name, lit)) = item.name_value_literal() else { invalid() };
+        match (name.as_str(), lit.kind) {
+            ("encoding", LitKind::Str(s, _)) if config.bitwise.is_none() => {
+                config.bitwise = Some(match s.as_str() {
+                    "bitvector" => true,
+                    "int" => false,
+                    _ => invalid(),
+                })
+            }
+            ("prover", LitKind::Str(s, _)) if config.prover.is_none() => config.prover = Some(s),
+            ("rlimit", LitKind::Int(n, _)) if config.rlimit.is_none() => {
+                config.rlimit = Some(u64::try_from(n.get()).unwrap_or_else(|_| invalid()))
+            }
+            ("overflow", LitKind::Str(s, _)) if config.overflow.is_none() => {
+                config.overflow = Some(match s.as_str() {
+                    "check" => OverflowMode::Check,
+                    "wrap" => OverflowMode::Wrap,
+                    "assume" => OverflowMode::Assume,
+                    _ => invalid(),
+                })
+            }
+            _ => invalid(),
+        }
+    }
+    Some(config)
+}
+
 pub(crate) fn creusot_clause_attrs<'tcx>(
     tcx: TyCtxt<'tcx>,
     def_id: DefId,
@@ -106,6 +106,14 @@ contracts_items! {{
         is_fn_mut_impl_hist_inv           get_fn_mut_impl_hist_inv
     fn Fn::postcondition                 ["fn_impl_postcond"]
         is_fn_impl_postcond             get_fn_impl_postcond
+    fn fn_ptr_of                         ["fn_ptr_of"]
+        is_fn_ptr_of                    get_fn_ptr_of
+    fn unsize                            ["creusot_unsize"]
+        is_unsize                       get_unsize
+    fn fresh                             ["creusot_fresh"]
+        is_fresh                        get_fresh
+    fn PointerExt::addr_logic            ["creusot_addr_logic"]
+        is_addr_logic                   get_addr_logic
     type Int                             ["creusot_int"]
         is_int_ty                       get_int_ty
     type Snapshot                        ["snapshot_ty"]
@@ -116,6 +124,42 @@ contracts_items! {{
         is_fn_pure_ty                   get_fn_pure_ty
     trait FnPure                         ["fn_pure_trait"]
         is_fn_pure_trait                get_fn_pure_trait
+    trait RoundTrip                      ["creusot_round_trip"]
+        is_round_trip_trait             get_round_trip_trait
+    fn RoundTrip::from_logic             ["creusot_round_trip_from_logic"]
+        is_round_trip_from_logic        get_round_trip_from_logic
+    fn borrow_to_owned_consistent        ["creusot_borrow_to_owned_consistent"]
+        is_borrow_to_owned_consistent   get_borrow_to_owned_consistent
+    fn ord_cmp_laws                      ["creusot_ord_cmp_laws"]
+        is_ord_cmp_laws                 get_ord_cmp_laws
+    trait TryFromSpec                    ["creusot_try_from_spec"]
+        is_try_from_spec_trait          get_try_from_spec_trait
+    fn TryFromSpec::returns              ["creusot_try_from_spec_returns"]
+        is_try_from_spec_returns        get_try_from_spec_returns
+    trait DefaultSpec                    ["creusot_default_spec"]
+        is_default_spec_trait           get_default_spec_trait
+    fn DefaultSpec::is_default           ["creusot_default_spec_is_default"]
+        is_default_spec_is_default      get_default_spec_is_default
+    trait ExtendSpec                     ["creusot_extend_spec"]
+        is_extend_spec_trait            get_extend_spec_trait
+    fn Appended::appended                ["creusot_appended"]
+        is_appended                     get_appended
+    trait DerefSpec                      ["creusot_deref_spec"]
+        is_deref_spec_trait             get_deref_spec_trait
+    fn DerefSpec::target                 ["creusot_deref_spec_target"]
+        is_deref_spec_target            get_deref_spec_target
+    trait DisplaySpec                    ["creusot_display_spec"]
+        is_display_spec_trait           get_display_spec_trait
+    fn DisplaySpec::writes               ["creusot_display_spec_writes"]
+        is_display_spec_writes          get_display_spec_writes
+    trait AddSpec                        ["creusot_add_spec"]
+        is_add_spec_trait               get_add_spec_trait
+    fn AddSpec::add_logic                ["creusot_add_spec_add_logic"]
+        is_add_spec_add_logic           get_add_spec_add_logic
+    trait MulSpec                        ["creusot_mul_spec"]
+        is_mul_spec_trait               get_mul_spec_trait
+    fn MulSpec::mul_logic                ["creusot_mul_spec_mul_logic"]
+        is_mul_spec_mul_logic           get_mul_spec_mul_logic
 }}
 
 contracts_items! { #[std_items] {
@@ -125,4 +169,12 @@ contracts_items! { #[std_items] {
         is_deref_mut                    get_deref_mut
     fn Box::new                          ["box_new"]
         is_box_new                      get_box_new
+    trait From                           ["From"]
+        is_from_trait                   get_from_trait
+    trait Default                        ["Default"]
+        is_default_trait                get_default_trait
+    trait ToOwned                        ["ToOwned"]
+        is_to_owned_trait               get_to_owned_trait
+    trait Ord                            ["Ord"]
+        is_ord_trait                    get_ord_trait
 }}
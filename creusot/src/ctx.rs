@@ -2,11 +2,12 @@ use crate::{
     backend::ty_inv::is_tyinv_trivial,
     callbacks,
     contracts_items::{
-        get_inv_function, get_resolve_function, get_resolve_method, is_extern_spec, is_logic,
-        is_open_inv_param, is_predicate, is_prophetic, opacity_witness_name,
+        BackendConfig, OverflowMode, backend_config, get_inv_function, get_resolve_function,
+        get_resolve_method, is_bitwise, is_extern_spec, is_logic, is_open_inv_param, is_predicate,
+        is_prophetic, is_spec, is_trusted, opacity_witness_name,
     },
     creusot_items::{self, CreusotItems},
-    error::{CannotFetchThir, CreusotResult, Error},
+    error::{CannotFetchThir, CreusotResult, Error, RefinementError},
     metadata::{BinaryMetadata, Metadata},
     naming::variable_name,
     options::Options,
@@ -14,12 +15,13 @@ use crate::{
         self,
         external::{ExternSpec, extract_extern_specs_from_item},
         fmir, pearlite,
-        pearlite::ScopedTerm,
+        pearlite::{ScopedTerm, Term},
         specification::{ContractClauses, PreSignature, inherited_extern_spec, pre_sig_of},
-        traits::{TraitImpl, TraitResolved},
+        traits::{ClosedCrates, TraitImpl, TraitResolved},
     },
     util::{erased_identity_for_item, parent_module},
 };
+use indexmap::IndexSet;
 use once_map::unsync::OnceMap;
 use rustc_ast::{
     Fn, FnSig, NodeId,
@@ -38,16 +40,16 @@ use rustc_middle::{
     mir::{Promoted, TerminatorKind},
     thir,
     ty::{
-        Clause, GenericArg, GenericArgsRef, ParamEnv, Predicate, ResolverAstLowering, Ty, TyCtxt,
-        TypingEnv, TypingMode, Visibility,
+        Clause, GenericArg, GenericArgsRef, ParamEnv, Predicate, ResolverAstLowering, TraitRef, Ty,
+        TyCtxt, TypeVisitableExt, TypingEnv, TypingMode, Visibility,
     },
 };
-use rustc_span::{Span, Symbol};
+use rustc_span::{Span, Symbol, sym};
 use rustc_trait_selection::traits::normalize_param_env_or_error;
 use rustc_type_ir::inherent::Ty as _;
 use std::{
     cell::{OnceCell, RefCell},
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ops::Deref,
 };
 use why3::Ident;
@@ -155,13 +157,28 @@ pub struct TranslationCtx<'tcx> {
     laws: OnceMap<DefId, Box<Vec<DefId>>>,
     fmir_body: OnceMap<BodyId, Box<fmir::Body<'tcx>>>,
     terms: OnceMap<DefId, Box<Option<ScopedTerm<'tcx>>>>,
-    trait_impl: OnceMap<DefId, Box<TraitImpl<'tcx>>>,
+    trait_impl: OnceMap<DefId, Box<Result<TraitImpl<'tcx>, Vec<RefinementError>>>>,
     sig: OnceMap<DefId, Box<PreSignature<'tcx>>>,
     bodies: OnceMap<LocalDefId, Box<BodyWithBorrowckFacts<'tcx>>>,
     opacity: OnceMap<DefId, Box<Opacity>>,
     renamer: RefCell<HashMap<HirId, Ident>>,
     pub corenamer: RefCell<HashMap<Ident, HirId>>,
     crate_name: OnceCell<why3::Symbol>,
+    /// Uses of `assume!` in translated bodies, in translation order.
+    assumptions: RefCell<Vec<(Span, Term<'tcx>)>>,
+    /// Mutually recursive logic functions, mapped to the index of their group.
+    recursion_groups: RefCell<HashMap<DefId, usize>>,
+    /// Impls from other crates that are trusted to refine their trait, in order of first use.
+    trusted_external_impls: RefCell<IndexSet<DefId>>,
+    /// Is the local crate `#![no_std]`? See [`TranslationCtx::is_std_only`].
+    no_std: bool,
+    /// Memoized results of [`TranslationCtx::resolve_item`].
+    resolved_items:
+        RefCell<HashMap<(DefId, GenericArgsRef<'tcx>, TypingEnv<'tcx>), TraitResolved<'tcx>>>,
+    /// The crates given by `--closed-crates`.
+    pub(crate) closed_crates: ClosedCrates,
+    /// The ambiguous resolutions that were already reported, see [`TranslationCtx::resolve_item`].
+    reported_ambiguities: RefCell<HashSet<(DefId, GenericArgsRef<'tcx>)>>,
 }
 
 impl<'tcx> Deref for TranslationCtx<'tcx> {
@@ -203,6 +220,8 @@ impl<'tcx> TranslationCtx<'tcx> {
     pub(crate) fn new(tcx: TyCtxt<'tcx>, opts: Options) -> Self {
         let params_open_inv = gather_params_open_inv(tcx);
         let creusot_items = creusot_items::local_creusot_items(tcx);
+        let closed_crates = ClosedCrates::new(&opts.closed_crates);
+        let no_std = tcx.hir().krate_attrs().iter().any(|attr| attr.has_name(sym::no_std));
 
         Self {
             tcx,
@@ -222,6 +241,13 @@ impl<'tcx> TranslationCtx<'tcx> {
             renamer: Default::default(),
             corenamer: Default::default(),
             crate_name: Default::default(),
+            assumptions: Default::default(),
+            trusted_external_impls: Default::default(),
+            recursion_groups: Default::default(),
+            no_std,
+            resolved_items: Default::default(),
+            closed_crates,
+            reported_ambiguities: Default::default(),
         }
     }
 
@@ -246,7 +272,7 @@ impl<'tcx> TranslationCtx<'tcx> {
         }
     }
 
-    queryish!(trait_impl, DefId, TraitImpl<'tcx>, translate_impl);
+    queryish!(trait_impl, DefId, Result<TraitImpl<'tcx>, Vec<RefinementError>>, translate_impl);
 
     queryish!(fmir_body, BodyId, fmir::Body<'tcx>, translation::function::fmir);
 
@@ -275,7 +301,7 @@ impl<'tcx> TranslationCtx<'tcx> {
                     let bound = bound.iter().map(|b| b.0).collect();
                     Ok(Box::new(Some(ScopedTerm(
                         bound,
-                        pearlite::normalize(self.tcx, self.typing_env(def_id), term),
+                        pearlite::normalize(self, self.typing_env(def_id), term),
                     ))))
                 } else {
                     Ok(Box::new(None))
@@ -333,7 +359,7 @@ impl<'tcx> TranslationCtx<'tcx> {
         span: Span,
     ) -> Option<(DefId, GenericArgsRef<'tcx>)> {
         let ty = self.normalize_erasing_regions(typing_env, ty);
-        if is_tyinv_trivial(self.tcx, typing_env, ty, span) {
+        if is_tyinv_trivial(self, typing_env, ty, span) {
             None
         } else {
             let inv_did = get_inv_function(self.tcx);
@@ -342,6 +368,92 @@ impl<'tcx> TranslationCtx<'tcx> {
         }
     }
 
+    /// Resolve a trait item with [`TraitResolved::resolve_item`], remembering the result for the
+    /// rest of the session: the same items are resolved many times when translating generic code.
+    pub(crate) fn resolve_item(
+        &self,
+        typing_env: TypingEnv<'tcx>,
+        trait_item_def_id: DefId,
+        substs: GenericArgsRef<'tcx>,
+    ) -> TraitResolved<'tcx> {
+        // Without type parameters or aliases, the resolution only depends on the bounds of the
+        // environment which do not mention its generic parameters, such as a trivially false
+        // `where u32: Tr`. Without such bounds, the resolution does not depend on the environment
+        // at all: e.g. the uses of a blanket impl at a given type in all the functions of the
+        // crate share one entry. They all rely on the refinement of the blanket impl, which is
+        // proved once for the generic impl by `translate_impl`, while its bounds are checked by
+        // rustc at each use.
+        let global_bounds =
+            typing_env.param_env.caller_bounds().iter().any(|clause| !clause.has_param());
+        let key_env = if substs.has_param() || substs.has_aliases() || global_bounds {
+            typing_env
+        } else {
+            TypingEnv::fully_monomorphized()
+        };
+        let key = (trait_item_def_id, substs, key_env);
+        if let Some(res) = self.resolved_items.borrow().get(&key).copied() {
+            return res;
+        }
+        let res = TraitResolved::resolve_item(
+            self.tcx,
+            &self.closed_crates,
+            key_env,
+            trait_item_def_id,
+            substs,
+        );
+        self.resolved_items.borrow_mut().insert(key, res);
+        // The same item is resolved in many environments: only warn once.
+        if res == TraitResolved::Ambiguous
+            && self.reported_ambiguities.borrow_mut().insert((trait_item_def_id, substs))
+        {
+            let trait_id = self.trait_of_item(trait_item_def_id).unwrap();
+            let trait_ref = TraitRef::from_method(self.tcx, trait_id, substs);
+            self.dcx()
+                .struct_span_warn(
+                    self.def_span(trait_item_def_id),
+                    format!("ambiguous resolution of `{trait_ref}`"),
+                )
+                .with_note(
+                    "several bounds may apply once regions are erased, or trait selection reached the recursion limit",
+                )
+                .with_note("the item is treated as unknown")
+                .emit();
+        }
+        res
+    }
+
+    /// Resolve a trait item whose value is needed in a const context, such as an array length or
+    /// a const generic argument. As when rustc evaluates the constant, the impl is selected after
+    /// analysis: the items of the impls which cannot be specialized any more are then known.
+    pub(crate) fn resolve_const_item(
+        &self,
+        typing_env: TypingEnv<'tcx>,
+        trait_item_def_id: DefId,
+        substs: GenericArgsRef<'tcx>,
+    ) -> TraitResolved<'tcx> {
+        let typing_env = typing_env.with_post_analysis_normalized(self.tcx);
+        self.resolve_item(typing_env, trait_item_def_id, substs)
+    }
+
+    /// Resolve an associated constant of a trait to the constant which defines its value: the one
+    /// of the selected impl, or the default of the trait. A constant which is not a trait item is
+    /// its own definition.
+    ///
+    /// Returns `None` if the impl is not known, e.g. for `<T as Trait>::N` in code generic over
+    /// `T`: the constant is then abstract.
+    pub(crate) fn resolve_assoc_const(
+        &self,
+        typing_env: TypingEnv<'tcx>,
+        const_def_id: DefId,
+        substs: GenericArgsRef<'tcx>,
+    ) -> Option<(DefId, GenericArgsRef<'tcx>)> {
+        match self.resolve_item(typing_env, const_def_id, substs) {
+            TraitResolved::NotATraitItem => Some((const_def_id, substs)),
+            TraitResolved::Instance(def_id, substs) => Some((def_id, substs)),
+            _ => None,
+        }
+    }
+
     pub(crate) fn resolve(
         &self,
         typing_env: TypingEnv<'tcx>,
@@ -354,7 +466,7 @@ impl<'tcx> TranslationCtx<'tcx> {
         // a resolve
         if !ty.is_closure()
             && matches!(
-                TraitResolved::resolve_item(self.tcx, typing_env, trait_meth_id, substs),
+                self.resolve_item(typing_env, trait_meth_id, substs),
                 TraitResolved::NoInstance
             )
         {
@@ -369,6 +481,13 @@ impl<'tcx> TranslationCtx<'tcx> {
         self.tcx.dcx().span_fatal(span, msg.to_string())
     }
 
+    /// With `--fail-fast`, stop after the first error instead of reporting all of them.
+    pub(crate) fn abort_if_fail_fast(&self) {
+        if self.opts.fail_fast {
+            self.tcx.dcx().abort_if_errors();
+        }
+    }
+
     pub(crate) fn fatal_error(&self, span: Span, msg: &str) -> Diag<'tcx, FatalAbort> {
         // TODO: try to add a code back in
         self.tcx.dcx().struct_span_fatal(span, msg.to_string())
@@ -382,13 +501,255 @@ impl<'tcx> TranslationCtx<'tcx> {
         self.tcx.dcx().span_warn(span, msg.into())
     }
 
+    pub(crate) fn record_assumption(&self, span: Span, term: Term<'tcx>) {
+        self.assumptions.borrow_mut().push((span, term))
+    }
+
+    /// Print a note for every `assume!` encountered during translation, for every trusted
+    /// `#[variant]` of an extern spec, and for every function modelled with `#[pure(logic)]`.
+    pub(crate) fn report_assumptions(&self) {
+        for (span, term) in self.assumptions.borrow().iter() {
+            debug!("assumption at {span:?}: {term:?}");
+            self.tcx.dcx().span_note(*span, "unverified assumption introduced by `assume!`")
+        }
+        let mut trusted_variants: Vec<_> = self
+            .extern_spec_items
+            .iter()
+            .filter(|(_, id)| self.extern_specs[*id].contract.variant().is_some())
+            .map(|(spec_id, id)| (self.def_span(*spec_id), *id))
+            .collect();
+        trusted_variants.sort_by_key(|(span, _)| *span);
+        for (span, id) in trusted_variants {
+            self.tcx.dcx().span_note(
+                span,
+                format!(
+                    "termination of `{}` is assumed from the trusted `#[variant]` of its extern spec",
+                    self.def_path_str(id)
+                ),
+            );
+        }
+        let mut trusted_models: Vec<_> = self
+            .extern_spec_items
+            .iter()
+            .filter(|(_, id)| self.extern_specs[*id].logic)
+            .map(|(spec_id, id)| (self.def_span(*spec_id), *id))
+            .collect();
+        trusted_models.sort_by_key(|(span, _)| *span);
+        for (span, id) in trusted_models {
+            self.tcx.dcx().span_note(
+                span,
+                format!(
+                    "`{}` is trusted to agree with the logic function of its `#[pure(logic)]` extern spec",
+                    self.def_path_str(id)
+                ),
+            );
+        }
+    }
+
+    /// Record that verified code uses the impl `impl_id` from another crate, which is trusted to
+    /// refine the specification of its trait.
+    pub(crate) fn record_trusted_external_impl(&self, impl_id: DefId) {
+        self.trusted_external_impls.borrow_mut().insert(impl_id);
+    }
+
+    /// Has verified code used the impl `impl_id` from another crate, see
+    /// [`Self::record_trusted_external_impl`]?
+    pub(crate) fn is_trusted_external_impl(&self, impl_id: DefId) -> bool {
+        self.trusted_external_impls.borrow().contains(&impl_id)
+    }
+
+    /// With `--deny-trusted-external-impls`, emit an error listing the trusted impls from other
+    /// crates used by verified code.
+    pub(crate) fn deny_trusted_external_impls(&self) {
+        let impls = self.trusted_external_impls.borrow();
+        if !self.opts.deny_trusted_external_impls || impls.is_empty() {
+            return;
+        }
+        let mut err = self.tcx.dcx().struct_err(format!(
+            "verified code relies on {} trusted implementation(s) from other crates",
+            impls.len()
+        ));
+        for &impl_id in impls.iter() {
+            err.note(format!("`{}` is trusted", self.def_path_str(impl_id)));
+        }
+        err.help("add extern specs for these implementations, or wrap them in local types").emit();
+    }
+
+    /// With `--trusted-allowlist`, emit an error for every trusted item missing from the allowlist:
+    /// local items marked `#[trusted]`, including trusted modules, and the impls from other crates
+    /// used by verified code.
+    ///
+    /// Entries are item paths as printed in diagnostics, optionally prefixed with the crate name
+    /// for local items.
+    pub(crate) fn check_trusted_allowlist(&self) {
+        let Some(path) = &self.opts.trusted_allowlist else { return };
+        let allowed: HashSet<String> = match std::fs::read_to_string(path) {
+            Ok(contents) => contents
+                .lines()
+                .map(|line| line.split('#').next().unwrap().trim())
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect(),
+            Err(err) => self
+                .dcx()
+                .fatal(format!("could not read trusted allowlist {}: {err}", path.display())),
+        };
+        let krate = self.crate_name(LOCAL_CRATE);
+        let is_allowed = |def_id: DefId| {
+            let path = self.def_path_str(def_id);
+            allowed.contains(&path)
+                || def_id.is_local() && allowed.contains(&format!("{krate}::{path}"))
+        };
+
+        for def_id in self.hir_crate_items(()).definitions() {
+            let def_id = def_id.to_def_id();
+            if !is_trusted(self.tcx, def_id) || is_spec(self.tcx, def_id) || is_allowed(def_id) {
+                continue;
+            }
+            let reason = match self.def_kind(def_id) {
+                DefKind::Mod => "is a trusted module",
+                DefKind::Struct | DefKind::Enum | DefKind::Union => "is a trusted type",
+                DefKind::Impl { .. } => "is a trusted impl",
+                _ => "is marked `#[trusted]`",
+            };
+            let msg = format!(
+                "`{}` {reason}, but is not in the trusted allowlist",
+                self.def_path_str(def_id)
+            );
+            self.error(self.def_span(def_id), &msg).emit();
+        }
+        for &impl_id in self.trusted_external_impls.borrow().iter() {
+            if is_allowed(impl_id) {
+                continue;
+            }
+            self.tcx
+                .dcx()
+                .struct_err(format!(
+                    "`{}` is not in the trusted allowlist",
+                    self.def_path_str(impl_id)
+                ))
+                .with_note("it is an impl from another crate, trusted to refine its trait")
+                .emit();
+        }
+    }
+
+    /// With `--max-quantifier-depth`, emit an error for every contract clause and every
+    /// refinement obligation of the crate whose quantifiers are nested deeper than allowed.
+    pub(crate) fn check_quantifier_depth(&self) {
+        let Some(max) = self.opts.max_quantifier_depth else { return };
+        for def_id in self.hir().body_owners() {
+            let def_id = def_id.to_def_id();
+            if !matches!(self.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+                || !translation::should_translate(self.tcx, def_id)
+            {
+                continue;
+            }
+            let contract = &self.sig(def_id).contract;
+            for cond in contract.requires.iter().chain(&contract.ensures) {
+                let depth = cond.term.quantifier_depth();
+                if depth > max {
+                    self.error(
+                        cond.term.span,
+                        &format!("this specification nests {depth} quantifiers (maximum: {max})"),
+                    )
+                    .emit();
+                }
+            }
+        }
+
+        for impls in self.all_local_trait_impls(()).values() {
+            for impl_id in impls {
+                let Ok(TraitImpl { refinements, .. }) = self.trait_impl(impl_id.to_def_id()) else {
+                    continue;
+                };
+                for refn in refinements {
+                    let depth = refn.refn.quantifier_depth();
+                    if depth > max {
+                        let mut err = self.error(
+                            self.def_span(refn.impl_.0),
+                            &format!(
+                                "the refinement of `{}` nests {depth} quantifiers (maximum: {max})",
+                                self.def_path_str(refn.trait_.0)
+                            ),
+                        );
+                        err.span_note(
+                            self.def_span(refn.trait_.0),
+                            "the trait item is specified here",
+                        );
+                        err.emit();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record that the given logic functions are mutually recursive: their termination is
+    /// established by a common decrease of their variants.
+    pub(crate) fn record_recursion_group(&self, group: impl IntoIterator<Item = DefId>) {
+        let mut groups = self.recursion_groups.borrow_mut();
+        let idx = groups.values().max().map_or(0, |i| i + 1);
+        groups.extend(group.into_iter().map(|def_id| (def_id, idx)))
+    }
+
+    /// Are `a` and `b` distinct members of the same group of mutually recursive functions?
+    pub(crate) fn mutually_recursive(&self, a: DefId, b: DefId) -> bool {
+        let groups = self.recursion_groups.borrow();
+        a != b && groups.get(&a).is_some_and(|g| groups.get(&b) == Some(g))
+    }
+
     queryish!(laws, DefId, [DefId], laws_inner);
 
     // TODO Make private
     pub(crate) fn extern_spec(&self, def_id: DefId) -> Option<&ExternSpec<'tcx>> {
+        if self.is_std_only(def_id) {
+            return None;
+        }
         self.extern_specs.get(&def_id).or_else(|| self.externs.extern_spec(def_id))
     }
 
+    /// Is `def_id` an external function modelled by a logic function, with `#[pure(logic)]`?
+    ///
+    /// Such a function is a logic function for Creusot, which programs can also call.
+    pub(crate) fn is_logic_extern(&self, def_id: DefId) -> bool {
+        self.extern_spec(def_id).is_some_and(|spec| spec.logic)
+    }
+
+    /// The backend options of `def_id`, given by the `#[creusot::backend(...)]` attributes of the
+    /// modules containing it, the innermost module taking precedence.
+    pub(crate) fn module_backend_config(&self, def_id: DefId) -> BackendConfig {
+        let mut config = BackendConfig::default();
+        let mut id = def_id;
+        while let Some(parent) = self.tcx.opt_parent(id) {
+            if matches!(self.def_kind(parent), DefKind::Mod)
+                && let Some(outer) = backend_config(self.tcx, parent)
+            {
+                config.inherit(&outer);
+            }
+            id = parent;
+        }
+        config
+    }
+
+    /// Are the proof obligations of `def_id` in bitwise mode, by `#[bitwise_proof]` or by the
+    /// encoding of its module?
+    pub(crate) fn bitwise_mode(&self, def_id: DefId) -> bool {
+        is_bitwise(self.tcx, def_id) || self.module_backend_config(def_id).bitwise == Some(true)
+    }
+
+    /// How the integer arithmetic of `def_id` is modeled, given by the overflow mode of its module.
+    pub(crate) fn overflow_mode(&self, def_id: DefId) -> OverflowMode {
+        self.module_backend_config(def_id).overflow.unwrap_or_default()
+    }
+
+    /// Is `def_id` an item of `std`, used from a `#![no_std]` crate?
+    ///
+    /// The specifications of `creusot_contracts` are written for `std`, but most of them are about
+    /// the items that `std` reexports from `core` and `alloc`, which are still available. The
+    /// specifications of the items which only exist in `std` are not loaded.
+    pub(crate) fn is_std_only(&self, def_id: DefId) -> bool {
+        self.no_std && self.tcx.crate_name(def_id.krate) == sym::std
+    }
+
     pub(crate) fn should_export(&self) -> bool {
         self.opts.export_metadata
     }
@@ -421,6 +782,7 @@ impl<'tcx> TranslationCtx<'tcx> {
 
     pub(crate) fn metadata(&mut self) -> BinaryMetadata<'tcx> {
         BinaryMetadata::from_parts(
+            self.tcx,
             &mut self.terms,
             &self.creusot_items,
             &self.extern_specs,
@@ -546,6 +908,8 @@ impl<'tcx> TranslationCtx<'tcx> {
                     ItemType::Predicate { prophetic: is_prophetic(self.tcx, def_id) }
                 } else if is_logic(self.tcx, def_id) {
                     ItemType::Logic { prophetic: is_prophetic(self.tcx, def_id) }
+                } else if self.is_logic_extern(def_id) {
+                    ItemType::Logic { prophetic: false }
                 } else {
                     ItemType::Program
                 }
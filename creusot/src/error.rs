@@ -1,3 +1,4 @@
+use rustc_hir::def_id::DefId;
 use rustc_middle::ty::TyCtxt;
 use rustc_span::{DUMMY_SP, ErrorGuaranteed, Span};
 
@@ -89,6 +90,34 @@ impl Drop for CannotFetchThir {
     }
 }
 
+/// An error found while translating the refinement obligations of a trait impl.
+#[derive(Debug, Clone)]
+pub(crate) enum RefinementError {
+    /// The implementation of a trait item does not satisfy the additional trait bounds that the
+    /// extern spec of the trait item requires. The unfulfilled bounds were reported by rustc.
+    PredicateUnfulfilled { impl_item: DefId, span: Span },
+}
+
+impl RefinementError {
+    pub(crate) fn span(&self) -> Span {
+        match self {
+            RefinementError::PredicateUnfulfilled { span, .. } => *span,
+        }
+    }
+
+    pub(crate) fn emit(&self, tcx: TyCtxt) -> ErrorGuaranteed {
+        match self {
+            RefinementError::PredicateUnfulfilled { impl_item, .. } => tcx.dcx().span_err(
+                self.span(),
+                format!(
+                    "`{}` does not satisfy the bounds required by the extern spec of the trait",
+                    tcx.def_path_str(*impl_item)
+                ),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InternalError(pub &'static str);
 
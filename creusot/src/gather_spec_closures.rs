@@ -1,6 +1,7 @@
 use crate::{
     contracts_items::{
-        get_invariant_expl, is_assertion, is_before_loop, is_loop_variant, is_snapshot_closure,
+        get_invariant_expl, is_assertion, is_before_loop, is_inferred_variant, is_loop_variant,
+        is_snapshot_closure,
     },
     ctx::TranslationCtx,
     translation::pearlite::Term,
@@ -132,7 +133,17 @@ impl<'a, 'tcx> Visitor<'tcx> for InvariantsVisitor<'a, 'tcx> {
             let kind = if let Some(expl) = get_invariant_expl(self.ctx.tcx, *id) {
                 LoopSpecKind::Invariant(expl)
             } else if is_loop_variant(self.ctx.tcx, *id) {
-                self.ctx.warn(self.ctx.def_span(id), "Loop variants are currently unsupported.");
+                // Variants are inferred for loops that may not need one: they are silently
+                // ignored outside of `#[terminates]` functions.
+                if !self.ctx.sig(self.body.source.def_id()).contract.terminates {
+                    if is_inferred_variant(self.ctx.tcx, *id) {
+                        return self.super_rvalue(rvalue, loc);
+                    }
+                    self.ctx.warn(
+                        self.ctx.def_span(id),
+                        "Loop variants are only checked in `#[terminates]` functions.",
+                    );
+                }
                 LoopSpecKind::Variant
             } else {
                 if is_before_loop(self.ctx.tcx, *id) {
@@ -0,0 +1,282 @@
+//! Support for `--html-report`: write a page listing the proof obligations of each item with their
+//! status, from the results of the goals reported by Why3 during the run.
+//!
+//! As for `--stats`, a goal is attributed to the innermost item containing its span. The report
+//! is only a view of the collected results: writing it never runs the solvers again.
+
+use crate::{
+    backend::Why3Generator,
+    contracts_items::OverflowMode,
+    obligations::{ObligationKind, obligation_kind},
+    options::SpanMode,
+    translated_item::TranslatedItem,
+    util::path_of_span,
+};
+use rustc_hir::def_id::DefId;
+use rustc_span::Span;
+use std::{fmt::Write, path::Path};
+
+#[derive(Default)]
+pub(crate) struct Report {
+    items: Vec<ReportItem>,
+    /// Goals that could not be located in an item.
+    unattributed: Vec<GoalResult>,
+}
+
+struct ReportItem {
+    def_id: DefId,
+    kind: ObligationKind,
+    termination: bool,
+    overflow: OverflowMode,
+    span: Span,
+    goals: Vec<GoalResult>,
+}
+
+/// The result of a goal, as reported by Why3.
+pub(crate) struct GoalResult {
+    pub(crate) span: Option<Span>,
+    pub(crate) name: String,
+    pub(crate) explanations: Vec<String>,
+    pub(crate) answer: String,
+    pub(crate) time: f32,
+    /// The elements of the counterexample, if the prover found one.
+    pub(crate) model: Vec<String>,
+}
+
+impl GoalResult {
+    fn proved(&self) -> bool {
+        self.answer == "Valid"
+    }
+}
+
+impl Report {
+    pub(crate) fn record_item(
+        &mut self,
+        why3: &Why3Generator,
+        def_id: DefId,
+        item: &TranslatedItem,
+    ) {
+        let Some(local_id) = def_id.as_local() else { return };
+        // The goals of `--check-precondition-satisfiability` are not reported, see `prove`.
+        if let TranslatedItem::Vacuity { .. } = item {
+            return;
+        }
+        let (kind, termination) = obligation_kind(why3, def_id, item);
+        let overflow = why3.overflow_mode(def_id);
+        let span = why3.tcx.source_span(local_id);
+        self.items.push(ReportItem { def_id, kind, termination, overflow, span, goals: Vec::new() })
+    }
+
+    /// The index of the innermost item containing `span`.
+    fn item_index(&self, span: Option<Span>) -> Option<usize> {
+        let span = span?.source_callsite();
+        (0..self.items.len())
+            .filter(|&i| self.items[i].span.contains(span))
+            .min_by_key(|&i| self.items[i].span.hi() - self.items[i].span.lo())
+    }
+
+    /// The item to which a goal located at `span` is attributed.
+    pub(crate) fn item_at(&self, span: Option<Span>) -> Option<DefId> {
+        self.item_index(span).map(|i| self.items[i].def_id)
+    }
+
+    pub(crate) fn record_goal(&mut self, goal: GoalResult) {
+        match self.item_index(goal.span) {
+            Some(i) => self.items[i].goals.push(goal),
+            None => self.unattributed.push(goal),
+        }
+    }
+
+    /// The items of the report, with whether all their goals were proved, or `None` if no goal
+    /// was reported for them.
+    pub(crate) fn item_results(&self) -> impl Iterator<Item = (DefId, Option<bool>)> + '_ {
+        self.items.iter().map(|item| {
+            let proved = match item.status() {
+                Status::Proved => Some(true),
+                Status::Failed => Some(false),
+                Status::Unknown => None,
+            };
+            (item.def_id, proved)
+        })
+    }
+
+    pub(crate) fn write(&self, why3: &Why3Generator, path: &Path) {
+        let mut out = String::new();
+        self.render(why3, &mut out).unwrap();
+        if let Err(err) = std::fs::write(path, out) {
+            why3.dcx().fatal(format!("could not write the report to {}: {err}", path.display()));
+        }
+    }
+
+    fn render(&self, why3: &Why3Generator, out: &mut String) -> std::fmt::Result {
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html><head><meta charset=\"utf-8\">")?;
+        writeln!(out, "<title>Creusot report: {}</title>", escape(&why3.crate_name().to_string()))?;
+        writeln!(out, "<style>{STYLE}</style>")?;
+        writeln!(out, "</head><body>")?;
+        writeln!(out, "<h1>{}</h1>", escape(&why3.crate_name().to_string()))?;
+
+        let failed = self.items.iter().filter(|item| item.status() == Status::Failed).count();
+        writeln!(out, "<p>{} item(s), {failed} with failed goals.</p>", self.items.len())?;
+
+        writeln!(out, "<table>")?;
+        writeln!(out, "<tr><th>Item</th><th>Kind</th><th>Termination</th><th>Status</th></tr>")?;
+        for item in &self.items {
+            let status = item.status();
+            writeln!(
+                out,
+                "<tr class=\"{}\"><td><a href=\"#{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                status.class(),
+                anchor(why3, item.def_id),
+                escape(&why3.def_path_str(item.def_id)),
+                item.kind.as_str(),
+                if item.termination { "yes" } else { "no" },
+                status.as_str(),
+            )?;
+        }
+        writeln!(out, "</table>")?;
+
+        for item in &self.items {
+            let status = item.status();
+            writeln!(
+                out,
+                "<section id=\"{}\" class=\"{}\">",
+                anchor(why3, item.def_id),
+                status.class()
+            )?;
+            writeln!(
+                out,
+                "<h2>{} <small>{} &middot; {}</small></h2>",
+                escape(&why3.def_path_str(item.def_id)),
+                item.kind.as_str(),
+                status.as_str()
+            )?;
+            let span = why3.def_span(item.def_id);
+            writeln!(out, "<p>{}</p>", location(why3, span))?;
+            if item.overflow != OverflowMode::Check {
+                writeln!(out, "<p>Integer overflow: {}</p>", item.overflow.as_str())?;
+            }
+            if let Ok(snippet) = why3.sess.source_map().span_to_snippet(span) {
+                writeln!(out, "<pre>{}</pre>", escape(&snippet))?;
+            }
+            goals(why3, &item.goals, out)?;
+            writeln!(out, "</section>")?;
+        }
+
+        if !self.unattributed.is_empty() {
+            writeln!(out, "<section><h2>Other goals</h2>")?;
+            goals(why3, &self.unattributed, out)?;
+            writeln!(out, "</section>")?;
+        }
+
+        writeln!(out, "</body></html>")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Proved,
+    Failed,
+    /// No goal of the item was reported, e.g. because Why3 was not run.
+    Unknown,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Proved => "proved",
+            Status::Failed => "failed",
+            Status::Unknown => "no result",
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Status::Proved => "proved",
+            Status::Failed => "failed",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+impl ReportItem {
+    fn status(&self) -> Status {
+        if self.goals.is_empty() {
+            Status::Unknown
+        } else if self.goals.iter().all(GoalResult::proved) {
+            Status::Proved
+        } else {
+            Status::Failed
+        }
+    }
+}
+
+fn goals(why3: &Why3Generator, goals: &[GoalResult], out: &mut String) -> std::fmt::Result {
+    if goals.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "<ul>")?;
+    for goal in goals {
+        let class = if goal.proved() { "proved" } else { "failed" };
+        write!(
+            out,
+            "<li class=\"{class}\"><code>{}</code> {} &ndash; {} ({:.2}s)",
+            escape(&goal.name),
+            escape(&goal.explanations.join(", ")),
+            escape(&goal.answer),
+            goal.time
+        )?;
+        if let Some(span) = goal.span {
+            write!(out, " at {}", location(why3, span))?;
+        }
+        for elem in &goal.model {
+            write!(out, "<pre class=\"model\">{}</pre>", escape(elem))?;
+        }
+        writeln!(out, "</li>")?;
+    }
+    writeln!(out, "</ul>")
+}
+
+/// The location of `span`, with a link to its file.
+fn location(why3: &Why3Generator, span: Span) -> String {
+    let text = escape(&why3.sess.source_map().span_to_embeddable_string(span));
+    match path_of_span(why3.tcx, span, &SpanMode::Absolute) {
+        Some(path) => {
+            let line = why3.sess.source_map().lookup_char_pos(span.lo()).line;
+            format!("<a href=\"file://{}#L{line}\">{text}</a>", escape(&path.to_string_lossy()))
+        }
+        None => text,
+    }
+}
+
+fn anchor(why3: &Why3Generator, def_id: DefId) -> String {
+    why3.def_path_str(def_id)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn escape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '&' => res.push_str("&amp;"),
+            '"' => res.push_str("&quot;"),
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; } \
+    table { border-collapse: collapse; } \
+    td, th { border: 1px solid #ccc; padding: 0.2em 0.6em; text-align: left; } \
+    tr.failed, section.failed, li.failed { background: #fdd; } \
+    tr.proved, li.proved { background: #dfd; } \
+    section { margin-top: 1.5em; padding: 0.5em; } \
+    pre { background: #f4f4f4; padding: 0.5em; overflow-x: auto; }";
+
+#[cfg(test)]
+mod tests;
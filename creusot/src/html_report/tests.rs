@@ -0,0 +1,74 @@
+use super::{GoalResult, Report, ReportItem, escape};
+use crate::{contracts_items::OverflowMode, obligations::ObligationKind, test_support::*};
+use rustc_middle::ty::TyCtxt;
+use rustc_span::Span;
+
+const ITEMS: &str = "
+pub fn outer() {
+    fn inner() {}
+}
+
+pub fn other() {}
+
+pub fn unproved() {}
+";
+
+fn goal(span: Option<Span>, answer: &str) -> GoalResult {
+    GoalResult {
+        span,
+        name: "vc".into(),
+        explanations: Vec::new(),
+        answer: answer.into(),
+        time: 0.,
+        model: Vec::new(),
+    }
+}
+
+fn report(tcx: TyCtxt, paths: &[&str]) -> Report {
+    let mut report = Report::default();
+    for path in paths {
+        let def_id = local_item(tcx, path);
+        report.items.push(ReportItem {
+            def_id,
+            kind: ObligationKind::Body,
+            termination: false,
+            overflow: OverflowMode::Check,
+            span: tcx.source_span(def_id.expect_local()),
+            goals: Vec::new(),
+        });
+    }
+    report
+}
+
+// An item is proved when all its goals are, and has no result when Why3 reported no goal for it.
+#[test]
+fn goals_go_to_the_innermost_item() {
+    with_tcx(ITEMS, |tcx| {
+        let span = |path| Some(tcx.source_span(local_item(tcx, path).expect_local()));
+        let mut report = report(tcx, &["outer", "outer::inner", "other", "unproved"]);
+        assert_eq!(report.item_at(span("outer::inner")), Some(local_item(tcx, "outer::inner")));
+
+        report.record_goal(goal(span("outer::inner"), "Valid"));
+        report.record_goal(goal(span("outer"), "Valid"));
+        report.record_goal(goal(span("other"), "Valid"));
+        report.record_goal(goal(span("other"), "Timeout"));
+        report.record_goal(goal(None, "Valid"));
+        assert_eq!(report.unattributed.len(), 1);
+
+        let results: Vec<_> = report
+            .item_results()
+            .map(|(def_id, proved)| (tcx.def_path_str(def_id), proved))
+            .collect();
+        assert_eq!(results, [
+            ("outer".to_string(), Some(true)),
+            ("outer::inner".to_string(), Some(true)),
+            ("other".to_string(), Some(false)),
+            ("unproved".to_string(), None),
+        ]);
+    })
+}
+
+#[test]
+fn names_are_escaped() {
+    assert_eq!(escape("<S as Tr<\"&\">>::m"), "&lt;S as Tr&lt;&quot;&amp;&quot;&gt;&gt;::m");
+}
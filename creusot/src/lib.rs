@@ -41,6 +41,8 @@ pub mod options;
 
 mod analysis;
 mod backend;
+mod baseline;
+mod changes;
 mod cleanup_spec_closures;
 mod contracts_items;
 mod creusot_items;
@@ -50,15 +52,24 @@ mod debug;
 mod error;
 mod extended_location;
 mod gather_spec_closures;
+mod html_report;
 mod lints;
 mod metadata;
 mod naming;
+mod obligations;
+mod panic_report;
+mod refinements;
 mod resolve;
 mod run_why3;
+mod stats;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 mod translated_item;
 mod translation;
+mod trust_report;
 mod util;
 mod validate;
+mod vc_cache;
 mod very_stable_hash;
 
 rustc_fluent_macro::fluent_messages! { "../messages.ftl" }
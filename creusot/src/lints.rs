@@ -1,5 +1,6 @@
 pub(crate) mod contractless_external_function;
 mod experimental_types;
+pub(crate) mod vacuous_postcondition;
 
 use rustc_lint::LintStore;
 use rustc_session::Session;
@@ -8,6 +9,7 @@ pub fn register_lints(_sess: &Session, store: &mut LintStore) {
     store.register_lints(&[
         experimental_types::EXPERIMENTAL,
         contractless_external_function::CONTRACTLESS_EXTERNAL_FUNCTION,
+        vacuous_postcondition::VACUOUS_POSTCONDITION,
     ]);
     store.register_late_pass(move |_| Box::new(crate::validate::GhostValidate {}));
     store.register_late_pass(move |_| Box::new(experimental_types::Experimental {}));
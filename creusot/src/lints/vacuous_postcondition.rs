@@ -0,0 +1,92 @@
+use crate::{
+    backend::is_trusted_item,
+    contracts_items::is_extern_spec,
+    ctx::TranslationCtx,
+    translation::{
+        pearlite::{BinOp, Literal, Term, TermKind},
+        specification::contract_clauses_of,
+    },
+};
+use rustc_hir::def_id::DefId;
+use rustc_macros::LintDiagnostic;
+use rustc_session::declare_tool_lint;
+use rustc_span::Span;
+
+// Like `contractless_external_function`, this lint is emitted during translation, with
+// `TyCtxt::emit_node_span_lint`: the postconditions are not available to lint passes.
+#[derive(Debug, LintDiagnostic)]
+#[diag(creusot_vacuous_postcondition)]
+pub(crate) struct VacuousPostcondition {
+    /// Location of the postcondition
+    #[label]
+    pub(crate) span: Span,
+}
+
+declare_tool_lint! {
+    /// The `vacuous_postcondition` lint warns about postconditions that are true whatever the
+    /// function does, such as `#[ensures(true)]` or `#[ensures(result == result)]`.
+    ///
+    /// Such postconditions are often a mistake: a stronger property was intended. A postcondition
+    /// which is just `true` is not linted, since `#[ensures(true)]` is also a common way of giving
+    /// a function a contract, for instance to have it translated.
+    pub(crate) creusot::VACUOUS_POSTCONDITION,
+    Warn,
+    "postconditions that are syntactically always true"
+}
+
+/// Warn about the vacuous postconditions of `def_id`.
+///
+/// Trusted functions and extern specs are not linted: `#[ensures(true)]` is the usual way of
+/// giving them a contract that says nothing.
+pub(crate) fn lint_vacuous_postconditions(ctx: &TranslationCtx, def_id: DefId) {
+    if is_trusted_item(ctx.tcx, def_id) || is_extern_spec(ctx.tcx, def_id) {
+        return;
+    }
+    let Ok(clauses) = contract_clauses_of(ctx, def_id) else { return };
+    for &ens_id in clauses.ensures() {
+        let Some(local_id) = ens_id.as_local() else { continue };
+        let Ok(Some(term)) = ctx.term(ens_id) else { continue };
+        let term = &term.1;
+        // `#[ensures(true)]` is written on purpose, see `VACUOUS_POSTCONDITION`.
+        if matches!(term.kind, TermKind::Lit(Literal::Bool(true))) {
+            continue;
+        }
+        if is_vacuous(term) {
+            let hir_id = ctx.local_def_id_to_hir_id(local_id);
+            let span = term.span;
+            ctx.emit_node_span_lint(VACUOUS_POSTCONDITION, hir_id, span, VacuousPostcondition {
+                span,
+            });
+        }
+    }
+}
+
+/// Is `term` true, regardless of the value of its variables?
+///
+/// This is only a syntactic check, which catches the common slips.
+fn is_vacuous(term: &Term) -> bool {
+    match &term.kind {
+        TermKind::Lit(Literal::Bool(b)) => *b,
+        TermKind::Binary { op: BinOp::Eq | BinOp::Le | BinOp::Ge, lhs, rhs } => same(lhs, rhs),
+        TermKind::Binary { op: BinOp::And, lhs, rhs } => is_vacuous(lhs) && is_vacuous(rhs),
+        TermKind::Binary { op: BinOp::Or, lhs, rhs } => is_vacuous(lhs) || is_vacuous(rhs),
+        TermKind::Impl { lhs, rhs } => {
+            is_vacuous(rhs) || matches!(lhs.kind, TermKind::Lit(Literal::Bool(false)))
+        }
+        TermKind::Quant { body, .. } => is_vacuous(body),
+        _ => false,
+    }
+}
+
+/// Are `lhs` and `rhs` the same variable, or the same field of the same variable?
+fn same(lhs: &Term, rhs: &Term) -> bool {
+    match (&lhs.kind, &rhs.kind) {
+        (TermKind::Var(x), TermKind::Var(y)) => x.0 == y.0,
+        (TermKind::Projection { lhs: l1, idx: i1 }, TermKind::Projection { lhs: l2, idx: i2 }) => {
+            i1 == i2 && same(l1, l2)
+        }
+        (TermKind::Cur { term: t1 }, TermKind::Cur { term: t2 })
+        | (TermKind::Fin { term: t1 }, TermKind::Fin { term: t2 }) => same(t1, t2),
+        _ => false,
+    }
+}
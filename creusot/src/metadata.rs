@@ -111,6 +111,16 @@ impl<'tcx> CrateMetadata<'tcx> {
 
         let metadata = load_binary_metadata(tcx, cnum, &binary_path)?;
 
+        // The metadata must come from the same build of the crate as the one rustc is using: the
+        // items (and thus the trait impls) of a crate depend on its active `cfg`s and features.
+        if metadata.crate_hash != tcx.crate_hash(cnum).as_u128() {
+            tcx.dcx().fatal(format!(
+                "the Creusot metadata of crate `{}` (at {}) was generated for a different configuration of this crate; rebuild it with the current features",
+                tcx.crate_name(cnum),
+                binary_path.display()
+            ))
+        }
+
         let mut meta = CrateMetadata::new();
 
         for (def_id, summary) in metadata.terms.into_iter() {
@@ -130,6 +140,8 @@ impl<'tcx> CrateMetadata<'tcx> {
 // a proper index map after parsing.
 #[derive(TyDecodable, TyEncodable)]
 pub(crate) struct BinaryMetadata<'tcx> {
+    /// Hash of the crate this metadata was generated for, which accounts for its active `cfg`s.
+    crate_hash: u128,
     terms: Vec<(DefId, ScopedTerm<'tcx>)>,
     creusot_items: CreusotItems,
     extern_specs: HashMap<DefId, ExternSpec<'tcx>>,
@@ -138,6 +150,7 @@ pub(crate) struct BinaryMetadata<'tcx> {
 
 impl<'tcx> BinaryMetadata<'tcx> {
     pub(crate) fn from_parts(
+        tcx: TyCtxt<'tcx>,
         terms: &mut OnceMap<DefId, Box<Option<ScopedTerm<'tcx>>>>,
         items: &CreusotItems,
         extern_specs: &HashMap<DefId, ExternSpec<'tcx>>,
@@ -150,6 +163,7 @@ impl<'tcx> BinaryMetadata<'tcx> {
             .collect();
 
         BinaryMetadata {
+            crate_hash: tcx.crate_hash(LOCAL_CRATE).as_u128(),
             terms,
             creusot_items: items.clone(),
             extern_specs: extern_specs.clone(),
@@ -0,0 +1,124 @@
+//! Support for `--list-obligations`: list the proof obligations of the crate, instead of writing
+//! them out for Why3.
+//!
+//! There is one obligation per generated module. Its id is the name of the module, which is
+//! derived from the stable path of the Rust item (see [`crate::naming::ModulePath`]), so that it
+//! can be used in allow/deny lists or to compare two runs.
+
+use crate::{
+    backend::Why3Generator,
+    contracts_items::{OverflowMode, is_law},
+    options::ObligationsFormat,
+    translated_item::{FileModule, TranslatedItem},
+};
+use rustc_hir::def_id::DefId;
+use serde::Serialize;
+use why3::declaration::Attribute;
+
+#[derive(Serialize)]
+struct Obligation {
+    id: String,
+    kind: ObligationKind,
+    /// Does the obligation include the proof that the item terminates?
+    termination: bool,
+    span: String,
+    /// The overflow mode of the item, when it is not the default one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overflow: Option<&'static str>,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ObligationKind {
+    /// The body of a program function must satisfy its contract.
+    Body,
+    /// The body of a logic function or predicate must satisfy its contract.
+    Logic,
+    /// A law must hold.
+    Law,
+    /// An impl must refine the specification of its trait.
+    Refinement,
+    /// The precondition of a program function must be satisfiable, see
+    /// `--check-precondition-satisfiability`.
+    Satisfiability,
+}
+
+impl ObligationKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            ObligationKind::Body => "body",
+            ObligationKind::Logic => "logic",
+            ObligationKind::Law => "law",
+            ObligationKind::Refinement => "refinement",
+            ObligationKind::Satisfiability => "satisfiability",
+        }
+    }
+}
+
+/// Print the obligations of the modules that a full run would output.
+pub(crate) fn list_obligations(why3: &mut Why3Generator, format: ObligationsFormat) {
+    let items: Vec<_> = why3.modules().collect();
+    let mut obligations = Vec::new();
+    for (def_id, item) in items {
+        let (kind, termination) = obligation_kind(why3, def_id, &item);
+        let overflow = why3.overflow_mode(def_id);
+        let overflow = (overflow != OverflowMode::Check).then(|| overflow.as_str());
+        for modl in item.modules() {
+            let span = span_of(why3, def_id, &modl);
+            obligations.push(Obligation {
+                id: modl.modl.name.to_string(),
+                kind,
+                termination,
+                span,
+                overflow,
+            });
+        }
+    }
+
+    match format {
+        ObligationsFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&obligations).unwrap())
+        }
+        ObligationsFormat::Table => print_table(&obligations),
+    }
+}
+
+/// The kind of the obligations of `item`, and whether they include the proof that it terminates.
+pub(crate) fn obligation_kind(
+    why3: &Why3Generator,
+    def_id: DefId,
+    item: &TranslatedItem,
+) -> (ObligationKind, bool) {
+    match item {
+        TranslatedItem::Program { .. } => {
+            (ObligationKind::Body, why3.sig(def_id).contract.terminates)
+        }
+        TranslatedItem::Logic { .. } => {
+            let kind =
+                if is_law(why3.tcx, def_id) { ObligationKind::Law } else { ObligationKind::Logic };
+            (kind, why3.sig(def_id).contract.variant.is_some())
+        }
+        TranslatedItem::Impl { .. } => (ObligationKind::Refinement, false),
+        TranslatedItem::Vacuity { .. } => (ObligationKind::Satisfiability, false),
+    }
+}
+
+/// The location of the obligation, as given to Why3 if spans are enabled.
+fn span_of(why3: &Why3Generator, def_id: DefId, modl: &FileModule) -> String {
+    for attr in &modl.modl.attrs {
+        if let Attribute::Span(file, line, col, _, _) = attr {
+            return format!("{file}:{line}:{col}");
+        }
+    }
+    why3.sess.source_map().span_to_embeddable_string(why3.def_span(def_id))
+}
+
+fn print_table(obligations: &[Obligation]) {
+    let id_width = obligations.iter().map(|o| o.id.len()).max().unwrap_or(0).max("ID".len());
+    println!("{:id_width$}  {:10}  {:11}  SPAN", "ID", "KIND", "TERMINATION");
+    for o in obligations {
+        let termination = if o.termination { "yes" } else { "no" };
+        println!("{:id_width$}  {:10}  {:11}  {}", o.id, o.kind.as_str(), termination, o.span);
+    }
+    println!("{} obligation(s)", obligations.len());
+}
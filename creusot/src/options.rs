@@ -1,6 +1,7 @@
 use std::{collections::HashMap, path::PathBuf};
 
 use why3::Symbol;
+pub use why3::printer::Why3Version;
 
 #[derive(Debug, Clone)]
 pub enum SpanMode {
@@ -36,9 +37,49 @@ pub struct Options {
     pub in_cargo: bool,
     pub span_mode: SpanMode,
     pub simple_triggers: bool,
+    pub report_assumptions: bool,
+    pub closed_crates: Vec<String>,
+    pub since: Option<String>,
+    pub deny_trusted_external_impls: bool,
+    pub trusted_allowlist: Option<PathBuf>,
+    pub list_obligations: Option<ObligationsFormat>,
+    pub max_quantifier_depth: Option<usize>,
+    pub simplify: Vec<SimplifyPass>,
+    pub dump_refinements_all: Option<PathBuf>,
+    pub emit_refinement_graph: Option<PathBuf>,
+    pub dump_param_env: Option<PathBuf>,
+    pub solver_log: Option<PathBuf>,
+    pub rlimit: Option<u64>,
+    pub stats: bool,
+    pub only_refinements: bool,
+    pub check_specs_only: bool,
+    pub html_report: Option<PathBuf>,
+    pub panic_report: bool,
+    pub baseline: Option<PathBuf>,
+    pub update_baseline: bool,
+    pub check_coma_round_trip: bool,
+    pub fail_fast: bool,
+    pub check_precondition_satisfiability: bool,
+    pub why3_version: Why3Version,
+    pub vc_cache: Option<PathBuf>,
+    pub report_trust_dependencies: bool,
+    pub max_parallel_solvers: Option<u32>,
     pub why3_cmd: Option<Why3Command>,
 }
 
+/// A simplification pass over pearlite terms, see `translation::pearlite::simplify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimplifyPass {
+    ConstFold,
+    TrivialLogic,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ObligationsFormat {
+    Table,
+    Json,
+}
+
 #[derive(Debug, Clone)]
 pub enum Output {
     Directory(PathBuf), // One file per Coma module
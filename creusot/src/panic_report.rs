@@ -0,0 +1,117 @@
+//! Support for `--panic-report`: summarize which verified functions may panic.
+//!
+//! The panics of a verified body are proof obligations, so a body can only panic in the functions
+//! it calls. A function is thus panic-free if it is `#[pure]`, which includes `no_panic`, or if
+//! every function it calls is: otherwise, the report lists the calls through which it may panic,
+//! along with the conditions declared by its `#[panics_when]` clauses. A function that may panic
+//! without declaring any is reported with a warning.
+//!
+//! The report is not verified: it is an over-approximation computed from the contracts and the
+//! calls of each body, not from the results of the proofs. A function reported as panicking may
+//! not reach any of these calls, and its `#[panics_when]` conditions are not checked.
+
+use crate::{
+    backend::Why3Generator,
+    ctx::{BodyId, ItemType},
+    translation::{fmir, specification::contract_clauses_of},
+};
+use indexmap::IndexSet;
+use rustc_hir::def_id::DefId;
+
+struct PanicEntry {
+    def_id: DefId,
+    /// The function has a `no_panic` contract, checked when verifying its body.
+    no_panic: bool,
+    /// The functions called by the body that may panic.
+    through: IndexSet<String>,
+    /// The source of the `panics_when` clauses of the function.
+    panics_when: Vec<String>,
+}
+
+pub(crate) fn report_panics(why3: &Why3Generator) {
+    let mut entries: Vec<_> = why3
+        .verified_program_functions()
+        .into_iter()
+        .map(|def_id| panic_entry(why3, def_id))
+        .collect();
+    entries.sort_by_key(|e| why3.def_span(e.def_id));
+
+    let mut undeclared = 0;
+    for e in &entries {
+        let name = why3.def_path_str(e.def_id);
+        let span = why3.def_span(e.def_id);
+        if e.no_panic {
+            why3.dcx().span_note(span, format!("`{name}` cannot panic: it is `#[pure]`"));
+        } else if e.through.is_empty() {
+            why3.dcx().span_note(span, format!("`{name}` cannot panic"));
+        } else if e.panics_when.is_empty() {
+            undeclared += 1;
+            why3.warn(
+                span,
+                format!(
+                    "`{name}` may panic through {}, but declares no `#[panics_when]`",
+                    quoted(&e.through)
+                ),
+            );
+        } else {
+            why3.dcx().span_note(
+                span,
+                format!(
+                    "`{name}` may panic when {}, through {}",
+                    e.panics_when.join(" or "),
+                    quoted(&e.through)
+                ),
+            );
+        }
+    }
+    let may_panic = entries.iter().filter(|e| !e.through.is_empty()).count();
+    why3.dcx()
+        .struct_note(format!(
+            "{} function(s), {may_panic} may panic, {undeclared} without `#[panics_when]`",
+            entries.len()
+        ))
+        .with_note(
+            "this report is an unverified over-approximation: the calls which may panic are not \
+             checked to be reachable, and the `#[panics_when]` conditions are not checked",
+        )
+        .emit();
+}
+
+fn panic_entry(why3: &Why3Generator, def_id: DefId) -> PanicEntry {
+    let no_panic = why3.sig(def_id).contract.no_panic;
+    let mut through = IndexSet::new();
+    // A `no_panic` body may only call `no_panic` functions, which is checked by the purity
+    // analysis.
+    if !no_panic {
+        let body = why3.fmir_body(BodyId::new(def_id.expect_local(), None));
+        for block in body.blocks.values() {
+            for stmt in &block.stmts {
+                let fmir::Statement::Call(_, callee, _, _, _) = stmt else { continue };
+                if may_panic(why3, *callee) {
+                    through.insert(why3.def_path_str(*callee));
+                }
+            }
+        }
+    }
+    let source_map = why3.sess.source_map();
+    let panics_when = contract_clauses_of(why3, def_id)
+        .map(|clauses| clauses.panics_when().to_vec())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|id| {
+            let term = why3.term(id).ok()??;
+            source_map.span_to_snippet(term.1.span).ok().map(|cond| format!("`{cond}`"))
+        })
+        .collect();
+    PanicEntry { def_id, no_panic, through, panics_when }
+}
+
+fn quoted(names: &IndexSet<String>) -> String {
+    names.iter().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", ")
+}
+
+/// Can a call to `callee` panic, according to its contract?
+fn may_panic(why3: &Why3Generator, callee: DefId) -> bool {
+    matches!(why3.item_type(callee), ItemType::Program | ItemType::Closure)
+        && !why3.sig(callee).contract.no_panic
+}
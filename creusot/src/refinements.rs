@@ -0,0 +1,387 @@
+//! Support for `--dump-refinements-all`: write every refinement obligation of the crate to a file,
+//! for golden tests of their generation, and for `--emit-refinement-graph`: write which impl items
+//! refine which trait items as a DOT graph, and for `--dump-param-env`: write the bounds assumed by
+//! each refinement.
+//!
+//! The output must not change between runs, nor when unrelated items are added to the crate: impls
+//! are sorted by their stable hash, refinements keep the order of [`TraitImpl::refinements`], and
+//! terms are printed without spans, with items named by their path and variables numbered in order
+//! of appearance.
+
+use crate::{
+    ctx::TranslationCtx,
+    translation::{
+        pearlite::{Ident, Literal, PIdent, Pattern, PatternKind, Term, TermKind},
+        traits::TraitImpl,
+    },
+    very_stable_hash::get_very_stable_hash,
+};
+use itertools::Itertools;
+use rustc_middle::{
+    mir::ProjectionElem,
+    ty::{GenericArgsRef, Ty, TyKind, TypingEnv},
+};
+use rustc_span::def_id::DefId;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    fmt::{Result, Write},
+    path::Path,
+};
+
+#[derive(Serialize)]
+struct DumpedRefinement {
+    trait_item: String,
+    impl_item: String,
+    refinement: String,
+}
+
+/// Write the refinements of all the local trait impls to `path`, as JSON.
+pub(crate) fn dump_refinements(ctx: &TranslationCtx, path: &Path) {
+    let mut impls: Vec<DefId> =
+        ctx.all_local_trait_impls(()).values().flatten().map(|id| id.to_def_id()).collect();
+    impls.sort_by_cached_key(|impl_id| get_very_stable_hash(impl_id, &ctx.tcx));
+
+    let mut dumped = Vec::new();
+    for impl_id in impls {
+        let Ok(TraitImpl { refinements, .. }) = ctx.trait_impl(impl_id) else { continue };
+        for refn in refinements {
+            let mut printer = TermPrinter { ctx, out: String::new(), idents: HashMap::new() };
+            printer.term(&refn.refn).unwrap();
+            dumped.push(DumpedRefinement {
+                trait_item: ctx.def_path_str(refn.trait_.0),
+                impl_item: ctx.def_path_str(refn.impl_.0),
+                refinement: printer.out,
+            });
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&dumped).unwrap();
+    if let Err(err) = std::fs::write(path, json + "\n") {
+        ctx.dcx().fatal(format!("could not write refinements to {}: {err}", path.display()));
+    }
+}
+
+#[derive(Serialize)]
+struct DumpedParamEnv {
+    trait_item: String,
+    impl_item: String,
+    bounds: Vec<String>,
+}
+
+/// Write the bounds in scope for the refinements of all the local trait impls to `path`, as JSON.
+///
+/// These are the predicates of the `param_env` of the impl item, which the refinement obligation
+/// may assume: the bounds of the impl and of the item itself, but not those which only hold for
+/// the trait.
+pub(crate) fn dump_param_env(ctx: &TranslationCtx, path: &Path) {
+    let mut impls: Vec<DefId> =
+        ctx.all_local_trait_impls(()).values().flatten().map(|id| id.to_def_id()).collect();
+    impls.sort_by_cached_key(|impl_id| get_very_stable_hash(impl_id, &ctx.tcx));
+
+    let mut dumped = Vec::new();
+    for impl_id in impls {
+        let Ok(TraitImpl { refinements, .. }) = ctx.trait_impl(impl_id) else { continue };
+        for refn in refinements {
+            let typing_env = TypingEnv::non_body_analysis(ctx.tcx, refn.impl_.0);
+            dumped.push(DumpedParamEnv {
+                trait_item: ctx.def_path_str(refn.trait_.0),
+                impl_item: ctx.def_path_str(refn.impl_.0),
+                bounds: typing_env
+                    .param_env
+                    .caller_bounds()
+                    .iter()
+                    .map(|clause| clause.to_string())
+                    .collect(),
+            });
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&dumped).unwrap();
+    if let Err(err) = std::fs::write(path, json + "\n") {
+        ctx.dcx().fatal(format!("could not write the bounds to {}: {err}", path.display()));
+    }
+}
+
+/// Write the refinements of all the local trait impls to `path`, as a DOT graph.
+///
+/// There is a node for each trait item and each impl item, and the items of an impl are grouped
+/// in a cluster. An edge goes from each impl item to the trait item it refines, and from each impl
+/// to the laws which are proved for it (e.g. `#[forall_impls]` laws).
+pub(crate) fn emit_refinement_graph(ctx: &TranslationCtx, path: &Path) {
+    let mut impls: Vec<DefId> =
+        ctx.all_local_trait_impls(()).values().flatten().map(|id| id.to_def_id()).collect();
+    impls.sort_by_cached_key(|impl_id| get_very_stable_hash(impl_id, &ctx.tcx));
+
+    let node = |def_id: DefId| format!("n{:x}", get_very_stable_hash(&def_id, &ctx.tcx).as_u64());
+    let label = |def_id: DefId| ctx.def_path_str(def_id).replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut out = String::new();
+    let mut trait_items = Vec::new();
+    let mut edges = Vec::new();
+    writeln!(out, "digraph refinements {{").unwrap();
+    writeln!(out, "  rankdir=LR;").unwrap();
+    writeln!(out, "  node [shape=box];").unwrap();
+    for impl_id in impls {
+        let Ok(TraitImpl { laws, refinements }) = ctx.trait_impl(impl_id) else { continue };
+        writeln!(out, "  subgraph cluster_{} {{", node(impl_id)).unwrap();
+        writeln!(out, "    label=\"{}\";", label(impl_id)).unwrap();
+        writeln!(out, "    {} [label=\"impl\", shape=ellipse];", node(impl_id)).unwrap();
+        for refn in refinements {
+            let (impl_item, trait_item) = (refn.impl_.0, refn.trait_.0);
+            if impl_item != impl_id {
+                let kind = if laws.contains(&impl_item) { "law" } else { "refines" };
+                writeln!(out, "    {} [label=\"{}\"];", node(impl_item), label(impl_item)).unwrap();
+                edges.push((node(impl_item), node(trait_item), kind));
+            } else {
+                edges.push((node(impl_id), node(trait_item), "law"));
+            }
+            trait_items.push(trait_item);
+        }
+        writeln!(out, "  }}").unwrap();
+    }
+    trait_items.sort_by_cached_key(|def_id| get_very_stable_hash(def_id, &ctx.tcx));
+    trait_items.dedup();
+    for trait_item in trait_items {
+        writeln!(out, "  {} [label=\"{}\", style=rounded];", node(trait_item), label(trait_item))
+            .unwrap();
+    }
+    for (from, to, kind) in edges {
+        let style = if kind == "law" { ", style=dashed" } else { "" };
+        writeln!(out, "  {from} -> {to} [label=\"{kind}\"{style}];").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    if let Err(err) = std::fs::write(path, out) {
+        ctx.dcx()
+            .fatal(format!("could not write the refinement graph to {}: {err}", path.display()));
+    }
+}
+
+/// Print terms as s-expressions, see the module documentation.
+struct TermPrinter<'a, 'tcx> {
+    ctx: &'a TranslationCtx<'tcx>,
+    out: String,
+    idents: HashMap<Ident, usize>,
+}
+
+impl<'tcx> TermPrinter<'_, 'tcx> {
+    fn ident(&mut self, ident: Ident) -> Result {
+        let next = self.idents.len();
+        let idx = *self.idents.entry(ident).or_insert(next);
+        write!(self.out, "{}#{idx}", ident.name().to_string())
+    }
+
+    fn item(&mut self, def_id: DefId, subst: GenericArgsRef<'tcx>) -> Result {
+        write!(self.out, "{}", self.ctx.def_path_str(def_id))?;
+        if !subst.is_empty() {
+            write!(self.out, "::<{}>", subst.iter().map(|arg| arg.to_string()).join(", "))?;
+        }
+        Ok(())
+    }
+
+    fn node(&mut self, head: &str, args: &[&Term<'tcx>]) -> Result {
+        write!(self.out, "({head}")?;
+        self.terms(args.iter().copied())?;
+        write!(self.out, ")")
+    }
+
+    fn terms<'b>(&mut self, terms: impl IntoIterator<Item = &'b Term<'tcx>>) -> Result
+    where
+        'tcx: 'b,
+    {
+        for t in terms {
+            write!(self.out, " ")?;
+            self.term(t)?;
+        }
+        Ok(())
+    }
+
+    fn binders(&mut self, binders: &[(PIdent, Ty<'tcx>)]) -> Result {
+        write!(self.out, "(")?;
+        for (i, &(x, ty)) in binders.iter().enumerate() {
+            if i > 0 {
+                write!(self.out, " ")?;
+            }
+            self.ident(x.0)?;
+            write!(self.out, ": {ty}")?;
+        }
+        write!(self.out, ")")
+    }
+
+    fn term(&mut self, term: &Term<'tcx>) -> Result {
+        match &term.kind {
+            TermKind::Var(x) => self.ident(x.0),
+            TermKind::Lit(lit) => self.literal(lit),
+            TermKind::SeqLiteral(elems) => {
+                write!(self.out, "(seq")?;
+                self.terms(elems.iter())?;
+                write!(self.out, ")")
+            }
+            TermKind::Cast { arg } => {
+                write!(self.out, "(cast {} ", term.ty)?;
+                self.term(arg)?;
+                write!(self.out, ")")
+            }
+            TermKind::Coerce { arg } => {
+                write!(self.out, "(coerce {} ", term.ty)?;
+                self.term(arg)?;
+                write!(self.out, ")")
+            }
+            TermKind::Item(def_id, subst) => self.item(*def_id, subst),
+            TermKind::Const(c) => write!(self.out, "(const {c})"),
+            TermKind::Assert { cond } => self.node("assert", &[cond]),
+            TermKind::Binary { op, lhs, rhs } => self.node(&format!("{op:?}"), &[lhs, rhs]),
+            TermKind::Unary { op, arg } => self.node(&format!("{op:?}"), &[arg]),
+            TermKind::Quant { kind, binder, trigger, body } => {
+                write!(self.out, "({kind:?} ")?;
+                self.binders(binder)?;
+                for trig in trigger {
+                    write!(self.out, " (trigger")?;
+                    self.terms(trig.0.iter())?;
+                    write!(self.out, ")")?;
+                }
+                write!(self.out, " ")?;
+                self.term(body)?;
+                write!(self.out, ")")
+            }
+            TermKind::Call { id, subst, args } => {
+                write!(self.out, "(")?;
+                self.item(*id, subst)?;
+                self.terms(args.iter())?;
+                write!(self.out, ")")
+            }
+            TermKind::Constructor { typ, variant, fields } => {
+                let adt = self.ctx.adt_def(*typ);
+                let name = self.ctx.def_path_str(adt.variant(*variant).def_id);
+                write!(self.out, "({name}")?;
+                self.terms(fields.iter())?;
+                write!(self.out, ")")
+            }
+            TermKind::Tuple { fields } => {
+                write!(self.out, "(tuple")?;
+                self.terms(fields.iter())?;
+                write!(self.out, ")")
+            }
+            TermKind::Cur { term } => self.node("cur", &[term]),
+            TermKind::Fin { term } => self.node("fin", &[term]),
+            TermKind::Impl { lhs, rhs } => self.node("==>", &[lhs, rhs]),
+            TermKind::Match { scrutinee, arms } => {
+                write!(self.out, "(match ")?;
+                self.term(scrutinee)?;
+                for (pat, arm) in arms {
+                    write!(self.out, " (")?;
+                    self.pattern(pat)?;
+                    write!(self.out, " ")?;
+                    self.term(arm)?;
+                    write!(self.out, ")")?;
+                }
+                write!(self.out, ")")
+            }
+            TermKind::Let { pattern, arg, body } => {
+                write!(self.out, "(let ")?;
+                self.pattern(pattern)?;
+                self.terms([&**arg, &**body])?;
+                write!(self.out, ")")
+            }
+            TermKind::Projection { lhs, idx } => {
+                write!(self.out, "(proj {} ", idx.as_usize())?;
+                self.term(lhs)?;
+                write!(self.out, ")")
+            }
+            TermKind::Old { term } => self.node("old", &[term]),
+            TermKind::Closure { bound, body } => {
+                write!(self.out, "(closure ")?;
+                self.binders(bound)?;
+                write!(self.out, " ")?;
+                self.term(body)?;
+                write!(self.out, ")")
+            }
+            TermKind::Reborrow { inner, cur, fin, projection } => {
+                write!(self.out, "(reborrow")?;
+                self.terms([&**inner, &**cur, &**fin])?;
+                for elem in projection {
+                    match elem {
+                        ProjectionElem::Deref => write!(self.out, " *")?,
+                        ProjectionElem::Field(idx, _) => write!(self.out, " .{}", idx.as_usize())?,
+                        ProjectionElem::Index(ix) => {
+                            write!(self.out, " [")?;
+                            self.term(ix)?;
+                            write!(self.out, "]")?
+                        }
+                        ProjectionElem::Downcast(_, variant) => {
+                            write!(self.out, " as {}", variant.as_usize())?
+                        }
+                        _ => write!(self.out, " ..")?,
+                    }
+                }
+                write!(self.out, ")")
+            }
+            TermKind::Precondition { item, subst, params } => {
+                write!(self.out, "(precondition ")?;
+                self.item(*item, subst)?;
+                self.terms(params.iter())?;
+                write!(self.out, ")")
+            }
+            TermKind::Postcondition { item, subst, params } => {
+                write!(self.out, "(postcondition ")?;
+                self.item(*item, subst)?;
+                self.terms(params.iter())?;
+                write!(self.out, ")")
+            }
+        }
+    }
+
+    fn literal(&mut self, lit: &Literal<'tcx>) -> Result {
+        match lit {
+            Literal::Char(c) => write!(self.out, "{c:?}"),
+            Literal::Bool(b) => write!(self.out, "{b}"),
+            Literal::Integer(i) => write!(self.out, "{i}"),
+            Literal::UInteger(u) => write!(self.out, "{u}"),
+            Literal::MachSigned(i, ty) => write!(self.out, "{i}{}", ty.name_str()),
+            Literal::MachUnsigned(u, ty) => write!(self.out, "{u}{}", ty.name_str()),
+            Literal::Float(f, ty) => write!(self.out, "{}{}", f.0, ty.name_str()),
+            Literal::String(s) => write!(self.out, "{s:?}"),
+            Literal::ZST => write!(self.out, "()"),
+            Literal::Function(def_id, subst) => self.item(*def_id, subst),
+        }
+    }
+
+    fn pattern(&mut self, pat: &Pattern<'tcx>) -> Result {
+        match &pat.kind {
+            PatternKind::Constructor(variant, fields) => {
+                let name = match pat.ty.kind() {
+                    TyKind::Adt(adt, _) => self.ctx.def_path_str(adt.variant(*variant).def_id),
+                    _ => format!("{}", variant.as_usize()),
+                };
+                write!(self.out, "({name}")?;
+                self.patterns(fields)?;
+                write!(self.out, ")")
+            }
+            PatternKind::Deref(pat) => {
+                write!(self.out, "(deref ")?;
+                self.pattern(pat)?;
+                write!(self.out, ")")
+            }
+            PatternKind::Tuple(fields) => {
+                write!(self.out, "(tuple")?;
+                self.patterns(fields)?;
+                write!(self.out, ")")
+            }
+            PatternKind::Wildcard => write!(self.out, "_"),
+            PatternKind::Binder(x) => self.ident(x.0),
+            PatternKind::Bool(b) => write!(self.out, "{b}"),
+        }
+    }
+
+    fn patterns(&mut self, pats: &[Pattern<'tcx>]) -> Result {
+        for pat in pats {
+            write!(self.out, " ")?;
+            self.pattern(pat)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+use super::{dump_param_env, emit_refinement_graph};
+use crate::{ctx::TranslationCtx, test_support::*, very_stable_hash::get_very_stable_hash};
+use std::fs;
+
+const IMPLS: &str = "
+#![feature(register_tool)]
+#![register_tool(creusot)]
+
+pub trait Tr {
+    #[creusot::decl::logic]
+    fn m(self) -> bool;
+}
+
+pub struct S;
+
+impl Tr for S {
+    #[creusot::decl::logic]
+    fn m(self) -> bool {
+        true
+    }
+}
+
+pub struct W<T>(pub T);
+
+impl<T: Clone> Tr for W<T> {
+    #[creusot::decl::logic]
+    fn m(self) -> bool {
+        false
+    }
+}
+";
+
+/// The name of the node of the item at `path` in the refinement graph.
+fn node(ctx: &TranslationCtx, path: &str) -> String {
+    format!("n{:x}", get_very_stable_hash(&local_item(ctx.tcx, path), &ctx.tcx).as_u64())
+}
+
+// Each impl item refines the trait item it implements, which is a single node for all the impls.
+#[test]
+fn graph_links_impl_items_to_trait_items() {
+    with_ctx(IMPLS, |ctx| {
+        let dir = tempdir::TempDir::new("creusot-test").unwrap();
+        let path = dir.path().join("refinements.dot");
+        emit_refinement_graph(ctx, &path);
+        let dot = fs::read_to_string(&path).unwrap();
+
+        assert!(dot.starts_with("digraph refinements {\n"), "{dot}");
+        assert_eq!(dot.matches("subgraph cluster_").count(), 2, "{dot}");
+        let trait_item = node(ctx, "Tr::m");
+        assert_eq!(dot.matches(&format!("  {trait_item} [label=\"Tr::m\"")).count(), 1, "{dot}");
+        for impl_item in ["<S as Tr>::m", "<W<T> as Tr>::m"] {
+            let edge = format!("{} -> {trait_item} [label=\"refines\"];", node(ctx, impl_item));
+            assert!(dot.contains(&edge), "no edge from `{impl_item}` in\n{dot}");
+        }
+    })
+}
+
+// The refinements of the items of an impl may assume the bounds of the impl.
+#[test]
+fn param_env_has_the_bounds_of_the_impl() {
+    with_ctx(IMPLS, |ctx| {
+        let dir = tempdir::TempDir::new("creusot-test").unwrap();
+        let path = dir.path().join("param_env.json");
+        dump_param_env(ctx, &path);
+        let dumped: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+
+        let bounds = |impl_item: &str| -> Vec<String> {
+            let dumped = dumped.as_array().unwrap().iter().find(|d| d["impl_item"] == impl_item);
+            let dumped = dumped.unwrap_or_else(|| panic!("no refinement of `{impl_item}`"));
+            assert_eq!(dumped["trait_item"], "Tr::m");
+            let bounds = dumped["bounds"].as_array().unwrap();
+            bounds.iter().map(|b| b.as_str().unwrap().to_string()).collect()
+        };
+        assert!(bounds("<W<T> as Tr>::m").iter().any(|b| b.contains("Clone")));
+        assert!(!bounds("<S as Tr>::m").iter().any(|b| b.contains("Clone")));
+    })
+}
@@ -1,8 +1,10 @@
 use crate::{
     backend::Why3Generator,
-    options::{Options, Why3Sub},
+    html_report::GoalResult,
+    options::{Options, Why3Command, Why3Sub},
+    vc_cache::{self, VcCache},
 };
-use include_dir::{Dir, include_dir};
+use include_dir::{Dir, DirEntry, include_dir};
 use rustc_ast::{
     Block, DUMMY_NODE_ID, Expr, ExprKind, Pat, PatKind, PathSegment, Ty, TyKind,
     mut_visit::DummyAstNode,
@@ -11,21 +13,24 @@ use rustc_ast::{
 };
 use rustc_ast_pretty::pprust::expr_to_string;
 use rustc_span::{
-    BytePos, DUMMY_SP, Span, Symbol, SyntaxContext, def_id::LocalDefId, source_map::dummy_spanned,
+    BytePos, DUMMY_SP, Span, Symbol, SyntaxContext,
+    def_id::{DefId, LocalDefId},
+    source_map::dummy_spanned,
     symbol::Ident,
 };
 use serde_json::Deserializer;
 use std::{
-    collections::{HashMap, hash_map::Entry},
+    collections::{HashMap, VecDeque, hash_map::Entry},
     fmt::{Display, Formatter, Write},
     io::BufReader,
-    path::PathBuf,
-    process::{Command, Stdio},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    time::Instant,
 };
 use tempdir::TempDir;
 use why3::{
     ce_models::{ConcreteTerm, FunLitElt, Goal, Loc, ProverResult, TBool, Term, Why3Span},
-    declaration::Attribute,
+    declaration::{Attribute, Module},
 };
 
 static PRELUDE: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/../prelude");
@@ -40,7 +45,49 @@ impl Display for Why3Sub {
     }
 }
 
-pub(super) fn run_why3<'tcx>(ctx: &Why3Generator<'tcx>, file: Option<PathBuf>) {
+/// The prefix of the attribute giving the solver resource limit of a module.
+pub(crate) const RLIMIT_ATTR: &str = "creusot:rlimit:";
+
+/// The explanation of the goals of `--check-precondition-satisfiability`, which state that the
+/// precondition of a function is satisfiable: failing to prove them reveals a possibly vacuous
+/// specification.
+pub(crate) const SATISFIABILITY_EXPL: &str = "satisfiable precondition";
+
+/// The prefix of the attribute giving the solver of a module.
+pub(crate) const PROVER_ATTR: &str = "creusot:prover:";
+
+/// The prefix of the attribute recording the overflow mode of a module, when it is not the default
+/// one: its proof results only hold for this model of integer arithmetic.
+pub(crate) const OVERFLOW_ATTR: &str = "creusot:overflow:";
+
+/// The solver options of a module, given by its [`RLIMIT_ATTR`] and [`PROVER_ATTR`] attributes.
+#[derive(Clone, PartialEq, Eq, Default)]
+pub(crate) struct ModuleSolver {
+    rlimit: Option<u64>,
+    prover: Option<String>,
+}
+
+pub(crate) fn module_solver(modl: &Module) -> ModuleSolver {
+    let mut solver = ModuleSolver::default();
+    for attr in &modl.attrs {
+        let Attribute::Attr(attr) = attr else { continue };
+        if let Some(rlimit) = attr.strip_prefix(RLIMIT_ATTR) {
+            solver.rlimit = rlimit.parse().ok();
+        } else if let Some(prover) = attr.strip_prefix(PROVER_ATTR) {
+            solver.prover = Some(prover.to_string());
+        }
+    }
+    solver
+}
+
+/// Run Why3 on `file`, whose modules are given with their solver options, and with their keys
+/// in the cache of `--vc-cache` and their items.
+pub(super) fn run_why3<'tcx>(
+    ctx: &Why3Generator<'tcx>,
+    file: Option<PathBuf>,
+    solvers: Vec<(why3::Symbol, ModuleSolver)>,
+    vc_keys: HashMap<why3::Symbol, (String, DefId)>,
+) {
     let Some(why3_cmd) = &ctx.opts.why3_cmd else { return };
     let Some(mut output_file) = file else {
         ctx.crash_and_error(DUMMY_SP, "cannot run why3 without file")
@@ -48,73 +95,374 @@ pub(super) fn run_why3<'tcx>(ctx: &Why3Generator<'tcx>, file: Option<PathBuf>) {
     if matches!(why3_cmd.sub, Why3Sub::Replay) {
         output_file.set_extension("");
     }
+    check_why3_version(ctx, why3_cmd);
     let temp_dir = TempDir::new("creusot_why3_prelude").expect("could not create temp dir");
     let mut prelude_dir: PathBuf = temp_dir.as_ref().into();
     prelude_dir.push("creusot");
     std::fs::create_dir(&prelude_dir).unwrap();
 
     PRELUDE.extract(&prelude_dir).expect("could extract prelude into temp dir");
-    let mut command = Command::new(&why3_cmd.path);
-    command
-        .args([
-            "-C",
-            &why3_cmd.config_file.to_string_lossy(),
-            "--warn-off=unused_variable",
-            "--warn-off=clone_not_abstract",
-            "--warn-off=axiom_abstract",
-            "--debug=coma_no_trivial",
-            &why3_cmd.sub.to_string(),
-            "-L",
-        ])
-        .arg(temp_dir.path().as_os_str())
-        .arg(&output_file)
-        .args(why3_cmd.args.split_ascii_whitespace());
-
-    if matches!(why3_cmd.sub, Why3Sub::Prove) {
-        command.arg("--json");
-        let span_map = &ctx.span_map.borrow();
-        let mut child = command.stdout(Stdio::piped()).spawn().expect("could not run why3");
-        let mut stdout = BufReader::new(child.stdout.take().unwrap());
-        let de = Deserializer::from_reader(&mut stdout);
-        for value in de.into_iter::<Goal>() {
-            match value {
-                Ok(x) => {
-                    let ProverResult { answer, step, time, .. } = &x.prover_result;
+    // With `--max-parallel-solvers`, the modules proved one at a time each run a single solver,
+    // in at most this many Why3 processes at once; otherwise, the one Why3 process runs at most
+    // this many solvers at once.
+    let per_module = ctx.opts.only_refinements || ctx.opts.vc_cache.is_some();
+    let jobs_config = ctx.opts.max_parallel_solvers.map(|jobs| {
+        let path = temp_dir.path().join("jobs.conf");
+        let running = if per_module { 1 } else { jobs };
+        std::fs::write(&path, format!("[main]\nrunning_provers_max = {running}\n"))
+            .expect("could not write the Why3 configuration");
+        path
+    });
+    // The solver of a module replaces the ones given in the arguments of the command.
+    let command = |solver: &ModuleSolver| {
+        let mut command = Command::new(&why3_cmd.path);
+        command.args(["-C", &why3_cmd.config_file.to_string_lossy()]);
+        if let Some(jobs_config) = &jobs_config {
+            command.arg("--extra-config").arg(jobs_config);
+        }
+        command
+            .args([
+                "--warn-off=unused_variable",
+                "--warn-off=clone_not_abstract",
+                "--warn-off=axiom_abstract",
+                "--debug=coma_no_trivial",
+                &why3_cmd.sub.to_string(),
+                "-L",
+            ])
+            .arg(temp_dir.path().as_os_str())
+            .arg(&output_file);
+        let mut args = why3_cmd.args.split_ascii_whitespace();
+        while let Some(arg) = args.next() {
+            if solver.prover.is_some() && matches!(arg, "-P" | "--prover") {
+                args.next();
+            } else if solver.prover.is_none() || !arg.starts_with("--prover=") {
+                command.arg(arg);
+            }
+        }
+        if let Some(prover) = &solver.prover {
+            command.args(["-P", prover]);
+        }
+        if let Some(rlimit) = solver.rlimit {
+            command.arg(format!("--stepslimit={rlimit}"));
+        }
+        command
+    };
+
+    if !matches!(why3_cmd.sub, Why3Sub::Prove) {
+        command(&ModuleSolver::default()).status().expect("could not run why3");
+        ctx.crash_and_error(DUMMY_SP, "did not run why3 prove")
+    }
+
+    if ctx.opts.only_refinements {
+        prove_refinements(ctx, &command, solvers);
+        return;
+    }
+
+    if let Some(path) = &ctx.opts.vc_cache {
+        prove_cached(ctx, &command, solvers, &vc_keys, path);
+        return;
+    }
+
+    // Modules are proved in groups sharing the same solver options, unless they all share them.
+    let mut groups: Vec<(ModuleSolver, Vec<why3::Symbol>)> = Vec::new();
+    for (name, solver) in solvers {
+        match groups.iter_mut().find(|(s, _)| *s == solver) {
+            Some((_, names)) => names.push(name),
+            None => groups.push((solver, vec![name])),
+        }
+    }
+    if groups.len() <= 1 {
+        let solver = groups.pop().map(|(solver, _)| solver).unwrap_or_default();
+        prove(ctx, command(&solver));
+    } else {
+        for (solver, names) in groups {
+            let mut command = command(&solver);
+            for name in names {
+                command.arg("-T").arg(name.to_string());
+            }
+            if !prove(ctx, command) && ctx.opts.fail_fast {
+                break;
+            }
+        }
+    }
+}
+
+/// The environment in which the solvers of `--vc-cache` run, which is part of the key of each
+/// obligation: the options of the solvers, the prelude, the Why3 configuration, which records the
+/// versions of the provers it detected, and the version of Why3.
+pub(crate) fn vc_environment(ctx: &Why3Generator) -> String {
+    fn push_dir(dir: &Dir, env: &mut String) {
+        for entry in dir.entries() {
+            match entry {
+                DirEntry::Dir(dir) => push_dir(dir, env),
+                DirEntry::File(file) => {
+                    writeln!(env, "{}", file.path().display()).unwrap();
+                    env.push_str(&String::from_utf8_lossy(file.contents()));
+                }
+            }
+        }
+    }
+    let mut env = String::new();
+    push_dir(&PRELUDE, &mut env);
+    let Some(why3_cmd) = &ctx.opts.why3_cmd else { return env };
+    writeln!(env, "{}", why3_cmd.args).unwrap();
+    env.push_str(&std::fs::read_to_string(&why3_cmd.config_file).unwrap_or_default());
+    if let Ok(output) = Command::new(&why3_cmd.path).arg("--version").output() {
+        env.push_str(&String::from_utf8_lossy(&output.stdout));
+    }
+    env
+}
+
+/// Warn if the installed Why3 is older than the release that the generated code is written for
+/// (see `--why3-version`), since it may reject its syntax.
+fn check_why3_version(ctx: &Why3Generator, why3_cmd: &Why3Command) {
+    fn major_minor(version: &str) -> Option<(u32, u32)> {
+        let mut parts = version.split(['.', '+']);
+        Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+    }
+    let Ok(output) = Command::new(&why3_cmd.path).arg("--version").output() else { return };
+    let output = String::from_utf8_lossy(&output.stdout);
+    let Some(installed) = output.trim().strip_prefix("Why3 platform, version ") else { return };
+    let targeted = ctx.opts.why3_version.as_str();
+    if let (Some(installed_version), Some(targeted_version)) =
+        (major_minor(installed), major_minor(targeted))
+        && installed_version < targeted_version
+    {
+        ctx.dcx().warn(format!(
+            "the generated code is written for Why3 {targeted}, but the installed version is \
+             {installed}, which may not accept it (see `--why3-version`)"
+        ));
+    }
+}
+
+/// Prove the refinement modules one at a time, to report for each impl item whether it conforms
+/// to the specification of its trait.
+fn prove_refinements(
+    ctx: &Why3Generator,
+    command: &dyn Fn(&ModuleSolver) -> Command,
+    solvers: Vec<(why3::Symbol, ModuleSolver)>,
+) {
+    let items = crate::backend::traits::refinement_modules(ctx);
+    let mut failed = 0;
+    prove_each(ctx, command, &solvers, |(name, _), proved| {
+        if !proved {
+            failed += 1;
+        }
+        let (span, item) = match items.get(name) {
+            Some(&id) => (ctx.def_span(id), ctx.def_path_str(id)),
+            None => (DUMMY_SP, name.to_string()),
+        };
+        if proved {
+            ctx.dcx().span_note(span, format!("`{item}` refines its trait item"));
+        } else {
+            ctx.error(span, &format!("`{item}` does not refine its trait item")).emit();
+        }
+        proved || !ctx.opts.fail_fast
+    });
+    ctx.dcx().note(format!("{} of {} refinement(s) proved", solvers.len() - failed, solvers.len()));
+}
+
+/// Prove the modules one at a time for `--vc-cache`, except those whose obligation is in the cache
+/// or is the same as that of a module already proved by this run: the solvers are only called
+/// once for identical obligations. The modules which are not proved by the solvers are recorded
+/// as proved in the `--html-report` and the `--stats` if their obligation is.
+fn prove_cached(
+    ctx: &Why3Generator,
+    command: &dyn Fn(&ModuleSolver) -> Command,
+    solvers: Vec<(why3::Symbol, ModuleSolver)>,
+    vc_keys: &HashMap<why3::Symbol, (String, DefId)>,
+    path: &Path,
+) {
+    let mut cache = VcCache::load(ctx, path);
+    let plan = vc_cache::plan(&cache, solvers.iter().map(|(name, _)| (*name, &*vc_keys[name].0)));
+    for name in &plan.cached {
+        record_cached(ctx, name, vc_keys[name].1);
+    }
+    let to_prove: Vec<_> =
+        solvers.into_iter().filter(|(name, _)| plan.to_prove.contains(name)).collect();
+    prove_each(ctx, command, &to_prove, |(first, _), proved| {
+        let key = &vc_keys[first].0;
+        let others = &plan.same_as[key];
+        if proved {
+            cache.record_proved(key.clone());
+        }
+        for name in others {
+            if proved {
+                record_cached(ctx, name, vc_keys[name].1);
+            } else {
+                let (name, first) = (name.to_string(), first.to_string());
+                ctx.dcx().err(format!(
+                    "the obligation of `{name}` is the same as that of `{first}`, which is not proved"
+                ));
+            }
+        }
+        proved || !ctx.opts.fail_fast
+    });
+    cache.save(ctx, path);
+}
+
+/// Record the module `name` of `def_id` as proved without running the solvers, see
+/// [`prove_cached`].
+fn record_cached(ctx: &Why3Generator, name: &why3::Symbol, def_id: DefId) {
+    let span = Some(ctx.def_span(def_id));
+    if let Some(stats) = &ctx.stats {
+        stats.borrow_mut().record_cached();
+    }
+    if let Some(report) = &ctx.report {
+        report.borrow_mut().record_goal(GoalResult {
+            span,
+            name: name.to_string(),
+            explanations: vec!["cached".into()],
+            answer: "Valid".into(),
+            time: 0.,
+            model: Vec::new(),
+        });
+    }
+}
+
+/// Prove each of `modules` in its own Why3 process, with at most `--max-parallel-solvers` of them
+/// running at the same time, and call `on_result` with the result of each module in the order of
+/// `modules`. The remaining modules are not proved once `on_result` returns `false`.
+fn prove_each(
+    ctx: &Why3Generator,
+    command: &dyn Fn(&ModuleSolver) -> Command,
+    modules: &[(why3::Symbol, ModuleSolver)],
+    mut on_result: impl FnMut(&(why3::Symbol, ModuleSolver), bool) -> bool,
+) {
+    let jobs = ctx.opts.max_parallel_solvers.unwrap_or(1) as usize;
+    let spawn_module = |(name, solver): &(why3::Symbol, ModuleSolver)| {
+        let mut command = command(solver);
+        command.arg("-T").arg(name.to_string());
+        spawn(command)
+    };
+    // The processes of the next modules, started ahead of their turn.
+    let mut running: VecDeque<_> = modules.iter().take(jobs).map(spawn_module).collect();
+    for (i, module) in modules.iter().enumerate() {
+        let (child, start) = running.pop_front().unwrap();
+        let proved = collect(ctx, child, start);
+        if !on_result(module, proved) {
+            for (mut child, _) in running {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+            return;
+        }
+        if let Some(next) = modules.get(i + jobs) {
+            running.push_back(spawn_module(next));
+        }
+    }
+}
+
+/// Run `why3 prove`, and report the goals that were not proved. Returns whether all the goals
+/// were proved.
+///
+/// The goals of `--check-precondition-satisfiability` are the exception: they are reported when
+/// they are proved.
+///
+/// With `--fail-fast`, Why3 is stopped after the first goal that is not proved.
+fn prove(ctx: &Why3Generator, command: Command) -> bool {
+    let (child, start) = spawn(command);
+    collect(ctx, child, start)
+}
+
+/// Is the goal at `span` part of an obligation that is known to fail in the `--baseline`?
+fn known_failure(ctx: &Why3Generator, span: Option<Span>) -> bool {
+    let (Some(baseline), Some(report)) = (&ctx.baseline, &ctx.report) else { return false };
+    report.borrow().item_at(span).is_some_and(|def_id| baseline.tolerates(ctx, def_id))
+}
+
+fn spawn(mut command: Command) -> (Child, Instant) {
+    command.arg("--json");
+    let start = Instant::now();
+    (command.stdout(Stdio::piped()).spawn().expect("could not run why3"), start)
+}
+
+/// Report the results of the Why3 process `child` started at `start`, see [`prove`].
+fn collect(ctx: &Why3Generator, mut child: Child, start: Instant) -> bool {
+    let span_map = &ctx.span_map.borrow();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let de = Deserializer::from_reader(&mut stdout);
+    let mut proved = true;
+    let mut stopped = false;
+    for value in de.into_iter::<Goal>() {
+        match value {
+            Ok(x) => {
+                let ProverResult { answer, step, time, .. } = &x.prover_result;
+                if let Some(stats) = &ctx.stats {
+                    let span = span_map.decode_span(&x.term.loc);
+                    stats.borrow_mut().record_goal(span, &x.term.explanations, *time);
+                }
+                if x.term.explanations.iter().any(|expl| expl.contains(SATISFIABILITY_EXPL)) {
                     if answer != "Valid" {
-                        let span = span_map.decode_span(&x.term.loc);
-                        let msg = format!(
-                            "Prover reported {answer:?} (time: {time:?}, steps: {step:?}) when trying to solve goal {:?} {:?}",
-                            x.term.goal_name, x.term.explanations
-                        );
+                        proved = false;
+                        let span = span_map.decode_span(&x.term.loc).unwrap_or_default();
+                        ctx.error(
+                            span,
+                            "the precondition of this function may be contradictory, so that it would be verified vacuously",
+                        )
+                        .emit();
+                    }
+                    continue;
+                }
+                let mut model_msgs = Vec::new();
+                if answer != "Valid" {
+                    proved = false;
+                    let span = span_map.decode_span(&x.term.loc);
+                    let msg = format!(
+                        "Prover reported {answer:?} (time: {time:?}, steps: {step:?}) when trying to solve goal {:?} {:?}",
+                        x.term.goal_name, x.term.explanations
+                    );
+                    // With `--baseline`, the known failures are not errors, see `check_baseline`.
+                    if known_failure(ctx, span) {
+                        ctx.dcx().struct_span_warn(span.unwrap_or_default(), msg).emit();
+                    } else {
                         ctx.error(span.unwrap_or_default(), &msg).emit();
-                        for model in x.prover_result.model_elems() {
-                            let span = span_map.decode_span(&model.location);
-                            let mut msg = format!("Model Element for {}\n", model.lsymbol.name);
-                            if span.is_none() {
-                                writeln!(msg, "Span: {:?}", &model.location).unwrap();
-                            }
-                            writeln!(msg, "Type: {:?}", model.value.value_type).unwrap();
-                            let term = term_to_ast(&model.value.value_term);
-                            writeln!(msg, "Term: {}", expr_to_string(&term)).unwrap();
-                            let cterm = cterm_to_ast(&model.value.value_concrete_term);
-                            writeln!(msg, "Concrete Term: {}", expr_to_string(&cterm)).unwrap();
-                            ctx.dcx().span_note(span.unwrap_or_default(), msg)
+                    }
+                    for model in x.prover_result.model_elems() {
+                        let span = span_map.decode_span(&model.location);
+                        let mut msg = format!("Model Element for {}\n", model.lsymbol.name);
+                        if span.is_none() {
+                            writeln!(msg, "Span: {:?}", &model.location).unwrap();
                         }
+                        writeln!(msg, "Type: {:?}", model.value.value_type).unwrap();
+                        let term = term_to_ast(&model.value.value_term);
+                        writeln!(msg, "Term: {}", expr_to_string(&term)).unwrap();
+                        let cterm = cterm_to_ast(&model.value.value_concrete_term);
+                        writeln!(msg, "Concrete Term: {}", expr_to_string(&cterm)).unwrap();
+                        ctx.dcx().span_note(span.unwrap_or_default(), msg.clone());
+                        model_msgs.push(msg);
                     }
                 }
-                Err(err) => {
-                    let msg = format!("error parsing why3 output {err:?}");
-                    ctx.error(DUMMY_SP, &msg).emit();
+                if let Some(report) = &ctx.report {
+                    report.borrow_mut().record_goal(GoalResult {
+                        span: span_map.decode_span(&x.term.loc),
+                        name: x.term.goal_name.clone(),
+                        explanations: x.term.explanations.clone(),
+                        answer: answer.clone(),
+                        time: *time,
+                        model: model_msgs,
+                    });
                 }
             }
+            Err(err) => {
+                proved = false;
+                let msg = format!("error parsing why3 output {err:?}");
+                ctx.error(DUMMY_SP, &msg).emit();
+            }
         }
-        if !child.wait().expect("could not close why3").success() {
-            ctx.crash_and_error(DUMMY_SP, "why3 did not exit successfully")
-        };
-    } else {
-        command.status().expect("could not run why3");
-        ctx.crash_and_error(DUMMY_SP, "did not run why3 prove")
+        if !proved && ctx.opts.fail_fast {
+            child.kill().expect("could not stop why3");
+            stopped = true;
+            break;
+        }
+    }
+    if !child.wait().expect("could not close why3").success() && !stopped {
+        ctx.crash_and_error(DUMMY_SP, "why3 did not exit successfully")
+    };
+    if let Some(stats) = &ctx.stats {
+        stats.borrow_mut().record_why3(start.elapsed());
     }
+    proved
 }
 
 pub type SpanData = (SyntaxContext, Option<LocalDefId>);
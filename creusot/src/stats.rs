@@ -0,0 +1,126 @@
+//! Support for `--stats`: measure the time spent on each item, to find the slow ones.
+//!
+//! The translation time of an item is measured around [`Why3Generator::translate`]; for an impl,
+//! this includes the generation of its refinement obligations. The solving time of an item is the
+//! sum of the prover times reported by Why3 for the goals located in the item, and goals about a
+//! `#[variant]` are counted as termination.
+
+use crate::{backend::Why3Generator, obligations::ObligationKind};
+use rustc_hir::def_id::DefId;
+use rustc_span::Span;
+use std::{collections::HashMap, time::Duration};
+
+#[derive(Default)]
+pub(crate) struct Stats {
+    items: HashMap<DefId, ItemStats>,
+    /// Solving time of the goals that could not be located in an item.
+    unattributed: Duration,
+    /// Wall-clock time of the Why3 runs.
+    why3: Duration,
+    /// Number of modules proved from the cache of `--vc-cache`, without running the solvers.
+    cached: usize,
+}
+
+struct ItemStats {
+    kind: ObligationKind,
+    span: Span,
+    translation: Duration,
+    solving: Duration,
+    termination: Duration,
+}
+
+impl ItemStats {
+    fn total(&self) -> Duration {
+        self.translation + self.solving + self.termination
+    }
+}
+
+impl Stats {
+    pub(crate) fn record_translation(
+        &mut self,
+        def_id: DefId,
+        kind: ObligationKind,
+        span: Span,
+        time: Duration,
+    ) {
+        let item = self.items.entry(def_id).or_insert(ItemStats {
+            kind,
+            span,
+            translation: Duration::ZERO,
+            solving: Duration::ZERO,
+            termination: Duration::ZERO,
+        });
+        item.translation += time
+    }
+
+    /// Record the prover time of a goal, located in the innermost item containing `span`.
+    pub(crate) fn record_goal(&mut self, span: Option<Span>, explanations: &[String], time: f32) {
+        let time = Duration::from_secs_f32(time.max(0.));
+        let item = span.map(Span::source_callsite).and_then(|span| {
+            self.items
+                .values_mut()
+                .filter(|item| item.span.contains(span))
+                .min_by_key(|item| item.span.hi() - item.span.lo())
+        });
+        let Some(item) = item else {
+            self.unattributed += time;
+            return;
+        };
+        if explanations.iter().any(|expl| expl.contains("variant")) {
+            item.termination += time
+        } else {
+            item.solving += time
+        }
+    }
+
+    pub(crate) fn record_why3(&mut self, time: Duration) {
+        self.why3 += time
+    }
+
+    pub(crate) fn record_cached(&mut self) {
+        self.cached += 1
+    }
+
+    /// The items, slowest first.
+    fn sorted_items(&self) -> Vec<(DefId, &ItemStats)> {
+        let mut items: Vec<_> = self.items.iter().map(|(&def_id, item)| (def_id, item)).collect();
+        items.sort_by(|(_, i1), (_, i2)| i2.total().cmp(&i1.total()).then(i1.span.cmp(&i2.span)));
+        items
+    }
+
+    /// Emit a note with the times of each item, slowest first, and one with the total times.
+    pub(crate) fn report(&self, why3: &Why3Generator) {
+        let secs = |d: Duration| format!("{:.3}s", d.as_secs_f64());
+        let items = self.sorted_items();
+        for (def_id, item) in &items {
+            why3.dcx().span_note(
+                item.span,
+                format!(
+                    "{} `{}`: {} ({} translation, {} solving, {} termination)",
+                    item.kind.as_str(),
+                    why3.def_path_str(*def_id),
+                    secs(item.total()),
+                    secs(item.translation),
+                    secs(item.solving),
+                    secs(item.termination),
+                ),
+            );
+        }
+
+        let translation: Duration = items.iter().map(|(_, item)| item.translation).sum();
+        let solving: Duration =
+            items.iter().map(|(_, item)| item.solving + item.termination).sum::<Duration>()
+                + self.unattributed;
+        why3.dcx().note(format!(
+            "translation: {}, solving: {} ({} unattributed), why3: {} wall-clock, {} module(s) from the VC cache",
+            secs(translation),
+            secs(solving),
+            secs(self.unattributed),
+            secs(self.why3),
+            self.cached,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests;
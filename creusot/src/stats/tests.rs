@@ -0,0 +1,43 @@
+use super::Stats;
+use crate::{obligations::ObligationKind, test_support::*};
+use std::time::Duration;
+
+const ITEMS: &str = "
+pub fn outer() {
+    fn inner() {}
+}
+
+pub fn other() {}
+";
+
+#[test]
+fn goals_go_to_the_innermost_item() {
+    with_tcx(ITEMS, |tcx| {
+        let span = |path| tcx.source_span(local_item(tcx, path).expect_local());
+        let mut stats = Stats::default();
+        for path in ["outer", "outer::inner", "other"] {
+            stats.record_translation(
+                local_item(tcx, path),
+                ObligationKind::Body,
+                span(path),
+                Duration::from_millis(1),
+            );
+        }
+        stats.record_goal(Some(span("outer::inner")), &[], 0.5);
+        stats.record_goal(Some(span("outer::inner")), &["loop variant".into()], 0.25);
+        stats.record_goal(Some(span("outer")), &[], 0.125);
+        stats.record_goal(None, &[], 2.);
+
+        let inner = &stats.items[&local_item(tcx, "outer::inner")];
+        assert_eq!(inner.solving, Duration::from_millis(500));
+        assert_eq!(inner.termination, Duration::from_millis(250));
+        let outer = &stats.items[&local_item(tcx, "outer")];
+        assert_eq!(outer.solving, Duration::from_millis(125));
+        assert_eq!(stats.unattributed, Duration::from_secs(2));
+
+        // Slowest first, then in source order.
+        let order: Vec<_> =
+            stats.sorted_items().into_iter().map(|(def_id, _)| tcx.def_path_str(def_id)).collect();
+        assert_eq!(order, ["outer::inner", "outer", "other"]);
+    })
+}
@@ -0,0 +1,190 @@
+//! Helpers to query the compiler on small crates, for the unit tests of the translation.
+//!
+//! The crate is compiled up to analysis, then the test works directly with its [`TyCtxt`]:
+//! it builds trait references from the paths of the items of the crate and type arguments, and
+//! resolves them in controlled typing environments.
+//!
+//! This module is available to the tests of other crates with the `test-support` feature. Like
+//! Creusot itself, they must be built with `rustc_private`.
+//!
+//! ```ignore
+//! with_tcx("pub trait Tr { fn m(); } pub struct S; impl Tr for S { fn m() {} }", |tcx| {
+//!     let trait_ref = trait_ref(tcx, "Tr", &[local_ty(tcx, "S").into()]);
+//!     let resolved = resolve(tcx, TypingEnv::fully_monomorphized(), trait_ref, "m");
+//!     assert!(matches!(resolved, TraitResolved::Instance(..)));
+//! });
+//! ```
+
+pub use crate::{
+    ctx::TranslationCtx,
+    translation::traits::{TraitResolved, UnknownReason},
+};
+use crate::{
+    options::{Options, Output, SpanMode, Why3Version},
+    translation::traits::ClosedCrates,
+};
+use rustc_driver::{Callbacks, Compilation};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_interface::interface::Compiler;
+use rustc_middle::ty::{GenericArg, TraitRef, Ty, TyCtxt, TypingEnv};
+use rustc_span::Symbol;
+use std::{collections::HashMap, fs};
+
+struct AfterAnalysis<F, R> {
+    f: Option<F>,
+    result: Option<R>,
+}
+
+impl<F, R> Callbacks for AfterAnalysis<F, R>
+where
+    F: for<'tcx> FnOnce(TyCtxt<'tcx>) -> R + Send,
+    R: Send,
+{
+    fn after_analysis<'tcx>(&mut self, _: &Compiler, tcx: TyCtxt<'tcx>) -> Compilation {
+        self.result = Some((self.f.take().unwrap())(tcx));
+        Compilation::Stop
+    }
+}
+
+/// Compile the library crate `source` up to analysis, and call `f` with its context.
+///
+/// Panics if the crate does not compile.
+pub fn with_tcx<R: Send>(source: &str, f: impl for<'tcx> FnOnce(TyCtxt<'tcx>) -> R + Send) -> R {
+    let dir = tempdir::TempDir::new("creusot-test").unwrap();
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, source).unwrap();
+    let args = [
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name=test_crate".to_string(),
+        "--edition=2021".to_string(),
+        "--emit=metadata".to_string(),
+        format!("--out-dir={}", dir.path().display()),
+        file.display().to_string(),
+    ];
+    let mut callbacks = AfterAnalysis { f: Some(f), result: None };
+    rustc_driver::run_compiler(&args, &mut callbacks);
+    callbacks.result.expect("the test crate does not compile")
+}
+
+/// Compile the library crate `source` up to analysis, and call `f` with a translation context for
+/// it, as created by a run of Creusot without any option.
+///
+/// The crate does not depend on `creusot-contracts`: its items can be marked with the attributes
+/// that the macros of `creusot-contracts` expand to, e.g. `#[creusot::decl::logic]`, once
+/// `#![feature(register_tool)]` and `#![register_tool(creusot)]` are given.
+pub fn with_ctx<R: Send>(
+    source: &str,
+    f: impl for<'tcx> FnOnce(&TranslationCtx<'tcx>) -> R + Send,
+) -> R {
+    with_tcx(source, |tcx| f(&TranslationCtx::new(tcx, default_options())))
+}
+
+fn default_options() -> Options {
+    Options {
+        extern_paths: HashMap::new(),
+        metadata_path: None,
+        export_metadata: false,
+        should_output: false,
+        output: Output::None,
+        monolithic: false,
+        prefix: Vec::new(),
+        in_cargo: false,
+        span_mode: SpanMode::Off,
+        simple_triggers: false,
+        report_assumptions: false,
+        closed_crates: Vec::new(),
+        since: None,
+        deny_trusted_external_impls: false,
+        trusted_allowlist: None,
+        list_obligations: None,
+        max_quantifier_depth: None,
+        simplify: Vec::new(),
+        dump_refinements_all: None,
+        emit_refinement_graph: None,
+        dump_param_env: None,
+        solver_log: None,
+        rlimit: None,
+        stats: false,
+        only_refinements: false,
+        check_specs_only: false,
+        html_report: None,
+        panic_report: false,
+        baseline: None,
+        update_baseline: false,
+        check_coma_round_trip: false,
+        fail_fast: false,
+        check_precondition_satisfiability: false,
+        why3_version: Why3Version::default(),
+        vc_cache: None,
+        report_trust_dependencies: false,
+        max_parallel_solvers: None,
+        why3_cmd: None,
+    }
+}
+
+/// The item of the test crate at `path`, e.g. `"Tr"`, `"Tr::m"` or `"f"`.
+pub fn local_item(tcx: TyCtxt, path: &str) -> DefId {
+    tcx.hir_crate_items(())
+        .definitions()
+        .map(LocalDefId::to_def_id)
+        .find(|&def_id| tcx.def_path_str(def_id) == path)
+        .unwrap_or_else(|| panic!("no item `{path}` in the test crate"))
+}
+
+/// The type defined at `path` in the test crate, with its own generic parameters.
+pub fn local_ty<'tcx>(tcx: TyCtxt<'tcx>, path: &str) -> Ty<'tcx> {
+    tcx.type_of(local_item(tcx, path)).instantiate_identity()
+}
+
+/// The type parameter `name` of the item at `path`.
+pub fn param_ty<'tcx>(tcx: TyCtxt<'tcx>, path: &str, name: &str) -> Ty<'tcx> {
+    let generics = tcx.generics_of(local_item(tcx, path));
+    let param = generics
+        .own_params
+        .iter()
+        .find(|p| p.name.as_str() == name)
+        .unwrap_or_else(|| panic!("`{path}` has no parameter `{name}`"));
+    Ty::new_param(tcx, param.index, param.name)
+}
+
+/// The typing environment inside the item at `path`: its generic parameters, with their bounds.
+pub fn typing_env<'tcx>(tcx: TyCtxt<'tcx>, path: &str) -> TypingEnv<'tcx> {
+    TypingEnv::non_body_analysis(tcx, local_item(tcx, path))
+}
+
+/// The trait reference `<args[0] as Trait<args[1..]>>`, for the trait at `path`.
+pub fn trait_ref<'tcx>(tcx: TyCtxt<'tcx>, path: &str, args: &[GenericArg<'tcx>]) -> TraitRef<'tcx> {
+    TraitRef::new(tcx, local_item(tcx, path), args.iter().copied())
+}
+
+/// Resolve the item `name` of the trait of `trait_ref` in `typing_env`, as it is resolved for a
+/// call, without any crate given by `--closed-crates`.
+pub fn resolve<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    typing_env: TypingEnv<'tcx>,
+    trait_ref: TraitRef<'tcx>,
+    name: &str,
+) -> TraitResolved<'tcx> {
+    let item = tcx
+        .associated_items(trait_ref.def_id)
+        .filter_by_name_unhygienic(Symbol::intern(name))
+        .next()
+        .unwrap_or_else(|| panic!("no item `{name}` in `{}`", tcx.def_path_str(trait_ref.def_id)));
+    TraitResolved::resolve_item(
+        tcx,
+        &ClosedCrates::default(),
+        typing_env,
+        item.def_id,
+        trait_ref.args,
+    )
+}
+
+/// The impl of the trait of `trait_ref` in `typing_env`, if it is a user-defined impl.
+pub fn impl_of<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    typing_env: TypingEnv<'tcx>,
+    trait_ref: TraitRef<'tcx>,
+) -> Option<DefId> {
+    TraitResolved::impl_id_of_trait(tcx, typing_env, trait_ref.def_id, trait_ref.args)
+}
@@ -22,6 +22,11 @@ pub enum TranslatedItem {
         /// Trait refinement obligations
         modls: Vec<FileModule>,
     },
+    Vacuity {
+        /// The goal that the precondition of a program function is contradictory, which must
+        /// *not* be proved, with `--check-precondition-satisfiability`
+        modl: FileModule,
+    },
 }
 
 impl<'a> TranslatedItem {
@@ -31,6 +36,7 @@ impl<'a> TranslatedItem {
             Logic { proof_modl } => Box::new(proof_modl.into_iter()),
             Program { modl } => Box::new(modl.into_iter()),
             Impl { modls } => Box::new(modls.into_iter()),
+            Vacuity { modl } => Box::new(std::iter::once(modl)),
         }
     }
 }
@@ -18,19 +18,26 @@ use crate::{
     options::Output,
     translated_item::FileModule,
     validate::{
-        validate_impls, validate_opacity, validate_purity, validate_terminates, validate_traits,
-        validate_trusted,
+        validate_impls, validate_maintains_invariant, validate_opacity, validate_purity,
+        validate_terminates, validate_traits, validate_trusted,
     },
 };
 use ctx::TranslationCtx;
 use rustc_hir::{def::DefKind, def_id::DefId};
 use rustc_middle::ty::TyCtxt;
 use rustc_span::DUMMY_SP;
-use std::{fs::File, io::Write, path::PathBuf, time::Instant};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Instant,
+};
 use why3::{
     Symbol,
     declaration::{Attribute, Decl, Module},
-    printer::{render_decls, render_module},
+    printer::{Why3Version, render_decls, render_module},
 };
 
 pub(crate) fn before_analysis(ctx: &mut TranslationCtx) -> Result<(), Box<dyn std::error::Error>> {
@@ -91,12 +98,13 @@ pub(crate) fn before_analysis(ctx: &mut TranslationCtx) -> Result<(), Box<dyn st
     validate_traits(ctx);
     validate_impls(ctx);
     validate_trusted(ctx);
+    validate_maintains_invariant(ctx);
 
     debug!("before_analysis: {:?}", start.elapsed());
     Ok(())
 }
 
-fn should_translate(tcx: TyCtxt, mut def_id: DefId) -> bool {
+pub(crate) fn should_translate(tcx: TyCtxt, mut def_id: DefId) -> bool {
     loop {
         if is_no_translate(tcx, def_id) {
             return false;
@@ -114,6 +122,24 @@ fn should_translate(tcx: TyCtxt, mut def_id: DefId) -> bool {
 pub(crate) fn after_analysis(ctx: TranslationCtx) -> Result<(), Box<dyn std::error::Error>> {
     let mut why3 = Why3Generator::new(ctx);
 
+    // The specification closures and logic bodies have been checked in `before_analysis`: only
+    // the contracts remain to be elaborated.
+    if why3.opts.check_specs_only {
+        for def_id in why3.hir().body_owners() {
+            let def_id = def_id.to_def_id();
+            if should_translate(why3.tcx, def_id)
+                && why3.def_kind(def_id).is_fn_like()
+                && !is_spec(why3.tcx, def_id)
+            {
+                why3.sig(def_id);
+            }
+        }
+        if why3.dcx().has_errors().is_some() {
+            return Err(Box::new(InternalError("Errors in the specifications")));
+        }
+        return Ok(());
+    }
+
     let mut err = None;
     let start = Instant::now();
     for def_id in why3.hir().body_owners() {
@@ -128,25 +154,50 @@ pub(crate) fn after_analysis(ctx: TranslationCtx) -> Result<(), Box<dyn std::err
             continue;
         }
 
+        crate::lints::vacuous_postcondition::lint_vacuous_postconditions(&why3, def_id);
+
+        // The refinement obligations of impls do not depend on the translation of bodies.
+        if why3.opts.only_refinements {
+            continue;
+        }
+
         info!("Translating body {:?}", def_id);
         // Ok to ignore, because we call `abort_if_errors` at the end of the next loop
         if let Err(e) = why3.translate(def_id) {
             CannotFetchThir::merge_opt(&mut err, e);
         }
+        if why3.opts.fail_fast
+            && let Some(err) = err.take()
+        {
+            err.abort(why3.tcx);
+        }
+        why3.abort_if_fail_fast();
     }
 
     for impls in why3.all_local_trait_impls(()).values() {
         for impl_id in impls {
+            if !why3.impl_touched(impl_id.to_def_id()) {
+                info!("Skipping untouched impl {:?}", impl_id);
+                continue;
+            }
             // Ok to ignore, because we call `abort_if_errors` at the end of the loop
             if let Err(e) = why3.translate(impl_id.to_def_id()) {
                 CannotFetchThir::merge_opt(&mut err, e);
             }
         }
     }
+    why3.translate_monomorphizations();
     if let Some(err) = err {
         err.abort(why3.tcx);
     }
 
+    if why3.opts.report_assumptions {
+        why3.report_assumptions();
+    }
+    why3.deny_trusted_external_impls();
+    why3.check_trusted_allowlist();
+    why3.check_quantifier_depth();
+
     debug!("after_analysis_translate: {:?}", start.elapsed());
     let start = Instant::now();
 
@@ -158,19 +209,97 @@ pub(crate) fn after_analysis(ctx: TranslationCtx) -> Result<(), Box<dyn std::err
         metadata::dump_exports(&mut why3);
     }
 
-    if why3.should_compile() {
-        use crate::run_why3::run_why3;
+    if why3.opts.panic_report {
+        crate::panic_report::report_panics(&why3);
+    }
+
+    if let Some(path) = &why3.opts.dump_refinements_all {
+        crate::refinements::dump_refinements(&why3, path);
+    }
+
+    if let Some(path) = &why3.opts.emit_refinement_graph {
+        crate::refinements::emit_refinement_graph(&why3, path);
+    }
+
+    if let Some(path) = &why3.opts.dump_param_env {
+        crate::refinements::dump_param_env(&why3, path);
+    }
+
+    if let Some(format) = why3.opts.list_obligations {
+        crate::obligations::list_obligations(&mut why3, format);
+    } else if why3.should_compile() {
+        use crate::{
+            run_why3::{module_solver, run_why3, vc_environment},
+            vc_cache::vc_key,
+        };
 
         let output_target = why3.opts.output.clone();
         let prefix = why3.opts.prefix.clone();
-        let modules = why3.modules();
-        let modules = modules.flat_map(|item| item.modules());
+        let items: Vec<_> = why3.modules().collect();
+        if let Some(report) = &why3.report {
+            for (def_id, item) in &items {
+                report.borrow_mut().record_item(&why3, *def_id, item);
+            }
+        }
+        let mut solvers = Vec::new();
+        let mut owners = Vec::new();
+        let modules: Vec<_> = items
+            .into_iter()
+            .flat_map(|(def_id, item)| item.modules().map(move |modl| (def_id, modl)))
+            .map(|(def_id, modl)| {
+                solvers.push((modl.modl.name, module_solver(&modl.modl)));
+                owners.push(def_id);
+                modl
+            })
+            .collect();
+        let vc_keys = match &why3.opts.vc_cache {
+            Some(_) => {
+                let environment = vc_environment(&why3);
+                let version = why3.opts.why3_version;
+                modules
+                    .iter()
+                    .zip(owners)
+                    .map(|(modl, def_id)| {
+                        (modl.modl.name, (vc_key(&modl.modl, version, &environment), def_id))
+                    })
+                    .collect()
+            }
+            None => HashMap::new(),
+        };
 
-        let file = print_crate(output_target, prefix, modules)?;
-        run_why3(&why3, file);
+        if why3.opts.check_coma_round_trip {
+            for modl in &modules {
+                check_round_trip(&why3, &modl.modl);
+            }
+        }
+
+        if let Some(dir) = &why3.opts.solver_log {
+            for modl in &modules {
+                write_solver_log(dir, modl, why3.opts.why3_version)?;
+            }
+        }
+
+        let file = print_crate(output_target, prefix, why3.opts.why3_version, modules.into_iter())?;
+        run_why3(&why3, file, solvers, vc_keys);
+
+        if let (Some(report), Some(path)) = (&why3.report, &why3.opts.html_report) {
+            report.borrow().write(&why3, path);
+        }
+        if let (Some(report), Some(baseline), Some(path)) =
+            (&why3.report, &why3.baseline, &why3.opts.baseline)
+        {
+            crate::baseline::check_baseline(&why3, baseline, &report.borrow(), path);
+        }
+        if let Some(report) = &why3.report {
+            crate::trust_report::report_trust_dependencies(&why3, &report.borrow());
+        }
     }
     debug!("after_analysis_dump: {:?}", start.elapsed());
 
+    if let Some(stats) = &why3.stats {
+        stats.borrow().report(&why3);
+    }
+
     Ok(())
 }
 
@@ -179,7 +308,15 @@ pub enum OutputHandle {
     File(Box<dyn Write>),            // Monolithic output
 }
 
-fn module_output(modl: &FileModule, output: &mut OutputHandle) -> std::io::Result<()> {
+/// Output a module, and return the file it was written to, if it has its own file.
+///
+/// A file is only rewritten if its contents changed, so that the files of unchanged items keep
+/// their modification time, and Why3 can reuse its sessions for them.
+fn module_output(
+    modl: &FileModule,
+    version: Why3Version,
+    output: &mut OutputHandle,
+) -> std::io::Result<Option<PathBuf>> {
     match output {
         OutputHandle::Directory(dir, prefix) => {
             let mut path = dir.clone();
@@ -187,9 +324,56 @@ fn module_output(modl: &FileModule, output: &mut OutputHandle) -> std::io::Resul
             path.set_extension("coma");
             let prefix = path.parent().unwrap();
             std::fs::create_dir_all(prefix).unwrap();
-            modular_output(modl, &mut std::io::BufWriter::new(File::create(path).unwrap()))
+            let mut contents = Vec::new();
+            modular_output(modl, version, &mut contents)?;
+            if std::fs::read(&path).ok().as_ref() != Some(&contents) {
+                std::fs::write(&path, contents)?;
+            }
+            Ok(Some(path))
         }
-        OutputHandle::File(w) => monolithic_output(modl, &mut *w),
+        OutputHandle::File(w) => monolithic_output(modl, version, &mut *w).map(|()| None),
+    }
+}
+
+/// Write the module of an obligation to its own file in `dir` for `--solver-log`, named by the id
+/// of the obligation. The module is self-contained: it includes the declarations and laws that
+/// the obligation depends on.
+fn write_solver_log(dir: &Path, modl: &FileModule, version: Why3Version) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let mut path = dir.join(modl.modl.name.to_string());
+    path.set_extension("coma");
+    let mut contents = Vec::new();
+    modular_output(modl, version, &mut contents)?;
+    std::fs::write(path, contents)
+}
+
+/// Check that `modl` is unchanged by a round trip through its serialization, for
+/// `--check-coma-round-trip`: the Coma printed from the deserialized module must be the same.
+fn check_round_trip(ctx: &TranslationCtx, modl: &Module) {
+    let version = ctx.opts.why3_version;
+    let print = |modl: &Module| {
+        let mut out = Vec::new();
+        render_module(modl, version, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    if !survives_round_trip(modl, print) {
+        let name = modl.name.to_string();
+        ctx.dcx().err(format!("the Coma module `{name}` does not survive a round trip"));
+    }
+}
+
+/// Is `value` printed the same after a round trip through its serialization?
+///
+/// This only detects the losses which change the printed output: a node whose information is
+/// lost but not printed is not caught.
+fn survives_round_trip<T: Serialize + DeserializeOwned>(
+    value: &T,
+    print: impl Fn(&T) -> String,
+) -> bool {
+    let json = serde_json::to_string(value).unwrap();
+    match serde_json::from_str::<T>(&json) {
+        Ok(copy) => print(&copy) == print(value),
+        Err(_) => false,
     }
 }
 
@@ -203,35 +387,44 @@ fn show_attribute(attr: &Attribute) -> String {
     }
 }
 
-fn modular_output<T: Write>(modl: &FileModule, out: &mut T) -> std::io::Result<()> {
+fn modular_output<T: Write>(
+    modl: &FileModule,
+    version: Why3Version,
+    out: &mut T,
+) -> std::io::Result<()> {
     let FileModule { path: _, modl: Module { name: _, decls, attrs, meta } } = modl;
     let attrs = attrs.iter().map(|attr| Decl::Comment(show_attribute(attr)));
     let meta = meta.iter().map(|s| Decl::Comment(s.clone()));
     let decls: Vec<Decl> = attrs.chain(meta).chain(decls.iter().cloned()).collect();
-    render_decls(&decls, out)?;
+    render_decls(&decls, version, out)?;
     writeln!(out)?;
     Ok(())
 }
 
-fn monolithic_output<T: Write>(modl: &FileModule, out: &mut T) -> std::io::Result<()> {
-    render_module(&modl.modl, out)?;
+fn monolithic_output<T: Write>(
+    modl: &FileModule,
+    version: Why3Version,
+    out: &mut T,
+) -> std::io::Result<()> {
+    render_module(&modl.modl, version, out)?;
     writeln!(out)?;
     Ok(())
 }
 
-// Remove coma files in the `verif/{krate}/` directory to avoid obsolete files left after
-// (re)moving functions in source code.
+// Remove the coma files in the `verif/{krate}/` directory which were not written by this run, to
+// avoid obsolete files left after (re)moving functions in source code.
 // We don't want to just `remove_dir_all()` because it may contain
 // `proof.json`, `why3session.xml`, and `why3shapes.xml` files that users want to preserve.
-fn remove_coma_files(dir: &PathBuf) -> std::io::Result<()> {
+fn remove_coma_files(dir: &PathBuf, written: &HashSet<PathBuf>) -> std::io::Result<()> {
     if dir.exists() {
         for entry in std::fs::read_dir(dir)? {
             let entry = entry?;
             let path = entry.path();
             if path.is_dir() {
-                remove_coma_files(&path)?;
+                remove_coma_files(&path, written)?;
                 let _ = std::fs::remove_dir(path); // remove the directory if it's empty, do nothing otherwise
-            } else if path.extension().is_some_and(|ext| ext == "coma") {
+            } else if path.extension().is_some_and(|ext| ext == "coma") && !written.contains(&path)
+            {
                 std::fs::remove_file(&path)?;
             }
         }
@@ -242,15 +435,17 @@ fn remove_coma_files(dir: &PathBuf) -> std::io::Result<()> {
 fn print_crate<I: Iterator<Item = FileModule>>(
     output_target: Output,
     prefix: Vec<Symbol>,
+    version: Why3Version,
     modules: I,
 ) -> std::io::Result<Option<PathBuf>> {
+    let mut outdir = None;
     let (root, mut output) = match output_target {
         Output::Directory(dir) => {
-            let mut outdir = dir.clone();
+            let mut path = dir.clone();
             for m in &prefix {
-                outdir.push(m.to_string());
+                path.push(m.to_string());
             }
-            remove_coma_files(&outdir)?;
+            outdir = Some(path);
             (Some(dir.clone()), OutputHandle::Directory(dir, prefix))
         }
         Output::File(ref f) => {
@@ -264,12 +459,22 @@ fn print_crate<I: Iterator<Item = FileModule>>(
         Output::None => return Ok(None),
     };
 
+    let mut written = HashSet::new();
     for modl in modules {
-        module_output(&modl, &mut output)?;
+        if let Some(path) = module_output(&modl, version, &mut output)? {
+            written.insert(path);
+        }
     }
 
     //flush the buffer before running why3
     drop(output);
 
+    if let Some(outdir) = outdir {
+        remove_coma_files(&outdir, &written)?;
+    }
+
     Ok(root)
 }
+
+#[cfg(test)]
+mod tests;
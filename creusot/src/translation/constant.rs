@@ -3,9 +3,10 @@ use crate::{
     ctx::TranslationCtx,
     translation::{fmir::Operand, pearlite::Literal, traits::TraitResolved},
 };
+use rustc_hir::def::DefKind;
 use rustc_middle::{
     mir::{self, ConstOperand, ConstValue, UnevaluatedConst, interpret::AllocRange},
-    ty::{Const, ConstKind, Ty, TyCtxt, TypingEnv},
+    ty::{self, Const, ConstKind, Ty, TypingEnv},
 };
 use rustc_span::{DUMMY_SP, Span};
 use rustc_target::abi::Size;
@@ -58,6 +59,14 @@ fn from_mir_constant_kind<'tcx>(
         return Operand::Promoted(p, ck.ty());
     }
 
+    // An associated constant whose impl is not known stays abstract.
+    if let mir::Const::Unevaluated(UnevaluatedConst { def, args, promoted: None }, ty) = ck
+        && ctx.def_kind(def) == DefKind::AssocConst
+        && ctx.resolve_assoc_const(env, def, args).is_none()
+    {
+        return Operand::Constant(Term { kind: TermKind::Item(def, args), ty, span });
+    }
+
     Operand::Constant(Term {
         kind: TermKind::Lit(try_to_bits(ctx, env, ck.ty(), span, ck)),
         ty: ck.ty(),
@@ -80,8 +89,9 @@ pub(crate) fn from_ty_const<'tcx>(
         return Term { kind: TermKind::Lit(Literal::Function(u.def, u.args)), ty, span };
     };
 
+    // Const generic parameters are abstract constants, until instantiated
     if let ConstKind::Param(_) = c.kind() {
-        ctx.crash_and_error(span, "const generic parameters are not yet supported");
+        return Term { kind: TermKind::Const(c), ty, span };
     }
 
     return Term { kind: TermKind::Lit(try_to_bits(ctx, env, ty, span, c)), ty, span };
@@ -96,7 +106,7 @@ fn try_to_bits<'tcx, C: ToBits<'tcx> + std::fmt::Debug>(
 ) -> Literal<'tcx> {
     use rustc_middle::ty::{FloatTy, IntTy, UintTy};
     use rustc_type_ir::TyKind::{Bool, Char, Float, FnDef, Int, Uint};
-    let Some(bits) = c.get_bits(ctx.tcx, env, ty) else {
+    let Some(bits) = c.get_bits(ctx, env, ty) else {
         ctx.fatal_error(span, &format!("Could not determine value of constant. Creusot currently does not support generic associated constants.")).emit()
     };
 
@@ -149,9 +159,7 @@ fn try_to_bits<'tcx, C: ToBits<'tcx> + std::fmt::Debug>(
         }
         _ if ty.is_unit() => Literal::ZST,
         FnDef(def_id, subst) => {
-            let method = TraitResolved::resolve_item(ctx.tcx, env, *def_id, subst)
-                .to_opt(*def_id, subst)
-                .unwrap();
+            let method = ctx.resolve_item(env, *def_id, subst).to_opt(*def_id, subst).unwrap();
             Literal::Function(method.0, method.1)
         }
         _ => {
@@ -161,14 +169,32 @@ fn try_to_bits<'tcx, C: ToBits<'tcx> + std::fmt::Debug>(
 }
 
 trait ToBits<'tcx> {
-    fn get_bits(&self, tcx: TyCtxt<'tcx>, env: TypingEnv<'tcx>, ty: Ty<'tcx>) -> Option<u128>;
+    fn get_bits(
+        &self,
+        ctx: &TranslationCtx<'tcx>,
+        env: TypingEnv<'tcx>,
+        ty: Ty<'tcx>,
+    ) -> Option<u128>;
 }
 
 impl<'tcx> ToBits<'tcx> for Const<'tcx> {
-    fn get_bits(&self, tcx: TyCtxt<'tcx>, env: TypingEnv<'tcx>, ty: Ty<'tcx>) -> Option<u128> {
+    fn get_bits(
+        &self,
+        ctx: &TranslationCtx<'tcx>,
+        env: TypingEnv<'tcx>,
+        ty: Ty<'tcx>,
+    ) -> Option<u128> {
+        let tcx = ctx.tcx;
         let scalar = match self.kind() {
             ConstKind::Value(_, _) => self.try_to_scalar()?.0,
             ConstKind::Unevaluated(u) => {
+                // A constant of a trait, e.g. `<u8 as Trait>::N` in an array length, is evaluated
+                // from the impl that defines it, which is selected in a const context.
+                let u = match ctx.resolve_const_item(env, u.def, u.args) {
+                    TraitResolved::Instance(def, args) => ty::UnevaluatedConst::new(def, args),
+                    _ => u,
+                };
+                let env = env.with_post_analysis_normalized(tcx);
                 tcx.const_eval_resolve_for_typeck(env, u, DUMMY_SP).ok()?.ok()?.try_to_scalar()?
             }
             _ => return None,
@@ -180,7 +206,24 @@ impl<'tcx> ToBits<'tcx> for Const<'tcx> {
 }
 
 impl<'tcx> ToBits<'tcx> for mir::Const<'tcx> {
-    fn get_bits(&self, tcx: TyCtxt<'tcx>, env: TypingEnv<'tcx>, _: Ty<'tcx>) -> Option<u128> {
-        self.try_eval_bits(tcx, env)
+    fn get_bits(
+        &self,
+        ctx: &TranslationCtx<'tcx>,
+        env: TypingEnv<'tcx>,
+        ty: Ty<'tcx>,
+    ) -> Option<u128> {
+        match *self {
+            // Same as above: the value of a constant of a trait is the one of its impl.
+            mir::Const::Unevaluated(UnevaluatedConst { def, args, promoted: None }, _) => {
+                match ctx.resolve_const_item(env, def, args) {
+                    TraitResolved::Instance(def, args) => {
+                        let u = ty::UnevaluatedConst::new(def, args);
+                        Const::new(ctx.tcx, ConstKind::Unevaluated(u)).get_bits(ctx, env, ty)
+                    }
+                    _ => self.try_eval_bits(ctx.tcx, env.with_post_analysis_normalized(ctx.tcx)),
+                }
+            }
+            _ => self.try_eval_bits(ctx.tcx, env),
+        }
     }
 }
@@ -1,10 +1,10 @@
 use crate::{
+    contracts_items::is_pure_logic,
     ctx::*,
     error::CreusotResult,
     translation::{
         pearlite::PIdent,
         specification::{ContractClauses, contract_clauses_of},
-        traits::TraitResolved,
     },
     util::erased_identity_for_item,
 };
@@ -29,6 +29,8 @@ pub(crate) struct ExternSpec<'tcx> {
     pub output: Ty<'tcx>,
     // Additional predicates we must verify to call this function
     pub additional_predicates: Vec<Predicate<'tcx>>,
+    /// The function is modelled by a logic function, see `#[pure(logic)]`.
+    pub logic: bool,
 }
 
 impl<'tcx> ExternSpec<'tcx> {
@@ -60,7 +62,7 @@ pub(crate) fn extract_extern_specs_from_item<'tcx>(
     let (id, subst) = visit.items.pop().unwrap();
 
     let (id, _) =
-        TraitResolved::resolve_item(ctx.tcx, ctx.typing_env(def_id_), id, subst).to_opt(id, subst).unwrap_or_else(|| {
+        ctx.resolve_item(ctx.typing_env(def_id_), id, subst).to_opt(id, subst).unwrap_or_else(|| {
             let mut err = ctx.fatal_error(
                 ctx.def_span(def_id_),
                 "could not derive original instance from external specification",
@@ -157,7 +159,13 @@ pub(crate) fn extract_extern_specs_from_item<'tcx>(
         .collect();
 
     let (inputs, output) = inputs_and_output_from_thir(ctx, def_id_, &thir);
-    Ok((id, ExternSpec { contract, additional_predicates, subst, inputs, output }))
+
+    // A logic function has no way to update the value behind a mutable reference.
+    let logic = is_pure_logic(ctx.tcx, def_id_);
+    if logic && inputs.iter().any(|(_, _, ty)| ty.is_mutable_ptr()) {
+        ctx.error(span, "`pure(logic)` functions cannot take mutable references").emit();
+    }
+    Ok((id, ExternSpec { contract, additional_predicates, subst, inputs, output, logic }))
 }
 
 // We shouldn't need a full visitor... or an index set, there should be a single item per extern spec method.
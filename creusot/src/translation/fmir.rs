@@ -254,10 +254,18 @@ pub struct Invariant<'tcx> {
     pub(crate) expl: String,
 }
 
+/// The variant of a loop, which must decrease at each iteration.
+#[derive(Clone, Debug)]
+pub struct LoopVariant<'tcx> {
+    pub(crate) term: Term<'tcx>,
+    /// The local holding the value of the variant at the start of the current iteration.
+    pub(crate) old: Ident,
+}
+
 #[derive(Clone, Debug)]
 pub struct Block<'tcx> {
     pub(crate) invariants: Vec<Invariant<'tcx>>,
-    pub(crate) variant: Option<Term<'tcx>>,
+    pub(crate) variant: Option<LoopVariant<'tcx>>,
     pub(crate) stmts: Vec<Statement<'tcx>>,
     pub(crate) terminator: Terminator<'tcx>,
 }
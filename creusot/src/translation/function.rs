@@ -3,7 +3,7 @@ mod terminator;
 
 use crate::{
     analysis::NotFinalPlaces,
-    backend::ty_inv::is_tyinv_trivial,
+    backend::{ty::is_int, ty_inv::is_tyinv_trivial},
     contracts_items::{is_snapshot_closure, is_spec},
     ctx::*,
     extended_location::ExtendedLocation,
@@ -237,6 +237,8 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
                 }
             }
 
+            let variant = variant.and_then(|term| self.loop_variant(term));
+
             if let Err(err) = self.resolve_places_between_blocks(bb) {
                 err.crash(self.ctx, self.basic_block_first_span(bbd))
             }
@@ -266,6 +268,32 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
         self.ctx.typing_env(self.body_id.def_id())
     }
 
+    /// The variant of a loop, if it is checked: only the loops of the functions which must
+    /// terminate have to decrease it at each iteration. The value of the variant at the start of
+    /// the iteration is kept in a fresh local, assigned at the loop header.
+    fn loop_variant(&mut self, term: Term<'tcx>) -> Option<fmir::LoopVariant<'tcx>> {
+        if !self.ctx.sig(self.body_id.def_id()).contract.terminates {
+            return None;
+        }
+        if !is_int(self.tcx(), term.ty) {
+            self.ctx
+                .error(term.span, &format!("Unsupported loop variant type: `{}`", term.ty))
+                .with_note("loop variants must be integers of type `Int`")
+                .emit();
+            return None;
+        }
+        let old = self.ctx.fresh("old_variant");
+        self.vars.insert(old, LocalDecl { span: term.span, ty: term.ty, temp: true, arg: false });
+        let place = fmir::Place { local: old, projections: Box::new([]) };
+        let span = term.span;
+        self.emit_statement(fmir::Statement::Assignment(
+            place,
+            RValue::Snapshot(term.clone()),
+            span,
+        ));
+        Some(fmir::LoopVariant { term, old })
+    }
+
     fn emit_statement(&mut self, s: fmir::Statement<'tcx>) {
         self.current_block.0.push(s);
     }
@@ -297,7 +325,7 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
         let p = self.translate_place(pl)?;
 
         if !is_tyinv_trivial(
-            self.tcx(),
+            self.ctx,
             self.typing_env(),
             place_ty.ty,
             self.tcx().def_span(self.body_id.def_id()),
@@ -331,7 +359,7 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
 
         let rhs_ty = rhs.ty(self.body, self.tcx()).ty;
         let triv_inv = if is_tyinv_trivial(
-            self.tcx(),
+            self.ctx,
             self.typing_env(),
             rhs_ty,
             self.tcx().def_span(self.body_id.def_id()),
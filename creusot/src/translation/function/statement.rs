@@ -1,8 +1,10 @@
 use super::{BodyTranslator, TranslationError};
 use crate::{
     analysis::NotFinalPlaces,
+    backend::ty_inv::is_tyinv_trivial,
     contracts_items::{
-        is_assertion, is_before_loop, is_invariant, is_snapshot_closure, is_spec, is_variant,
+        get_fn_ptr_of, get_unsize, is_assertion, is_assumption, is_before_loop, is_invariant,
+        is_range_bounds, is_snapshot_closure, is_spec, is_unsafe_requires, is_variant,
     },
     extended_location::ExtendedLocation,
     translation::{
@@ -11,12 +13,13 @@ use crate::{
     },
 };
 use rustc_borrowck::consumers::TwoPhaseActivation;
+use rustc_hir::{BlockCheckMode, Node, UnsafeSource, def_id::LocalDefId};
 use rustc_middle::{
     mir::{
-        BorrowKind::*, CastKind, Location, Operand::*, Place, Rvalue, SourceInfo, Statement,
-        StatementKind,
+        AggregateKind, BorrowKind::*, CastKind, Location, Operand::*, Place, Rvalue, SourceInfo,
+        Statement, StatementKind,
     },
-    ty::{TyKind, adjustment::PointerCoercion},
+    ty::{Ty, TyCtxt, TyKind, adjustment::PointerCoercion},
 };
 use rustc_mir_dataflow::ResultsCursor;
 use rustc_span::Span;
@@ -170,10 +173,29 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                             let places = self.tree.visible_places(si.scope);
                             assertion.subst(inline_pearlite_subst(self.ctx, &places));
                             self.check_use_in_logic(&assertion, loc);
+                            let trusted = is_assumption(self.tcx(), *def_id);
+                            // The bounds of the element of a `for` loop over a range follow from
+                            // its invariants, see `desugar_for`.
+                            if trusted && !is_range_bounds(self.tcx(), *def_id) {
+                                self.ctx.record_assumption(si.span, assertion.clone());
+                            }
+                            let msg = if is_unsafe_requires(self.tcx(), *def_id) {
+                                if !in_unsafe_block(self.tcx(), def_id.expect_local()) {
+                                    self.ctx
+                                        .error(
+                                            si.span,
+                                            "`unsafe_requires!` can only be used in an `unsafe` block",
+                                        )
+                                        .emit();
+                                }
+                                "expl:unsafe precondition"
+                            } else {
+                                "expl:assertion"
+                            };
                             self.emit_statement(fmir::Statement::Assertion {
                                 cond: assertion,
-                                msg: "expl:assertion".to_owned(),
-                                trusted: false,
+                                msg: msg.to_owned(),
+                                trusted,
                             });
                             return Ok(());
                         } else if is_spec(self.tcx(), *def_id) {
@@ -214,11 +236,49 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                     // treat &[T; N] to &[T] casts as normal assignments
                     RValue::Operand(self.translate_operand(op)?)
                 } else {
-                    // TODO: Since we don't do anything with casts into `dyn` objects, just ignore them
+                    // The coercion into a trait object, e.g. `&T` into `&dyn Tr`: the trait object
+                    // is opaque, see `unsize` in `creusot_contracts`.
+                    let tcx = self.tcx();
+                    let subst = tcx.mk_args(&[op.ty(self.body, tcx).into(), (*ty).into()]);
+                    let dest = self.translate_place(place.as_ref())?;
+                    let arg = self.translate_operand(op)?;
+                    self.emit_statement(fmir::Statement::Call(
+                        dest,
+                        get_unsize(tcx),
+                        subst,
+                        Box::new([arg]),
+                        span,
+                    ));
                     return Ok(());
                 }
             }
             Rvalue::RawPtr(_, pl) => RValue::Ptr(self.translate_place(pl.as_ref())?),
+            Rvalue::Cast(
+                CastKind::PointerCoercion(
+                    PointerCoercion::ReifyFnPointer | PointerCoercion::ClosureFnPointer(_),
+                    _,
+                ),
+                op,
+                ty,
+            ) => {
+                // A function pointer is an opaque value: creating it gives it the specification
+                // of the function it points to, see `fn_ptr_of` in `creusot_contracts`.
+                let tcx = self.tcx();
+                let sig = tcx.instantiate_bound_regions_with_erased(ty.fn_sig(tcx));
+                let args_ty = Ty::new_tup(tcx, sig.inputs());
+                let subst =
+                    tcx.mk_args(&[args_ty.into(), op.ty(self.body, tcx).into(), (*ty).into()]);
+                let dest = self.translate_place(place.as_ref())?;
+                let arg = self.translate_operand(op)?;
+                self.emit_statement(fmir::Statement::Call(
+                    dest,
+                    get_fn_ptr_of(tcx),
+                    subst,
+                    Box::new([arg]),
+                    span,
+                ));
+                return Ok(());
+            }
             Rvalue::Cast(
                 CastKind::PointerCoercion(..)
                 | CastKind::PointerExposeProvenance
@@ -246,6 +306,15 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
         if !ty.is_unit() {
             self.emit_assignment(place, rval, span);
         }
+
+        // The invariant of a type is assumed for all its values, so it must be established as
+        // soon as a value is built, even if it is never passed to or returned from a function.
+        if let Rvalue::Aggregate(box AggregateKind::Adt(..), _) = rvalue
+            && !is_tyinv_trivial(self.ctx, self.typing_env(), ty, span)
+        {
+            let pl = self.translate_place(place.as_ref())?;
+            self.emit_statement(fmir::Statement::AssertTyInv { pl });
+        }
         Ok(())
     }
 
@@ -271,3 +340,20 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
         }
     }
 }
+
+/// Is the spec closure `def_id` in an `unsafe` block of the enclosing item?
+fn in_unsafe_block(tcx: TyCtxt, def_id: LocalDefId) -> bool {
+    let hir_id = tcx.local_def_id_to_hir_id(def_id);
+    for (_, node) in tcx.hir().parent_iter(hir_id) {
+        match node {
+            Node::Block(block)
+                if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) =>
+            {
+                return true;
+            }
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => {}
+        }
+    }
+    false
+}
@@ -11,7 +11,7 @@ use crate::{
     translation::{
         fmir::{self, *},
         pearlite::{Term, TermKind, UnOp},
-        traits::{self, TraitResolved},
+        traits,
     },
 };
 use itertools::Itertools;
@@ -30,7 +30,7 @@ use rustc_mir_dataflow::{
     move_paths::{HasMoveData, LookupResult},
     on_all_children_bits,
 };
-use rustc_span::Span;
+use rustc_span::{Span, sym};
 use rustc_trait_selection::error_reporting::InferCtxtErrorExt;
 use std::collections::{HashMap, HashSet};
 
@@ -89,8 +89,11 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
             }
             Unreachable => term = Terminator::Abort(terminator.source_info.span),
             &Call { ref func, ref args, destination, mut target, fn_span, .. } => {
-                let Some((fun_def_id, subst)) = func_defid(func) else {
-                    self.ctx.fatal_error(fn_span, "unsupported function call type").emit()
+                let fn_ptr_call = func.ty(self.body, self.tcx()).is_fn_ptr();
+                let (fun_def_id, subst) = match func_defid(func) {
+                    Some(fun) => fun,
+                    None if fn_ptr_call => self.fn_ptr_call_item(func),
+                    None => self.ctx.fatal_error(fn_span, "unsupported function call type").emit(),
                 };
                 if let Some((need, resolved)) = resolved_during.take() {
                     if let Err(err) =
@@ -111,18 +114,37 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                     self.check_use_in_logic(&assertion, location);
                     self.emit_snapshot_assign(destination, assertion, span);
                 } else {
-                    let func_args: Box<[_]> = args
+                    let mut func_args: Box<[_]> = args
                         .iter()
                         .map(|arg| {
                             self.translate_operand(&arg.node)
                                 .unwrap_or_else(|err| err.crash(self.ctx, arg.span))
                         })
                         .collect();
+                    if fn_ptr_call {
+                        let func = self
+                            .translate_operand(func)
+                            .unwrap_or_else(|err| err.crash(self.ctx, fn_span));
+                        func_args = self.fn_ptr_call_args(func, func_args, subst.type_at(1), span);
+                    }
 
                     if is_box_new(self.tcx(), fun_def_id) {
                         let [arg] = *func_args.into_array().unwrap();
                         self.emit_assignment(&destination, RValue::Operand(arg), span);
                     } else {
+                        if self.ctx.is_std_only(fun_def_id)
+                            && self.ctx.externs.extern_spec(fun_def_id).is_some()
+                        {
+                            let msg = format!(
+                                "the specification of `{}` is only available with `std`",
+                                self.ctx.def_path_str(fun_def_id)
+                            );
+                            self.ctx
+                                .error(span, &msg)
+                                .with_note("this crate is `#![no_std]`")
+                                .emit();
+                        }
+
                         let predicates = self
                             .ctx
                             .extern_spec(fun_def_id)
@@ -142,6 +164,7 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                         );
                         if let Err(errs) = res {
                             infcx.err_ctxt().report_fulfillment_errors(errs);
+                            self.ctx.abort_if_fail_fast();
                         }
 
                         let (fun_def_id, subst) = resolve_function(
@@ -266,6 +289,39 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
             _ => unreachable!("Resume assertions"),
         }
     }
+
+    /// A call `f(a, b)` through the function pointer `f` is translated as `Fn::call(&f, (a, b))`:
+    /// it has the specification of the pointer given by `FnExt`.
+    fn fn_ptr_call_item(&self, func: &Operand<'tcx>) -> (DefId, GenericArgsRef<'tcx>) {
+        let tcx = self.tcx();
+        let fn_ptr_ty = func.ty(self.body, tcx);
+        let sig = tcx.instantiate_bound_regions_with_erased(fn_ptr_ty.fn_sig(tcx));
+        let args_ty = Ty::new_tup(tcx, sig.inputs());
+        let call = tcx
+            .associated_items(tcx.lang_items().fn_trait().unwrap())
+            .filter_by_name_unhygienic(sym::call)
+            .next()
+            .unwrap()
+            .def_id;
+        (call, tcx.mk_args(&[fn_ptr_ty.into(), args_ty.into()]))
+    }
+
+    /// Pack the arguments of a call through a function pointer in a tuple, as expected by
+    /// `Fn::call`. Shared references are translated as their target, so the pointer is passed
+    /// as is for `&self`.
+    fn fn_ptr_call_args(
+        &mut self,
+        func: fmir::Operand<'tcx>,
+        args: Box<[fmir::Operand<'tcx>]>,
+        args_ty: Ty<'tcx>,
+        span: Span,
+    ) -> Box<[fmir::Operand<'tcx>]> {
+        let local = self.ctx.fresh("args");
+        self.vars.insert(local, LocalDecl { span, ty: args_ty, temp: true, arg: false });
+        let place = fmir::Place { local, projections: Box::new([]) };
+        self.emit_statement(fmir::Statement::Assignment(place.clone(), RValue::Tuple(args), span));
+        Box::new([func, fmir::Operand::Move(place)])
+    }
 }
 
 /// # Parameters
@@ -280,7 +336,8 @@ fn resolve_function<'tcx>(
 ) -> (DefId, GenericArgsRef<'tcx>) {
     let res;
     if ctx.trait_of_item(def_id).is_some() {
-        res = TraitResolved::resolve_item(ctx.tcx, typing_env, def_id, subst)
+        res = ctx
+            .resolve_item(typing_env, def_id, subst)
             .to_opt(def_id, subst)
             .expect("could not find instance")
     } else {
@@ -14,8 +14,8 @@ use std::{
 
 use crate::{
     contracts_items::{
-        get_ghost_inner_logic, get_index_logic, is_assertion, is_deref, is_ghost_ty, is_snap_ty,
-        is_spec,
+        get_ghost_inner_logic, get_index_logic, is_assertion, is_deref, is_ghost_ty, is_opaque,
+        is_snap_ty, is_spec,
     },
     error::{CreusotResult, Error},
     translation::TranslationCtx,
@@ -27,6 +27,7 @@ use rustc_hir::{
     HirId, OwnerId,
     def_id::{DefId, LocalDefId},
 };
+use rustc_hir_typeck::expr_use_visitor::PlaceBase;
 use rustc_macros::{TyDecodable, TyEncodable, TypeFoldable, TypeVisitable};
 pub(crate) use rustc_middle::thir;
 use rustc_middle::{
@@ -35,8 +36,9 @@ use rustc_middle::{
         AdtExpr, ArmId, Block, ClosureExpr, ExprId, ExprKind, Pat, PatKind, StmtId, StmtKind, Thir,
     },
     ty::{
-        CanonicalUserType, GenericArg, GenericArgs, GenericArgsRef, Ty, TyCtxt, TyKind,
-        TypeFoldable, TypeVisitable, TypeVisitableExt, TypingEnv, UserTypeKind, int_ty, uint_ty,
+        self, CanonicalUserType, Const, GenericArg, GenericArgs, GenericArgsRef, Ty, TyCtxt,
+        TyKind, TypeFoldable, TypeVisitable, TypeVisitableExt, TypingEnv, UpvarCapture,
+        UserTypeKind, int_ty, uint_ty,
     },
 };
 use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
@@ -45,8 +47,10 @@ use rustc_target::abi::{FieldIdx, VariantIdx};
 use rustc_type_ir::{FloatTy, IntTy, Interner, UintTy};
 
 mod normalize;
+mod simplify;
 
 pub(crate) use normalize::*;
+pub(crate) use simplify::simplify;
 
 #[derive(Copy, Clone, Debug, TyDecodable, TyEncodable, TypeFoldable, TypeVisitable)]
 pub enum BinOp {
@@ -166,6 +170,10 @@ pub enum TermKind<'tcx> {
         arg: Box<Term<'tcx>>,
     },
     Item(DefId, GenericArgsRef<'tcx>),
+    /// A constant appearing in types, such as the const generic parameter `N` of `[T; N]`.
+    ///
+    /// It is evaluated once instantiated with a value.
+    Const(Const<'tcx>),
     Assert {
         cond: Box<Term<'tcx>>,
     },
@@ -526,6 +534,19 @@ pub(crate) fn pearlite_with_triggers<'tcx>(
     Ok((bound, triggers, body))
 }
 
+/// The variables bound by `pattern`, if it only contains tuples, binders and wildcards.
+fn tuple_leaves<'tcx>(pattern: &Pattern<'tcx>, leaves: &mut Vec<(PIdent, Ty<'tcx>)>) -> bool {
+    match &pattern.kind {
+        PatternKind::Binder(x) => leaves.push((*x, pattern.ty)),
+        PatternKind::Wildcard => leaves.push((Ident::fresh_local("__").into(), pattern.ty)),
+        PatternKind::Tuple(fields) => {
+            return fields.iter().all(|field| tuple_leaves(field, leaves));
+        }
+        _ => return false,
+    }
+    true
+}
+
 struct ThirTerm<'a, 'tcx> {
     ctx: &'a TranslationCtx<'tcx>,
     item_id: LocalDefId,
@@ -678,6 +699,20 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                         }
                     }
                     LitKind::Char(c) => Literal::Char(c),
+                    LitKind::Float(f, _) => {
+                        let TyKind::Float(fty) = *ty.kind() else { unreachable!() };
+                        let digits = f.as_str().replace('_', "");
+                        // Parse `f32` literals directly, to avoid rounding them twice.
+                        let value = match fty {
+                            FloatTy::F32 => digits.parse::<f32>().map(f64::from),
+                            FloatTy::F64 => digits.parse::<f64>(),
+                            FloatTy::F16 | FloatTy::F128 => {
+                                return Err(Error::msg(span, format!("unsupported type {ty}")));
+                            }
+                        };
+                        let value = value.map_err(|_| Error::msg(span, "invalid float literal"))?;
+                        Literal::Float((if neg { -value } else { value }).into(), fty)
+                    }
                     _ => unimplemented!("Unsupported literal"),
                 };
                 Ok(Term { ty, span, kind: TermKind::Lit(lit) })
@@ -722,6 +757,33 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
 
                         Ok(Term { ty, span, kind: TermKind::Old { term: Box::new(term) } })
                     }
+                    Some(Captured) => {
+                        let closure = self.expr_term(args[0])?;
+                        // The variable is passed by value: peel off the scopes to get to it.
+                        let mut var = args[1];
+                        let var = loop {
+                            match self.thir[var].kind {
+                                ExprKind::Scope { value, .. } | ExprKind::Use { source: value } => {
+                                    var = value
+                                }
+                                ExprKind::VarRef { id }
+                                | ExprKind::UpvarRef { var_hir_id: id, .. } => break id.0,
+                                _ => {
+                                    return Err(Error::msg(
+                                        self.thir[var].span,
+                                        "the second argument of `captured` must be a variable",
+                                    ));
+                                }
+                            }
+                        };
+                        match closure_captured_var(self.ctx, closure, var) {
+                            Some(term) => Ok(term.span(span)),
+                            None => Err(Error::msg(
+                                span,
+                                "this variable is not captured by a closure defined in this crate",
+                            )),
+                        }
+                    }
                     Some(ResultCheck) => Ok(Term::unit(self.ctx.tcx).span(span)),
                     Some(Dead) => Err(Error::msg(
                         span,
@@ -806,6 +868,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 args,
                 ..
             }) => {
+                self.check_opaque(span, ty)?;
                 let mut fields: Vec<_> = fields
                     .iter()
                     .map(|f| Ok((f.name, self.expr_term(f.expr)?)))
@@ -895,6 +958,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 })
             }
             ExprKind::Field { lhs, name, .. } => {
+                self.check_opaque(span, self.thir[lhs].ty)?;
                 let lhs = self.expr_term(lhs)?;
                 Ok(lhs.proj(name, ty).span(span))
             }
@@ -915,6 +979,9 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
             ExprKind::NamedConst { def_id, args, ref user_ty, .. } => {
                 Ok(Term { ty, span, kind: TermKind::item(def_id, args, user_ty, self.ctx.tcx) })
             }
+            ExprKind::ConstParam { param, .. } => {
+                Ok(Term { ty, span, kind: TermKind::Const(Const::new_param(self.ctx.tcx, param)) })
+            }
             ExprKind::ZstLiteral { ref user_ty, .. } => match ty.kind() {
                 TyKind::FnDef(def_id, subst) => Ok(Term {
                     ty,
@@ -950,6 +1017,29 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
         Ok(Term { ty, ..res? })
     }
 
+    /// The definition of a `#[creusot::opaque]` type is only visible in the specifications of its
+    /// own module: elsewhere, it can only be used through its view and its logic functions.
+    fn check_opaque(&self, span: Span, ty: Ty<'tcx>) -> CreusotResult<()> {
+        let TyKind::Adt(adt, _) = ty.kind() else { return Ok(()) };
+        if !is_opaque(self.ctx.tcx, adt.did()) {
+            return Ok(());
+        }
+        let visible = adt.did().as_local().is_some_and(|did| {
+            let module = self.ctx.tcx.parent_module_from_def_id(did).to_def_id();
+            self.ctx.tcx.is_descendant_of(self.item_id.to_def_id(), module)
+        });
+        if visible {
+            return Ok(());
+        }
+        Err(Error::msg(
+            span,
+            format!(
+                "the definition of the opaque type `{}` is not visible outside of its module",
+                self.ctx.def_path_str(adt.did())
+            ),
+        ))
+    }
+
     fn arm_term(&self, arm: ArmId) -> CreusotResult<(Pattern<'tcx>, Term<'tcx>)> {
         let arm = &self.thir[arm];
 
@@ -983,6 +1073,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 Ok(Pattern { ty: pat.ty, span: pat.span, kind: PatternKind::Binder(ident) })
             }
             PatKind::Variant { subpatterns, adt_def, variant_index, args, .. } => {
+                self.check_opaque(pat.span, pat.ty)?;
                 let mut fields: Vec<_> = subpatterns
                     .iter()
                     .map(|pat| Ok((pat.field, self.pattern_term(&pat.pattern, mut_allowed)?)))
@@ -1006,6 +1097,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 })
             }
             PatKind::Leaf { subpatterns } => {
+                self.check_opaque(pat.span, pat.ty)?;
                 let mut fields: Vec<_> = subpatterns
                     .iter()
                     .map(|pat| Ok((pat.field, self.pattern_term(&pat.pattern, mut_allowed)?)))
@@ -1060,7 +1152,16 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
             PatKind::AscribeUserType { ascription: _, subpattern } => {
                 self.pattern_term(subpattern, mut_allowed)
             }
-            ref pk => todo!("lower_pattern: unsupported pattern kind {:?}", pk),
+            PatKind::Or { .. } => {
+                Err(Error::msg(pat.span, "or-patterns are not supported in Pearlite"))
+            }
+            PatKind::Range(_) => {
+                Err(Error::msg(pat.span, "range patterns are not supported in Pearlite"))
+            }
+            PatKind::Slice { .. } | PatKind::Array { .. } => {
+                Err(Error::msg(pat.span, "slice patterns are not supported in Pearlite"))
+            }
+            ref pk => Err(Error::msg(pat.span, format!("unsupported pattern in Pearlite: {pk:?}"))),
         }
     }
 
@@ -1076,7 +1177,15 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 let span = self.thir[*expr].span;
                 Ok(Term::let_(Pattern::wildcard(arg.ty), arg, inner).span(span))
             }
-            StmtKind::Let { pattern, initializer, init_scope, .. } => {
+            StmtKind::Let { pattern, initializer, else_block, init_scope, .. } => {
+                // The `else` branch diverges, and cannot be translated: the term would be
+                // partial, undefined when the pattern does not match.
+                if else_block.is_some() {
+                    return Err(Error::msg(
+                        pattern.span,
+                        "let-else is not supported in Pearlite, because terms must be total: use a `match` instead",
+                    ));
+                }
                 let pattern = self.pattern_term(pattern, false)?;
                 if let Some(initializer) = initializer {
                     let initializer = self.expr_term(*initializer)?;
@@ -1102,12 +1211,65 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
         match self.thir[body].kind {
             ExprKind::Scope { value, .. } => self.quant_term(value),
             ExprKind::Closure(box ClosureExpr { closure_id, .. }) => {
-                pearlite_with_triggers(self.ctx, closure_id)
+                let (binder, trigger, body) = pearlite_with_triggers(self.ctx, closure_id)?;
+                let typing_env = TypingEnv::non_body_analysis(self.ctx.tcx, closure_id);
+                let (binder, body) = self.flatten_tuple_binders(typing_env, binder, &trigger, body);
+                Ok((binder, trigger, body))
             }
             _ => Err(Error::msg(self.thir[body].span, "unexpected error in quantifier")),
         }
     }
 
+    /// Quantify directly over the variables of the tuple patterns of a binder, so that
+    /// `forall<(i, j): (Int, Int)> P` is `forall<i: Int, j: Int> P`, and triggers may mention
+    /// `i` and `j`.
+    ///
+    /// The patterns of the binder are matched by the `let`s at the start of `body`, see
+    /// [`pearlite_with_triggers`].
+    fn flatten_tuple_binders(
+        &self,
+        typing_env: TypingEnv<'tcx>,
+        binder: QuantBinder<'tcx>,
+        trigger: &[Trigger<'tcx>],
+        mut body: Term<'tcx>,
+    ) -> (QuantBinder<'tcx>, Term<'tcx>) {
+        let mut flat = Vec::with_capacity(binder.len());
+        let mut binder = binder.into_iter();
+        for (ident, ty) in &mut binder {
+            let TermKind::Let {
+                pattern,
+                arg: box Term { kind: TermKind::Var(x), .. },
+                body: inner,
+            } = &body.kind
+            else {
+                flat.push((ident, ty));
+                continue;
+            };
+            if *x != ident {
+                // The `let` matches the pattern of a later variable.
+                flat.push((ident, ty));
+                continue;
+            }
+            // The variable is still used if the `let` was written by the user.
+            let used = inner.free_vars().contains(&ident.0)
+                || trigger.iter().flat_map(|t| &t.0).any(|t| t.free_vars().contains(&ident.0));
+            let mut leaves = Vec::new();
+            if used || !tuple_leaves(pattern, &mut leaves) {
+                // The remaining patterns are matched inside this one.
+                flat.push((ident, ty));
+                break;
+            }
+            flat.extend(
+                leaves
+                    .into_iter()
+                    .map(|(x, ty)| (x, self.ctx.normalize_erasing_regions(typing_env, ty))),
+            );
+            body = (**inner).clone();
+        }
+        flat.extend(binder);
+        (flat.into(), body)
+    }
+
     // Creates a 'logical' reborrow of a mutable borrow.
     // The idea is that the expression `&mut ** X` for `X : &mut &mut T` should produces a pearlite value of type `&mut T`.
     //
@@ -1158,6 +1320,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 self.logical_reborrow_inner(expr.unwrap())
             }
             ExprKind::Field { lhs, name, .. } => {
+                self.check_opaque(span, self.thir[*lhs].ty)?;
                 let (cur, fin, inner, mut proj) = self.logical_reborrow_inner(*lhs)?;
                 proj.push(ProjectionElem::Field(*name, ty));
                 Ok((cur.proj(*name, ty).span(span), fin.proj(*name, ty).span(span), inner, proj))
@@ -1262,6 +1425,36 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
     }
 }
 
+/// The current value of the variable `var` captured by the closure `self_`, or `None` if `self_`
+/// is not a local closure capturing `var`.
+fn closure_captured_var<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    self_: Term<'tcx>,
+    var: HirId,
+) -> Option<Term<'tcx>> {
+    let TyKind::Closure(def_id, subst) = *self_.ty.kind() else { return None };
+    let def_id = def_id.as_local()?;
+    let (ix, (cap, ty)) =
+        ctx.closure_captures(def_id).iter().zip(subst.as_closure().upvar_tys()).enumerate().find(
+            |(_, (cap, _))| {
+                cap.place.projections.is_empty()
+                    && match cap.place.base {
+                        PlaceBase::Local(hir_id) => hir_id == var,
+                        PlaceBase::Upvar(upvar_id) => upvar_id.var_path.hir_id == var,
+                        PlaceBase::Rvalue | PlaceBase::StaticItem => false,
+                    }
+            },
+        )?;
+    let proj = self_.proj(ix.into(), ty);
+    Some(match cap.info.capture_kind {
+        UpvarCapture::ByValue => proj,
+        UpvarCapture::ByRef(ty::BorrowKind::Mutable | ty::BorrowKind::UniqueImmutable) => {
+            proj.cur()
+        }
+        UpvarCapture::ByRef(ty::BorrowKind::Immutable) => proj.shr_deref(),
+    })
+}
+
 fn is_ghost_ty_deref<'tcx>(
     tcx: TyCtxt<'tcx>,
     fn_id: DefId,
@@ -1309,6 +1502,7 @@ pub(crate) enum Stub {
     ResultCheck,
     Dead,
     SeqLiteral,
+    Captured,
 }
 
 pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stub> {
@@ -1326,6 +1520,7 @@ pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stu
             "dead" => Some(Stub::Dead),
             "closure_result_constraint" => Some(Stub::ResultCheck),
             "seq_literal" => Some(Stub::SeqLiteral),
+            "closure_captured" => Some(Stub::Captured),
             _ => None,
         }
     } else {
@@ -1368,6 +1563,7 @@ pub fn super_visit_term<'tcx, V: TermVisitor<'tcx>>(term: &Term<'tcx>, visitor:
         TermKind::Cast { arg } => visitor.visit_term(arg),
         TermKind::Coerce { arg } => visitor.visit_term(arg),
         TermKind::Item(_, _) => {}
+        TermKind::Const(_) => {}
         TermKind::Binary { op: _, lhs, rhs } => {
             visitor.visit_term(lhs);
             visitor.visit_term(rhs);
@@ -1426,6 +1622,7 @@ pub(crate) fn super_visit_mut_term<'tcx, V: TermVisitorMut<'tcx>>(
         TermKind::Cast { arg } => visitor.visit_mut_term(&mut *arg),
         TermKind::Coerce { arg } => visitor.visit_mut_term(arg),
         TermKind::Item(_, _) => {}
+        TermKind::Const(_) => {}
         TermKind::Binary { op: _, lhs, rhs } => {
             visitor.visit_mut_term(&mut *lhs);
             visitor.visit_mut_term(&mut *rhs);
@@ -1700,6 +1897,7 @@ impl<'tcx> Term<'tcx> {
             TermKind::Cast { arg } => arg.subst_with(bound, subst),
             TermKind::Coerce { arg } => arg.subst_with(bound, subst),
             TermKind::Item(_, _) => {}
+            TermKind::Const(_) => {}
             TermKind::Binary { lhs, rhs, .. } => {
                 lhs.subst_with(bound, subst);
                 rhs.subst_with(bound, subst)
@@ -1772,6 +1970,40 @@ impl<'tcx> Term<'tcx> {
         }
     }
 
+    /// The nesting depth of the quantifiers of the term.
+    ///
+    /// Directly nested quantifiers of the same kind, such as `forall<x> forall<y> P`, bind their
+    /// variables at once and count as one level.
+    pub(crate) fn quantifier_depth(&self) -> usize {
+        struct MaxDepth(usize);
+        impl<'tcx> TermVisitor<'tcx> for MaxDepth {
+            fn visit_term(&mut self, term: &Term<'tcx>) {
+                self.0 = self.0.max(term.quantifier_depth())
+            }
+        }
+
+        let mut depth = MaxDepth(0);
+        match &self.kind {
+            TermKind::Quant { kind, trigger, body, .. } => {
+                trigger.iter().flat_map(|t| &t.0).for_each(|t| depth.visit_term(t));
+                let mut body = &**body;
+                while let TermKind::Quant { kind: inner, trigger, body: inner_body, .. } =
+                    &body.kind
+                    && inner == kind
+                {
+                    trigger.iter().flat_map(|t| &t.0).for_each(|t| depth.visit_term(t));
+                    body = inner_body;
+                }
+                depth.visit_term(body);
+                depth.0 + 1
+            }
+            _ => {
+                super_visit_term(self, &mut depth);
+                depth.0
+            }
+        }
+    }
+
     pub(crate) fn free_vars(&self) -> HashSet<Ident> {
         let mut free = HashSet::new();
         self.free_vars_inner(&HashSet::new(), &mut free);
@@ -1792,6 +2024,7 @@ impl<'tcx> Term<'tcx> {
             TermKind::Cast { arg } => arg.free_vars_inner(bound, free),
             TermKind::Coerce { arg } => arg.free_vars_inner(bound, free),
             TermKind::Item(_, _) => {}
+            TermKind::Const(_) => {}
             TermKind::Binary { lhs, rhs, .. } => {
                 lhs.free_vars_inner(bound, free);
                 rhs.free_vars_inner(bound, free)
@@ -1,43 +1,51 @@
 use crate::{
     contracts_items::{get_builtin, is_box_new},
-    translation::{
-        pearlite::{BinOp, Literal, Term, TermKind, TermVisitorMut, UnOp, super_visit_mut_term},
-        traits::TraitResolved,
+    ctx::TranslationCtx,
+    translation::pearlite::{
+        BinOp, Literal, Term, TermKind, TermVisitorMut, UnOp, super_visit_mut_term,
     },
 };
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::{GenericArgsRef, TyCtxt, TypingEnv};
 
 pub(crate) fn normalize<'tcx>(
-    tcx: TyCtxt<'tcx>,
+    ctx: &TranslationCtx<'tcx>,
     typing_env: TypingEnv<'tcx>,
     mut term: Term<'tcx>,
 ) -> Term<'tcx> {
-    NormalizeTerm { typing_env, tcx }.visit_mut_term(&mut term);
-    let term = tcx.normalize_erasing_regions(typing_env, term);
+    NormalizeTerm { typing_env, ctx }.visit_mut_term(&mut term);
+    let term = ctx.normalize_erasing_regions(typing_env, term);
     term
 }
 
-struct NormalizeTerm<'tcx> {
+struct NormalizeTerm<'a, 'tcx> {
     typing_env: TypingEnv<'tcx>,
-    tcx: TyCtxt<'tcx>,
+    ctx: &'a TranslationCtx<'tcx>,
 }
 
-impl<'tcx> TermVisitorMut<'tcx> for NormalizeTerm<'tcx> {
+impl<'tcx> TermVisitorMut<'tcx> for NormalizeTerm<'_, 'tcx> {
     fn visit_mut_term(&mut self, term: &mut Term<'tcx>) {
         super_visit_mut_term(term, self);
         match &mut term.kind {
             TermKind::Call { id, subst, args } => {
-                (*id, *subst) = TraitResolved::resolve_item(self.tcx, self.typing_env, *id, subst)
+                (*id, *subst) = self
+                    .ctx
+                    .resolve_item(self.typing_env, *id, subst)
                     .to_opt(*id, subst)
                     .unwrap_or_else(|| {
                         panic!("could not resolve trait instance {:?}", (*id, *subst))
                     });
-                term.kind =
-                    optimize_builtin(self.tcx, *id, subst, std::mem::replace(args, Box::new([])));
+                term.kind = optimize_builtin(
+                    self.ctx.tcx,
+                    *id,
+                    subst,
+                    std::mem::replace(args, Box::new([])),
+                );
             }
             TermKind::Item(id, subst) => {
-                (*id, *subst) = TraitResolved::resolve_item(self.tcx, self.typing_env, *id, subst)
+                (*id, *subst) = self
+                    .ctx
+                    .resolve_item(self.typing_env, *id, subst)
                     .to_opt(*id, subst)
                     .unwrap_or_else(|| {
                         panic!("could not resolve trait instance {:?}", (*id, *subst))
@@ -0,0 +1,140 @@
+//! Optional simplification passes over pearlite terms, enabled with `--simplify`.
+//!
+//! They are applied to the contracts of functions and to the refinement obligations of impls,
+//! before these terms are handed to the backend. Each pass rewrites the term bottom-up, so that
+//! running it a second time leaves the term unchanged.
+
+use crate::{
+    options::SimplifyPass,
+    translation::pearlite::{
+        BinOp, Literal, Term, TermKind, TermVisitorMut, UnOp, super_visit_mut_term,
+    },
+};
+use rustc_middle::ty::TyCtxt;
+
+/// The available passes, in the order in which they are applied.
+const PASSES: &[(SimplifyPass, for<'tcx> fn(TyCtxt<'tcx>, &mut Term<'tcx>))] =
+    &[(SimplifyPass::ConstFold, const_fold), (SimplifyPass::TrivialLogic, trivial_logic)];
+
+/// Apply the enabled passes to `term`.
+pub(crate) fn simplify<'tcx>(tcx: TyCtxt<'tcx>, enabled: &[SimplifyPass], term: &mut Term<'tcx>) {
+    if enabled.is_empty() {
+        return;
+    }
+    Simplify { tcx, enabled }.visit_mut_term(term);
+
+    // The passes must reach a fixpoint in one run: check it in debug builds, and thus in tests.
+    #[cfg(debug_assertions)]
+    {
+        let mut again = term.clone();
+        Simplify { tcx, enabled }.visit_mut_term(&mut again);
+        debug_assert_eq!(
+            format!("{term:?}"),
+            format!("{again:?}"),
+            "simplification is not idempotent"
+        );
+    }
+}
+
+struct Simplify<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    enabled: &'a [SimplifyPass],
+}
+
+impl<'tcx> TermVisitorMut<'tcx> for Simplify<'_, 'tcx> {
+    fn visit_mut_term(&mut self, term: &mut Term<'tcx>) {
+        super_visit_mut_term(term, self);
+        for (pass, f) in PASSES {
+            if self.enabled.contains(pass) {
+                f(self.tcx, term)
+            }
+        }
+    }
+}
+
+/// Evaluate operations on integer and boolean literals.
+///
+/// Only operations with the same meaning in every backend theory are evaluated: in particular,
+/// divisions are left alone, as well as operations that would overflow an `i128`.
+fn const_fold<'tcx>(_: TyCtxt<'tcx>, term: &mut Term<'tcx>) {
+    let folded = match &term.kind {
+        TermKind::Binary { op, lhs, rhs } => match (&lhs.kind, &rhs.kind) {
+            (TermKind::Lit(l), TermKind::Lit(r)) => fold_binop(*op, l, r),
+            _ => None,
+        },
+        TermKind::Unary { op: UnOp::Not, arg } => match arg.kind {
+            TermKind::Lit(Literal::Bool(b)) => Some(Literal::Bool(!b)),
+            _ => None,
+        },
+        TermKind::Unary { op: UnOp::Neg, arg } => match &arg.kind {
+            TermKind::Lit(l) => int_value(l).and_then(i128::checked_neg).map(Literal::Integer),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let Some(lit) = folded {
+        term.kind = TermKind::Lit(lit)
+    }
+}
+
+/// The value of a literal of type `Int`.
+fn int_value(lit: &Literal) -> Option<i128> {
+    match *lit {
+        Literal::Integer(i) => Some(i),
+        Literal::UInteger(u) => i128::try_from(u).ok(),
+        _ => None,
+    }
+}
+
+fn fold_binop<'tcx>(op: BinOp, lhs: &Literal<'tcx>, rhs: &Literal<'tcx>) -> Option<Literal<'tcx>> {
+    use BinOp::*;
+    if let (Some(a), Some(b)) = (int_value(lhs), int_value(rhs)) {
+        return match op {
+            Add => a.checked_add(b).map(Literal::Integer),
+            Sub => a.checked_sub(b).map(Literal::Integer),
+            Mul => a.checked_mul(b).map(Literal::Integer),
+            Lt => Some(Literal::Bool(a < b)),
+            Le => Some(Literal::Bool(a <= b)),
+            Gt => Some(Literal::Bool(a > b)),
+            Ge => Some(Literal::Bool(a >= b)),
+            Eq => Some(Literal::Bool(a == b)),
+            Ne => Some(Literal::Bool(a != b)),
+            _ => None,
+        };
+    }
+    match (op, lhs, rhs) {
+        (Eq, Literal::Bool(a), Literal::Bool(b)) => Some(Literal::Bool(a == b)),
+        (Ne, Literal::Bool(a), Literal::Bool(b)) => Some(Literal::Bool(a != b)),
+        _ => None,
+    }
+}
+
+/// Remove `true` and `false` from conjunctions, disjunctions, implications and quantifiers, such as
+/// the ones built by [`crate::translation::specification::PreContract::requires_conj`].
+fn trivial_logic<'tcx>(tcx: TyCtxt<'tcx>, term: &mut Term<'tcx>) {
+    let span = term.span;
+    let Term { ty, kind, .. } = std::mem::replace(term, Term::true_(tcx));
+    let mut res =
+        match kind {
+            TermKind::Impl { lhs, rhs } => (*lhs).implies(*rhs),
+            TermKind::Binary { op: BinOp::And, lhs, rhs } => (*lhs).conj(*rhs),
+            TermKind::Binary { op: BinOp::Or, lhs, rhs } => match (&lhs.kind, &rhs.kind) {
+                // ⟙ ∨ A = ⟙ and A ∨ ⟘ = A
+                (TermKind::Lit(Literal::Bool(true)), _)
+                | (_, TermKind::Lit(Literal::Bool(false))) => *lhs,
+                // A ∨ ⟙ = ⟙ and ⟘ ∨ A = A
+                (_, TermKind::Lit(Literal::Bool(true)))
+                | (TermKind::Lit(Literal::Bool(false)), _) => *rhs,
+                _ => Term { ty, kind: TermKind::Binary { op: BinOp::Or, lhs, rhs }, span },
+            },
+            TermKind::Quant { kind, binder, trigger, body } => (*body).quant(kind, binder, trigger),
+            kind => Term { ty, kind, span },
+        };
+    if res.span.is_dummy() {
+        res.span = span;
+    }
+    *term = res
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,100 @@
+use super::simplify;
+use crate::{
+    options::SimplifyPass,
+    test_support::*,
+    translation::pearlite::{BinOp, Literal, Term, TermKind},
+};
+use rustc_middle::ty::TyCtxt;
+use rustc_span::DUMMY_SP;
+use why3::Ident;
+
+const ALL: &[SimplifyPass] = &[SimplifyPass::ConstFold, SimplifyPass::TrivialLogic];
+
+// The smart constructors of `Term` already simplify trivial conjunctions and implications: the
+// terms are built directly instead.
+
+fn int<'tcx>(tcx: TyCtxt<'tcx>, i: i128) -> Term<'tcx> {
+    Term { kind: TermKind::Lit(Literal::Integer(i)), ty: tcx.types.i128, span: DUMMY_SP }
+}
+
+fn bool_var<'tcx>(tcx: TyCtxt<'tcx>, name: &str) -> Term<'tcx> {
+    Term::var(Ident::fresh_local(name), tcx.types.bool)
+}
+
+fn binary<'tcx>(tcx: TyCtxt<'tcx>, lhs: Term<'tcx>, op: BinOp, rhs: Term<'tcx>) -> Term<'tcx> {
+    let ty = if matches!(op, BinOp::Add | BinOp::Sub | BinOp::Mul) {
+        tcx.types.i128
+    } else {
+        tcx.types.bool
+    };
+    lhs.bin_op(ty, op, rhs)
+}
+
+fn implies<'tcx>(tcx: TyCtxt<'tcx>, lhs: Term<'tcx>, rhs: Term<'tcx>) -> Term<'tcx> {
+    let kind = TermKind::Impl { lhs: Box::new(lhs), rhs: Box::new(rhs) };
+    Term { kind, ty: tcx.types.bool, span: DUMMY_SP }
+}
+
+fn simplified<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    passes: &[SimplifyPass],
+    mut term: Term<'tcx>,
+) -> Term<'tcx> {
+    simplify(tcx, passes, &mut term);
+    term
+}
+
+fn same(a: &Term, b: &Term) -> bool {
+    format!("{a:?}") == format!("{b:?}")
+}
+
+#[test]
+fn const_fold() {
+    with_tcx("", |tcx| {
+        // 1 + 2 < 4
+        let sum = binary(tcx, int(tcx, 1), BinOp::Add, int(tcx, 2));
+        let term =
+            simplified(tcx, &[SimplifyPass::ConstFold], binary(tcx, sum, BinOp::Lt, int(tcx, 4)));
+        assert!(matches!(term.kind, TermKind::Lit(Literal::Bool(true))), "{term:?}");
+
+        // Overflowing operations are left alone.
+        let term = binary(tcx, int(tcx, i128::MAX), BinOp::Add, int(tcx, 1));
+        let term = simplified(tcx, &[SimplifyPass::ConstFold], term);
+        assert!(matches!(term.kind, TermKind::Binary { op: BinOp::Add, .. }), "{term:?}");
+    })
+}
+
+#[test]
+fn trivial_logic() {
+    with_tcx("", |tcx| {
+        // (true && a) || false  ~>  a
+        let a = bool_var(tcx, "a");
+        let conj = binary(tcx, Term::true_(tcx), BinOp::And, a.clone());
+        let term = binary(tcx, conj.clone(), BinOp::Or, Term::false_(tcx));
+        let term = simplified(tcx, &[SimplifyPass::TrivialLogic], term);
+        assert!(same(&term, &a), "{term:?}");
+
+        // Without the pass, the term is unchanged.
+        let unchanged = simplified(tcx, &[SimplifyPass::ConstFold], conj.clone());
+        assert!(same(&conj, &unchanged), "{unchanged:?}");
+    })
+}
+
+// The passes apply bottom-up: folding a subterm may enable a simplification of its parent, which
+// is done in the same run.
+#[test]
+fn idempotent() {
+    with_tcx("", |tcx| {
+        let a = bool_var(tcx, "a");
+        let b = bool_var(tcx, "b");
+        // (1 < 2 ==> a) && (b || 2 + 2 == 5)
+        let lhs = implies(tcx, binary(tcx, int(tcx, 1), BinOp::Lt, int(tcx, 2)), a.clone());
+        let sum = binary(tcx, int(tcx, 2), BinOp::Add, int(tcx, 2));
+        let rhs = binary(tcx, b.clone(), BinOp::Or, binary(tcx, sum, BinOp::Eq, int(tcx, 5)));
+        let once = simplified(tcx, ALL, binary(tcx, lhs, BinOp::And, rhs));
+        assert!(same(&once, &binary(tcx, a, BinOp::And, b)), "{once:?}");
+
+        let twice = simplified(tcx, ALL, once.clone());
+        assert!(same(&once, &twice), "{twice:?}");
+    })
+}
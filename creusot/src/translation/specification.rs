@@ -1,14 +1,16 @@
 use crate::{
     backend::closures::ClosSubst,
     contracts_items::{
-        creusot_clause_attrs, get_fn_mut_impl_hist_inv, is_fn_impl_postcond,
-        is_fn_mut_impl_hist_inv, is_fn_mut_impl_postcond, is_fn_once_impl_postcond,
-        is_fn_once_impl_precond, is_no_panic, is_open_inv_result, is_terminates,
+        InvariantMode, creusot_clause_attrs, get_addr_logic, get_fn_mut_impl_hist_inv,
+        invariant_mode, is_diverges, is_fn_impl_postcond, is_fn_mut_impl_hist_inv,
+        is_fn_mut_impl_postcond, is_fn_once_impl_postcond, is_fn_once_impl_precond, is_fresh,
+        is_maintains_invariant, is_no_panic, is_open_inv_result, is_terminates,
     },
     ctx::*,
     naming::{name, variable_name},
     translation::pearlite::{
-        Ident, Literal, PIdent, Term, TermKind, TermVisitorMut, normalize, type_invariant_term,
+        BinOp, Ident, Literal, PIdent, Term, TermKind, TermVisitorMut, normalize, simplify,
+        super_visit_mut_term, type_invariant_term,
     },
     util::erased_identity_for_item,
 };
@@ -35,6 +37,9 @@ pub struct PreContract<'tcx> {
     pub(crate) variant: Option<Term<'tcx>>,
     pub(crate) requires: Vec<Condition<'tcx>>,
     pub(crate) ensures: Vec<Condition<'tcx>>,
+    /// Postconditions that the implementations of a trait method must satisfy, in addition to
+    /// `ensures`. They are not part of the contract seen by callers.
+    pub(crate) impls_ensure: Vec<Condition<'tcx>>,
     pub(crate) no_panic: bool,
     pub(crate) terminates: bool,
     pub(crate) extern_no_spec: bool,
@@ -43,9 +48,14 @@ pub struct PreContract<'tcx> {
 }
 
 impl<'tcx> PreContract<'tcx> {
-    pub(crate) fn normalize(mut self, tcx: TyCtxt<'tcx>, typing_env: TypingEnv<'tcx>) -> Self {
+    pub(crate) fn normalize(
+        mut self,
+        ctx: &TranslationCtx<'tcx>,
+        typing_env: TypingEnv<'tcx>,
+    ) -> Self {
         for term in self.terms_mut() {
-            *term = normalize(tcx, typing_env, std::mem::replace(term, /*Dummy*/ Term::true_(tcx)));
+            let dummy = Term::true_(ctx.tcx);
+            *term = normalize(ctx, typing_env, std::mem::replace(term, dummy));
         }
         self
     }
@@ -63,6 +73,7 @@ impl<'tcx> PreContract<'tcx> {
         self.requires
             .iter()
             .chain(self.ensures.iter())
+            .chain(self.impls_ensure.iter())
             .map(|cond| &cond.term)
             .chain(self.variant.iter())
     }
@@ -71,6 +82,7 @@ impl<'tcx> PreContract<'tcx> {
         self.requires
             .iter_mut()
             .chain(self.ensures.iter_mut())
+            .chain(self.impls_ensure.iter_mut())
             .map(|cond| &mut cond.term)
             .chain(self.variant.iter_mut())
     }
@@ -98,8 +110,13 @@ pub struct ContractClauses {
     variant: Option<DefId>,
     requires: Vec<DefId>,
     ensures: Vec<DefId>,
+    impls_ensure: Vec<DefId>,
+    /// The conditions under which the function may panic, see `--panic-report`.
+    panics_when: Vec<DefId>,
     pub(crate) no_panic: bool,
     pub(crate) terminates: bool,
+    /// The function never returns, see [`is_diverges`].
+    pub(crate) diverges: bool,
 }
 
 impl ContractClauses {
@@ -108,8 +125,11 @@ impl ContractClauses {
             variant: None,
             requires: Vec::new(),
             ensures: Vec::new(),
+            impls_ensure: Vec::new(),
+            panics_when: Vec::new(),
             no_panic: false,
             terminates: false,
+            diverges: false,
         }
     }
 
@@ -122,8 +142,11 @@ impl ContractClauses {
         let bound_with_result =
             &bound.into_iter().chain(std::iter::once(name::result())).collect::<Box<_>>();
         let bound = bound_with_result.split_last().unwrap().1;
-        let has_user_contract =
-            !self.requires.is_empty() || !self.ensures.is_empty() || self.variant.is_some();
+        let has_user_contract = !self.requires.is_empty()
+            || !self.ensures.is_empty()
+            || !self.impls_ensure.is_empty()
+            || self.variant.is_some()
+            || self.diverges;
         let n_requires = self.requires.len();
         let mut requires = Vec::new();
         for req_id in self.requires {
@@ -149,6 +172,23 @@ impl ContractClauses {
             };
             ensures.push(Condition { term, expl });
         }
+        let n_impls_ensure = self.impls_ensure.len();
+        let mut impls_ensure = Vec::new();
+        for ens_id in self.impls_ensure {
+            log::trace!("impls_ensure clause {:?}", ens_id);
+            let term = ctx.term_fail_fast(ens_id).unwrap().rename(bound_with_result);
+            let expl = if n_impls_ensure == 1 {
+                format!("expl:{} impls ensure", fn_name)
+            } else {
+                format!("expl:{} impls ensure #{}", fn_name, impls_ensure.len())
+            };
+            impls_ensure.push(Condition { term, expl });
+        }
+        // The continuation of a call to a diverging function is unreachable.
+        if self.diverges {
+            let expl = format!("expl:{} diverges", fn_name);
+            ensures.push(Condition { term: Term::false_(ctx.tcx), expl });
+        }
 
         let mut variant = None;
         if let Some(var_id) = self.variant {
@@ -162,6 +202,7 @@ impl ContractClauses {
             variant,
             requires,
             ensures,
+            impls_ensure,
             no_panic: self.no_panic,
             terminates: self.terminates,
             extern_no_spec: false,
@@ -169,8 +210,26 @@ impl ContractClauses {
         })
     }
 
+    pub(crate) fn variant(&self) -> Option<DefId> {
+        self.variant
+    }
+
+    pub(crate) fn ensures(&self) -> &[DefId] {
+        &self.ensures
+    }
+
+    pub(crate) fn panics_when(&self) -> &[DefId] {
+        &self.panics_when
+    }
+
     pub(crate) fn iter_ids(&self) -> impl Iterator<Item = DefId> + '_ {
-        self.requires.iter().chain(self.ensures.iter()).chain(self.variant.iter()).cloned()
+        self.requires
+            .iter()
+            .chain(self.ensures.iter())
+            .chain(self.impls_ensure.iter())
+            .chain(self.panics_when.iter())
+            .chain(self.variant.iter())
+            .cloned()
     }
 }
 
@@ -202,6 +261,12 @@ pub(crate) fn contract_clauses_of(
     let ensures = creusot_clause_attrs(ctx.tcx, def_id, "ensures")
         .map(get_creusot_item)
         .collect::<Result<Vec<_>, _>>()?;
+    let impls_ensure = creusot_clause_attrs(ctx.tcx, def_id, "impls_ensure")
+        .map(get_creusot_item)
+        .collect::<Result<Vec<_>, _>>()?;
+    let panics_when = creusot_clause_attrs(ctx.tcx, def_id, "panics_when")
+        .map(get_creusot_item)
+        .collect::<Result<Vec<_>, _>>()?;
     let mut variant = None;
     for arg in creusot_clause_attrs(ctx.tcx, def_id, "variant") {
         if std::mem::replace(&mut variant, Some(get_creusot_item(arg)?)).is_some() {
@@ -210,8 +275,18 @@ pub(crate) fn contract_clauses_of(
     }
     let terminates = is_terminates(ctx.tcx, def_id);
     let no_panic = is_no_panic(ctx.tcx, def_id);
-
-    Ok(ContractClauses { requires, ensures, variant, terminates, no_panic })
+    let diverges = is_diverges(ctx.tcx, def_id);
+
+    Ok(ContractClauses {
+        requires,
+        ensures,
+        impls_ensure,
+        panics_when,
+        variant,
+        terminates,
+        no_panic,
+        diverges,
+    })
 }
 
 pub(crate) fn inherited_extern_spec<'tcx>(
@@ -257,6 +332,7 @@ pub(crate) fn contract_of<'tcx>(ctx: &TranslationCtx<'tcx>, def_id: DefId) -> Pr
         // We do NOT normalize the contract here. See below.
         let bound = spec.inputs.iter().map(|(ident, _, _)| ident.0);
         let contract = spec.contract.get_pre(ctx, fn_name, bound).instantiate(ctx.tcx, spec.subst);
+        let contract = trust_extern_variant(contract);
         PreSignature {
             inputs: EarlyBinder::bind(spec.inputs).instantiate(ctx.tcx, spec.subst),
             output: EarlyBinder::bind(spec.output).instantiate(ctx.tcx, spec.subst),
@@ -265,6 +341,8 @@ pub(crate) fn contract_of<'tcx>(ctx: &TranslationCtx<'tcx>, def_id: DefId) -> Pr
     } else if contract.is_empty()
         && let Some((parent_id, subst)) = inherited_extern_spec(ctx, def_id)
     {
+        // The impl comes from another crate: nothing checks that it refines the extern spec.
+        ctx.record_trusted_external_impl(ctx.parent(def_id));
         let spec = ctx.extern_spec(parent_id).cloned().unwrap();
         let bound = spec.inputs.iter().map(|(ident, _, _)| ident.0);
         // We do NOT normalize the contract here: indeed, we do not have a valid non-redundant param
@@ -272,6 +350,7 @@ pub(crate) fn contract_of<'tcx>(ctx: &TranslationCtx<'tcx>, def_id: DefId) -> Pr
         // and normalized in the caller context (such extern specs are only evaluated in the context
         // of a specific call).
         let contract = spec.contract.get_pre(ctx, fn_name, bound).instantiate(ctx.tcx, subst);
+        let contract = trust_extern_variant(contract);
         PreSignature {
             inputs: EarlyBinder::bind(spec.inputs).instantiate(ctx.tcx, subst),
             output: EarlyBinder::bind(spec.output).instantiate(ctx.tcx, subst),
@@ -289,11 +368,18 @@ pub(crate) fn contract_of<'tcx>(ctx: &TranslationCtx<'tcx>, def_id: DefId) -> Pr
                 expl: format!("expl:{} requires false", fn_name),
             });
         }
-        let contract = contract.normalize(ctx.tcx, ctx.typing_env(def_id));
+        let contract = contract.normalize(ctx, ctx.typing_env(def_id));
         PreSignature { inputs, output, contract }
     }
 }
 
+/// The body of a function with an extern spec is not verified, so a `#[variant]` in the extern
+/// spec cannot be checked: it is trusted, and justifies the termination of the function.
+fn trust_extern_variant<'tcx>(mut contract: PreContract<'tcx>) -> PreContract<'tcx> {
+    contract.terminates |= contract.variant.is_some();
+    contract
+}
+
 #[derive(TypeVisitable, TypeFoldable, Debug, Clone)]
 pub struct PreSignature<'tcx> {
     pub(crate) inputs: Box<[(PIdent, Span, Ty<'tcx>)]>,
@@ -302,8 +388,12 @@ pub struct PreSignature<'tcx> {
 }
 
 impl<'tcx> PreSignature<'tcx> {
-    pub(crate) fn normalize(mut self, tcx: TyCtxt<'tcx>, typing_env: TypingEnv<'tcx>) -> Self {
-        self.contract = self.contract.normalize(tcx, typing_env);
+    pub(crate) fn normalize(
+        mut self,
+        ctx: &TranslationCtx<'tcx>,
+        typing_env: TypingEnv<'tcx>,
+    ) -> Self {
+        self.contract = self.contract.normalize(ctx, typing_env);
         self
     }
 
@@ -319,16 +409,27 @@ impl<'tcx> PreSignature<'tcx> {
             None => "closure",
         };
 
+        // The methods of a `#[creusot::maintains_invariant]` impl block cannot open the type
+        // invariants, see `validate_maintains_invariant`.
+        let maintained = ctx
+            .impl_of_method(def_id)
+            .is_some_and(|impl_id| is_maintains_invariant(ctx.tcx, impl_id));
+
         let params_open_inv: HashSet<usize> = ctx
             .params_open_inv(def_id)
             .iter()
             .copied()
             .flatten()
+            .filter(|_| !maintained)
             .map(|&i| if ctx.tcx.is_closure_like(def_id) { i + 1 } else { i })
             .collect();
 
+        let mode =
+            if maintained { InvariantMode::default() } else { invariant_mode(ctx.tcx, def_id) };
+
         let new_requires = self.inputs.iter().enumerate().filter_map(|(i, (ident, span, ty))| {
-            if !params_open_inv.contains(&i)
+            if !mode.open_args
+                && !params_open_inv.contains(&i)
                 && let Some(term) = type_invariant_term(ctx, typing_env, ident.0, *span, *ty)
             {
                 let expl =
@@ -342,7 +443,8 @@ impl<'tcx> PreSignature<'tcx> {
 
         let ret_ty_span: Option<Span> =
             try { ctx.tcx.hir().get_fn_output(def_id.as_local()?)?.span() };
-        if !is_open_inv_result(ctx.tcx, def_id)
+        if !mode.open_result
+            && (maintained || !is_open_inv_result(ctx.tcx, def_id))
             && let Some(term) = type_invariant_term(
                 ctx,
                 typing_env,
@@ -361,6 +463,11 @@ pub(crate) fn pre_sig_of<'tcx>(ctx: &TranslationCtx<'tcx>, def_id: DefId) -> Pre
     let mut presig = contract_of(ctx, def_id);
     let contract = &mut presig.contract;
 
+    let mut fresh = Fresh { ctx, typing_env: ctx.typing_env(def_id), inputs: &presig.inputs };
+    for post in contract.ensures.iter_mut().chain(&mut contract.impls_ensure) {
+        fresh.visit_mut_term(&mut post.term);
+    }
+
     let fn_ty = ctx.tcx.type_of(def_id).instantiate_identity();
 
     if let TyKind::Closure(_, subst) = fn_ty.kind() {
@@ -427,9 +534,55 @@ pub(crate) fn pre_sig_of<'tcx>(ctx: &TranslationCtx<'tcx>, def_id: DefId) -> Pre
         }
     }
 
+    for cond in presig.contract.requires.iter_mut().chain(&mut presig.contract.ensures) {
+        simplify(ctx.tcx, &ctx.opts.simplify, &mut cond.term);
+    }
+
     presig
 }
 
+/// Replaces `fresh(x)` in a postcondition by the fact that `x` does not alias the inputs of the
+/// function, see `creusot_contracts::fresh`.
+struct Fresh<'a, 'tcx> {
+    ctx: &'a TranslationCtx<'tcx>,
+    typing_env: TypingEnv<'tcx>,
+    inputs: &'a [(PIdent, Span, Ty<'tcx>)],
+}
+
+impl<'tcx> Fresh<'_, 'tcx> {
+    /// The address of `ptr`, if it is a raw pointer.
+    fn addr(&self, ptr: Term<'tcx>) -> Option<Term<'tcx>> {
+        let TyKind::RawPtr(pointee, _) = ptr.ty.kind() else { return None };
+        let subst = self.ctx.mk_args(&[GenericArg::from(ptr.ty), GenericArg::from(*pointee)]);
+        Some(Term::call(self.ctx.tcx, self.typing_env, get_addr_logic(self.ctx.tcx), subst, [ptr]))
+    }
+}
+
+impl<'tcx> TermVisitorMut<'tcx> for Fresh<'_, 'tcx> {
+    fn visit_mut_term(&mut self, term: &mut Term<'tcx>) {
+        super_visit_mut_term(term, self);
+        let TermKind::Call { id, args, .. } = &term.kind else { return };
+        if !is_fresh(self.ctx.tcx, *id) {
+            return;
+        }
+        let arg = &args[0];
+        if arg.ty.is_ref() && !arg.ty.is_mutable_ptr() {
+            self.ctx.error(term.span, "a shared reference cannot be `fresh`").emit();
+        }
+        let fresh = match self.addr(arg.clone()) {
+            // Owned values and mutable borrows are not aliased.
+            None => Term::true_(self.ctx.tcx),
+            Some(addr) => self
+                .inputs
+                .iter()
+                .filter_map(|&(ident, _, ty)| self.addr(Term::var(ident, ty)))
+                .map(|input| addr.clone().bin_op(self.ctx.types.bool, BinOp::Ne, input))
+                .fold(Term::true_(self.ctx.tcx), Term::conj),
+        };
+        *term = fresh.span(term.span);
+    }
+}
+
 pub fn inputs_and_output_from_thir<'tcx>(
     ctx: &TranslationCtx<'tcx>,
     def_id: DefId,
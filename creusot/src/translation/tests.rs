@@ -0,0 +1,72 @@
+use super::{modular_output, survives_round_trip, write_solver_log};
+use crate::{naming::ModulePath, test_support::*, translated_item::FileModule};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use why3::{
+    declaration::{Decl, Module},
+    printer::{Why3Version, render_module},
+};
+
+fn print(modl: &Module) -> String {
+    let mut out = Vec::new();
+    render_module(modl, Why3Version::V1_8, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn module_survives_round_trip() {
+    let modl = Module {
+        name: "M".into(),
+        decls: Box::new([Decl::Comment("a comment".into())]),
+        attrs: Vec::new(),
+        meta: Some("meta".into()),
+    };
+    assert!(survives_round_trip(&modl, print));
+}
+
+/// A node whose serialization drops a field that is printed.
+#[derive(Serialize, Deserialize)]
+struct Lossy {
+    name: String,
+    #[serde(skip)]
+    attr: Option<String>,
+}
+
+#[test]
+fn lossy_node_is_caught() {
+    let print = |node: &Lossy| format!("{} {:?}", node.name, node.attr);
+    let node = Lossy { name: "x".into(), attr: Some("attr".into()) };
+    assert!(!survives_round_trip(&node, print));
+    let node = Lossy { name: "x".into(), attr: None };
+    assert!(survives_round_trip(&node, print));
+}
+
+// Each module gets its own file, named by the module, with the contents of the modular output.
+#[test]
+fn solver_log_writes_one_file_per_module() {
+    with_tcx("pub fn f() {} pub fn g() {}", |tcx| {
+        let module = |item: &str, name: &str| FileModule {
+            path: ModulePath::new(tcx, local_item(tcx, item)),
+            modl: Module {
+                name: name.into(),
+                decls: Box::new([Decl::Comment(item.into())]),
+                attrs: Vec::new(),
+                meta: None,
+            },
+        };
+        let modules = [module("f", "M_f"), module("g", "M_g")];
+
+        let dir = tempdir::TempDir::new("creusot-test").unwrap();
+        let log = dir.path().join("solver-log");
+        for modl in &modules {
+            write_solver_log(&log, modl, Why3Version::V1_8).unwrap();
+        }
+        assert_eq!(fs::read_dir(&log).unwrap().count(), 2);
+        for modl in &modules {
+            let mut expected = Vec::new();
+            modular_output(modl, Why3Version::V1_8, &mut expected).unwrap();
+            let written = fs::read(log.join(format!("{}.coma", modl.modl.name))).unwrap();
+            assert_eq!(written, expected);
+        }
+    })
+}
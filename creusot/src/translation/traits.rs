@@ -1,7 +1,16 @@
-use super::pearlite::{Term, TermKind};
+use super::pearlite::{Pattern, Term, TermKind, simplify};
 use crate::{
-    contracts_items::{is_law, is_pearlite, is_spec},
+    contracts_items::{
+        get_add_spec_add_logic, get_add_spec_trait, get_appended, get_borrow_to_owned_consistent,
+        get_builtin, get_default_spec_is_default, get_default_spec_trait, get_deref_spec_target,
+        get_deref_spec_trait, get_display_spec_trait, get_display_spec_writes,
+        get_extend_spec_trait, get_mul_spec_mul_logic, get_mul_spec_trait, get_ord_cmp_laws,
+        get_round_trip_from_logic, get_round_trip_trait, get_try_from_spec_returns,
+        get_try_from_spec_trait, is_default_trait, is_deref, is_deref_mut, is_forall_impls,
+        is_from_trait, is_law, is_ord_trait, is_pearlite, is_spec, is_to_owned_trait, is_trusted,
+    },
     ctx::*,
+    error::RefinementError,
     naming::name,
     util::erased_identity_for_item,
     very_stable_hash::get_very_stable_hash,
@@ -11,21 +20,25 @@ use rustc_infer::{
     infer::{DefineOpaqueTypes, InferCtxt, TyCtxtInferExt},
     traits::{Obligation, ObligationCause, TraitEngine},
 };
-use rustc_middle::ty::{
-    Const, ConstKind, EarlyBinder, GenericArgsRef, ParamConst, ParamEnv, ParamTy, Predicate,
-    TraitRef, Ty, TyCtxt, TyKind, TypeFoldable, TypeFolder, TypingEnv, TypingMode,
+use rustc_middle::{
+    traits::{BuiltinImplSource, CodegenObligationError},
+    ty::{
+        AliasTyKind, Const, ConstKind, EarlyBinder, GenericArgsRef, ParamConst, ParamEnv, ParamTy,
+        Predicate, TraitRef, Ty, TyCtxt, TyKind, TypeFoldable, TypeFolder, TypingEnv, TypingMode,
+    },
 };
+use rustc_session::config::CrateType;
 use rustc_span::{DUMMY_SP, Span};
 use rustc_trait_selection::{
     error_reporting::InferCtxtErrorExt,
+    infer::InferCtxtExt as _,
     traits::{FulfillmentError, ImplSource, InCrate, TraitEngineExt, orphan_check_trait_ref},
 };
 use rustc_type_ir::fold::TypeSuperFoldable;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
 pub(crate) struct Refinement<'tcx> {
-    #[allow(dead_code)]
     pub(crate) trait_: (DefId, GenericArgsRef<'tcx>),
     pub(crate) impl_: (DefId, GenericArgsRef<'tcx>),
     pub(crate) refn: Term<'tcx>,
@@ -54,7 +67,61 @@ impl<'tcx> TranslationCtx<'tcx> {
         laws
     }
 
-    pub(crate) fn translate_impl(&self, impl_id: DefId) -> TraitImpl<'tcx> {
+    /// The refinement obligation of the function `impl_item`, which implements `trait_item`: the
+    /// contract of the implementation must conform to the one of the trait, instantiated with the
+    /// arguments of the trait in the impl.
+    ///
+    /// Fails if the additional bounds of the extern spec of `trait_item` do not hold for the impl.
+    pub(crate) fn refinement(
+        &self,
+        trait_item: DefId,
+        impl_item: DefId,
+    ) -> Result<Refinement<'tcx>, RefinementError> {
+        let impl_id = self.tcx.parent(impl_item);
+        let trait_ref = self.tcx.impl_trait_ref(impl_id).unwrap().instantiate_identity();
+        let subst = erased_identity_for_item(self.tcx, impl_item);
+        let refn_subst = subst.rebase_onto(self.tcx, impl_id, trait_ref.args);
+
+        // TODO: Clean up and abstract
+        let predicates = self
+            .extern_spec(trait_item)
+            .map(|p| p.predicates_for(self.tcx, refn_subst))
+            .unwrap_or_else(Vec::new);
+
+        let infcx = self.tcx.infer_ctxt().ignoring_regions().build(TypingMode::non_body_analysis());
+
+        let res = evaluate_additional_predicates(
+            &infcx,
+            predicates,
+            self.param_env(impl_item),
+            self.def_span(impl_item),
+        );
+        if let Err(errs) = res {
+            infcx.err_ctxt().report_fulfillment_errors(errs);
+            let err =
+                RefinementError::PredicateUnfulfilled { impl_item, span: self.def_span(impl_item) };
+            // Otherwise, the error is reported with the other errors of the impl by `lower_impl`.
+            if self.opts.fail_fast {
+                err.emit(self.tcx);
+                self.abort_if_fail_fast();
+            }
+            return Err(err);
+        }
+
+        let mut refn = logic_refinement_term(self, impl_item, trait_item, refn_subst);
+        if let Some(spec) = spec_trait_term(self, impl_item, trait_item, refn_subst) {
+            refn = refn.conj(spec);
+        }
+        Ok(Refinement { trait_: (trait_item, refn_subst), impl_: (impl_item, subst), refn })
+    }
+
+    /// Gather the laws of an impl, and the refinement obligations of its items.
+    ///
+    /// All the errors found in the items of the impl are returned.
+    pub(crate) fn translate_impl(
+        &self,
+        impl_id: DefId,
+    ) -> Result<TraitImpl<'tcx>, Vec<RefinementError>> {
         assert!(self.trait_id_of_impl(impl_id).is_some(), "{impl_id:?} is not a trait impl");
         let trait_ref = self.tcx.impl_trait_ref(impl_id).unwrap().instantiate_identity();
 
@@ -62,11 +129,14 @@ impl<'tcx> TranslationCtx<'tcx> {
         let implementor_map = self.tcx.impl_item_implementor_ids(impl_id);
 
         let mut refinements = Vec::new();
+        let mut errors = Vec::new();
         let mut implementor_map =
             self.with_stable_hashing_context(|hcx| implementor_map.to_sorted(&hcx, true));
         implementor_map.sort_by_cached_key(|(trait_item, impl_item)| {
             get_very_stable_hash(&[**trait_item, **impl_item] as &[_], &self.tcx)
         });
+        let overridden: HashSet<DefId> =
+            implementor_map.iter().map(|&(&trait_item, _)| trait_item).collect();
         for (&trait_item, &impl_item) in implementor_map {
             if is_law(self.tcx, trait_item) {
                 laws.push(impl_item);
@@ -74,46 +144,136 @@ impl<'tcx> TranslationCtx<'tcx> {
 
             // Don't generate refinements for impls that come from outside crates
             if !impl_id.is_local() {
+                self.record_trusted_external_impl(impl_id);
                 continue;
             }
 
-            let subst = erased_identity_for_item(self.tcx, impl_item);
-
-            let refn_subst = subst.rebase_onto(self.tcx, impl_id, trait_ref.args);
-
             if !self.tcx.def_kind(trait_item).is_fn_like() {
                 continue;
             }
 
-            // TODO: Clean up and abstract
-            let predicates = self
-                .extern_spec(trait_item)
-                .map(|p| p.predicates_for(self.tcx, refn_subst))
-                .unwrap_or_else(Vec::new);
-
-            let infcx =
-                self.tcx.infer_ctxt().ignoring_regions().build(TypingMode::non_body_analysis());
-
-            let res = evaluate_additional_predicates(
-                &infcx,
-                predicates,
-                self.param_env(impl_item),
-                self.def_span(impl_item),
-            );
-            if let Err(errs) = res {
-                infcx.err_ctxt().report_fulfillment_errors(errs);
-                self.crash_and_error(rustc_span::DUMMY_SP, "error above");
+            match self.refinement(trait_item, impl_item) {
+                Ok(refn) => refinements.push(refn),
+                Err(err) => errors.push(err),
             }
 
-            let refn = logic_refinement_term(self, impl_item, trait_item, refn_subst);
-            refinements.push(Refinement {
-                trait_: (trait_item, refn_subst),
-                impl_: (impl_item, subst),
-                refn,
-            });
+            // With specialization, the item may override a `default` item of a less specialized
+            // impl. The callers which resolve to this base impl may run this item instead, so it
+            // must also conform to the contract of the item it overrides.
+            if let Some((base_item, base_subst)) =
+                self.overridden_item(impl_id, trait_item, impl_item)
+            {
+                refinements.push(Refinement {
+                    trait_: (base_item, base_subst),
+                    impl_: (impl_item, erased_identity_for_item(self.tcx, impl_item)),
+                    refn: logic_refinement_term(self, impl_item, base_item, base_subst),
+                });
+            }
         }
 
-        TraitImpl { laws, refinements }
+        // Laws which are proved for each impl: the `#[forall_impls]` laws of the trait which use
+        // their default body, and the laws relating the trait to other traits.
+        if impl_id.is_local() {
+            let mut impl_laws = Vec::new();
+            for &law in self.laws(trait_ref.def_id) {
+                if !is_forall_impls(self.tcx, law) || overridden.contains(&law) {
+                    continue;
+                }
+                let law_subst = erased_identity_for_item(self.tcx, law).rebase_onto(
+                    self.tcx,
+                    trait_ref.def_id,
+                    trait_ref.args,
+                );
+                let typing_env = TypingEnv::non_body_analysis(self.tcx, impl_id);
+                if !law_applies(self.tcx, law, law_subst, typing_env) {
+                    continue;
+                }
+                impl_laws.push((law, law_subst));
+            }
+            impl_laws.extend(self.cross_trait_laws(impl_id, trait_ref.self_ty()));
+
+            for (law, law_subst) in impl_laws {
+                refinements.push(Refinement {
+                    trait_: (law, law_subst),
+                    impl_: (impl_id, erased_identity_for_item(self.tcx, impl_id)),
+                    refn: law_instance_term(self, impl_id, law, law_subst),
+                });
+            }
+        }
+
+        for refn in &mut refinements {
+            simplify(self.tcx, &self.opts.simplify, &mut refn.refn);
+        }
+
+        if errors.is_empty() { Ok(TraitImpl { laws, refinements }) } else { Err(errors) }
+    }
+
+    /// Laws relating the items of several traits, which are expected of the types implementing all
+    /// of them.
+    ///
+    /// Such a law does not belong to any of the traits, so it is proved at the local impl `impl_id`
+    /// of one of them, for its self type `ty`. It is then a law of this impl: it only holds for
+    /// the types of the local impls, and is not available otherwise.
+    pub(crate) fn cross_trait_laws(
+        &self,
+        impl_id: DefId,
+        ty: Ty<'tcx>,
+    ) -> Vec<(DefId, GenericArgsRef<'tcx>)> {
+        let tcx = self.tcx;
+        let Some(trait_id) = tcx.trait_id_of_impl(impl_id) else { return Vec::new() };
+        let mut laws = Vec::new();
+
+        // `x.to_owned().borrow()@ == x@`, proved at the impl of `ToOwned`: its `Owned` type always
+        // implements `Borrow<Self>`.
+        if is_to_owned_trait(tcx, trait_id) {
+            let law = get_borrow_to_owned_consistent(tcx);
+            let law_subst = tcx.mk_args(&[ty.into()]);
+            if law_applies(tcx, law, law_subst, TypingEnv::non_body_analysis(tcx, impl_id)) {
+                laws.push((law, law_subst));
+            }
+        }
+
+        // `cmp` is a total order and `partial_cmp` agrees with it, proved at the impl of `Ord`.
+        if is_ord_trait(tcx, trait_id) {
+            let law = get_ord_cmp_laws(tcx);
+            let law_subst = tcx.mk_args(&[ty.into()]);
+            if law_applies(tcx, law, law_subst, TypingEnv::non_body_analysis(tcx, impl_id)) {
+                laws.push((law, law_subst));
+            }
+        }
+
+        laws
+    }
+
+    /// The item of a less specialized impl overridden by `impl_item`, the item of `impl_id` which
+    /// implements `trait_item`, with its arguments in the generic parameters of `impl_item`.
+    fn overridden_item(
+        &self,
+        impl_id: DefId,
+        trait_item: DefId,
+        impl_item: DefId,
+    ) -> Option<(DefId, GenericArgsRef<'tcx>)> {
+        let tcx = self.tcx;
+        let trait_id = tcx.trait_id_of_impl(impl_id)?;
+        let (node, base_item) = tcx
+            .trait_def(trait_id)
+            .ancestors(tcx, impl_id)
+            .ok()?
+            .skip(1)
+            .take_while(|node| !node.is_from_trait())
+            .find_map(|node| Some((node, node.item(tcx, trait_item)?.def_id)))?;
+
+        let infcx = tcx.infer_ctxt().build(TypingMode::non_body_analysis());
+        let base_args = rustc_trait_selection::traits::translate_args(
+            &infcx,
+            self.param_env(impl_id),
+            impl_id,
+            erased_identity_for_item(tcx, impl_id),
+            node,
+        );
+        let base_subst =
+            erased_identity_for_item(tcx, impl_item).rebase_onto(tcx, impl_id, base_args);
+        Some((base_item, base_subst))
     }
 }
 
@@ -128,9 +288,30 @@ fn logic_refinement_term<'tcx>(
     // Get the contract of the trait version
     let mut trait_sig = EarlyBinder::bind(ctx.sig(trait_item_id).clone())
         .instantiate(ctx.tcx, refn_subst)
-        .normalize(ctx.tcx, typing_env);
-
-    let mut impl_sig = ctx.sig(impl_item_id).clone();
+        .normalize(ctx, typing_env);
+
+    // Normalize the impl signature too: logic functions of the trait called in the contract of the
+    // impl (e.g. `result == self.spec_fn(x)`) then resolve to their definition for this impl,
+    // like the calls in the trait contract. Both sides of the refinement refer to the same
+    // functions, even when the impl overrides a default definition of the trait.
+    let mut impl_sig = ctx.sig(impl_item_id).clone().normalize(ctx, typing_env);
+
+    // The argument and return types of the trait signature may mention generic parameters of
+    // the impl or `Self` through projections: normalize them so that the type invariants of
+    // those types (e.g. `T: Invariant`) are resolved in the same way on both sides of the
+    // refinement, and so that `result` gets the concrete type of the impl (e.g. for a method
+    // returning `Self`).
+    for (_, _, ty) in trait_sig.inputs.iter_mut() {
+        *ty = ctx.normalize_erasing_regions(typing_env, *ty);
+    }
+    trait_sig.output = ctx.normalize_erasing_regions(typing_env, trait_sig.output);
+    // The types of the impl are normalized in the same way, so that the binders agree with the
+    // trait: e.g. an impl returning `&'a T` where the trait returns `Self::Item`, which resolves to
+    // `&'a T` for this impl.
+    for (_, _, ty) in impl_sig.inputs.iter_mut() {
+        *ty = ctx.normalize_erasing_regions(typing_env, *ty);
+    }
+    impl_sig.output = ctx.normalize_erasing_regions(typing_env, impl_sig.output);
 
     if !is_pearlite(ctx.tcx, impl_item_id) {
         trait_sig.add_type_invariant_spec(ctx, trait_item_id, typing_env);
@@ -138,6 +319,21 @@ fn logic_refinement_term<'tcx>(
     }
 
     let span = ctx.tcx.def_span(impl_item_id);
+    // The trait item and its implementation normally have the same arity (rustc checks that they
+    // agree on the presence of a receiver), so the zip below binds every input, including when
+    // there is none at all (e.g. `fn new() -> Self`). In this last case, the refinement only
+    // quantifies over `result`.
+    if trait_sig.inputs.len() != impl_sig.inputs.len() {
+        ctx.crash_and_error(
+            span,
+            &format!(
+                "this item takes {} argument(s), but its trait item `{}` takes {}",
+                impl_sig.inputs.len(),
+                ctx.def_path_str(trait_item_id),
+                trait_sig.inputs.len()
+            ),
+        )
+    }
     let mut args = Vec::new();
     let mut subst = HashMap::new();
     for (&(id, _, _), (id2, _, ty)) in trait_sig.inputs.iter().zip(impl_sig.inputs.iter()) {
@@ -151,12 +347,18 @@ fn logic_refinement_term<'tcx>(
 
     let mut impl_postcond = impl_sig.contract.ensures_conj(ctx.tcx);
     impl_postcond.subst(&subst);
-    let trait_postcond = trait_sig.contract.ensures_conj(ctx.tcx);
+    // The `#[impls_ensure]` clauses of the trait item are only obligations of its impls.
+    let trait_postcond = trait_sig
+        .contract
+        .impls_ensure
+        .iter()
+        .fold(trait_sig.contract.ensures_conj(ctx.tcx), |post, cond| post.conj(cond.term.clone()));
 
     let retty = impl_sig.output;
 
-    let post_refn =
-        impl_postcond.implies(trait_postcond).forall((name::result().into(), retty)).span(span);
+    let post_refn = split_on_variants(ctx, retty, impl_postcond.implies(trait_postcond))
+        .forall((name::result().into(), retty))
+        .span(span);
 
     let mut refn = trait_precond.implies(impl_precond.conj(post_refn));
     refn = args.into_iter().rfold(refn, |acc, r| acc.forall(r).span(span));
@@ -164,6 +366,239 @@ fn logic_refinement_term<'tcx>(
     refn
 }
 
+/// Enums with more variants than this are not case-split by `split_on_variants`.
+const MAX_SPLIT_VARIANTS: usize = 4;
+
+/// If `result` has an enum type, case-split `body` on its variant.
+///
+/// This helps the solvers when the impl and the trait specify the result variant by variant (e.g.
+/// the impl always returns `Ok`, while the trait allows both `Ok` and `Err`): each case of the
+/// refinement then only involves the postconditions about one variant.
+///
+/// Each arm holds a copy of `body`, and the solvers split the goal again on the matches it
+/// already contains, so the split is only done for enums with few variants.
+fn split_on_variants<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    ty: Ty<'tcx>,
+    body: Term<'tcx>,
+) -> Term<'tcx> {
+    let TyKind::Adt(adt, args) = ty.kind() else { return body };
+    if !adt.is_enum()
+        || !(2..=MAX_SPLIT_VARIANTS).contains(&adt.variants().len())
+        || is_trusted(ctx.tcx, adt.did())
+        || get_builtin(ctx.tcx, adt.did()).is_some()
+    {
+        return body;
+    }
+    let arms = adt
+        .variants()
+        .iter_enumerated()
+        .map(|(varidx, var)| {
+            let fields = var.fields.iter().map(|f| Pattern::wildcard(f.ty(ctx.tcx, args)));
+            (Pattern::constructor(varidx, fields, ty), body.clone())
+        })
+        .collect();
+    Term {
+        ty: ctx.types.bool,
+        kind: TermKind::Match { scrutinee: Box::new(Term::var(name::result(), ty)), arms },
+        span: body.span,
+    }
+}
+
+/// The statement of the law `law`, instantiated with `law_subst` for the impl `impl_id`.
+fn law_instance_term<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    impl_id: DefId,
+    law: DefId,
+    law_subst: GenericArgsRef<'tcx>,
+) -> Term<'tcx> {
+    let typing_env = TypingEnv::non_body_analysis(ctx.tcx, impl_id);
+    let sig = EarlyBinder::bind(ctx.sig(law).clone())
+        .instantiate(ctx.tcx, law_subst)
+        .normalize(ctx, typing_env);
+
+    let span = ctx.tcx.def_span(impl_id);
+    let refn = sig.contract.requires_conj(ctx.tcx).implies(sig.contract.ensures_conj(ctx.tcx));
+    sig.inputs.iter().rfold(refn, |acc, &(id, _, ty)| {
+        acc.forall((id, ctx.normalize_erasing_regions(typing_env, ty))).span(span)
+    })
+}
+
+/// How a spec trait of `creusot_contracts` specifies the required method of the trait it extends.
+#[derive(Clone, Copy)]
+enum SpecPost {
+    /// `RoundTrip<T>`: `From::from` determines its result to be `RoundTrip::from_logic(value)`.
+    RoundTrip,
+    /// `TryFromSpec<T>`: `TryFrom::try_from` implies `TryFromSpec::returns`, it fails exactly
+    /// when `TryFromSpec::fails` holds.
+    TryFrom,
+    /// `DefaultSpec`: the value returned by `Default::default` satisfies `is_default`.
+    Default,
+    /// `DerefSpec`: the reference returned by `deref` points to `DerefSpec::target`, and the
+    /// final value of the reference returned by `deref_mut` is the target of the final value of
+    /// the pointer.
+    Deref { mutable: bool },
+    /// `AddSpec` or `MulSpec`: the operator determines its result to be the given model of its
+    /// arguments, so that the algebraic laws stated on the model hold for the operator.
+    Operator(DefId),
+    /// `ExtendSpec<A>`: `Extend::extend` implies `Appended::appended`, the items produced by the
+    /// iterator are appended to the view of the collection.
+    Extend,
+    /// `DisplaySpec`: `Display::fmt` implies `DisplaySpec::writes`, on success the characters
+    /// `displayed` are appended to the formatter.
+    Display,
+}
+
+/// The spec trait which specifies `trait_item`, with its arguments for the instance `refn_subst`
+/// of the trait.
+///
+/// Only the required method of a trait is specified, e.g. `extend` and not `extend_one`.
+fn spec_trait_of<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    trait_item: DefId,
+    refn_subst: GenericArgsRef<'tcx>,
+) -> Option<(DefId, GenericArgsRef<'tcx>, SpecPost)> {
+    let trait_id = tcx.trait_of_item(trait_item)?;
+    if tcx.defaultness(trait_item).has_value() {
+        return None;
+    }
+    let self_ty = refn_subst.type_at(0);
+    let self_args = tcx.mk_args(&[self_ty.into()]);
+    let lang_items = tcx.lang_items();
+    let spec = if is_from_trait(tcx, trait_id) {
+        (get_round_trip_trait(tcx), tcx.mk_args(&refn_subst[..2]), SpecPost::RoundTrip)
+    } else if is_default_trait(tcx, trait_id) {
+        (get_default_spec_trait(tcx), self_args, SpecPost::Default)
+    } else if is_deref(tcx, trait_item) || is_deref_mut(tcx, trait_item) {
+        let mutable = is_deref_mut(tcx, trait_item);
+        (get_deref_spec_trait(tcx), self_args, SpecPost::Deref { mutable })
+    } else if Some(trait_id) == lang_items.add_trait() || Some(trait_id) == lang_items.mul_trait() {
+        // The model only describes the operator with `Rhs = Self`.
+        if refn_subst.type_at(1) != self_ty {
+            return None;
+        }
+        let (spec_trait, model) = if Some(trait_id) == lang_items.add_trait() {
+            (get_add_spec_trait(tcx), get_add_spec_add_logic(tcx))
+        } else {
+            (get_mul_spec_trait(tcx), get_mul_spec_mul_logic(tcx))
+        };
+        (spec_trait, self_args, SpecPost::Operator(model))
+    } else {
+        // `TryFrom`, `Extend` and `Display` are not diagnostic items: they are found as the
+        // supertrait of their spec trait.
+        let extends =
+            |spec| trait_id != spec && tcx.supertrait_def_ids(spec).any(|id| id == trait_id);
+        if extends(get_try_from_spec_trait(tcx)) {
+            (get_try_from_spec_trait(tcx), tcx.mk_args(&refn_subst[..2]), SpecPost::TryFrom)
+        } else if extends(get_extend_spec_trait(tcx)) {
+            (get_extend_spec_trait(tcx), tcx.mk_args(&refn_subst[..2]), SpecPost::Extend)
+        } else if extends(get_display_spec_trait(tcx)) {
+            (get_display_spec_trait(tcx), self_args, SpecPost::Display)
+        } else {
+            return None;
+        }
+    };
+    Some(spec)
+}
+
+/// For an impl of a trait method specified by a spec trait (see [`SpecPost`]), whose type also
+/// implements the spec trait, the contract of the impl must imply the property stated by the spec
+/// trait.
+///
+/// The inputs and the result of the impl satisfy their type invariants (this is part of the
+/// contract that the body is proved against), so they can be used to prove the property.
+fn spec_trait_term<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    impl_item_id: DefId,
+    trait_item_id: DefId,
+    refn_subst: GenericArgsRef<'tcx>,
+) -> Option<Term<'tcx>> {
+    let tcx = ctx.tcx;
+    let (spec_trait, spec_args, spec) = spec_trait_of(tcx, trait_item_id, refn_subst)?;
+    let typing_env = TypingEnv::non_body_analysis(tcx, impl_item_id);
+    let (infcx, param_env) = tcx.infer_ctxt().build_with_typing_env(typing_env);
+    if !infcx.type_implements_trait(spec_trait, spec_args, param_env).must_apply_modulo_regions() {
+        return None;
+    }
+
+    let mut impl_sig = ctx.sig(impl_item_id).clone().normalize(ctx, typing_env);
+    impl_sig.add_type_invariant_spec(ctx, impl_item_id, typing_env);
+    let inputs: Vec<_> = impl_sig.inputs.iter().map(|&(id, _, ty)| Term::var(id, ty)).collect();
+    let result = Term::var(name::result(), impl_sig.output);
+    let call = |id, args, terms: Vec<Term<'tcx>>| Term::call(tcx, typing_env, id, args, terms);
+    let post = match (spec, &inputs[..]) {
+        (SpecPost::RoundTrip, [value]) => {
+            result.eq(tcx, call(get_round_trip_from_logic(tcx), spec_args, vec![value.clone()]))
+        }
+        (SpecPost::TryFrom, [value]) => {
+            call(get_try_from_spec_returns(tcx), spec_args, vec![value.clone(), result])
+        }
+        (SpecPost::Default, []) => call(get_default_spec_is_default(tcx), spec_args, vec![result]),
+        (SpecPost::Deref { mutable }, [ptr]) => {
+            let target = |ptr| call(get_deref_spec_target(tcx), spec_args, vec![ptr]);
+            if mutable {
+                let cur = result.clone().cur().eq(tcx, target(ptr.clone().cur()));
+                cur.conj(result.fin().eq(tcx, target(ptr.clone().fin())))
+            } else {
+                let target_ty = impl_sig.output.builtin_deref(true)?;
+                let self_ty = refn_subst.type_at(0);
+                result.coerce(target_ty).eq(tcx, target(ptr.clone().coerce(self_ty)))
+            }
+        }
+        (SpecPost::Operator(model), [lhs, rhs]) => {
+            result.eq(tcx, call(model, spec_args, vec![lhs.clone(), rhs.clone()]))
+        }
+        (SpecPost::Extend, [this, iter]) => {
+            let args = tcx.mk_args(&[refn_subst[0], refn_subst[1], iter.ty.into()]);
+            let (cur, fin) = (this.clone().cur(), this.clone().fin());
+            call(get_appended(tcx), args, vec![cur, iter.clone(), fin])
+        }
+        (SpecPost::Display, [this, f]) => {
+            call(get_display_spec_writes(tcx), spec_args, vec![this.clone(), f.clone(), result])
+        }
+        _ => return None,
+    };
+
+    let span = ctx.tcx.def_span(impl_item_id);
+    let post = impl_sig
+        .contract
+        .ensures_conj(tcx)
+        .implies(post)
+        .forall((name::result().into(), impl_sig.output))
+        .span(span);
+    let refn = impl_sig.contract.requires_conj(tcx).implies(post);
+    Some(impl_sig.inputs.iter().rfold(refn, |refn, &(id, _, ty)| refn.forall((id, ty)).span(span)))
+}
+
+/// Do the `where` clauses of the law `law` itself hold for `subst` in `typing_env`?
+///
+/// A law with such clauses, like `fn cmp_mono(...) where Self: Ord`, only holds for the instances
+/// of its trait which satisfy them. It is only made available for these instances, and only
+/// proved at their impls.
+///
+/// The clauses are normalized first, so that the constants they mention are evaluated: e.g.
+/// `[T; N]: Default` holds for `N = 2 * 2`, but not for `N = 40`.
+pub(crate) fn law_applies<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    law: DefId,
+    subst: GenericArgsRef<'tcx>,
+    typing_env: TypingEnv<'tcx>,
+) -> bool {
+    let predicates: Vec<_> = tcx
+        .predicates_of(law)
+        .instantiate_own(tcx, subst)
+        .map(|(clause, _)| {
+            let predicate = clause.as_predicate();
+            tcx.try_normalize_erasing_regions(typing_env, predicate).unwrap_or(predicate)
+        })
+        .collect();
+    if predicates.is_empty() {
+        return true;
+    }
+    let (infcx, param_env) = tcx.infer_ctxt().build_with_typing_env(typing_env);
+    evaluate_additional_predicates(&infcx, predicates, param_env, tcx.def_span(law)).is_ok()
+}
+
 pub(crate) fn evaluate_additional_predicates<'tcx>(
     infcx: &InferCtxt<'tcx>,
     p: Vec<Predicate<'tcx>>,
@@ -183,13 +618,13 @@ pub(crate) fn evaluate_additional_predicates<'tcx>(
 
 /// The result of [`Self::resolve_assoc_item_opt`]: given the id of a trait item and some
 /// type parameters, we might find an actual implementation of the item.
-#[derive(Debug)]
-pub(crate) enum TraitResolved<'tcx> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraitResolved<'tcx> {
     NotATraitItem,
     /// An instance (like `impl Clone for i32 { ... }`) exists for the given type parameters.
     Instance(DefId, GenericArgsRef<'tcx>),
     /// A known instance exists, but we don't know which one.
-    UnknownFound,
+    UnknownFound(UnknownReason),
     /// We don't know if an instance exists.
     UnknownNotFound,
     /// We know that no instance exists.
@@ -197,6 +632,46 @@ pub(crate) enum TraitResolved<'tcx> {
     /// For example, in `fn<T> f(x: T) { let _ = x.clone() }`, we  don't have an
     /// instance for `T::clone` until we know more about `T`.
     NoInstance,
+    /// Trait selection was ambiguous: several bounds of the typing environment apply (e.g. `T:
+    /// Lt<'a> + Lt<'b>` once regions are erased), or selection reached the recursion limit.
+    Ambiguous,
+}
+
+/// Why a trait item resolved to [`TraitResolved::UnknownFound`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownReason {
+    /// The instance is a bound of the typing environment, like `T: Clone` in a function generic
+    /// over `T`.
+    ParamSource,
+    /// The item of the impl `base` is `default`, and may be overridden by a more specialized
+    /// impl.
+    StillSpecializable { base: DefId },
+    /// The instance is provided by the compiler, e.g. for a trait object, a function pointer, or
+    /// an opaque type outside of its defining scope.
+    Builtin,
+}
+
+impl UnknownReason {
+    /// Explain why a call of `item` is not resolved to the item of an impl, for a diagnostic.
+    pub(crate) fn explain(self, tcx: TyCtxt, item: DefId) -> String {
+        let item = tcx.def_path_str(item);
+        match self {
+            UnknownReason::ParamSource => format!(
+                "`{item}` is not resolved to an impl, because its impl is only known from a bound \
+                 of the generic parameters: the specification of the trait item is used"
+            ),
+            UnknownReason::StillSpecializable { base } => format!(
+                "`{item}` is not resolved to an impl, because the item of `{}` is `default` and \
+                 may be specialized: remove `default` to use this implementation",
+                tcx.def_path_str(base)
+            ),
+            UnknownReason::Builtin => format!(
+                "`{item}` is not resolved to an impl, because its impl is provided by the \
+                 compiler (e.g. for a trait object, a function pointer or an opaque type): the \
+                 specification of the trait item is used"
+            ),
+        }
+    }
 }
 
 impl<'tcx> TraitResolved<'tcx> {
@@ -204,12 +679,14 @@ impl<'tcx> TraitResolved<'tcx> {
     ///
     /// # Parameters
     /// - `tcx`: The global context
+    /// - `closed_crates`: The crates whose traits have no unknown implementations.
     /// - `typing_env`: The scope of type variables, see <https://rustc-dev-guide.rust-lang.org/param_env/param_env_summary.html>.
     /// - `trait_item_def_id`: The trait item we are trying to resolve.
     /// - `substs`: The type parameters we are instantiating the trait item with. This
     ///   can include the `Self` parameter.
     pub(crate) fn resolve_item(
         tcx: TyCtxt<'tcx>,
+        closed_crates: &ClosedCrates,
         typing_env: TypingEnv<'tcx>,
         trait_item_def_id: DefId,
         substs: GenericArgsRef<'tcx>,
@@ -223,15 +700,22 @@ impl<'tcx> TraitResolved<'tcx> {
         };
         let trait_ref = tcx.normalize_erasing_regions(typing_env, trait_ref);
 
-        let source = if let Ok(source) =
-            tcx.codegen_select_candidate(typing_env.as_query_input(trait_ref))
-        {
-            source
-        } else if still_specializable(tcx, typing_env.param_env, trait_item_def_id, trait_ref, None)
-        {
-            return TraitResolved::UnknownNotFound;
-        } else {
-            return TraitResolved::NoInstance;
+        let source = match tcx.codegen_select_candidate(typing_env.as_query_input(trait_ref)) {
+            Ok(source) => source,
+            Err(CodegenObligationError::Ambiguity) => return TraitResolved::Ambiguous,
+            Err(_)
+                if still_specializable(
+                    tcx,
+                    closed_crates,
+                    typing_env.param_env,
+                    trait_item_def_id,
+                    trait_ref,
+                    None,
+                ) =>
+            {
+                return TraitResolved::UnknownNotFound;
+            }
+            Err(_) => return TraitResolved::NoInstance,
         };
         trace!("TraitResolved::resolve {source:?}",);
 
@@ -239,12 +723,15 @@ impl<'tcx> TraitResolved<'tcx> {
             ImplSource::UserDefined(impl_data) => {
                 if still_specializable(
                     tcx,
+                    closed_crates,
                     typing_env.param_env,
                     trait_item_def_id,
                     trait_ref,
                     Some(source),
                 ) {
-                    return TraitResolved::UnknownFound;
+                    return TraitResolved::UnknownFound(UnknownReason::StillSpecializable {
+                        base: impl_data.impl_def_id,
+                    });
                 }
 
                 // Find the id of the actual associated method we will be running
@@ -273,13 +760,36 @@ impl<'tcx> TraitResolved<'tcx> {
 
                 TraitResolved::Instance(leaf_def.item.def_id, leaf_substs)
             }
-            ImplSource::Param(_) => TraitResolved::UnknownFound,
-            ImplSource::Builtin(_, _) => match *substs.type_at(0).kind() {
-                rustc_middle::ty::Closure(closure_def_id, closure_substs) => {
-                    TraitResolved::Instance(closure_def_id, closure_substs)
+            ImplSource::Param(_) => TraitResolved::UnknownFound(UnknownReason::ParamSource),
+            // A method called on a trait object, through the vtable of its trait or of one of its
+            // supertraits (e.g. `Named::name` on a `dyn Animal` with `trait Animal: Named`).
+            // The method is the one of the trait that defines it, with `Self` set to the trait
+            // object: the call uses the contract declared in that trait.
+            ImplSource::Builtin(BuiltinImplSource::Object(_), _) => {
+                TraitResolved::UnknownFound(UnknownReason::Builtin)
+            }
+            ImplSource::Builtin(_, _) => {
+                match *builtin_self_ty(tcx, typing_env, trait_ref).kind() {
+                    rustc_middle::ty::Closure(closure_def_id, closure_substs) => {
+                        TraitResolved::Instance(closure_def_id, closure_substs)
+                    }
+                    // The `Fn*` traits of a function pointer: the function it points to is
+                    // unknown, so the pointer is specified by the opaque predicates of `FnExt`,
+                    // which are tied to the function when the pointer is created.
+                    rustc_middle::ty::FnPtr(..) => {
+                        TraitResolved::UnknownFound(UnknownReason::Builtin)
+                    }
+                    // An opaque type whose hidden type is not revealed in this scope: the call is
+                    // specified by the bounds of the opaque type, i.e. by the postcondition of the
+                    // function which returns it.
+                    TyKind::Alias(AliasTyKind::Opaque, _) => {
+                        TraitResolved::UnknownFound(UnknownReason::Builtin)
+                    }
+                    // Any other impl provided by the compiler, e.g. `Clone` for a tuple or
+                    // `DiscriminantKind` for an enum.
+                    _ => TraitResolved::UnknownFound(UnknownReason::Builtin),
                 }
-                _ => unimplemented!(),
-            },
+            }
         }
     }
 
@@ -306,6 +816,9 @@ impl<'tcx> TraitResolved<'tcx> {
         }
     }
 
+    /// The item to use for `did` with `substs`: the instance if it is known, or else the trait
+    /// item itself, which stays abstract (e.g. `<T as View>::view` in a function generic over
+    /// `T: View`). Returns `None` if there may be no instance at all.
     pub fn to_opt(
         self,
         did: DefId,
@@ -313,7 +826,9 @@ impl<'tcx> TraitResolved<'tcx> {
     ) -> Option<(DefId, GenericArgsRef<'tcx>)> {
         match self {
             TraitResolved::Instance(did, substs) => Some((did, substs)),
-            TraitResolved::NotATraitItem | TraitResolved::UnknownFound => Some((did, substs)),
+            TraitResolved::NotATraitItem
+            | TraitResolved::UnknownFound(_)
+            | TraitResolved::Ambiguous => Some((did, substs)),
             _ => None,
         }
     }
@@ -354,8 +869,69 @@ fn instantiate_params_with_infer<'tcx, T: TypeFoldable<TyCtxt<'tcx>>>(
     value.fold_with(&mut Folder { ctx, tys: Default::default(), consts: Default::default() })
 }
 
+/// The crates which belong to a closed world, given by `--closed-crates`.
+///
+/// By default, we assume that a downstream crate may always add implementations of a trait, as
+/// allowed by the orphan rules. For a trait defined in one of the closed crates, we instead assume
+/// that all of its implementations are already known, so that [`TraitResolved::resolve_item`] can
+/// resolve more items.
+#[derive(Default)]
+pub(crate) struct ClosedCrates(HashSet<String>);
+
+impl ClosedCrates {
+    pub(crate) fn new(crates: &[String]) -> Self {
+        ClosedCrates(crates.iter().cloned().collect())
+    }
+
+    /// Can a crate we don't know of implement the trait `trait_id`?
+    fn open_to_downstream(&self, tcx: TyCtxt, trait_id: DefId) -> bool {
+        !self.0.contains(tcx.crate_name(trait_id.krate).as_str())
+    }
+
+    /// Are all the implementations of the local trait `trait_id` defined in the current crate?
+    ///
+    /// This is the case in a binary crate, since no crate can depend on it, or if the crate was
+    /// declared closed.
+    pub(crate) fn all_impls_known(&self, tcx: TyCtxt, trait_id: DefId) -> bool {
+        trait_id.is_local()
+            && (tcx.crate_types().iter().all(|ty| *ty == CrateType::Executable)
+                || !self.open_to_downstream(tcx, trait_id))
+    }
+}
+
+/// The type whose builtin impl was selected for `trait_ref` in `typing_env`.
+///
+/// When a function returns `impl Fn..`, the builtin impl can be selected for the opaque type. Its
+/// hidden type (usually the closure returned by the function) is only revealed in the scope which
+/// defines it, i.e. in the body of the function: elsewhere, the opaque type is kept, so that the
+/// callers of the function only rely on its postcondition.
+fn builtin_self_ty<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    typing_env: TypingEnv<'tcx>,
+    trait_ref: TraitRef<'tcx>,
+) -> Ty<'tcx> {
+    match *trait_ref.self_ty().kind() {
+        TyKind::Alias(AliasTyKind::Opaque, alias) if reveals(typing_env, alias.def_id) => {
+            tcx.erase_regions(tcx.type_of(alias.def_id).instantiate(tcx, alias.args))
+        }
+        _ => trait_ref.self_ty(),
+    }
+}
+
+/// Is the hidden type of the opaque type `opaque_id` revealed in `typing_env`?
+fn reveals(typing_env: TypingEnv, opaque_id: DefId) -> bool {
+    match typing_env.typing_mode {
+        TypingMode::PostAnalysis => true,
+        TypingMode::PostBorrowckAnalysis { defined_opaque_types } => {
+            opaque_id.as_local().is_some_and(|id| defined_opaque_types.contains(&id))
+        }
+        TypingMode::Coherence | TypingMode::Analysis { .. } => false,
+    }
+}
+
 fn still_specializable<'tcx>(
     tcx: TyCtxt<'tcx>,
+    closed_crates: &ClosedCrates,
     param_env: ParamEnv<'tcx>,
     trait_item_def_id: DefId,
     trait_ref: TraitRef<'tcx>,
@@ -394,9 +970,10 @@ fn still_specializable<'tcx>(
     let infcx = tcx.infer_ctxt().ignoring_regions().build(rustc_type_ir::TypingMode::Coherence);
     let (param_env, trait_ref) =
         instantiate_params_with_infer(&infcx, param_env.and(trait_ref)).into_parts();
-    if orphan_check_trait_ref(&infcx, trait_ref, InCrate::Remote, |ty| Ok::<_, !>(ty))
-        .unwrap()
-        .is_ok()
+    if closed_crates.open_to_downstream(tcx, trait_ref.def_id)
+        && orphan_check_trait_ref(&infcx, trait_ref, InCrate::Remote, |ty| Ok::<_, !>(ty))
+            .unwrap()
+            .is_ok()
     {
         // A downstream or cousin crate is allowed to implement some
         // generic parameters of this trait-ref.
@@ -432,3 +1009,6 @@ fn still_specializable<'tcx>(
 
     false
 }
+
+#[cfg(test)]
+mod tests;
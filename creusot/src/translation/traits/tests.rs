@@ -0,0 +1,294 @@
+use super::{ClosedCrates, Refinement, TraitResolved, UnknownReason};
+use crate::{
+    ctx::TranslationCtx,
+    test_support::*,
+    translation::pearlite::{Term, TermKind, TermVisitor, super_visit_term},
+};
+use rustc_middle::ty::{GenericArgs, TraitRef, Ty, TypingEnv};
+use rustc_span::Symbol;
+
+const TRAITS: &str = "
+pub trait Tr {
+    fn m();
+}
+
+pub struct S;
+
+impl Tr for S {
+    fn m() {}
+}
+
+pub struct Unimplemented;
+
+pub fn free() {}
+
+pub fn bounded<T: Tr>() {}
+
+pub fn unbounded<T>() {}
+
+pub trait Lt<'a> {
+    fn m();
+}
+
+pub fn twice<'a, 'b, T: Lt<'a> + Lt<'b>>() {}
+";
+
+#[test]
+fn not_a_trait_item() {
+    with_tcx(TRAITS, |tcx| {
+        let free = local_item(tcx, "free");
+        let resolved = TraitResolved::resolve_item(
+            tcx,
+            &ClosedCrates::default(),
+            TypingEnv::fully_monomorphized(),
+            free,
+            GenericArgs::empty(),
+        );
+        assert_eq!(resolved, TraitResolved::NotATraitItem);
+    })
+}
+
+#[test]
+fn instance() {
+    with_tcx(TRAITS, |tcx| {
+        let trait_ref = trait_ref(tcx, "Tr", &[local_ty(tcx, "S").into()]);
+        let resolved = resolve(tcx, TypingEnv::fully_monomorphized(), trait_ref, "m");
+        let TraitResolved::Instance(def_id, _) = resolved else {
+            panic!("expected an instance, found {resolved:?}")
+        };
+        assert_eq!(tcx.def_path_str(def_id), "<S as Tr>::m");
+    })
+}
+
+#[test]
+fn unknown_found() {
+    with_tcx(TRAITS, |tcx| {
+        let trait_ref = trait_ref(tcx, "Tr", &[param_ty(tcx, "bounded", "T").into()]);
+        let resolved = resolve(tcx, typing_env(tcx, "bounded"), trait_ref, "m");
+        assert_eq!(resolved, TraitResolved::UnknownFound(UnknownReason::ParamSource));
+    })
+}
+
+// The impl of a trait is only found for a type which implements it.
+#[test]
+fn impl_of_trait() {
+    with_tcx(TRAITS, |tcx| {
+        let env = TypingEnv::fully_monomorphized();
+        let implemented = trait_ref(tcx, "Tr", &[local_ty(tcx, "S").into()]);
+        let impl_id = impl_of(tcx, env, implemented).expect("no impl of `Tr` for `S`");
+        assert_eq!(tcx.type_of(impl_id).instantiate_identity(), local_ty(tcx, "S"));
+        let unimplemented = trait_ref(tcx, "Tr", &[local_ty(tcx, "Unimplemented").into()]);
+        assert_eq!(impl_of(tcx, env, unimplemented), None);
+    })
+}
+
+// A downstream crate may implement `Tr` for its own types.
+#[test]
+fn unknown_not_found() {
+    with_tcx(TRAITS, |tcx| {
+        let trait_ref = trait_ref(tcx, "Tr", &[param_ty(tcx, "unbounded", "T").into()]);
+        let resolved = resolve(tcx, typing_env(tcx, "unbounded"), trait_ref, "m");
+        assert_eq!(resolved, TraitResolved::UnknownNotFound);
+    })
+}
+
+// No crate but this one can implement `Tr` for `Unimplemented`.
+#[test]
+fn no_instance() {
+    with_tcx(TRAITS, |tcx| {
+        let trait_ref = trait_ref(tcx, "Tr", &[local_ty(tcx, "Unimplemented").into()]);
+        let resolved = resolve(tcx, TypingEnv::fully_monomorphized(), trait_ref, "m");
+        assert_eq!(resolved, TraitResolved::NoInstance);
+    })
+}
+
+// Once regions are erased, both bounds of `T` apply.
+#[test]
+fn ambiguous() {
+    with_tcx(TRAITS, |tcx| {
+        let args = [param_ty(tcx, "twice", "T").into(), tcx.lifetimes.re_erased.into()];
+        let trait_ref = trait_ref(tcx, "Lt", &args);
+        let resolved = resolve(tcx, typing_env(tcx, "twice"), trait_ref, "m");
+        assert_eq!(resolved, TraitResolved::Ambiguous);
+    })
+}
+
+// The blanket impl may be specialized for `T`, by an impl for a type that `T` may be.
+#[test]
+fn unknown_found_still_specializable() {
+    let source = "
+        #![feature(min_specialization)]
+        pub trait Tr {
+            fn m();
+        }
+
+        impl<T> Tr for T {
+            default fn m() {}
+        }
+
+        pub fn generic<T>() {}
+    ";
+    with_tcx(source, |tcx| {
+        let trait_ref = trait_ref(tcx, "Tr", &[param_ty(tcx, "generic", "T").into()]);
+        let resolved = resolve(tcx, typing_env(tcx, "generic"), trait_ref, "m");
+        let TraitResolved::UnknownFound(UnknownReason::StillSpecializable { base }) = resolved
+        else {
+            panic!("expected an item which may be specialized, found {resolved:?}")
+        };
+        assert_eq!(tcx.trait_id_of_impl(base), Some(local_item(tcx, "Tr")));
+    })
+}
+
+// `Clone` for a tuple is provided by the compiler.
+#[test]
+fn builtin() {
+    with_tcx(TRAITS, |tcx| {
+        let tuple = Ty::new_tup(tcx, &[tcx.types.u32, tcx.types.u32]);
+        let trait_ref = TraitRef::new(tcx, tcx.lang_items().clone_trait().unwrap(), [tuple]);
+        let resolved = resolve(tcx, TypingEnv::fully_monomorphized(), trait_ref, "clone");
+        assert_eq!(resolved, TraitResolved::UnknownFound(UnknownReason::Builtin));
+    })
+}
+
+// The cache of resolutions is not shared with an environment which has a trivially false bound.
+#[test]
+fn cached_resolution_uses_global_bounds() {
+    let source = "
+        #![feature(trivial_bounds)]
+        #![allow(trivial_bounds)]
+        pub trait Tr {
+            fn m();
+        }
+
+        pub fn free() {}
+
+        pub fn bounded() where u32: Tr {}
+    ";
+    with_ctx(source, |ctx| {
+        let tcx = ctx.tcx;
+        let m = local_item(tcx, "Tr::m");
+        let args = tcx.mk_args(&[tcx.types.u32.into()]);
+        let free = ctx.resolve_item(typing_env(tcx, "free"), m, args);
+        let bounded = ctx.resolve_item(typing_env(tcx, "bounded"), m, args);
+        assert_eq!(free, TraitResolved::NoInstance);
+        assert_eq!(bounded, TraitResolved::UnknownFound(UnknownReason::ParamSource));
+    })
+}
+
+const REFINEMENTS: &str = "
+#![feature(register_tool)]
+#![register_tool(creusot)]
+
+pub struct W<U>(pub U);
+
+pub trait Conv<T> {
+    #[creusot::decl::logic]
+    fn conv(self) -> T;
+
+    #[creusot::decl::logic]
+    fn pick<V>(self, v: V) -> V;
+}
+
+impl<U> Conv<U> for W<U> {
+    #[creusot::decl::logic]
+    fn conv(self) -> U {
+        self.0
+    }
+
+    #[creusot::decl::logic]
+    fn pick<V>(self, v: V) -> V {
+        v
+    }
+}
+
+pub enum Two { A, B }
+
+pub enum Five { A, B, C, D, E }
+
+pub trait Choose {
+    #[creusot::decl::logic]
+    fn two(self) -> Two;
+
+    #[creusot::decl::logic]
+    fn five(self) -> Five;
+}
+
+impl Choose for () {
+    #[creusot::decl::logic]
+    fn two(self) -> Two {
+        Two::A
+    }
+
+    #[creusot::decl::logic]
+    fn five(self) -> Five {
+        Five::A
+    }
+}
+";
+
+/// The refinement obligation of the item `name` in the only impl of the trait at `trait_path`.
+fn refinement_of<'tcx>(
+    ctx: &TranslationCtx<'tcx>,
+    trait_path: &str,
+    name: &str,
+) -> Refinement<'tcx> {
+    let trait_id = local_item(ctx.tcx, trait_path);
+    let impl_id = ctx.all_impls(trait_id).next().unwrap();
+    let item = |container| {
+        ctx.associated_items(container)
+            .filter_by_name_unhygienic(Symbol::intern(name))
+            .next()
+            .unwrap()
+            .def_id
+    };
+    ctx.refinement(item(trait_id), item(impl_id))
+        .unwrap_or_else(|_| panic!("no refinement for `{name}`"))
+}
+
+fn has_match(term: &Term) -> bool {
+    struct HasMatch(bool);
+    impl<'tcx> TermVisitor<'tcx> for HasMatch {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            self.0 |= matches!(term.kind, TermKind::Match { .. });
+            super_visit_term(term, self)
+        }
+    }
+    let mut visitor = HasMatch(false);
+    visitor.visit_term(term);
+    visitor.0
+}
+
+// The trait item is instantiated with the arguments of the trait in the impl.
+#[test]
+fn refinement_instantiates_trait() {
+    with_ctx(REFINEMENTS, |ctx| {
+        let refn = refinement_of(ctx, "Conv", "conv");
+        assert_eq!(ctx.def_path_str(refn.trait_.0), "Conv::conv");
+        assert_eq!(ctx.def_path_str(refn.impl_.0), "<W<U> as Conv<U>>::conv");
+        let &[self_ty, arg] = &refn.trait_.1[..] else {
+            panic!("expected the arguments of `Conv`, found {:?}", refn.trait_.1)
+        };
+        assert_eq!(self_ty.expect_ty().to_string(), "W<U>");
+        assert_eq!(arg.expect_ty().to_string(), "U");
+    })
+}
+
+// The parameters of the trait item are those of the impl item.
+#[test]
+fn refinement_keeps_item_params() {
+    with_ctx(REFINEMENTS, |ctx| {
+        let refn = refinement_of(ctx, "Conv", "pick");
+        assert_eq!(refn.trait_.1.len(), 3);
+        assert_eq!(refn.impl_.1.len(), 2);
+        assert_eq!(refn.trait_.1.type_at(2), refn.impl_.1.type_at(1));
+    })
+}
+
+// The refinement is case-split on the variant of the result, for enums with few variants only.
+#[test]
+fn refinement_splits_on_variants() {
+    with_ctx(REFINEMENTS, |ctx| {
+        assert!(has_match(&refinement_of(ctx, "Choose", "two").refn));
+        assert!(!has_match(&refinement_of(ctx, "Choose", "five").refn));
+    })
+}
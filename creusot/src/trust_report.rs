@@ -0,0 +1,85 @@
+//! Support for `--report-trust-dependencies`: list the proved obligations which may rely on
+//! trusted facts, to review how much of the proof of a crate rests on trust.
+//!
+//! The trusted facts are the trusted laws, the contracts given by extern specs, and the contracts
+//! of the items of impls from other crates, which are assumed to refine their traits. An
+//! obligation may rely on the trusted facts among the dependencies of its item. Why3 does not
+//! report the unsat cores of the solvers, so these are the facts available to the proof: some of
+//! them may not be used by it.
+
+use crate::{
+    backend::Why3Generator,
+    contracts_items::{is_law, is_trusted},
+    ctx::TranslationCtx,
+    html_report::Report,
+};
+use indexmap::{IndexMap, IndexSet};
+use rustc_hir::def_id::DefId;
+
+/// The dependencies of the translation of each item.
+#[derive(Default)]
+pub(crate) struct TrustDependencies {
+    dependencies: IndexMap<DefId, IndexSet<DefId>>,
+}
+
+impl TrustDependencies {
+    pub(crate) fn record(&mut self, self_id: DefId, deps: impl Iterator<Item = DefId>) {
+        self.dependencies.entry(self_id).or_default().extend(deps.filter(|&dep| dep != self_id));
+    }
+
+    /// The trusted facts in the context of the obligation of `def_id`, including the refinement
+    /// obligations of its items for an impl.
+    fn trusted_facts(
+        &self,
+        ctx: &TranslationCtx,
+        def_id: DefId,
+    ) -> IndexSet<(DefId, &'static str)> {
+        self.dependencies
+            .iter()
+            .filter(|&(&item, _)| item == def_id || ctx.tcx.opt_parent(item) == Some(def_id))
+            .flat_map(|(_, deps)| deps)
+            .filter_map(|&dep| Some((dep, trusted_fact(ctx, dep)?)))
+            .collect()
+    }
+}
+
+/// The kind of trusted fact that `def_id` introduces, if any.
+fn trusted_fact(ctx: &TranslationCtx, def_id: DefId) -> Option<&'static str> {
+    let tcx = ctx.tcx;
+    if is_law(tcx, def_id) && is_trusted(tcx, def_id) {
+        Some("trusted law")
+    } else if ctx.extern_spec(def_id).is_some() {
+        Some("extern spec")
+    } else if let Some(impl_id) = tcx.impl_of_method(def_id)
+        && ctx.is_trusted_external_impl(impl_id)
+    {
+        Some("item of a trusted impl from another crate")
+    } else {
+        None
+    }
+}
+
+/// Print the proved obligations which may rely on trusted facts, with these facts.
+pub(crate) fn report_trust_dependencies(why3: &Why3Generator, report: &Report) {
+    let Some(trust) = &why3.trust else { return };
+    let trust = trust.borrow();
+    let mut proved = 0;
+    let mut trusting = 0;
+    for (def_id, result) in report.item_results() {
+        if result != Some(true) {
+            continue;
+        }
+        proved += 1;
+        let facts = trust.trusted_facts(why3, def_id);
+        if facts.is_empty() {
+            continue;
+        }
+        trusting += 1;
+        let id = why3.module_path(def_id).why3_ident().to_string();
+        println!("{id} ({}) may rely on:", why3.def_path_str(def_id));
+        for (fact, kind) in facts {
+            println!("  {kind} `{}`", why3.def_path_str(fact));
+        }
+    }
+    println!("{trusting} of {proved} proved obligation(s) may rely on trusted facts");
+}
@@ -14,18 +14,20 @@ pub(crate) use self::{
     traits::{validate_impls, validate_traits},
 };
 
-use rustc_hir::{HirId, def_id::DefId};
+use rustc_hir::{HirId, def::DefKind, def_id::DefId};
 use rustc_middle::ty::TyCtxt;
 use rustc_span::Symbol;
 
 use crate::{
     contracts_items::{
-        get_builtin, is_ghost_deref, is_ghost_deref_mut, is_snapshot_deref, is_trusted,
+        get_builtin, invariant_mode, is_extern_spec, is_ghost_deref, is_ghost_deref_mut,
+        is_maintains_invariant, is_open_inv_result, is_pure_logic, is_snapshot_deref, is_trusted,
     },
     ctx::TranslationCtx,
 };
 
-/// Validate that creusot buitins are annotated with `#[trusted]`.
+/// Validate that creusot buitins are annotated with `#[trusted]`, and that `#[pure(logic)]` is
+/// only used in extern specs.
 pub(crate) fn validate_trusted(ctx: &TranslationCtx) {
     for def_id in ctx.hir_crate_items(()).definitions() {
         let def_id = def_id.to_def_id();
@@ -36,6 +38,40 @@ pub(crate) fn validate_trusted(ctx: &TranslationCtx) {
             )
             .emit();
         }
+        if is_pure_logic(ctx.tcx, def_id) && !is_extern_spec(ctx.tcx, def_id) {
+            ctx.error(ctx.def_span(def_id), "`pure(logic)` can only be used in `extern_spec!`")
+                .emit();
+        }
+    }
+}
+
+/// Warn about the methods of an `impl` block with `#[creusot::maintains_invariant]` which open
+/// type invariants: the attribute overrides their opening (see `add_type_invariant_spec`), so that
+/// each of them assumes the invariants of its arguments, and proves the invariants of its result
+/// and of the final values of its mutable borrows.
+pub(crate) fn validate_maintains_invariant(ctx: &TranslationCtx) {
+    for def_id in ctx.hir_crate_items(()).definitions() {
+        let def_id = def_id.to_def_id();
+        if ctx.def_kind(def_id) != DefKind::AssocFn
+            || !ctx.impl_of_method(def_id).is_some_and(|i| is_maintains_invariant(ctx.tcx, i))
+        {
+            continue;
+        }
+        let mode = invariant_mode(ctx.tcx, def_id);
+        let opened = if mode.open_args || ctx.params_open_inv(def_id).is_some_and(|p| !p.is_empty())
+        {
+            "its arguments"
+        } else if mode.open_result || is_open_inv_result(ctx.tcx, def_id) {
+            "its result"
+        } else {
+            continue;
+        };
+        ctx.warn(
+            ctx.def_span(def_id),
+            format!(
+                "this method opens the type invariants of {opened}, which is ignored: the methods of a `#[creusot::maintains_invariant]` impl block must all maintain the type invariants"
+            ),
+        );
     }
 }
 
@@ -131,7 +131,10 @@ impl PurityVisitor<'_, '_> {
         let tcx = self.ctx.tcx;
         let stub = pearlite_stub(tcx, self.thir[fun].ty);
 
-        if matches!(stub, Some(Stub::Fin))
+        if self.context.is_logic() && self.ctx.is_logic_extern(func_did) {
+            // Programs call the function itself, specifications its logical model.
+            Purity::Logic { prophetic: false }
+        } else if matches!(stub, Some(Stub::Fin))
             || is_predicate(tcx, func_did) && is_prophetic(tcx, func_did)
             || is_logic(tcx, func_did) && is_prophetic(tcx, func_did)
         {
@@ -185,10 +188,8 @@ impl<'a, 'tcx> thir::visit::Visitor<'a, 'tcx> for PurityVisitor<'a, 'tcx> {
                 if let &FnDef(func_did, subst) = self.thir[fun].ty.kind() {
                     // try to specialize the called function if it is a trait method.
                     let subst = self.ctx.erase_regions(subst);
-                    let Some((func_did, _)) =
-                        TraitResolved::resolve_item(self.ctx.tcx, self.typing_env, func_did, subst)
-                            .to_opt(func_did, subst)
-                    else {
+                    let resolved = self.ctx.resolve_item(self.typing_env, func_did, subst);
+                    let Some((func_did, _)) = resolved.to_opt(func_did, subst) else {
                         self.thir_failed = Some(self.ctx.dcx().span_err(
                             fn_span,
                             format!("no instance of {} found", self.ctx.def_path_str(func_did)),
@@ -248,7 +249,11 @@ impl<'a, 'tcx> thir::visit::Visitor<'a, 'tcx> for PurityVisitor<'a, 'tcx> {
                                 self.ctx.def_path_str(func_did),
                             );
 
-                            self.ctx.dcx().span_err(self.thir[fun].span, msg);
+                            let mut err = self.ctx.error(self.thir[fun].span, &msg);
+                            if let TraitResolved::UnknownFound(reason) = resolved {
+                                err = err.with_note(reason.explain(self.ctx.tcx, func_did));
+                            }
+                            err.emit();
                         }
                     }
                 } else if matches!(self.context, Purity::Logic { .. }) {
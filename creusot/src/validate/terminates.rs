@@ -32,7 +32,7 @@
 
 use crate::{
     backend::is_trusted_item,
-    contracts_items::{has_variant_clause, is_no_translate, is_pearlite},
+    contracts_items::{has_variant_clause, is_loop_variant, is_no_translate, is_pearlite},
     ctx::TranslationCtx,
     error::CannotFetchThir,
     translation::{
@@ -99,8 +99,15 @@ pub(crate) fn validate_terminates(ctx: &TranslationCtx) -> Result<(), CannotFetc
         };
         if let Some(loop_span) = function_data.has_loops {
             let fun_span = ctx.tcx.def_span(def_id);
-            let mut error = ctx.error(fun_span, "`#[terminates]` function must not contain loops.");
+            let mut error = ctx.error(
+                fun_span,
+                "`#[terminates]` function must not contain loops without a `#[variant]`.",
+            );
             error.span_note(loop_span, "looping occurs here");
+            error.help(
+                "the variant is inferred for `for` loops over a range `a..b`, and `while i < n` \
+                 loops ending with `i += 1`, when they have an `#[invariant]`",
+            );
             error.emit();
         }
     }
@@ -122,6 +129,28 @@ pub(crate) fn validate_terminates(ctx: &TranslationCtx) -> Result<(), CannotFetc
             continue;
         }
         let in_cycle: IndexSet<_> = cycle.into_iter().collect();
+
+        // Mutually recursive logic functions of the same trait (typically default methods) are
+        // allowed if they all have a variant: the VC of each function checks that the variant of
+        // the callee decreases at every call inside the group.
+        let members: Vec<_> = std::iter::once(root).chain(in_cycle.iter().copied()).collect();
+        // Nodes for default methods in an impl block are covered too, since the VCs are those of
+        // the default methods.
+        let trait_of = |n: graph::NodeIndex| {
+            ctx.tcx.trait_of_item(call_graph.node_weight(n).unwrap().def_id())
+        };
+        if let Some(trait_id) = trait_of(root)
+            && members.iter().all(|&n| {
+                let data = &additional_data[&n];
+                data.is_pearlite && data.has_variant && trait_of(n) == Some(trait_id)
+            })
+        {
+            ctx.record_recursion_group(
+                members.iter().map(|&n| call_graph.node_weight(n).unwrap().def_id()),
+            );
+            continue;
+        }
+
         let mut cycle = Vec::new();
         // Build the cycle in the right order.
         depth_first_search(&call_graph, [root], |n| match n {
@@ -274,10 +303,11 @@ struct FunctionData {
     is_pearlite: bool,
     /// `true` if the function has a `#[variant]` annotation.
     ///
-    /// For now, mutually recursive functions are never allowed, so this only matter for
-    /// the simple recursion check.
+    /// Mutually recursive functions are only allowed for logic functions of a same trait, which
+    /// must all have a variant.
     has_variant: bool,
-    /// `Some` if the function contains a loop construct (contains the location of the loop).
+    /// `Some` if the function contains a loop construct without a variant (contains the location
+    /// of the loop).
     ///
     /// The body of external function are not visited, so this field will be `false`.
     has_loops: Option<Span>,
@@ -313,10 +343,10 @@ impl<'tcx> BuildFunctionsGraph<'tcx> {
         call_span: Span,
     ) -> Result<(), CannotFetchThir> {
         let tcx = ctx.tcx;
-        let (called_id, generic_args) =
-            TraitResolved::resolve_item(tcx, typing_env, called_id, generic_args)
-                .to_opt(called_id, generic_args)
-                .unwrap();
+        let (called_id, generic_args) = ctx
+            .resolve_item(typing_env, called_id, generic_args)
+            .to_opt(called_id, generic_args)
+            .unwrap();
 
         // TODO: this code is kind of a soup, rework or refactor into a function
         let (called_node, bounds, impl_self_bound) = 'bl: {
@@ -380,8 +410,7 @@ impl<'tcx> BuildFunctionsGraph<'tcx> {
 
             let subst = EarlyBinder::bind(trait_ref.args).instantiate(tcx, generic_args);
             for &item in tcx.associated_item_def_ids(trait_ref.def_id) {
-                let TraitResolved::Instance(item_id, _) =
-                    TraitResolved::resolve_item(tcx, typing_env, item, subst)
+                let TraitResolved::Instance(item_id, _) = ctx.resolve_item(typing_env, item, subst)
                 else {
                     continue;
                 };
@@ -638,7 +667,7 @@ struct FunctionCalls<'thir, 'tcx> {
     /// - The generic args for this call.
     /// - The span of the call (for error messages).
     calls: IndexSet<(DefId, &'tcx GenericArgs<'tcx>, Span)>,
-    /// `Some` if the function contains a loop construct.
+    /// `Some` if the function contains a loop construct without a variant.
     has_loops: Option<Span>,
     /// If `Some`, we should error with a [`CannotFetchThir`] error.
     thir_failed: Option<ErrorGuaranteed>,
@@ -681,8 +710,10 @@ impl<'thir, 'tcx> thir::visit::Visitor<'thir, 'tcx> for FunctionCalls<'thir, 'tc
                 self.calls.extend(closure_visitor.calls);
                 self.has_loops = self.has_loops.or(closure_visitor.has_loops);
             }
-            thir::ExprKind::Loop { .. } => {
-                self.has_loops = Some(expr.span);
+            thir::ExprKind::Loop { body } => {
+                if !loop_has_variant(self.tcx, self.thir, body) {
+                    self.has_loops = Some(expr.span);
+                }
             }
             _ => {}
         }
@@ -690,6 +721,26 @@ impl<'thir, 'tcx> thir::visit::Visitor<'thir, 'tcx> for FunctionCalls<'thir, 'tc
     }
 }
 
+/// Whether the body of a loop starts with a loop variant closure, as generated by `#[variant]`
+/// (or inferred by `#[invariant]`). Its decrease is then checked at each iteration.
+fn loop_has_variant<'tcx>(tcx: TyCtxt<'tcx>, thir: &thir::Thir<'tcx>, body: thir::ExprId) -> bool {
+    let peel_scopes = |mut expr: thir::ExprId| {
+        while let thir::ExprKind::Scope { value, .. } = thir[expr].kind {
+            expr = value;
+        }
+        expr
+    };
+    let thir::ExprKind::Block { block } = thir[peel_scopes(body)].kind else { return false };
+    thir[block].stmts.iter().any(|&stmt| match thir[stmt].kind {
+        thir::StmtKind::Let { initializer: Some(init), .. } => matches!(
+            thir[peel_scopes(init)].kind,
+            thir::ExprKind::Closure(box thir::ClosureExpr { closure_id, .. })
+                if is_loop_variant(tcx, closure_id.to_def_id())
+        ),
+        _ => false,
+    })
+}
+
 /// Gather the loops in `ghost!` code for a given function.
 struct GhostLoops<'thir, 'tcx> {
     thir: &'thir thir::Thir<'tcx>,
@@ -1,19 +1,57 @@
 use rustc_hir::def::DefKind;
 
 use crate::{
-    contracts_items::{is_law, is_open_inv_result, is_trusted},
-    ctx::TranslationCtx,
+    contracts_items::{
+        has_variant_clause, invariant_mode, is_forall_impls, is_law, is_open_inv_result,
+        is_terminates, is_trusted,
+    },
+    ctx::{ItemType, TranslationCtx},
     validate::is_overloaded_item,
 };
 
 /// Validate that laws have no additional generic parameters.
 ///
 /// This is because laws are auto-loaded, and we do not want to generate polymorphic WhyML code.
+///
+/// Also validate that `#[forall_impls]` laws have a default body, and are only used when all the
+/// impls of the trait are known.
 pub(crate) fn validate_traits(ctx: &TranslationCtx) {
     let mut law_violations = Vec::new();
 
     for trait_item_id in ctx.hir_crate_items(()).trait_items() {
         let trait_item = ctx.hir().trait_item(trait_item_id);
+        let def_id = trait_item.owner_id.def_id.to_def_id();
+
+        if is_forall_impls(ctx.tcx, def_id) {
+            if !is_law(ctx.tcx, def_id) {
+                ctx.error(trait_item.span, "`#[forall_impls]` can only be used on laws").emit();
+            } else if !ctx.defaultness(def_id).has_value() {
+                ctx.error(trait_item.span, "`#[forall_impls]` laws must have a default body")
+                    .emit();
+            } else if !ctx.closed_crates.all_impls_known(ctx.tcx, ctx.parent(def_id)) {
+                ctx.error(
+                    trait_item.span,
+                    "`#[forall_impls]` laws can only be proved when all the impls of the trait are known",
+                )
+                .with_note("other crates could add implementations of this trait")
+                .with_help("use a binary crate, or declare it closed with `--closed-crate`")
+                .emit();
+            }
+        }
+
+        // Only `#[terminates]` guarantees the termination of a program method, and requires its
+        // implementations to terminate as well.
+        if has_variant_clause(ctx.tcx, def_id)
+            && !is_terminates(ctx.tcx, def_id)
+            && ctx.item_type(def_id) == ItemType::Program
+        {
+            ctx.error(
+                trait_item.span,
+                "A `#[variant]` on a program method of a trait requires the method to be `#[terminates]`",
+            )
+            .with_help("add `#[terminates]`, so that the implementations are required to terminate")
+            .emit();
+        }
 
         if is_law(ctx.tcx, trait_item.owner_id.def_id.to_def_id())
             && !ctx.generics_of(trait_item.owner_id.def_id).own_params.is_empty()
@@ -77,10 +115,17 @@ pub(crate) fn validate_impls(ctx: &TranslationCtx) {
                 continue;
             }
 
+            // The implementation must not assume the invariant of a parameter which is open in the
+            // trait declaration, nor give callers the invariant of its result if it is open there.
+            let trait_mode = invariant_mode(ctx.tcx, trait_item);
+            let impl_mode = invariant_mode(ctx.tcx, impl_item);
+            let open_inv_impl = ctx.params_open_inv(impl_item);
+            let impl_param_open =
+                |i: &usize| impl_mode.open_args || open_inv_impl.is_some_and(|p| p.contains(i));
+
             if let Some(open_inv_trait) = ctx.params_open_inv(trait_item) {
-                let open_inv_impl = ctx.params_open_inv(impl_item).unwrap();
                 for &i in open_inv_trait {
-                    if !open_inv_impl.contains(&i) {
+                    if !impl_param_open(&i) {
                         let name_param = ctx.fn_arg_names(impl_item)[i];
                         ctx.error(
                             ctx.def_span(impl_item),
@@ -92,7 +137,22 @@ pub(crate) fn validate_impls(ctx: &TranslationCtx) {
                 }
             }
 
-            if is_open_inv_result(ctx.tcx, impl_item) && !is_open_inv_result(ctx.tcx, trait_item) {
+            if trait_mode.open_args {
+                for (i, name_param) in ctx.fn_arg_names(impl_item).iter().enumerate() {
+                    if !impl_param_open(&i) {
+                        ctx.error(
+                            ctx.def_span(impl_item),
+                            &format!(
+                                "Parameter `{name_param}` has an open invariant in the trait declaration, by `#[creusot::invariant_mode(open_args)]`, but not in the implementation."
+                            ),
+                        ).emit();
+                    }
+                }
+            }
+
+            let trait_result_open =
+                trait_mode.open_result || is_open_inv_result(ctx.tcx, trait_item);
+            if is_open_inv_result(ctx.tcx, impl_item) && !trait_result_open {
                 ctx.error(
                     ctx.def_span(impl_item),
                     &format!(
@@ -102,6 +162,16 @@ pub(crate) fn validate_impls(ctx: &TranslationCtx) {
                 ).emit();
             }
 
+            if impl_mode.open_result && !trait_result_open {
+                ctx.error(
+                    ctx.def_span(impl_item),
+                    &format!(
+                        "Function `{}` should not have `#[creusot::invariant_mode(open_result)]`, as the trait declaration ensures the invariant of its result",
+                        ctx.item_name(impl_item),
+                    ),
+                ).emit();
+            }
+
             if is_overloaded_item(ctx.tcx, trait_item) {
                 continue;
             };
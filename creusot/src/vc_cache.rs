@@ -0,0 +1,135 @@
+//! Support for `--vc-cache`: reuse the results of the obligations already proved, in this run or
+//! in a previous one.
+//!
+//! An obligation is the module of an item, which is self-contained: it declares the laws that the
+//! goal assumes along with the goal itself. It is identified by a hash of the Coma of its
+//! declarations, without the name of the module nor the source locations, and of the environment
+//! of the solvers (see `run_why3::vc_environment`): their options, the prelude, the Why3
+//! configuration and the version of Why3. Moving an item to another module, or two identical
+//! obligations of different items, thus share one result, while upgrading Why3 or a prover
+//! invalidates the cache. Only the proved obligations are recorded: the others are proved again.
+
+use crate::backend::Why3Generator;
+use rustc_data_structures::stable_hasher::{Hash64, StableHasher};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeSet, HashMap, hash_map::Entry},
+    hash::Hash,
+    io::ErrorKind,
+    path::Path,
+};
+use why3::{
+    Symbol,
+    declaration::{Attribute, Decl, Module},
+    printer::{Why3Version, render_decls},
+};
+
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct VcCache {
+    proved: BTreeSet<String>,
+}
+
+impl VcCache {
+    /// Read the cache at `path`, which is empty if the file does not exist yet.
+    pub(crate) fn load(ctx: &Why3Generator, path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                ctx.dcx().fatal(format!("invalid VC cache {}: {err}", path.display()))
+            }),
+            Err(err) if err.kind() == ErrorKind::NotFound => VcCache::default(),
+            Err(err) => {
+                ctx.dcx().fatal(format!("could not read the VC cache {}: {err}", path.display()))
+            }
+        }
+    }
+
+    pub(crate) fn save(&self, ctx: &Why3Generator, path: &Path) {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        if let Err(err) = std::fs::write(path, json + "\n") {
+            ctx.dcx().fatal(format!("could not write the VC cache {}: {err}", path.display()));
+        }
+    }
+
+    pub(crate) fn is_proved(&self, key: &str) -> bool {
+        self.proved.contains(key)
+    }
+
+    pub(crate) fn record_proved(&mut self, key: String) {
+        self.proved.insert(key);
+    }
+}
+
+/// The key of the obligation of `modl` in the cache, for the solvers run in `environment`.
+pub(crate) fn vc_key(modl: &Module, version: Why3Version, environment: &str) -> String {
+    let decls = modl.decls.iter().filter(|decl| !matches!(decl, Decl::LetSpans(_)));
+    let mut coma = Vec::new();
+    render_decls(decls, version, &mut coma).unwrap();
+    let coma = String::from_utf8(coma).unwrap();
+
+    let mut hasher = StableHasher::new();
+    strip_locations(&coma).hash(&mut hasher);
+    for attr in &modl.attrs {
+        // The solver options of the module, see `run_why3::module_solver`.
+        if let Attribute::Attr(attr) = attr {
+            attr.hash(&mut hasher);
+        }
+    }
+    environment.hash(&mut hasher);
+    format!("{:016x}", hasher.finish::<Hash64>().as_u64())
+}
+
+/// How the modules are proved with `--vc-cache`, see [`plan`].
+#[derive(Default)]
+pub(crate) struct Plan {
+    /// The modules to run the solvers on, one per obligation left to prove.
+    pub(crate) to_prove: Vec<Symbol>,
+    /// For each obligation left to prove, the other modules which share it.
+    pub(crate) same_as: HashMap<String, Vec<Symbol>>,
+    /// The modules whose obligation is in the cache.
+    pub(crate) cached: Vec<Symbol>,
+}
+
+/// Split `modules`, given with their keys, into the ones whose obligation is in `cache` and the
+/// ones to prove: the solvers are only run on the first module of each obligation.
+pub(crate) fn plan<'a>(
+    cache: &VcCache,
+    modules: impl IntoIterator<Item = (Symbol, &'a str)>,
+) -> Plan {
+    let mut plan = Plan::default();
+    for (name, key) in modules {
+        if cache.is_proved(key) {
+            plan.cached.push(name);
+            continue;
+        }
+        match plan.same_as.entry(key.to_owned()) {
+            Entry::Occupied(mut entry) => entry.get_mut().push(name),
+            Entry::Vacant(entry) => {
+                entry.insert(Vec::new());
+                plan.to_prove.push(name);
+            }
+        }
+    }
+    plan
+}
+
+/// Remove the source locations from the printed Coma: the `let%span` bindings and the
+/// `[#"file" ...]` attributes. The references `[%#name]` to bound spans are kept, since their
+/// names only depend on the order of the spans.
+fn strip_locations(coma: &str) -> String {
+    let mut stripped = String::with_capacity(coma.len());
+    for line in coma.lines().filter(|line| !line.trim_start().starts_with("let%span")) {
+        let mut rest = line;
+        while let Some(start) = rest.find("[#\"")
+            && let Some(end) = rest[start..].find(']')
+        {
+            stripped.push_str(&rest[..start]);
+            rest = &rest[start + end + 1..];
+        }
+        stripped.push_str(rest);
+        stripped.push('\n');
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests;
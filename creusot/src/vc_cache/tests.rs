@@ -0,0 +1,69 @@
+use super::{VcCache, plan, vc_key};
+use why3::{
+    Exp, Ident, Symbol,
+    declaration::{Attribute, Axiom, Decl, Goal, Module},
+    printer::Why3Version,
+};
+
+/// The module `name` of an obligation assuming `law` to prove `goal`, located at `line`.
+fn obligation(name: &str, line: usize, law: Exp, goal: Exp) -> Module {
+    let span = |col| Attribute::Span("lib.rs".into(), line, col, line, col + 10);
+    let law = Axiom { name: Ident::fresh_local("law"), rewrite: false, axiom: law };
+    let goal = Goal { name: Ident::fresh_local("vc"), goal: goal.with_attr(span(4)) };
+    Module {
+        name: Symbol::intern(name),
+        decls: Box::new([Decl::Axiom(law), Decl::Goal(goal)]),
+        attrs: vec![span(0)],
+        meta: None,
+    }
+}
+
+fn key(modl: &Module) -> String {
+    vc_key(modl, Why3Version::default(), "-P z3")
+}
+
+#[test]
+fn identical_obligations_share_a_key() {
+    let a = obligation("M_a__f", 3, Exp::mk_true(), Exp::mk_true());
+    let b = obligation("M_b__f", 12, Exp::mk_true(), Exp::mk_true());
+    assert_eq!(key(&a), key(&b));
+}
+
+#[test]
+fn key_covers_laws_goal_and_solver() {
+    let modl = obligation("M_f", 3, Exp::mk_true(), Exp::mk_true());
+    let other_law = obligation("M_f", 3, Exp::mk_false(), Exp::mk_true());
+    let other_goal = obligation("M_f", 3, Exp::mk_true(), Exp::mk_false());
+    assert_ne!(key(&modl), key(&other_law));
+    assert_ne!(key(&modl), key(&other_goal));
+    assert_ne!(key(&modl), vc_key(&modl, Why3Version::default(), "-P cvc5"));
+}
+
+#[test]
+fn key_covers_environment() {
+    let modl = obligation("M_f", 3, Exp::mk_true(), Exp::mk_true());
+    let upgraded = vc_key(&modl, Why3Version::default(), "-P z3\n[prover]\nversion = \"4.13.0\"");
+    assert_ne!(key(&modl), upgraded);
+}
+
+#[test]
+fn solvers_run_once_per_obligation() {
+    let a = obligation("M_a__f", 3, Exp::mk_true(), Exp::mk_true());
+    let b = obligation("M_b__f", 12, Exp::mk_true(), Exp::mk_true());
+    let c = obligation("M_c__f", 20, Exp::mk_true(), Exp::mk_false());
+    let keys: Vec<_> = [&a, &b, &c].into_iter().map(|modl| (modl.name, key(modl))).collect();
+    let modules = || keys.iter().map(|(name, key)| (*name, key.as_str()));
+
+    // `a` and `b` share one solver run, and `c` needs its own.
+    let first = plan(&VcCache::default(), modules());
+    assert_eq!(first.to_prove, vec![a.name, c.name]);
+    assert_eq!(first.same_as[&keys[0].1], vec![b.name]);
+    assert!(first.cached.is_empty());
+
+    // Once `a` is proved, the solvers only run on `c`.
+    let mut cache = VcCache::default();
+    cache.record_proved(keys[0].1.clone());
+    let second = plan(&cache, modules());
+    assert_eq!(second.to_prove, vec![c.name]);
+    assert_eq!(second.cached, vec![a.name, b.name]);
+}
@@ -16,6 +16,10 @@ use std::hash::{Hash, Hasher};
 // - Symbol is hashed as a string (not as a pointer).
 // - CrateNum is hashed as the crate name (not as a number).
 // - DefId is hashed as a DefPath
+//
+// Nothing that depends on the machine is hashed: in particular, no paths nor `StableCrateId`s
+// (which cargo derives from the location of path dependencies), so that the same crate built
+// from two different directories gets the same names.
 pub trait VeryStableHash<CTX> {
     fn very_stable_hash(&self, tcx: &CTX, hcx: &mut StableHasher);
 }
@@ -511,9 +515,9 @@ impl<CTX> VeryStableHash<CTX> for ty::ValTree<'_> {
 }
 
 impl<CTX> VeryStableHash<CTX> for ty::ParamConst {
-    fn very_stable_hash(&self, _tcx: &CTX, hcx: &mut StableHasher) {
+    fn very_stable_hash(&self, tcx: &CTX, hcx: &mut StableHasher) {
         self.index.hash(hcx);
-        self.name.hash(hcx);
+        self.name.very_stable_hash(tcx, hcx);
     }
 }
 
@@ -454,7 +454,7 @@ ast_enum_of_structs! {
 
 ast_struct! {
     pub struct QuantArg {
-        pub ident: Ident,
+        pub pat: Pat,
         pub colon_token: Token![:],
         pub ty: Box<Type>,
     }
@@ -1441,10 +1441,10 @@ pub(crate) mod parsing {
 
     impl Parse for QuantArg {
         fn parse(input: ParseStream) -> Result<Self> {
-            let ident = input.parse()?;
+            let pat = Pat::parse_single(input)?;
             let colon_token = input.parse()?;
             let ty = input.parse()?;
-            Ok(QuantArg { ident, colon_token, ty })
+            Ok(QuantArg { pat, colon_token, ty })
         }
     }
 
@@ -1968,7 +1968,7 @@ pub(crate) mod printing {
 
     impl ToTokens for QuantArg {
         fn to_tokens(&self, tokens: &mut TokenStream) {
-            self.ident.to_tokens(tokens);
+            self.pat.to_tokens(tokens);
             self.colon_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
         }
@@ -83,8 +83,14 @@ fn test_forall() {
         lt_token: Lt,
         args: [
             QuantArg {
-                ident: Ident {
-                    sym: x,
+                pat: Pat::Ident {
+                    attrs: [],
+                    by_ref: None,
+                    mutability: None,
+                    ident: Ident {
+                        sym: x,
+                    },
+                    subpat: None,
                 },
                 colon_token: Colon,
                 ty: Type::Path {
@@ -114,6 +120,87 @@ fn test_forall() {
     "###);
 }
 
+#[test]
+fn test_forall_tuple() {
+    snapshot!(quote!(forall<(i, j) : (u32, u32)> true) as Term, @r###"
+    TermQuant {
+        quant_token: Keyword [forall],
+        lt_token: Lt,
+        args: [
+            QuantArg {
+                pat: Pat::Tuple {
+                    attrs: [],
+                    paren_token: Paren,
+                    elems: [
+                        Pat::Ident {
+                            attrs: [],
+                            by_ref: None,
+                            mutability: None,
+                            ident: Ident {
+                                sym: i,
+                            },
+                            subpat: None,
+                        },
+                        Comma,
+                        Pat::Ident {
+                            attrs: [],
+                            by_ref: None,
+                            mutability: None,
+                            ident: Ident {
+                                sym: j,
+                            },
+                            subpat: None,
+                        },
+                    ],
+                },
+                colon_token: Colon,
+                ty: Type::Tuple {
+                    paren_token: Paren,
+                    elems: [
+                        Type::Path {
+                            qself: None,
+                            path: Path {
+                                leading_colon: None,
+                                segments: [
+                                    PathSegment {
+                                        ident: Ident {
+                                            sym: u32,
+                                        },
+                                        arguments: PathArguments::None,
+                                    },
+                                ],
+                            },
+                        },
+                        Comma,
+                        Type::Path {
+                            qself: None,
+                            path: Path {
+                                leading_colon: None,
+                                segments: [
+                                    PathSegment {
+                                        ident: Ident {
+                                            sym: u32,
+                                        },
+                                        arguments: PathArguments::None,
+                                    },
+                                ],
+                            },
+                        },
+                    ],
+                },
+            },
+        ],
+        gt_token: Gt,
+        trigger: [],
+        term: TermLit {
+            lit: Lit::Bool {
+                value: true,
+            },
+        },
+    }
+    "###);
+}
+
 #[test]
 fn test_exists() {
     snapshot!(quote!(exists<x : u32> true) as Term, @r###"
@@ -122,8 +209,14 @@ fn test_exists() {
         lt_token: Lt,
         args: [
             QuantArg {
-                ident: Ident {
-                    sym: x,
+                pat: Pat::Ident {
+                    attrs: [],
+                    by_ref: None,
+                    mutability: None,
+                    ident: Ident {
+                        sym: x,
+                    },
+                    subpat: None,
                 },
                 colon_token: Colon,
                 ty: Type::Path {
@@ -161,8 +254,14 @@ fn test_trigger() {
         lt_token: Lt,
         args: [
             QuantArg {
-                ident: Ident {
-                    sym: x,
+                pat: Pat::Ident {
+                    attrs: [],
+                    by_ref: None,
+                    mutability: None,
+                    ident: Ident {
+                        sym: x,
+                    },
+                    subpat: None,
                 },
                 colon_token: Colon,
                 ty: Type::Path {
@@ -182,8 +281,14 @@ fn test_trigger() {
             },
             Comma,
             QuantArg {
-                ident: Ident {
-                    sym: y,
+                pat: Pat::Ident {
+                    attrs: [],
+                    by_ref: None,
+                    mutability: None,
+                    ident: Ident {
+                        sym: y,
+                    },
+                    subpat: None,
                 },
                 colon_token: Colon,
                 ty: Type::Path {
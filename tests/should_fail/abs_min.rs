@@ -0,0 +1,8 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn abs_min() -> i32 {
+    // Overflows: violates the precondition of `i32::abs`
+    i32::MIN.abs()
+}
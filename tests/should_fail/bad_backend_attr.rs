@@ -0,0 +1,8 @@
+#![feature(custom_inner_attributes)]
+extern crate creusot_contracts;
+
+pub mod m {
+    #![creusot::backend(encoding = "float")]
+
+    pub fn f() {}
+}
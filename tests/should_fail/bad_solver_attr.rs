@@ -0,0 +1,5 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[creusot::solver(timeout = 10)]
+pub fn f() {}
@@ -0,0 +1,63 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::borrow::Borrow, *};
+
+pub struct Key(pub u32);
+
+impl View for Key {
+    type ViewTy = Int;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+pub struct Entry {
+    pub key: Key,
+    pub hits: u32,
+}
+
+// Error: the entry is built with a reset key, so borrowing it does not give back the original key.
+impl ToOwned for Key {
+    type Owned = Entry;
+
+    #[ensures(result.key@ == 0 && result.hits@ == self@)]
+    fn to_owned(&self) -> Entry {
+        Entry { key: Key(0), hits: self.0 }
+    }
+}
+
+impl Borrow<Key> for Entry {
+    #[ensures(*result == self.key)]
+    fn borrow(&self) -> &Key {
+        &self.key
+    }
+}
+
+pub struct Counter(pub u32);
+
+impl View for Counter {
+    type ViewTy = Int;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+// The blanket `impl<T: Clone> ToOwned for T` is not checked: the law is not available for it.
+impl Clone for Counter {
+    #[ensures(result@ == 0)]
+    fn clone(&self) -> Self {
+        Counter(0)
+    }
+}
+
+pub fn blanket(c: &Counter) {
+    let o = c.to_owned();
+    let b: &Counter = o.borrow();
+    proof_assert! { b@ == c@ }
+}
@@ -0,0 +1,122 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use ::std::cmp::Ordering;
+use creusot_contracts::{
+    logic::ord::{OrdLogic, ord_laws_impl},
+    *,
+};
+
+// A hand is smaller than the hand that beats it. This order is total, but not transitive: the
+// `trans` law of `OrdLogic` cannot be proved, so `Ord::cmp` cannot be specified with it. The laws
+// of `Ord::cmp` checked at the impl of `Ord` fail as well, with the counterexample
+// `Rock < Paper`, `Paper < Scissors` and `Rock > Scissors`.
+
+#[derive(Clone, Copy)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Hand {
+    #[logic]
+    #[open]
+    pub fn code_log(self) -> Int {
+        match self {
+            Hand::Rock => 0,
+            Hand::Paper => 1,
+            Hand::Scissors => 2,
+        }
+    }
+
+    #[ensures(result@ == self.code_log())]
+    pub fn code(self) -> u8 {
+        match self {
+            Hand::Rock => 0,
+            Hand::Paper => 1,
+            Hand::Scissors => 2,
+        }
+    }
+}
+
+impl DeepModel for Hand {
+    type DeepModelTy = Hand;
+
+    #[logic]
+    #[open]
+    fn deep_model(self) -> Hand {
+        self
+    }
+}
+
+impl OrdLogic for Hand {
+    #[logic]
+    #[open]
+    fn cmp_log(self, o: Self) -> Ordering {
+        pearlite! {
+            if self.code_log() == o.code_log() {
+                Ordering::Equal
+            } else if (self.code_log() + 1) % 3 == o.code_log() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+    }
+
+    #[predicate]
+    #[open]
+    fn le_log(self, o: Self) -> bool {
+        pearlite! { self.cmp_log(o) != Ordering::Greater }
+    }
+
+    #[predicate]
+    #[open]
+    fn lt_log(self, o: Self) -> bool {
+        pearlite! { self.cmp_log(o) == Ordering::Less }
+    }
+
+    #[predicate]
+    #[open]
+    fn ge_log(self, o: Self) -> bool {
+        pearlite! { self.cmp_log(o) != Ordering::Less }
+    }
+
+    #[predicate]
+    #[open]
+    fn gt_log(self, o: Self) -> bool {
+        pearlite! { self.cmp_log(o) == Ordering::Greater }
+    }
+
+    ord_laws_impl! {}
+}
+
+impl PartialEq for Hand {
+    #[ensures(result == (self.deep_model() == other.deep_model()))]
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    #[ensures(result == Some((*self).deep_model().cmp_log((*other).deep_model())))]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    #[ensures(result == (*self).deep_model().cmp_log((*other).deep_model()))]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.code(), other.code());
+        if a == b {
+            Ordering::Equal
+        } else if (a + 1) % 3 == b {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::cell::*, *};
+
+// The contents of a standard cell can change through any shared reference to it: they are not
+// known after `set`.
+pub fn get_after_set(c: &Cell<u32>) {
+    c.set(5);
+    let x = c.get();
+    proof_assert!(x@ == 5);
+}
+
+pub struct Small;
+
+impl CellInv<u32> for Small {
+    #[predicate]
+    #[open]
+    fn cell_inv(x: u32) -> bool {
+        pearlite! { x@ < 10 }
+    }
+}
+
+// The value does not satisfy the predicate of the cell.
+pub fn set_too_large(c: &PredCell<u32, Small>) {
+    c.set(10);
+}
@@ -0,0 +1,19 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Counter {
+    // Stronger guarantee under the active configuration
+    #[cfg_attr(creusot, ensures(result@ > 0))]
+    fn next(&mut self) -> u32;
+}
+
+pub struct Zero;
+
+impl Counter for Zero {
+    // Does not refine the active contract of the trait
+    #[ensures(result == 0u32)]
+    fn next(&mut self) -> u32 {
+        0
+    }
+}
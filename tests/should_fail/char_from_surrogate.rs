@@ -0,0 +1,10 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn surrogate() -> char {
+    unsafe { char::from_u32_unchecked(0xD800) }
+}
+
+pub fn maybe_surrogate(n: u32) -> char {
+    char::from_u32(n).unwrap()
+}
@@ -0,0 +1,13 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(X > 0)]
+const X: i32 = -5;
+
+pub struct S;
+
+impl S {
+    #[ensures(result@ < 10)]
+    pub const BIG: u32 = 10;
+}
@@ -0,0 +1,10 @@
+// CREUSOT_ARG=--deny-trusted-external-impls
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// `impl PartialEq for Vec<T>` has no extern spec of its own: its refinement of the spec of
+// `PartialEq::eq` is trusted.
+#[ensures(result == (x.deep_model() == y.deep_model()))]
+pub fn same(x: &Vec<u32>, y: &Vec<u32>) -> bool {
+    x == y
+}
@@ -0,0 +1,25 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Int, *};
+
+pub struct Clock(pub u64);
+
+impl Clock {
+    #[logic]
+    #[trusted]
+    pub fn ticks(self, n: Int) -> Int {
+        dead
+    }
+
+    #[law]
+    #[open(self)]
+    #[trusted]
+    #[ensures(forall<c: Clock, n: Int> c.ticks(n) + 1 == c.ticks(n + 1))]
+    pub fn ticks_succ() {}
+}
+
+// `ticks` is opaque: without the law, nothing is known about it.
+#[creusot::disable_law(Clock::ticks_succ)]
+pub fn tick(c: Clock) {
+    proof_assert! { c.ticks(0) + 1 == c.ticks(1) }
+}
@@ -0,0 +1,22 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::fmt::DisplaySpec, *};
+use std::fmt::{self, Display, Formatter};
+
+pub struct Name(pub String);
+
+// The contract of `fmt` only gives the length of the output, not the output itself.
+impl Display for Name {
+    #[ensures(result.is_ok() ==> (^f)@.len() == f@.len() + self.0@.len())]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl DisplaySpec for Name {
+    #[logic]
+    #[open]
+    fn displayed(&self) -> Seq<char> {
+        pearlite! { self.0@ }
+    }
+}
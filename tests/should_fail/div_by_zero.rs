@@ -0,0 +1,13 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// `b` may be zero.
+pub fn div(a: u32, b: u32) -> u32 {
+    a / b
+}
+
+// `b` may be zero.
+pub fn rem(a: i64, b: i64) -> i64 {
+    if b == -1 { 0 } else { a % b }
+}
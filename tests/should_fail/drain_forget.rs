@@ -0,0 +1,10 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Error: the `Drain` is leaked, so the vector keeps only the elements before the range.
+#[requires(v@.len() >= 3)]
+#[ensures((^v)@ == v@.subsequence(0, 1).concat(v@.subsequence(3, v@.len())))]
+pub fn forget_drain(v: &mut Vec<u32>) {
+    std::mem::forget(v.drain(1..3));
+}
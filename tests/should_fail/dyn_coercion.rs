@@ -0,0 +1,32 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Named {
+    #[ensures(result@ > 0)]
+    fn name(&self) -> u32;
+}
+
+pub struct Dog;
+
+impl Named for Dog {
+    #[ensures(result@ == 1)]
+    fn name(&self) -> u32 {
+        1
+    }
+}
+
+// The trait object forgets the concrete type: the call only follows the contract of the trait.
+pub fn name_through_dyn() {
+    let d = Dog;
+    let named: &dyn Named = &d;
+    let n = named.name();
+    proof_assert!(n@ == 1);
+}
+
+// The trait object is not known to be the value it was created from.
+pub fn same_value() {
+    let p: &dyn Named = &Dog;
+    let q: &dyn Named = &Dog;
+    proof_assert!(p == q);
+}
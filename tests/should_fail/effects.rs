@@ -0,0 +1,27 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Bump {
+    #[writes(self)]
+    fn bump(&mut self, other: &mut Ghost<Int>);
+}
+
+impl Bump for Ghost<Int> {
+    // Declares more effects than the trait allows
+    #[writes(self, other)]
+    #[allow(unused_variables)]
+    fn bump(&mut self, other: &mut Ghost<Int>) {}
+}
+
+#[trusted]
+#[writes(x)]
+#[allow(unused_variables)]
+pub fn set_first(x: &mut Ghost<Int>, y: &mut Ghost<Int>) {}
+
+pub fn caller(x: &mut Ghost<Int>, y: &mut Ghost<Int>) {
+    let old_x = snapshot!(**x);
+    set_first(x, y);
+    // `x` may have changed
+    proof_assert!(**x == *old_x);
+}
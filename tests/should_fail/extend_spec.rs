@@ -0,0 +1,28 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::iter::ExtendSpec, *};
+
+pub struct Stack<T>(Vec<T>);
+
+impl<T> View for Stack<T> {
+    type ViewTy = Seq<T>;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Seq<T> {
+        pearlite! { self.0@ }
+    }
+}
+
+// The contract of `extend` does not say that the items are appended.
+impl<T> Extend<T> for Stack<T> {
+    #[trusted]
+    #[ensures((^self)@.len() >= self@.len())]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.0.insert(0, x);
+        }
+    }
+}
+
+impl<T> ExtendSpec<T> for Stack<T> {}
@@ -0,0 +1,28 @@
+// CREUSOT_ARG=--fail-fast
+extern crate creusot_contracts;
+
+// The same independent failures as in `keep_going.rs`: with `--fail-fast`, only the first one is
+// reported. The bodies are translated before the impls, so this is the failure of `call_next`.
+
+pub struct S;
+
+impl Iterator for S {
+    type Item = ();
+    fn next(&mut self) -> Option<()> {
+        None
+    }
+}
+
+pub struct T;
+
+impl Iterator for T {
+    type Item = ();
+    fn next(&mut self) -> Option<()> {
+        None
+    }
+}
+
+// A third failure, at a call site: the bound of the extern spec does not hold for `S` either.
+pub fn call_next(mut s: S) {
+    s.next();
+}
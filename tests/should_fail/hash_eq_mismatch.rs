@@ -0,0 +1,54 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::hash::{Hash, HashLogic, Hasher},
+    *,
+};
+
+// Equality ignores `cache`, but the hash does not: equal keys may have different hashes.
+pub struct Key {
+    id: u32,
+    cache: u32,
+}
+
+impl DeepModel for Key {
+    type DeepModelTy = Int;
+
+    #[logic]
+    #[open]
+    fn deep_model(self) -> Int {
+        pearlite! { self.id@ }
+    }
+}
+
+impl PartialEq for Key {
+    #[ensures(result == (self.deep_model() == other.deep_model()))]
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    #[trusted]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.cache.hash(state);
+    }
+}
+
+impl HashLogic for Key {
+    type HashTy = (Int, Int);
+
+    #[logic]
+    #[open]
+    fn hash_log(self) -> (Int, Int) {
+        pearlite! { (self.id@, self.cache@) }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(a.deep_model() == b.deep_model() ==> a.hash_log() == b.hash_log())]
+    fn hash_eq_consistent(a: Self, b: Self) {}
+}
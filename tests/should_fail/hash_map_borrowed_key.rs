@@ -0,0 +1,28 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+use std::{borrow::Borrow, collections::HashMap};
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Id(pub u64);
+
+impl DeepModel for Id {
+    type DeepModelTy = Int;
+
+    #[logic]
+    #[open]
+    fn deep_model(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+impl Borrow<u64> for Id {
+    #[ensures(*result == self.0)]
+    fn borrow(&self) -> &u64 {
+        &self.0
+    }
+}
+
+// Error: indexing is only specified by the key type.
+pub fn by_borrowed_key(m: &HashMap<Id, u32>) -> u32 {
+    m[&1]
+}
@@ -0,0 +1,29 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{vec, *};
+
+pub trait Encode {
+    #[impls_ensure(result@.len() > 0)]
+    fn encode(&self) -> Vec<u8>;
+}
+
+impl Encode for () {
+    // Error: the encoding of `()` is empty
+    #[ensures(result@.len() == 0)]
+    fn encode(&self) -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+impl Encode for u8 {
+    // Error: nothing guarantees that the encoding is non-empty
+    fn encode(&self) -> Vec<u8> {
+        vec![*self]
+    }
+}
+
+// Error: the `impls_ensure` clause is not part of the contract of `encode`
+#[ensures(result@.len() > 0)]
+pub fn encode_generic<T: Encode>(x: &T) -> Vec<u8> {
+    x.encode()
+}
@@ -0,0 +1,28 @@
+extern crate creusot_contracts;
+
+// Independent failures: neither iterator implements the logical `Iterator` trait required by the
+// extern spec of `next`, which is checked for both impls and for a call. All of them are reported,
+// since `--keep-going` is the default.
+
+pub struct S;
+
+impl Iterator for S {
+    type Item = ();
+    fn next(&mut self) -> Option<()> {
+        None
+    }
+}
+
+pub struct T;
+
+impl Iterator for T {
+    type Item = ();
+    fn next(&mut self) -> Option<()> {
+        None
+    }
+}
+
+// A third failure, at a call site: the bound of the extern spec does not hold for `S` either.
+pub fn call_next(mut s: S) {
+    s.next();
+}
@@ -0,0 +1,46 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result == result)]
+pub fn same_result(x: u32) -> u32 {
+    x
+}
+
+#[ensures(x.0 <= x.0)]
+pub fn same_field(x: (u32, u32)) {}
+
+#[ensures(^x == ^x)]
+pub fn same_final(x: &mut u32) {}
+
+#[ensures(forall<i: Int> i >= 0 ==> true)]
+pub fn quantified() {}
+
+#[ensures(result@ > 0 || true)]
+pub fn disjunction() -> u32 {
+    1
+}
+
+#[ensures(false ==> result == 0u32)]
+pub fn false_premise() -> u32 {
+    1
+}
+
+// Not vacuous
+#[ensures(result == x)]
+pub fn identity(x: u32) -> u32 {
+    x
+}
+
+// Not linted: a literal `true` is the usual way of giving a function a contract
+#[ensures(true)]
+pub fn literal() {}
+
+#[allow(creusot::vacuous_postcondition)]
+#[ensures(true && true)]
+pub fn allowed() {}
+
+#[deny(creusot::vacuous_postcondition)]
+#[ensures(result == result)]
+pub fn denied() -> bool {
+    true
+}
@@ -0,0 +1,29 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{util::unreachable, *};
+
+// The case `None` is not excluded by a precondition
+#[logic]
+#[open]
+pub fn get_unchecked(x: Option<Int>) -> Int {
+    match x {
+        Some(y) => y,
+        None => unreachable(),
+    }
+}
+
+#[logic]
+#[open]
+#[requires(x != None)]
+pub fn get(x: Option<Int>) -> Int {
+    match x {
+        Some(y) => y,
+        None => unreachable(),
+    }
+}
+
+// The call does not establish the precondition of `get`
+#[logic]
+pub fn use_get() -> Int {
+    get(None)
+}
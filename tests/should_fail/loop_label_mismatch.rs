@@ -0,0 +1,10 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn mismatch(n: u32) {
+    let mut i = 0u32;
+    #[invariant('other: i <= n)]
+    'outer: while i < n {
+        i += 1;
+    }
+}
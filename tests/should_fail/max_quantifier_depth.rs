@@ -0,0 +1,10 @@
+// CREUSOT_ARG=--max-quantifier-depth=1
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(forall<i: Int> exists<j: Int> j > i)]
+pub fn alternation() {}
+
+// Consecutive quantifiers of the same kind count as one level
+#[ensures(forall<i: Int, j: Int> forall<k: Int> i + j + k == k + j + i)]
+pub fn block() {}
@@ -0,0 +1,14 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub mod counter {
+    #[creusot::opaque]
+    pub struct Counter {
+        pub count: u32,
+    }
+}
+
+#[ensures(result.count == 0u32)]
+pub fn new() -> counter::Counter {
+    counter::Counter { count: 0 }
+}
@@ -0,0 +1,34 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::ops::{Add, AddCommutative, AddSpec},
+    *,
+};
+
+#[derive(Clone, Copy)]
+pub struct Diff(pub i32);
+
+impl Add for Diff {
+    type Output = Diff;
+
+    #[ensures(result == self.add_logic(rhs))]
+    fn add(self, rhs: Self) -> Self {
+        Diff(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl AddSpec for Diff {
+    #[logic]
+    #[open]
+    fn add_logic(self, rhs: Self) -> Self {
+        pearlite! { Diff(self.0 - rhs.0) }
+    }
+}
+
+// The subtraction is not commutative: this law cannot be proved
+impl AddCommutative for Diff {
+    #[law]
+    #[open(self)]
+    #[ensures(a.add_logic(b) == b.add_logic(a))]
+    fn add_commutative(a: Self, b: Self) {}
+}
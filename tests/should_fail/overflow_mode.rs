@@ -0,0 +1,24 @@
+// WHY3PROVE
+#![feature(custom_inner_attributes)]
+extern crate creusot_contracts;
+
+// The overflow of `x + 1` is an error by default.
+pub mod check {
+    use creusot_contracts::*;
+
+    #[ensures(result@ == x@ + 1)]
+    pub fn incr(x: u8) -> u8 {
+        x + 1
+    }
+}
+
+// Wrapping does not give the mathematical result.
+pub mod wrap {
+    #![creusot::backend(overflow = "wrap")]
+    use creusot_contracts::*;
+
+    #[ensures(result@ == x@ + 1)]
+    pub fn incr(x: u8) -> u8 {
+        x + 1
+    }
+}
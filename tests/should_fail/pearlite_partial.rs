@@ -0,0 +1,18 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Logic functions must be total: patterns that may not match are rejected.
+
+#[logic]
+pub fn let_else(x: Option<Int>) -> Int {
+    let Some(y) = x else { return 0 };
+    y
+}
+
+#[logic]
+pub fn or_pattern(x: Option<Int>) -> Int {
+    match x {
+        Some(0) | None => 0,
+        Some(y) => y,
+    }
+}
@@ -0,0 +1,7 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// `u64::pow` requires the result to be in range.
+pub fn pow_overflow(x: u64) -> u64 {
+    x.pow(2)
+}
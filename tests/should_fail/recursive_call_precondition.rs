@@ -0,0 +1,28 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Error: the recursive call is made with an odd number. The failure is reported at the call, as a
+// recursive call violating the precondition.
+#[requires(n@ % 2 == 0)]
+#[variant(n)]
+#[ensures(result@ == n@ / 2)]
+pub fn half(n: u32) -> u32 {
+    if n == 0 { 0 } else { half(n - 1) + 1 }
+}
+
+// Error: `countdown_a(2)` calls `countdown_b(0)`. The calls between the two functions are
+// recursive calls too.
+#[requires(n@ > 0)]
+pub fn countdown_a(n: u32) {
+    if n > 1 {
+        countdown_b(n - 2)
+    }
+}
+
+#[requires(n@ > 0)]
+pub fn countdown_b(n: u32) {
+    if n > 1 {
+        countdown_a(n - 1)
+    }
+}
@@ -0,0 +1,31 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The bound is not a variable: no variant is inferred.
+#[terminates]
+pub fn conditional_step(v: &[bool]) -> usize {
+    let mut i = 0;
+    let mut n = 0;
+    #[invariant(i@ <= v@.len())]
+    while i < v.len() {
+        if v[i] {
+            n += 1;
+        }
+        i += 1;
+    }
+    n
+}
+
+// The body may skip the step.
+#[terminates]
+pub fn continue_skips_step(n: u32) {
+    let mut i = 0;
+    #[invariant(i@ <= n@)]
+    while i < n {
+        if i == 5 {
+            continue;
+        }
+        i += 1;
+    }
+}
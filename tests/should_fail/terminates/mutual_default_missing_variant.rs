@@ -0,0 +1,20 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Parity {
+    #[logic]
+    #[open(self)]
+    #[variant(n)]
+    #[requires(n >= 0)]
+    fn even(n: Int) -> bool {
+        if n == 0 { true } else { Self::odd(n - 1) }
+    }
+
+    // Without a variant, nothing shows that the group terminates
+    #[logic]
+    #[open(self)]
+    #[requires(n >= 0)]
+    fn odd(n: Int) -> bool {
+        if n == 0 { false } else { Self::even(n - 1) }
+    }
+}
@@ -0,0 +1,22 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Parity {
+    #[logic]
+    #[open(self)]
+    #[variant(n)]
+    #[requires(n >= 0)]
+    fn even(n: Int) -> bool {
+        if n == 0 { true } else { Self::odd(n) }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[variant(n)]
+    #[requires(n >= 0)]
+    fn odd(n: Int) -> bool {
+        // The variant does not decrease when going back to `even`
+        if n == 0 { false } else { Self::even(n) }
+    }
+}
@@ -0,0 +1,34 @@
+#![allow(unused)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Countdown {
+    // `#[terminates]` guarantees that `countdown` terminates
+    #[terminates]
+    #[variant(n)]
+    fn countdown(n: u32);
+}
+
+impl Countdown for () {
+    #[terminates]
+    #[variant(n)]
+    fn countdown(n: u32) {
+        if n > 0 {
+            Self::countdown(n - 1)
+        }
+    }
+}
+
+impl Countdown for i32 {
+    // Error: nothing justifies the termination of this implementation
+    fn countdown(n: u32) {
+        let mut i = n;
+        while i > 0 {}
+    }
+}
+
+pub trait Countup {
+    // Error: a variant alone does not guarantee termination
+    #[variant(n)]
+    fn countup(n: u32);
+}
@@ -0,0 +1,16 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Tokens, *};
+
+pub fn double_consume() {
+    let mut t = ghost!(Tokens::new(1int));
+    ghost! {
+        t.consume();
+        t.consume();
+    };
+}
+
+pub fn split_too_much() {
+    let mut t = ghost!(Tokens::new(1int));
+    let _u = ghost!(t.split(2int));
+}
@@ -0,0 +1,18 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The contract of an abstract trait method must be refined by impls that do not
+// provide a contract of their own.
+
+pub trait Tr {
+    #[ensures(result@ > 0)]
+    fn get(&self) -> u32;
+}
+
+impl Tr for () {
+    // This should not prove: there is no postcondition to establish `result@ > 0`
+    fn get(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,32 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::convert::RoundTrip, *};
+
+pub struct Narrow(pub u8);
+
+impl From<u16> for Narrow {
+    #[ensures(result.0@ == value@ % 256)]
+    fn from(value: u16) -> Self {
+        Narrow(value as u8)
+    }
+}
+
+impl RoundTrip<u16> for Narrow {
+    #[logic]
+    #[open]
+    fn from_logic(value: u16) -> Self {
+        Narrow(value as u8)
+    }
+
+    #[logic]
+    #[open]
+    fn back(self) -> u16 {
+        self.0 as u16
+    }
+
+    // This should not prove: the conversion loses the high byte
+    #[law]
+    #[open(self)]
+    #[ensures(Self::from_logic(value).back() == value)]
+    fn round_trip(value: u16) {}
+}
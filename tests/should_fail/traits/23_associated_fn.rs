@@ -0,0 +1,23 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Make: View<ViewTy = Int> + Sized {
+    #[ensures(result@ == 0)]
+    fn new() -> Self;
+
+    #[ensures(result@ == n@)]
+    fn with_size<U>(n: u32, filler: U) -> Self;
+}
+
+impl Make for u32 {
+    // Does not refine the trait: the result is not constrained
+    fn new() -> Self {
+        0
+    }
+
+    #[ensures(result@ <= n@)]
+    fn with_size<U>(n: u32, _filler: U) -> Self {
+        n
+    }
+}
@@ -0,0 +1,26 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl View for Point {
+    type ViewTy = (Int, Int);
+
+    #[logic]
+    #[open]
+    fn view(self) -> (Int, Int) {
+        pearlite! { (self.x@, self.y@) }
+    }
+}
+
+// The clone forgets `y`: it does not refine the spec of `Clone::clone`, and thus changes the view.
+impl Clone for Point {
+    #[ensures(result.x == self.x)]
+    fn clone(&self) -> Self {
+        Point { x: self.x, y: 0 }
+    }
+}
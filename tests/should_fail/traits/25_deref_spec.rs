@@ -0,0 +1,33 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::ops::{Deref, DerefMut, DerefSpec},
+    *,
+};
+
+pub struct Wrapper(pub u32);
+
+impl Deref for Wrapper {
+    type Target = u32;
+
+    #[ensures(*result == self.0)]
+    fn deref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+// The contract does not say that the changes made through the reference are changes of the
+// target: it does not refine the specification of `DerefSpec`.
+impl DerefMut for Wrapper {
+    #[ensures(*result == (*self).0)]
+    fn deref_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+}
+
+impl DerefSpec for Wrapper {
+    #[logic]
+    #[open]
+    fn target(self) -> u32 {
+        self.0
+    }
+}
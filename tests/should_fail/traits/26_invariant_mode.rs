@@ -0,0 +1,26 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct Even(pub u32);
+
+impl Invariant for Even {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ % 2 == 0 }
+    }
+}
+
+pub trait Make {
+    fn make() -> Even;
+}
+
+pub struct Impl;
+
+// The trait ensures the invariant of the result.
+impl Make for Impl {
+    #[creusot::invariant_mode(open_result)]
+    fn make() -> Even {
+        Even(1)
+    }
+}
@@ -0,0 +1,28 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Buckets {
+    const N: u32;
+
+    #[requires(Self::N@ > 0)]
+    #[ensures(result@ < Self::N@)]
+    fn bucket(&self, key: u32) -> u32;
+}
+
+pub struct NoBuckets;
+
+impl Buckets for NoBuckets {
+    const N: u32 = 0;
+
+    #[requires(Self::N@ > 0)]
+    #[ensures(result@ < Self::N@)]
+    fn bucket(&self, _: u32) -> u32 {
+        0
+    }
+}
+
+// `N = 0`: the condition on the constant does not hold, so the method cannot be called.
+pub fn no_bucket(key: u32) -> u32 {
+    NoBuckets.bucket(key)
+}
@@ -0,0 +1,39 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Len {
+    #[logic]
+    fn len_log(self) -> Int;
+
+    #[law]
+    #[ensures(x.len_log() <= 32)]
+    fn small(x: Self)
+    where
+        Self: Default;
+}
+
+pub mod arrays {
+    use super::*;
+
+    impl<const N: usize> Len for [u32; N] {
+        #[logic]
+        fn len_log(self) -> Int {
+            pearlite! { N@ }
+        }
+
+        #[law]
+        #[trusted]
+        #[ensures(x.len_log() <= 32)]
+        fn small(x: Self)
+        where
+            Self: Default,
+        {
+        }
+    }
+}
+
+// `[u32; 40]: Default` does not hold, so the law is not available here.
+pub fn large_array(a: [u32; 40]) {
+    proof_assert! { a.len_log() <= 32 };
+}
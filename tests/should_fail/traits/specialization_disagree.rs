@@ -0,0 +1,24 @@
+// WHY3PROVE
+#![feature(min_specialization)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Size {
+    fn size(&self) -> usize;
+}
+
+impl<T> Size for Vec<T> {
+    #[ensures(result@ == self@.len())]
+    default fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+// The body satisfies its own contract, but not the one of the base impl: a caller which
+// resolves `size` to the base impl would observe a different result.
+impl Size for Vec<()> {
+    #[ensures(result@ == 0)]
+    fn size(&self) -> usize {
+        0
+    }
+}
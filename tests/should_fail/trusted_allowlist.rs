@@ -0,0 +1,28 @@
+// CREUSOT_ARG=--trusted-allowlist=trusted_allowlist.txt
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[trusted]
+#[ensures(result == 1u32)]
+pub fn allowed() -> u32 {
+    1
+}
+
+#[trusted]
+#[ensures(result == 2u32)]
+pub fn not_allowed() -> u32 {
+    1
+}
+
+#[trusted]
+pub mod unchecked {
+    use creusot_contracts::*;
+
+    #[ensures(result == 3u32)]
+    pub fn in_trusted_module() -> u32 {
+        0
+    }
+}
+
+#[trusted]
+pub struct Opaque(u32);
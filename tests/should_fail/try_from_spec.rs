@@ -0,0 +1,29 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::convert::TryFromSpec, *};
+
+pub struct Percent(u8);
+
+// The contract of `try_from` does not say when it fails.
+impl TryFrom<u8> for Percent {
+    type Error = ();
+
+    #[ensures(match result { Ok(p) => p.0 == value, Err(_) => true })]
+    fn try_from(value: u8) -> Result<Self, ()> {
+        if value <= 100 { Ok(Percent(value)) } else { Err(()) }
+    }
+}
+
+impl TryFromSpec<u8> for Percent {
+    #[predicate]
+    #[open]
+    fn fails(value: u8) -> bool {
+        pearlite! { value@ > 100 }
+    }
+
+    #[predicate]
+    #[open]
+    fn converts(value: u8, result: Self) -> bool {
+        result.0 == value
+    }
+}
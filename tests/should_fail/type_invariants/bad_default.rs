@@ -0,0 +1,38 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, std::default::DefaultSpec, *};
+
+pub struct NonZero(pub u32);
+
+impl Invariant for NonZero {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ != 0 }
+    }
+}
+
+// The result of `default` violates the invariant of `NonZero`.
+impl Default for NonZero {
+    fn default() -> Self {
+        NonZero(0)
+    }
+}
+
+pub struct Small(pub u32);
+
+impl Default for Small {
+    #[ensures(result.0@ < 10)]
+    fn default() -> Self {
+        Small(1)
+    }
+}
+
+// The contract of `default` does not imply `is_default`.
+impl DefaultSpec for Small {
+    #[predicate]
+    #[open]
+    fn is_default(self) -> bool {
+        pearlite! { self.0@ == 1 }
+    }
+}
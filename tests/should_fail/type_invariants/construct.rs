@@ -0,0 +1,29 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct NonZero(pub u32);
+
+impl Invariant for NonZero {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ != 0 }
+    }
+}
+
+// The value is never used, but building it must still establish the invariant.
+pub fn unused_literal() {
+    let _x = NonZero(0);
+}
+
+pub enum Wrapped {
+    Empty,
+    Value(NonZero),
+}
+
+#[requires(n@ < 10)]
+pub fn bad_variant(n: u32) -> bool {
+    let w = Wrapped::Value(NonZero(n));
+    matches!(w, Wrapped::Empty)
+}
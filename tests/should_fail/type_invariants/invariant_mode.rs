@@ -0,0 +1,27 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct Even(pub u32);
+
+impl Invariant for Even {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ % 2 == 0 }
+    }
+}
+
+// The invariant of `x` is not assumed.
+#[creusot::invariant_mode(open_args)]
+#[ensures(x.0@ % 2 == 0)]
+pub fn open_arg(x: Even) {}
+
+#[creusot::invariant_mode(open_result)]
+pub fn open_result(x: u32) -> Even {
+    Even(x)
+}
+
+// The invariant of the result of `open_result` is not known.
+pub fn use_open_result() -> Even {
+    open_result(1)
+}
@@ -0,0 +1,37 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct Even(u32);
+
+impl Invariant for Even {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ % 2 == 0 }
+    }
+}
+
+#[creusot::maintains_invariant]
+impl Even {
+    pub fn new() -> Self {
+        Even(0)
+    }
+
+    #[requires(self.0@ + 2 <= u32::MAX@)]
+    pub fn add_two(&mut self) {
+        self.0 += 2;
+    }
+
+    // Forgets to maintain the invariant. Opening the invariants of its arguments would hide this
+    // without `#[creusot::maintains_invariant]`, which overrides it.
+    #[creusot::invariant_mode(open_args)]
+    #[requires(self.0@ < u32::MAX@)]
+    pub fn incr(&mut self) {
+        self.0 += 1;
+    }
+
+    pub fn get(&self) -> u32 {
+        self.0
+    }
+}
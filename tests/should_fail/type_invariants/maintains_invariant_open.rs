@@ -0,0 +1,23 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct Even(u32);
+
+impl Invariant for Even {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ % 2 == 0 }
+    }
+}
+
+#[creusot::maintains_invariant]
+impl Even {
+    // Methods of the block may not opt out of the invariant: the invariant of the result is still
+    // proved, which fails for an odd `x`.
+    #[creusot::invariant_mode(open_result)]
+    pub fn raw(x: u32) -> Self {
+        Even(x)
+    }
+}
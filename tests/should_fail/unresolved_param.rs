@@ -0,0 +1,20 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Tr {
+    fn m(&self);
+}
+
+impl Tr for u32 {
+    #[pure]
+    fn m(&self) {}
+}
+
+pub fn concrete(x: &u32) {
+    ghost! { x.m() };
+}
+
+// The impl of `Tr` for `T` is only known from the bound: the trait item is not pure.
+pub fn generic<T: Tr>(x: &T) {
+    ghost! { x.m() };
+}
@@ -0,0 +1,17 @@
+#![feature(min_specialization)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Tr {
+    fn m(&self);
+}
+
+impl<T> Tr for T {
+    #[pure]
+    default fn m(&self) {}
+}
+
+// The blanket impl may be specialized for `T`: the trait item is used, and it is not pure.
+pub fn generic<T>(x: &T) {
+    ghost! { x.m() };
+}
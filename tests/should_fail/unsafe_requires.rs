@@ -0,0 +1,14 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The safety condition of the block does not hold for an empty slice.
+pub fn first(v: &[u32]) -> u32 {
+    unsafe {
+        unsafe_requires!(0 < v@.len());
+        *v.get_unchecked(0)
+    }
+}
+
+pub fn outside(v: &[u32]) {
+    unsafe_requires!(v@.len() == v@.len());
+}
@@ -0,0 +1,12 @@
+// CREUSOT_ARG=--check-precondition-satisfiability WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The preconditions are contradictory: the goal `satisfiable` of this function cannot be proved,
+// so that it is reported when the proofs are run.
+#[requires(x@ > 0)]
+#[requires(x@ < 0)]
+#[ensures(result@ == 42)]
+pub fn contradictory(x: i32) -> i32 {
+    0
+}
@@ -0,0 +1,21 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[creusot::verify_monomorphized]
+pub fn exported<T>(x: T) -> T {
+    x
+}
+
+#[creusot::verify_monomorphized]
+fn private<T>(x: T) -> T {
+    x
+}
+
+pub fn generic_caller<T>(x: T) -> T {
+    private(x)
+}
+
+pub fn generic_value<T>(x: T) -> T {
+    let f = private::<T>;
+    f(x)
+}
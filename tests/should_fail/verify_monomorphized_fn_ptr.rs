@@ -0,0 +1,25 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Value {
+    fn value(&self) -> u32;
+}
+
+impl Value for u32 {
+    #[ensures(result == *self)]
+    fn value(&self) -> u32 {
+        *self
+    }
+}
+
+// The multiplication overflows for `u32`.
+#[creusot::verify_monomorphized]
+fn doubled<T: Value>(x: &T) -> u32 {
+    x.value() * 2
+}
+
+// `doubled::<u32>` is never called directly, but becomes a function pointer.
+pub fn reified() -> fn(&u32) -> u32 {
+    doubled::<u32>
+}
@@ -0,0 +1,33 @@
+// CREUSOT_ARG=--closed-crate=view_coherence WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{logic::FSet, *};
+
+pub trait Views {
+    #[logic]
+    fn seq_view(self) -> Seq<Int>;
+
+    #[logic]
+    fn set_view(self) -> FSet<Int>;
+
+    #[law]
+    #[forall_impls]
+    #[ensures(x.set_view() == x.seq_view().to_set())]
+    fn coherence(x: Self) {}
+}
+
+pub struct Pair(i32, i32);
+
+// The set view forgets the second element: the coherence law does not hold for this impl.
+impl Views for Pair {
+    #[logic]
+    #[open]
+    fn seq_view(self) -> Seq<Int> {
+        pearlite! { seq![self.0@, self.1@] }
+    }
+
+    #[logic]
+    #[open]
+    fn set_view(self) -> FSet<Int> {
+        pearlite! { FSet::singleton(self.0@) }
+    }
+}
@@ -0,0 +1,20 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result@ == 5)]
+pub fn abs_of_negative() -> i32 {
+    proof_assert!((-5).abs() == 5);
+    (-5i32).abs()
+}
+
+#[requires(x@ != i32::MIN@)]
+#[ensures(result@ >= 0)]
+#[ensures(result == x || result@ == -x@)]
+pub fn abs_i32(x: i32) -> i32 {
+    x.abs()
+}
+
+#[logic]
+#[open]
+#[ensures((-x).abs() == x.abs())]
+pub fn abs_neg(x: Int) {}
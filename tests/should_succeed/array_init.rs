@@ -0,0 +1,44 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[predicate]
+pub fn even(x: u32) -> bool {
+    pearlite! { x@ % 2 == 0 }
+}
+
+// The elements are pushed to a vector, whose view is the initialized prefix of the array: the
+// invariant only talks about the indices in `[0, i)`.
+#[requires(N@ < 1000)]
+#[ensures(result@.len() == N@)]
+#[ensures(forall<j: Int> 0 <= j && j < N@ ==> even(result@[j]) && result@[j]@ == 2 * j)]
+pub fn evens<const N: usize>() -> [u32; N] {
+    let mut prefix = Vec::with_capacity(N);
+    let mut i = 0;
+    #[invariant(i@ <= N@)]
+    #[invariant(prefix@.len() == i@)]
+    #[invariant(forall<j: Int> 0 <= j && j < i@ ==> even(prefix@[j]) && prefix@[j]@ == 2 * j)]
+    while i < N {
+        prefix.push(2 * i as u32);
+        i += 1;
+    }
+    <[u32; N]>::try_from(prefix).unwrap()
+}
+
+#[ensures(forall<j: Int> 0 <= j && j < N@ ==> result@[j]@ == j)]
+pub fn indices<const N: usize>() -> [usize; N] {
+    let mut prefix = Vec::new();
+    #[invariant(prefix@.len() == produced.len())]
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> prefix@[j]@ == j)]
+    for i in 0..N {
+        prefix.push(i);
+    }
+    match <[usize; N]>::try_from(prefix) {
+        Ok(a) => a,
+        Err(_) => unreachable!(),
+    }
+}
+
+pub fn use_evens() {
+    let a = evens::<4>();
+    proof_assert!(a@[3]@ == 6);
+}
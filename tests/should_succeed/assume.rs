@@ -0,0 +1,15 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn assume_bound(x: u32) -> u32 {
+    assume!(x@ < 100);
+    proof_assert!(x@ < 1000);
+    x + 1
+}
+
+pub fn assume_in_ghost(x: u32) {
+    ghost! {
+        assume!(x@ % 2 == 0);
+        proof_assert!((x@ + 1) % 2 == 1);
+    };
+}
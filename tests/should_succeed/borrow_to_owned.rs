@@ -0,0 +1,46 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::borrow::Borrow, *};
+
+// A key, borrowed from the owned entries of a cache.
+pub struct Key(pub u32);
+
+impl View for Key {
+    type ViewTy = Int;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+pub struct Entry {
+    pub key: Key,
+    pub hits: u32,
+}
+
+// The law `borrow_to_owned_consistent` is proved for this impl: the key of the entry built by
+// `to_owned` is the original one.
+impl ToOwned for Key {
+    type Owned = Entry;
+
+    #[ensures(result.key@ == self@ && result.hits@ == 0)]
+    fn to_owned(&self) -> Entry {
+        Entry { key: Key(self.0), hits: 0 }
+    }
+}
+
+impl Borrow<Key> for Entry {
+    #[ensures(*result == self.key)]
+    fn borrow(&self) -> &Key {
+        &self.key
+    }
+}
+
+// The law is available where the impl is used.
+pub fn lookup(k: &Key) {
+    let e = k.to_owned();
+    let b: &Key = e.borrow();
+    proof_assert! { b@ == k@ }
+}
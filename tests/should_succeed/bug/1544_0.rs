@@ -1,4 +1,4 @@
 extern crate creusot_contracts;
 
-// Should emit a readable error message, not a crash
+// Trait objects used to be rejected with an error.
 pub fn unsupported_type(_x: &dyn std::fmt::Debug) {}
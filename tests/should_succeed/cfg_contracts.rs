@@ -0,0 +1,25 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Counter {
+    #[cfg_attr(creusot, ensures(result@ > 0))]
+    #[cfg_attr(not(creusot), ensures(false))]
+    fn next(&mut self) -> u32;
+}
+
+pub struct One;
+
+impl Counter for One {
+    #[ensures(result == 1u32)]
+    fn next(&mut self) -> u32 {
+        1
+    }
+}
+
+// The clauses only present under an inactive configuration are not part of the contract
+#[cfg_attr(not(creusot), requires(false))]
+#[cfg_attr(creusot, ensures(result@ > 0), ensures(result@ < 1000))]
+pub fn bounded<C: Counter>(c: &mut C) -> u32 {
+    let x = c.next();
+    if x < 1000 { x } else { 1 }
+}
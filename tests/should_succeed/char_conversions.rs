@@ -0,0 +1,65 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::char::is_scalar_value, *};
+
+pub fn ascii_code() {
+    let a = 'A' as u32;
+    proof_assert!(a@ == 65);
+    let z = 'z' as u8;
+    proof_assert!(z@ == 122);
+}
+
+#[ensures(result@ < 0x110000)]
+pub fn code(c: char) -> u32 {
+    c as u32
+}
+
+#[ensures(c@ < 256 ==> result == Some(c as u8))]
+pub fn to_latin1(c: char) -> Option<u8> {
+    if (c as u32) < 256 { Some(c as u8) } else { None }
+}
+
+pub fn surrogate() {
+    let s = char::from_u32(0xD800);
+    proof_assert!(s == None);
+    let c = char::from_u32(0x41);
+    proof_assert!(c == Some('A'));
+}
+
+#[requires(is_scalar_value(n@))]
+#[ensures(result@ == n@)]
+pub fn from_scalar(n: u32) -> char {
+    match char::from_u32(n) {
+        Some(c) => c,
+        None => unreachable!(),
+    }
+}
+
+/// The length of the longest prefix of `s` made of decimal digits.
+#[ensures(result@ <= s@.len())]
+#[ensures(forall<j> 0 <= j && j < result@ ==> 48 <= s@[j]@ && s@[j]@ <= 57)]
+#[ensures(result@ < s@.len() ==> !(48 <= s@[result@]@ && s@[result@]@ <= 57))]
+pub fn digits_prefix(s: &[char]) -> usize {
+    let mut i = 0;
+    #[invariant(i@ <= s@.len())]
+    #[invariant(forall<j> 0 <= j && j < i@ ==> 48 <= s@[j]@ && s@[j]@ <= 57)]
+    while i < s.len() {
+        match s[i].to_digit(10) {
+            Some(_) => i += 1,
+            None => break,
+        }
+    }
+    i
+}
+
+pub fn classification(c: char) {
+    if c.is_ascii_digit() {
+        assert!(c.is_ascii_alphanumeric() && c.is_ascii_hexdigit());
+        assert!(c.to_digit(10).is_some());
+    }
+    if c.is_ascii_lowercase() {
+        let u = c.to_ascii_uppercase();
+        proof_assert!(u@ + 32 == c@);
+        assert!(u.is_ascii_uppercase());
+    }
+    assert!(!' '.is_ascii_alphabetic() && ' '.is_ascii_whitespace());
+}
@@ -0,0 +1,41 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn checked_add_u8() {
+    let none = u8::checked_add(200, 100);
+    proof_assert!(none == None);
+    let some = u8::checked_add(100, 100);
+    proof_assert!(some == Some(200u8));
+}
+
+#[ensures(result == None ==> a@ + b@ > u32::MAX@)]
+#[ensures(forall<r: u32> result == Some(r) ==> r@ == a@ + b@)]
+pub fn sum(a: u32, b: u32) -> Option<u32> {
+    a.checked_add(b)
+}
+
+#[requires(v@.len() <= 3)]
+#[requires(forall<i> 0 <= i && i < v@.len() ==> v@[i]@ <= 1000)]
+#[ensures(result != None)]
+pub fn sum_small(v: &[u16]) -> Option<u16> {
+    let mut acc = 0u16;
+    let mut i = 0;
+    #[invariant(i@ <= v@.len())]
+    #[invariant(acc@ <= 1000 * i@)]
+    while i < v.len() {
+        acc = acc.checked_add(v[i])?;
+        i += 1;
+    }
+    Some(acc)
+}
+
+pub fn checked_neg_rem() {
+    assert!(0u8.checked_neg() == Some(0));
+    assert!(1u8.checked_neg().is_none());
+    assert!(i8::MIN.checked_neg().is_none());
+    assert!(5i8.checked_neg() == Some(-5));
+
+    assert!((-7i8).checked_rem(2) == Some(-1));
+    assert!(7u8.checked_rem(0).is_none());
+    assert!(i8::MIN.checked_rem(-1).is_none());
+}
@@ -0,0 +1,24 @@
+// CREUSOT_ARG=--closed-crate=closed_crate
+#![feature(min_specialization)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// This crate plays the role of the upstream crate of a closed workspace: since no unknown crate
+// can implement `Tr`, the blanket implementation for `&T` cannot be specialized by a downstream
+// crate.
+
+pub trait Tr {
+    fn m(self) -> u32;
+}
+
+impl<T> Tr for &T {
+    #[ensures(result == 1u32)]
+    default fn m(self) -> u32 {
+        1
+    }
+}
+
+pub fn resolved<T>(x: &T) {
+    let r = x.m();
+    proof_assert!(r == 1u32);
+}
@@ -0,0 +1,42 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The captured `&mut u32` is a field of the closure: its final value is the prophecy of `r`.
+#[requires((*r)@ < 1_000)]
+#[ensures((^r)@ == (*r)@ + 2)]
+pub fn incr_twice(r: &mut u32) {
+    let mut incr = #[requires((*r)@ < 1_000_000)]
+    #[ensures((*r)@ == (*old(r))@ + 1)]
+    #[ensures(^r == ^old(r))]
+    move || {
+        *r += 1;
+    };
+    incr();
+    incr();
+}
+
+// A closure capturing `counter` by unique borrow, whose postcondition refers to the captured
+// state before and after the call.
+#[requires(counter@ == 0)]
+pub fn count_calls(mut counter: u32) {
+    let mut tick = #[requires(counter@ < 10)]
+    #[ensures(counter@ == old(counter)@ + 1)]
+    #[ensures(result == old(counter))]
+    || {
+        let before = counter;
+        counter += 1;
+        before
+    };
+    let a = tick();
+    let b = tick();
+    proof_assert!(a@ == 0 && b@ == 1);
+    proof_assert!(counter@ == 2);
+}
+
+pub fn shared_capture(x: u32) {
+    let r = &x;
+    let get = #[ensures(result == *r)]
+    move || *r;
+    let v = get();
+    proof_assert!(v == x);
+}
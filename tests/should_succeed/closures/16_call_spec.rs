@@ -0,0 +1,22 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Pair<T>(pub T, pub T);
+
+impl<T> Pair<T> {
+    #[requires(call_spec!(f, self.0).pre() && call_spec!(f, self.1).pre())]
+    #[ensures(call_spec!(f, self.0).holds(result.0))]
+    #[ensures(call_spec!(f, self.1).holds(result.1))]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Pair<U> {
+        Pair(f(self.0), f(self.1))
+    }
+}
+
+#[ensures(result.0@ == 2 && result.1@ == 4)]
+pub fn double() -> Pair<u32> {
+    Pair(1u32, 2u32).map(
+        #[requires(x@ < 100)]
+        #[ensures(result@ == 2 * x@)]
+        |x: u32| x * 2,
+    )
+}
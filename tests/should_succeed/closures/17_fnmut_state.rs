@@ -0,0 +1,20 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::ops::captured, *};
+
+#[ensures(result@ == n@)]
+pub fn count_calls(n: u32) -> u32 {
+    let mut count = 0u32;
+    let mut c = {
+        #[requires(count@ < u32::MAX@)]
+        #[ensures(count@ == old(count)@ + 1)]
+        || count += 1
+    };
+    let mut i = 0;
+    #[invariant(captured(c, count)@ == i@)]
+    #[invariant(i@ <= n@)]
+    while i < n {
+        c();
+        i += 1;
+    }
+    count
+}
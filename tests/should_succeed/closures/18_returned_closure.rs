@@ -0,0 +1,26 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The contract of the returned closure is exposed through the postcondition of `counter`
+#[requires(start@ < u32::MAX@)]
+#[ensures(result.precondition(()))]
+#[ensures(forall<st: _, r: u32> result.postcondition_mut((), st, r) ==> r == start)]
+pub fn counter(start: u32) -> impl FnMut() -> u32 {
+    let mut n = start;
+    #[requires(n@ < u32::MAX@)]
+    #[ensures(result == old(n) && n@ == old(n)@ + 1)]
+    move || {
+        let r = n;
+        n += 1;
+        r
+    }
+}
+
+// The hidden closure of `counter` is not revealed here: the call only relies on the postcondition
+// of `counter`.
+#[ensures(result@ == 5)]
+pub fn first_call() -> u32 {
+    let mut c = counter(5);
+    c()
+}
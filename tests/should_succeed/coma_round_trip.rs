@@ -0,0 +1,47 @@
+// CREUSOT_ARG=--check-coma-round-trip
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Programs, logic functions, laws and refinements all survive a round trip through the
+// serialization of their Coma modules.
+
+pub trait Double {
+    #[logic]
+    fn double(self) -> Int;
+
+    #[law]
+    #[ensures(x.double() == y.double() ==> x == y)]
+    fn double_injective(x: Self, y: Self);
+
+    #[ensures(result@ == self.double())]
+    fn double_prog(self) -> u64;
+}
+
+impl Double for u32 {
+    #[logic]
+    #[open]
+    fn double(self) -> Int {
+        pearlite! { 2 * self@ }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(x.double() == y.double() ==> x == y)]
+    fn double_injective(x: Self, y: Self) {}
+
+    #[ensures(result@ == 2 * self@)]
+    fn double_prog(self) -> u64 {
+        self as u64 * 2
+    }
+}
+
+#[requires(x@ < 1000)]
+#[ensures(result@ == x@ + 1)]
+pub fn incr(x: u32) -> u32 {
+    let mut y = x;
+    #[invariant(y@ <= x@ + 1)]
+    while y < x + 1 {
+        y += 1;
+    }
+    y
+}
@@ -0,0 +1,30 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result@.len() == N@)]
+#[ensures(forall<i: Int> 0 <= i && i < N@ ==> result@[i] == x)]
+pub fn fill<T: Copy, const N: usize>(x: T) -> [T; N] {
+    [x; N]
+}
+
+#[ensures(result == N)]
+pub fn len<const N: usize>() -> usize {
+    N
+}
+
+pub fn two_instances() {
+    let a = fill::<u32, 3>(7);
+    let b = fill::<u32, 5>(7);
+    proof_assert!(a@.len() == 3 && b@.len() == 5);
+    proof_assert!(a@[2] == 7u32 && b@[4] == 7u32);
+
+    let n = len::<4>();
+    proof_assert!(n@ == 4);
+}
+
+// The length of any array is its const length, not only of the arrays built in the function.
+#[ensures(result@ == N@)]
+pub fn param_len<T, const N: usize>(a: [T; N]) -> usize {
+    proof_assert!(a@.len() == N@);
+    a.len()
+}
@@ -0,0 +1,37 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(X > 0)]
+const X: i32 = 5;
+
+#[ensures(result@ == 3)]
+#[ensures(result@ > X@)]
+const Y: u32 = 1 + 2;
+
+#[ensures(result@ == 2 * X@)]
+pub const Z: i32 = 2 * X;
+
+#[ensures(result@ < 100)]
+pub static LIMIT: u64 = 42;
+
+#[ensures(result@ == 5)]
+pub fn use_x() -> i32 {
+    X
+}
+
+pub struct Grid;
+
+impl Grid {
+    #[ensures(result@ == 2 * X@)]
+    pub const WIDTH: i32 = 2 * X;
+}
+
+pub trait Bounded {
+    const MAX: u32;
+}
+
+impl Bounded for Grid {
+    #[ensures(result@ <= 100)]
+    const MAX: u32 = 64;
+}
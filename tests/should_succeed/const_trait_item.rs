@@ -0,0 +1,35 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Width {
+    const BITS: usize;
+}
+
+pub const fn bits_of(bytes: usize) -> usize {
+    bytes * 8
+}
+
+impl Width for u8 {
+    const BITS: usize = bits_of(1);
+}
+
+impl Width for u16 {
+    const BITS: usize = bits_of(2);
+}
+
+// The length of the array is a constant of a trait, evaluated from the impl for `u8`.
+#[ensures(result@.len() == 8)]
+pub fn byte_bits() -> [bool; <u8 as Width>::BITS] {
+    [false; <u8 as Width>::BITS]
+}
+
+#[ensures(result@ == N@)]
+pub fn width<const N: usize>() -> usize {
+    N
+}
+
+// The const generic argument is evaluated from the impl for `u16`.
+#[ensures(result@ == 16)]
+pub fn half_word_width() -> usize {
+    width::<{ <u16 as Width>::BITS }>()
+}
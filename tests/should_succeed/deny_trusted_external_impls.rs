@@ -0,0 +1,24 @@
+// CREUSOT_ARG=--deny-trusted-external-impls
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Only local impls, and std items with their own extern spec, are used.
+pub trait Get {
+    #[ensures(result@ <= 100)]
+    fn get(&self) -> u32;
+}
+
+pub struct Small;
+
+impl Get for Small {
+    #[ensures(result@ == 10)]
+    fn get(&self) -> u32 {
+        10
+    }
+}
+
+#[requires(v@.len() <= 100)]
+#[ensures(result@ == v@.len() + 10)]
+pub fn len_plus(v: &Vec<u32>) -> usize {
+    v.len() + Small.get() as usize
+}
@@ -0,0 +1,27 @@
+// CREUSOT_ARG=--rlimit=20000
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Int, *};
+
+pub struct Clock(pub u64);
+
+impl Clock {
+    #[logic]
+    #[open]
+    pub fn ticks(self, n: Int) -> Int {
+        pearlite! { self.0@ + n }
+    }
+
+    // Each instance of the law creates a term which triggers it again: the solvers loop on the
+    // obligations it is available to.
+    #[law]
+    #[open(self)]
+    #[ensures(forall<c: Clock, n: Int> #![trigger c.ticks(n)] c.ticks(n) + 1 == c.ticks(n + 1))]
+    pub fn ticks_succ() {}
+}
+
+#[creusot::disable_law(Clock::ticks_succ)]
+#[requires(c.0@ < 1000)]
+#[ensures(result@ == c.ticks(1))]
+pub fn tick(c: Clock) -> u64 {
+    c.0 + 1
+}
@@ -0,0 +1,39 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::fmt::DisplaySpec, *};
+use std::fmt::{self, Display, Formatter, Write};
+
+pub struct Name(pub String);
+
+impl Display for Name {
+    #[ensures(self.writes(f, result))]
+    #[ensures(result.is_ok() ==> (^f)@.len() == f@.len() + self.0@.len())]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl DisplaySpec for Name {
+    #[logic]
+    #[open]
+    fn displayed(&self) -> Seq<char> {
+        pearlite! { self.0@ }
+    }
+}
+
+pub struct Initial(pub char);
+
+impl Display for Initial {
+    #[ensures(result.is_ok() ==> (^f)@.len() == f@.len() + 2)]
+    #[ensures(result.is_ok() ==> (^f)@[f@.len()] == self.0)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char(self.0)?;
+        f.write_char('.')
+    }
+}
+
+// The length of the output follows from the specification of `Name` alone.
+#[ensures(result.is_ok() ==> (^f)@.len() == f@.len() + name.0@.len())]
+pub fn display_name(name: &Name, f: &mut Formatter<'_>) -> fmt::Result {
+    name.fmt(f)
+}
@@ -0,0 +1,40 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The code after a call to a diverging function is dead.
+#[ensures(result@ <= 10)]
+pub fn checked(x: u32) -> u32 {
+    if x > 10 {
+        std::process::exit(1)
+    }
+    x
+}
+
+#[creusot::diverges]
+pub fn spin() -> u32 {
+    loop {}
+}
+
+#[ensures(result == 0)]
+pub fn after_spin() -> u32 {
+    let _ = spin();
+    1
+}
+
+pub trait Fail {
+    #[creusot::diverges]
+    fn fail(&self) -> bool;
+}
+
+// A diverging trait method must be refined by a diverging implementation.
+impl Fail for () {
+    #[creusot::diverges]
+    fn fail(&self) -> bool {
+        std::process::abort()
+    }
+}
+
+#[ensures(false)]
+pub fn uses_fail<T: Fail>(x: T) {
+    x.fail();
+}
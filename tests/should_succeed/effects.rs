@@ -0,0 +1,40 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[trusted]
+#[writes(x)]
+#[allow(unused_variables)]
+#[ensures(*^x == 1)]
+pub fn set_first(x: &mut Ghost<Int>, y: &mut Ghost<Int>) {}
+
+pub fn caller(x: &mut Ghost<Int>, y: &mut Ghost<Int>) {
+    let old_y = snapshot!(**y);
+    set_first(x, y);
+    proof_assert!(**x == 1);
+    proof_assert!(**y == *old_y);
+}
+
+pub struct Cells {
+    pub a: Ghost<Int>,
+    pub b: Ghost<Int>,
+}
+
+pub trait Bump {
+    // Implementations may write to `self`, but not to `other`
+    #[writes(self)]
+    fn bump(&mut self, other: &mut Cells);
+}
+
+impl Bump for Cells {
+    // Writing nothing at all refines the trait
+    #[reads(self, other)]
+    #[allow(unused_variables)]
+    fn bump(&mut self, other: &mut Cells) {}
+}
+
+pub fn bump_twice<B: Bump>(b: &mut B, c: &mut Cells) {
+    let old = snapshot!(*c);
+    b.bump(c);
+    b.bump(c);
+    proof_assert!(*old == *c);
+}
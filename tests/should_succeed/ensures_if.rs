@@ -0,0 +1,33 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result@ <= v@.len())]
+#[ensures_if(v@.len() > 0, 0 <= result@ && result@ < v@.len())]
+#[ensures_if(v@.len() > 0, forall<i: Int> 0 <= i && i < v@.len() ==> v[i] <= v[result@])]
+pub fn argmax(v: &[u32]) -> usize {
+    let mut best = 0;
+    #[invariant(best@ <= produced.len())]
+    #[invariant(produced.len() > 0 ==> best@ < produced.len())]
+    #[invariant(forall<i: Int> 0 <= i && i < produced.len() ==> v[i] <= v[best@])]
+    for i in 0..v.len() {
+        if v[i] > v[best] {
+            best = i;
+        }
+    }
+    best
+}
+
+pub trait First {
+    #[ensures_if(v@.len() > 0, result == Some(v[0]))]
+    fn first(v: &[u32]) -> Option<u32>;
+}
+
+pub struct Std;
+
+impl First for Std {
+    #[ensures(v@.len() == 0 ==> result == None)]
+    #[ensures_if(v@.len() > 0, result == Some(v[0]))]
+    fn first(v: &[u32]) -> Option<u32> {
+        if v.len() > 0 { Some(v[0]) } else { None }
+    }
+}
@@ -0,0 +1,44 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::iter::{Appended, ExtendSpec},
+    *,
+};
+
+pub struct Stack<T>(Vec<T>);
+
+impl<T> View for Stack<T> {
+    type ViewTy = Seq<T>;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Seq<T> {
+        pearlite! { self.0@ }
+    }
+}
+
+impl<T> Stack<T> {
+    #[ensures((^self)@ == self@.push_back(x))]
+    pub fn push(&mut self, x: T) {
+        self.0.push(x)
+    }
+}
+
+// The iterator of a generic `I` has no `Iterator` bound, so the loop is trusted: its contract is
+// checked against the law of `ExtendSpec`.
+impl<T> Extend<T> for Stack<T> {
+    #[trusted]
+    #[ensures((*self).appended(iter, ^self))]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T> ExtendSpec<T> for Stack<T> {}
+
+// For a concrete iterator, `appended` unfolds to the items it produces.
+#[ensures((^s)@ == s@.concat(v@))]
+pub fn extend_with_vec(s: &mut Stack<u32>, v: Vec<u32>) {
+    s.extend(v)
+}
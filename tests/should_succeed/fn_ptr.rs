@@ -0,0 +1,30 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(x@ < 1000 && x@ > -1000)]
+#[ensures(result@ == 2 * x@)]
+pub fn double(x: i32) -> i32 {
+    x * 2
+}
+
+pub struct Op {
+    pub f: fn(i32) -> i32,
+}
+
+#[requires(call_spec!(op.f, x).pre())]
+#[ensures(call_spec!(op.f, x).holds(result))]
+pub fn apply(op: &Op, x: i32) -> i32 {
+    (op.f)(x)
+}
+
+#[ensures(result.f == op.f)]
+pub fn copy(op: &Op) -> Op {
+    Op { f: op.f }
+}
+
+#[ensures(result@ == 20)]
+pub fn store_and_apply() -> i32 {
+    let op = Op { f: double };
+    let op = copy(&op);
+    apply(&op, 10)
+}
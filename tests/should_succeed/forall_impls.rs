@@ -0,0 +1,39 @@
+// CREUSOT_ARG=--closed-crate=forall_impls
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// `Tr` cannot be implemented outside of this crate, so `positive` is not assumed: it is proved
+// for each of the two impls below.
+
+pub trait Tr {
+    #[logic]
+    fn value(self) -> Int;
+
+    #[law]
+    #[forall_impls]
+    #[ensures(x.value() > 0)]
+    fn positive(x: Self) {}
+}
+
+impl Tr for u32 {
+    #[open]
+    #[logic]
+    fn value(self) -> Int {
+        pearlite! { self@ + 1 }
+    }
+}
+
+impl Tr for bool {
+    #[open]
+    #[logic]
+    fn value(self) -> Int {
+        if self { 2 } else { 1 }
+    }
+}
+
+#[open]
+#[logic]
+#[ensures(result)]
+pub fn uses_law<T: Tr>(x: T) -> bool {
+    pearlite! { x.value() != 0 }
+}
@@ -0,0 +1,36 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::ptr::PointerExt, *};
+
+// The address of a fresh pointer differs from the address of the pointer inputs.
+#[trusted]
+#[ensures(fresh(result))]
+pub fn alloc(hint: *const u32) -> *mut u32 {
+    Box::into_raw(Box::new(0))
+}
+
+#[ensures(result.addr_logic() != hint.addr_logic())]
+pub fn alloc_elsewhere(hint: *const u32) -> *mut u32 {
+    alloc(hint)
+}
+
+// Owned values are always fresh.
+#[ensures(fresh(result))]
+#[ensures(result@ == Seq::EMPTY)]
+pub fn new_vec() -> Vec<u32> {
+    Vec::new()
+}
+
+pub trait Alloc {
+    #[ensures(fresh(result))]
+    fn alloc(&self, hint: *const u32) -> *mut u32;
+}
+
+pub struct A;
+
+// The impl must preserve the freshness guarantee of the trait.
+impl Alloc for A {
+    #[ensures(fresh(result))]
+    fn alloc(&self, hint: *const u32) -> *mut u32 {
+        alloc(hint)
+    }
+}
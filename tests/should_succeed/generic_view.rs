@@ -0,0 +1,43 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Wrap<T>(pub T);
+
+impl<T: View> View for Wrap<T> {
+    type ViewTy = T::ViewTy;
+
+    #[logic]
+    #[open]
+    fn view(self) -> T::ViewTy {
+        self.0.view()
+    }
+}
+
+// The view of `T` is only known by callers: it stays abstract here.
+#[ensures(result@ == x@)]
+pub fn pass<T: View>(x: T) -> T {
+    x
+}
+
+#[allow(unused_variables)]
+#[requires(x@ == y@)]
+#[ensures(result@ == y@)]
+pub fn left<T: View>(x: T, y: T) -> T {
+    x
+}
+
+#[ensures(result@ == x@)]
+pub fn wrap<T: View>(x: T) -> Wrap<T> {
+    pass(Wrap(x))
+}
+
+pub fn concrete() {
+    let n = pass(5u32);
+    proof_assert!(n@ == 5);
+
+    let v = wrap(vec![1u32, 2]);
+    proof_assert!(v@.len() == 2 && v@[1]@ == 2);
+
+    let m = left(3i64, 3i64);
+    proof_assert!(m@ == 3);
+}
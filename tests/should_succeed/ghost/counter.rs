@@ -0,0 +1,28 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// A linear search does at most one comparison per element.
+#[ensures(*result.1 <= v@.len())]
+#[ensures(result.0 == v@.contains(x))]
+pub fn search(v: &Vec<u32>, x: u32) -> (bool, Ghost<Int>) {
+    let mut cmp = ghost!(0int);
+    let mut i = 0;
+    #[invariant(*cmp == i@)]
+    #[invariant(forall<j: Int> 0 <= j && j < i@ ==> v[j] != x)]
+    while i < v.len() {
+        ghost_incr!(cmp);
+        if v[i] == x {
+            return (true, cmp);
+        }
+        i += 1;
+    }
+    (false, cmp)
+}
+
+// The counter can also be given by the caller.
+#[ensures(*^cmp == **cmp + 1)]
+#[ensures(result == (x <= y))]
+pub fn compare(x: u32, y: u32, cmp: &mut Ghost<Int>) -> bool {
+    ghost_incr!(*cmp);
+    x <= y
+}
@@ -0,0 +1,27 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Tokens, *};
+
+#[requires(t@ >= 1)]
+#[ensures((^t)@ == t@ - 1)]
+pub fn use_one(t: &mut Ghost<Tokens>) {
+    ghost! {
+        t.consume();
+    };
+}
+
+pub fn conservation() {
+    let mut t = ghost!(Tokens::new(3int));
+    let mut u = ghost!(t.split(2int));
+    proof_assert!(t@ == 1 && u@ == 2);
+    use_one(&mut t);
+    use_one(&mut u);
+    ghost! {
+        t.join(u.into_inner());
+        proof_assert!(t@ == 1);
+        t.consume();
+    };
+}
+
+// Only the view of `Tokens` is used: the fact that it is non-negative comes with it.
+#[ensures(t@ >= 0)]
+pub fn amount_nonneg(t: &Ghost<Tokens>) {}
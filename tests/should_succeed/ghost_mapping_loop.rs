@@ -0,0 +1,28 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Mapping, *};
+
+// `seen` records, in ghost code only, the indices visited by the loop.
+#[ensures(forall<j> 0 <= j && j < v@.len() ==> v[j] <= result)]
+pub fn max(v: &[u32]) -> u32 {
+    let mut m = 0;
+    let mut seen: Snapshot<Mapping<Int, bool>> = snapshot!(Mapping::cst(false));
+    let mut i = 0;
+    #[invariant(i@ <= v@.len())]
+    #[invariant(forall<j> seen.get(j) == (0 <= j && j < i@))]
+    #[invariant(forall<j> seen.get(j) ==> v[j] <= m)]
+    while i < v.len() {
+        if v[i] > m {
+            m = v[i];
+        }
+        seen = snapshot!(seen.set(i@, true));
+        i += 1;
+    }
+    proof_assert!(forall<j> 0 <= j && j < v@.len() ==> seen.get(j));
+    m
+}
+
+#[ensures(m.set(k, v).get(k) == v)]
+#[ensures(forall<k2: Int> k2 != k ==> m.set(k, v).get(k2) == m.get(k2))]
+pub fn laws(m: Snapshot<Mapping<Int, u32>>, k: Snapshot<Int>, v: Snapshot<u32>) {
+    let _ = snapshot!(m.set_get_same(*k, *v));
+}
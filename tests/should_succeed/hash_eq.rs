@@ -0,0 +1,25 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::hash::HashLogic, *};
+
+// The derived `PartialEq` compares both fields, which are both hashed: the consistency law of
+// `PartialEq` and `Hash` holds.
+#[derive(PartialEq, Eq, Hash, DeepModel)]
+pub struct Key {
+    id: u32,
+    tag: bool,
+}
+
+impl HashLogic for Key {
+    type HashTy = (Int, bool);
+
+    #[logic]
+    #[open]
+    fn hash_log(self) -> (Int, bool) {
+        pearlite! { (self.id@, self.tag) }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(a.deep_model() == b.deep_model() ==> a.hash_log() == b.hash_log())]
+    fn hash_eq_consistent(a: Self, b: Self) {}
+}
@@ -0,0 +1,35 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::History, *};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Open,
+    Close,
+}
+
+pub trait Resource {
+    #[ensures((**log).extended_by(*^log))]
+    #[ensures((**log).appended(*^log) == Seq::singleton(Event::Open).push_back(Event::Close))]
+    fn cycle(&mut self, log: &mut Ghost<History<Event>>);
+}
+
+pub struct File;
+
+impl Resource for File {
+    #[ensures((**log).extended_by(*^log))]
+    #[ensures((**log).appended(*^log) == Seq::singleton(Event::Open).push_back(Event::Close))]
+    fn cycle(&mut self, log: &mut Ghost<History<Event>>) {
+        ghost! {
+            log.push(Event::Open);
+            log.push(Event::Close);
+        };
+    }
+}
+
+pub fn use_resource<R: Resource>(r: &mut R) {
+    let mut log = History::new();
+    r.cycle(&mut log);
+    r.cycle(&mut log);
+    proof_assert!((*log)@.len() == 4);
+    proof_assert!((*log)@[0] == Event::Open && (*log)@[3] == Event::Close);
+}
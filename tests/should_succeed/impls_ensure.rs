@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::{vec, *};
+
+pub trait Encode {
+    // Every implementation must produce a non-empty encoding
+    #[impls_ensure(result@.len() > 0)]
+    fn encode(&self) -> Vec<u8>;
+}
+
+impl Encode for u8 {
+    #[ensures(result@ == Seq::singleton(*self))]
+    fn encode(&self) -> Vec<u8> {
+        vec![*self]
+    }
+}
+
+impl Encode for bool {
+    #[ensures(result@.len() == 1)]
+    fn encode(&self) -> Vec<u8> {
+        if *self { vec![1] } else { vec![0] }
+    }
+}
+
+/// A value prefixed by a tag.
+pub struct Tagged(pub u8, pub u8);
+
+impl Encode for Tagged {
+    #[ensures(result@ == seq![self.0, self.1])]
+    fn encode(&self) -> Vec<u8> {
+        vec![self.0, self.1]
+    }
+}
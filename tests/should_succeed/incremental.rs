@@ -0,0 +1,28 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Translated twice by the test runner to an output directory, with the last item changed in
+// between: only the file of this item must be rewritten.
+
+#[ensures(result@ == x@)]
+pub fn id(x: u32) -> u32 {
+    x
+}
+
+pub struct Wrapper(pub u32);
+
+impl View for Wrapper {
+    type ViewTy = Int;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+#[ensures(result@ >= 1)]
+pub fn changed() -> u32 {
+    let changed_item = 1;
+    changed_item
+}
@@ -0,0 +1,62 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+use std::{collections::HashMap, ops::Index};
+
+#[derive(Clone, Copy)]
+pub struct Key(pub usize);
+
+// A table of values indexed by keys, where a key may be absent.
+pub struct Table {
+    present: Vec<bool>,
+    values: Vec<u32>,
+}
+
+impl Invariant for Table {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.present@.len() == self.values@.len() }
+    }
+}
+
+impl Table {
+    #[predicate]
+    #[open]
+    pub fn contains(self, k: Key) -> bool {
+        pearlite! { k.0@ < self.present@.len() && self.present@[k.0@] }
+    }
+
+    #[logic]
+    #[open]
+    pub fn lookup(self, k: Key) -> u32 {
+        pearlite! { self.values@[k.0@] }
+    }
+
+    #[ensures(forall<k: Key> !result.contains(k))]
+    pub fn new() -> Self {
+        Table { present: Vec::new(), values: Vec::new() }
+    }
+}
+
+impl Index<Key> for Table {
+    type Output = u32;
+
+    #[requires(self.contains(k))]
+    #[ensures(*result == self.lookup(k))]
+    fn index(&self, k: Key) -> &u32 {
+        &self.values[k.0]
+    }
+}
+
+#[requires(t.contains(k1) && t.contains(k2))]
+#[requires(t.lookup(k1)@ + t.lookup(k2)@ <= u32::MAX@)]
+#[ensures(result@ == t.lookup(k1)@ + t.lookup(k2)@)]
+pub fn sum(t: &Table, k1: Key, k2: Key) -> u32 {
+    t[k1] + t[k2]
+}
+
+#[requires(m@.contains(1))]
+#[ensures(result == m@[1])]
+pub fn hashmap_index(m: &HashMap<u64, u32>) -> u32 {
+    m[&1]
+}
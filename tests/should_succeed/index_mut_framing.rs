@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Through `IndexMut` for `Vec`: the prophecy of the element borrow is tied back into the view.
+#[requires(a@.len() > 2)]
+#[ensures((^a)@[2] == 9u32)]
+#[ensures(forall<i> 0 <= i && i < a@.len() && i != 2 ==> (^a)@[i] == a@[i])]
+#[ensures((^a)@.len() == a@.len())]
+pub fn set_vec(a: &mut Vec<u32>) {
+    a[2] = 9;
+}
+
+#[requires(a@.len() > 2)]
+#[ensures((^a)@[2] == 9u32)]
+#[ensures(forall<i> 0 <= i && i < a@.len() && i != 2 ==> (^a)@[i] == a@[i])]
+pub fn set_slice(a: &mut [u32]) {
+    a[2] = 9;
+}
+
+pub fn set_array() {
+    let mut a = [0u32; 4];
+    a[2] = 9;
+    proof_assert!(a@[2] == 9u32);
+    proof_assert!(forall<i> 0 <= i && i < 4 && i != 2 ==> a@[i] == 0u32);
+}
+
+// The bounds obligation is generated for the index.
+#[requires(i@ < a@.len())]
+#[ensures((^a)@.ext_eq(a@.set(i@, v)))]
+pub fn set_at(a: &mut Vec<u32>, i: usize, v: u32) {
+    a[i] = v;
+}
@@ -0,0 +1,62 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn literals() {
+    proof_assert!(7 / 2 == 3);
+    proof_assert!(7 % 2 == 1);
+    proof_assert!((-7) / 2 == -3);
+    proof_assert!((-7) % 2 == -1);
+    proof_assert!(7 / (-2) == -3);
+    proof_assert!(7 % (-2) == 1);
+}
+
+#[requires(b@ != 0)]
+#[ensures(a@ == result.0@ * b@ + result.1@)]
+#[ensures(result.1@ < b@)]
+pub fn div_rem(a: u32, b: u32) -> (u32, u32) {
+    (a / b, a % b)
+}
+
+// Rust rounds toward zero: the remainder has the sign of the dividend.
+#[requires(b@ != 0 && !(a == i32::MIN && b@ == -1))]
+#[ensures(result@ == a@ / b@)]
+#[ensures(a@ < 0 ==> a@ % b@ <= 0)]
+pub fn div_signed(a: i32, b: i32) -> i32 {
+    a / b
+}
+
+pub fn signed_values() {
+    let x = -7i32;
+    assert!(x / 2 == -3);
+    assert!(x % 2 == -1);
+}
+
+#[logic]
+#[requires(a >= 0 && d > 0)]
+#[ensures(0 <= result && result < d)]
+pub fn rem_in_range(a: Int, d: Int) -> Int {
+    a % d
+}
+
+#[logic]
+#[requires(a >= 0 && d > 0)]
+#[ensures(0 <= result && result <= a)]
+pub fn div_in_range(a: Int, d: Int) -> Int {
+    a / d
+}
+
+#[logic]
+#[requires(d != 0)]
+#[ensures(result == a)]
+pub fn recompose(a: Int, d: Int) -> Int {
+    (a / d) * d + a % d
+}
+
+#[logic]
+#[requires(d != 0)]
+#[ensures(a <= 0 ==> result <= 0)]
+#[ensures(result.abs() < d.abs())]
+pub fn rem_sign(a: Int, d: Int) -> Int {
+    a % d
+}
@@ -0,0 +1,77 @@
+extern crate creusot_contracts;
+
+use creusot_contracts::{
+    logic::{Int, Seq},
+    *,
+};
+
+/// An iterator producing `0, 1, ..., n - 1`.
+pub struct Counter {
+    cur: usize,
+    n: usize,
+}
+
+impl Counter {
+    #[ensures(result.cur@ == 0 && result.n == n)]
+    pub fn new(n: usize) -> Self {
+        Counter { cur: 0, n }
+    }
+}
+
+impl ::std::iter::Iterator for Counter {
+    type Item = usize;
+
+    // Checked against the specification of `Iterator::next`, in terms of the model below.
+    fn next(&mut self) -> Option<usize> {
+        if self.cur >= self.n {
+            None
+        } else {
+            let r = self.cur;
+            self.cur += 1;
+            Some(r)
+        }
+    }
+}
+
+impl creusot_contracts::std::iter::Iterator for Counter {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self.cur@ >= self.n@ }
+    }
+
+    #[open]
+    #[predicate]
+    fn produces(self, visited: Seq<usize>, o: Self) -> bool {
+        pearlite! {
+            self.n == o.n && self.cur@ <= o.cur@
+            && (visited.len() > 0 ==> o.cur@ <= o.n@)
+            && visited.len() == o.cur@ - self.cur@
+            && forall<i: Int> 0 <= i && i < visited.len() ==> visited[i]@ == self.cur@ + i
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<usize>, b: Self, bc: Seq<usize>, c: Self) {}
+}
+
+#[requires(n@ <= 1000)]
+#[ensures(result@ * 2 == n@ * (n@ - 1))]
+pub fn sum_counter(n: usize) -> usize {
+    let mut sum = 0;
+    #[invariant(produced.len() <= n@)]
+    #[invariant(sum@ * 2 == produced.len() * (produced.len() - 1))]
+    for x in Counter::new(n) {
+        sum += x;
+    }
+    sum
+}
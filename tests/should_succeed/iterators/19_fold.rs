@@ -0,0 +1,16 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(n@ <= 1000)]
+#[ensures(result@ * 2 == n@ * (n@ - 1))]
+pub fn sum_range(n: u32) -> u64 {
+    (0..n).fold_inv(
+        0,
+        snapshot!(|prod: Seq<u32>| |acc: u64| acc@ * 2 == prod.len() * (prod.len() - 1)
+            && acc@ <= prod.len() * 1000),
+        #[requires(acc@ + i@ <= u64::MAX@)]
+        #[ensures(result@ == acc@ + i@)]
+        |acc: u64, i: u32| acc + i as u64,
+    )
+}
@@ -0,0 +1,22 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+use std::collections::VecDeque;
+
+// The elements of the vector are those produced by the range.
+#[ensures(result@.len() == n@)]
+#[ensures(forall<i: Int> 0 <= i && i < n@ ==> result[i]@ == i)]
+pub fn collect_range(n: usize) -> Vec<usize> {
+    (0..n).collect()
+}
+
+#[ensures(result@.len() == n@)]
+#[ensures(forall<i: Int> 0 <= i && i < n@ ==> result[i]@ == i)]
+pub fn collect_range_deque(n: usize) -> VecDeque<usize> {
+    (0..n).collect()
+}
+
+pub fn collect_then_index() {
+    let v: Vec<u32> = (0..10).collect();
+    proof_assert! { v@.len() == 10 };
+    assert!(v[3] == 3);
+}
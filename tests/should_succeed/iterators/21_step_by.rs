@@ -0,0 +1,25 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// `(0..10).step_by(2)` produces the elements of the range at the even indices.
+#[ensures(result@.len() == 5)]
+#[ensures(forall<i: Int> 0 <= i && i < 5 ==> result[i]@ == 2 * i)]
+pub fn evens() -> Vec<u32> {
+    let mut v = Vec::new();
+    #[invariant(v@ == *produced)]
+    #[invariant(forall<i: Int> 0 <= i && i < produced.len() ==> produced[i]@ == 2 * i)]
+    for x in (0..10u32).step_by(2) {
+        v.push(x);
+    }
+    v
+}
+
+#[ensures(result@ == 3)]
+pub fn take_skip() -> u32 {
+    let mut it = (0..10u32).skip(3).take(2);
+    match it.next() {
+        Some(x) => x,
+        None => unreachable!(),
+    }
+}
@@ -0,0 +1,53 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result.is_nan_log())]
+pub fn zero_div_zero() -> f64 {
+    0.0 / 0.0
+}
+
+#[ensures(result == a + b)]
+#[ensures(a.is_nan_log() || b.is_nan_log() ==> result.is_nan_log())]
+pub fn add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+#[ensures(result == a - b)]
+pub fn sub(a: f32, b: f32) -> f32 {
+    a - b
+}
+
+#[ensures(result == a * b)]
+pub fn mul(a: f64, b: f64) -> f64 {
+    a * b
+}
+
+#[ensures(result == a.lt_log(b))]
+#[ensures(a.is_nan_log() ==> !result)]
+pub fn lt(a: f64, b: f64) -> bool {
+    a < b
+}
+
+#[ensures(result == !x.is_nan_log())]
+pub fn eq_self(x: f64) -> bool {
+    x == x
+}
+
+#[ensures(result == x.is_nan_log())]
+pub fn is_nan(x: f32) -> bool {
+    x.is_nan()
+}
+
+pub fn signed_zeros() {
+    proof_assert!(-0.0f64 != 0.0);
+    proof_assert!((-0.0f64).eq_log(0.0));
+    proof_assert!((-0.0f64).is_zero_log() && (-0.0f64).is_negative_log());
+}
+
+// With real-number semantics, this assertion would be false.
+pub fn not_associative() {
+    let a = 0.1f64;
+    let b = 0.2f64;
+    let c = 0.3f64;
+    proof_assert!((a + b) + c != a + (b + c));
+}
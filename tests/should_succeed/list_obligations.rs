@@ -0,0 +1,41 @@
+// CREUSOT_ARG=--list-obligations=json
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// One obligation of each kind: a program function, a terminating logic function, a law and a
+// refinement.
+#[ensures(result@ == x@ + 1)]
+#[requires(x@ < 100)]
+pub fn incr(x: u32) -> u32 {
+    x + 1
+}
+
+#[logic]
+#[open]
+#[variant(n)]
+#[requires(n >= 0)]
+pub fn sum_to(n: Int) -> Int {
+    if n == 0 { 0 } else { n + sum_to(n - 1) }
+}
+
+pub trait Measure {
+    #[logic]
+    fn measure(self) -> Int;
+
+    #[law]
+    #[ensures(x.measure() >= 0)]
+    fn measure_nonneg(x: Self);
+}
+
+impl Measure for u32 {
+    #[logic]
+    #[open]
+    fn measure(self) -> Int {
+        pearlite! { self@ }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(x.measure() >= 0)]
+    fn measure_nonneg(x: Self) {}
+}
@@ -0,0 +1,22 @@
+extern crate creusot_contracts;
+use creusot_contracts::{util::unreachable, *};
+
+// The matches of logic functions are exhaustive. A case which cannot happen calls `unreachable`,
+// whose precondition `false` makes the function prove that this case is excluded by its own
+// precondition, and the callers establish this precondition.
+
+#[logic]
+#[open]
+#[requires(x != None)]
+pub fn get(x: Option<Int>) -> Int {
+    match x {
+        Some(y) => y,
+        None => unreachable(),
+    }
+}
+
+#[logic]
+#[ensures(result == 1)]
+pub fn use_get() -> Int {
+    get(Some(1))
+}
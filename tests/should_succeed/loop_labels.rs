@@ -0,0 +1,43 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Two sibling loops, each with its own invariant.
+#[ensures(result@ == 2 * n@)]
+pub fn siblings(n: u32) -> u64 {
+    let mut a = 0u64;
+    let mut i = 0u32;
+    #[invariant('first: i <= n)]
+    #[invariant('first: a@ == i@)]
+    'first: while i < n {
+        a += 1;
+        i += 1;
+    }
+    let mut j = 0u32;
+    #[invariant('second: j <= n)]
+    #[invariant('second: a@ == n@ + j@)]
+    'second: while j < n {
+        a += 1;
+        j += 1;
+    }
+    a
+}
+
+// The invariant of the outer loop is written on the inner loop, next to the code it is about.
+#[ensures(result@ == n@ * m@)]
+pub fn nested(n: u32, m: u32) -> u64 {
+    let mut a = 0u64;
+    let mut i = 0u32;
+    #[invariant(i <= n)]
+    'outer: while i < n {
+        let mut j = 0u32;
+        #[invariant('outer: a@ == i@ * m@)]
+        #[invariant(j <= m)]
+        #[invariant(a@ == i@ * m@ + j@)]
+        while j < m {
+            a += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    a
+}
@@ -0,0 +1,48 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The last arm is only reached when both guards are false.
+#[ensures(x@ < 0 ==> result == -1)]
+#[ensures(x@ == 0 ==> result == 0)]
+#[ensures(x@ > 0 ==> result == 1)]
+pub fn sign(x: i32) -> i32 {
+    match x {
+        n if n < 0 => -1,
+        0 => 0,
+        n if n > 0 => 1,
+        _ => unreachable!(),
+    }
+}
+
+// A later arm knows that the guards of the arms with the same pattern were false.
+#[ensures(match o {
+    Some(n) => if n@ < 10 { result == 0 } else if n@ < 100 { result == 1 } else { result == 2 },
+    None => result == 3,
+})]
+pub fn bucket(o: Option<u32>) -> u32 {
+    match o {
+        Some(n) if n < 10 => 0,
+        Some(n) if n < 100 => {
+            proof_assert! { 10 <= n@ };
+            1
+        }
+        Some(n) => {
+            proof_assert! { 100 <= n@ };
+            2
+        }
+        None => 3,
+    }
+}
+
+// Guards on tuples: the last arm is reached only when `a <= b` and `a != b`.
+#[ensures(result == (a@ < b@))]
+pub fn less(a: u32, b: u32) -> bool {
+    match (a, b) {
+        (x, y) if x > y => false,
+        (x, y) if x == y => false,
+        (x, y) => {
+            proof_assert! { x@ < y@ };
+            true
+        }
+    }
+}
@@ -0,0 +1,39 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+use std::mem;
+
+#[ensures(^a == *b && ^b == *a)]
+pub fn swap_values(a: &mut u32, b: &mut u32) {
+    mem::swap(a, b);
+}
+
+pub fn swap_locals() {
+    let mut x = 1u32;
+    let mut y = 2u32;
+    mem::swap(&mut x, &mut y);
+    proof_assert! { x@ == 2 && y@ == 1 };
+}
+
+// Swapping through reborrows of the fields of a pair.
+#[ensures((^p).0 == (*p).1 && (^p).1 == (*p).0)]
+pub fn swap_fields(p: &mut (u32, u32)) {
+    let (a, b) = p;
+    mem::swap(a, b);
+}
+
+#[ensures(result == *dst && ^dst == v)]
+pub fn replace_value(dst: &mut u32, v: u32) -> u32 {
+    mem::replace(dst, v)
+}
+
+pub fn replace_local() {
+    let mut x = 5u32;
+    let old = mem::replace(&mut x, 7);
+    proof_assert! { old@ == 5 && x@ == 7 };
+}
+
+#[ensures(result@ == v@)]
+#[ensures((^v)@ == Seq::EMPTY)]
+pub fn take_vec(v: &mut Vec<u32>) -> Vec<u32> {
+    mem::take(v)
+}
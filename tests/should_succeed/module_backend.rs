@@ -0,0 +1,58 @@
+#![feature(custom_inner_attributes)]
+extern crate creusot_contracts;
+
+// The items of this module are proved with machine integers encoded as bitvectors.
+pub mod bits {
+    #![creusot::backend(encoding = "bitvector", prover = "z3")]
+    use creusot_contracts::*;
+
+    #[ensures(x >= 0i8 ==> 0i8 <= result && result <= x)]
+    #[ensures(forall<i: _> 0i8 <= i && i < result ==> i & x == 0i8)]
+    pub fn rightmost_bit(x: i8) -> i8 {
+        x & x.wrapping_neg()
+    }
+
+    pub trait Mask {
+        #[ensures(result & self == 0u32)]
+        fn complement(self) -> u32;
+    }
+
+    // The refinement obligation of the impl also uses the encoding of the module.
+    impl Mask for u32 {
+        #[ensures(result == !self)]
+        fn complement(self) -> u32 {
+            !self
+        }
+    }
+
+    // Nested modules inherit the options of their parent, unless they override them.
+    pub mod arith {
+        #![creusot::backend(encoding = "int")]
+        use creusot_contracts::*;
+
+        #[requires(a@ < 1000 && b@ < 1000)]
+        #[ensures(result@ == a@ * b@)]
+        pub fn mul(a: u32, b: u32) -> u32 {
+            a * b
+        }
+    }
+}
+
+// Without attribute, the items of this module use the default options.
+pub mod numeric {
+    use creusot_contracts::*;
+
+    #[requires(n@ < 1000)]
+    #[ensures(result@ == n@ * (n@ + 1) / 2)]
+    pub fn triangle(n: u64) -> u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        #[invariant(i@ <= n@)]
+        #[invariant(sum@ == i@ * (i@ + 1) / 2)]
+        while i < n {
+            i += 1;
+            sum += i;
+        }
+        sum
+    }
+}
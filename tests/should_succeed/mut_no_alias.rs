@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Mutable borrows are modelled by their current and final values, so that two `&mut` arguments
+// are independent: writing through one leaves the other unchanged, without any hypothesis.
+
+#[ensures(^a == 1u32)]
+#[ensures(^b == *b)]
+pub fn write_first(a: &mut u32, b: &mut u32) {
+    *a = 1;
+}
+
+#[ensures(^a == *b && ^b == *a)]
+pub fn swap(a: &mut u32, b: &mut u32) {
+    let t = *a;
+    *a = *b;
+    *b = t;
+}
+
+#[requires(*b < 100u32)]
+#[ensures(result@ == (*b)@ + 1)]
+pub fn read_after_write(a: &mut u32, b: &mut u32) -> u32 {
+    *a = 0;
+    *b + 1
+}
+
+// The same holds for borrows of distinct parts of a value.
+#[ensures((^p).0 == 1u32 && (^p).1 == (*p).1)]
+pub fn write_field(p: &mut (u32, u32)) {
+    let (a, b) = (&mut p.0, &mut p.1);
+    write_first(a, b);
+}
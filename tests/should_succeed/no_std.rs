@@ -0,0 +1,17 @@
+#![no_std]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The specifications of the items that `std` reexports from `core` are still available in a
+// `no_std` crate.
+
+#[ensures(result@ <= a@ && result@ <= b@)]
+#[ensures(result == a || result == b)]
+pub fn smallest(a: u32, b: u32) -> u32 {
+    core::cmp::min(a, b)
+}
+
+#[ensures(result == None ==> a@ + b@ > u8::MAX@)]
+pub fn add(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b)
+}
@@ -0,0 +1,23 @@
+// CREUSOT_ARG=--only-refinements
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Counter {
+    #[ensures(result@ > 0)]
+    fn next(&mut self) -> u32;
+}
+
+pub struct Ones;
+
+impl Counter for Ones {
+    #[ensures(result@ == 1)]
+    fn next(&mut self) -> u32 {
+        1
+    }
+}
+
+// The body is not verified: only the refinement of `Counter::next` above is checked.
+#[ensures(result@ == 0)]
+pub fn wrong() -> u32 {
+    1
+}
@@ -0,0 +1,42 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub mod counter {
+    use creusot_contracts::*;
+
+    #[creusot::opaque]
+    pub struct Counter {
+        pub count: u32,
+    }
+
+    impl View for Counter {
+        type ViewTy = Int;
+
+        #[logic]
+        #[open(self)]
+        fn view(self) -> Int {
+            pearlite! { self.count@ }
+        }
+    }
+
+    impl Counter {
+        // Specifications in the module of `Counter` can use its fields.
+        #[ensures(result.count == 0u32)]
+        pub fn new() -> Self {
+            Counter { count: 0 }
+        }
+
+        #[requires(self@ < u32::MAX@)]
+        #[ensures((^self).count@ == self.count@ + 1)]
+        pub fn incr(&mut self) {
+            self.count += 1
+        }
+    }
+}
+
+// Outside of its module, a `Counter` is only used through its view.
+#[requires(c@ < 10)]
+#[ensures((^c)@ == c@ + 1)]
+pub fn incr(c: &mut counter::Counter) {
+    c.incr()
+}
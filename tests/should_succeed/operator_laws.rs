@@ -0,0 +1,88 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::ops::{
+        Add, AddAssociative, AddCommutative, AddIdentity, AddSpec, Mul, MulCommutative, MulSpec,
+    },
+    *,
+};
+
+/// Natural numbers modulo 256.
+#[derive(Clone, Copy)]
+pub struct Mod256(pub u8);
+
+impl Add for Mod256 {
+    type Output = Mod256;
+
+    #[ensures(result == self.add_logic(rhs))]
+    fn add(self, rhs: Self) -> Self {
+        Mod256(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl AddSpec for Mod256 {
+    #[logic]
+    #[open]
+    fn add_logic(self, rhs: Self) -> Self {
+        pearlite! { Mod256(self.0 + rhs.0) }
+    }
+}
+
+impl AddCommutative for Mod256 {
+    #[law]
+    #[open(self)]
+    #[ensures(a.add_logic(b) == b.add_logic(a))]
+    fn add_commutative(a: Self, b: Self) {}
+}
+
+impl AddAssociative for Mod256 {
+    #[law]
+    #[open(self)]
+    #[ensures(a.add_logic(b).add_logic(c) == a.add_logic(b.add_logic(c)))]
+    fn add_associative(a: Self, b: Self, c: Self) {}
+}
+
+impl AddIdentity for Mod256 {
+    #[logic]
+    #[open]
+    fn zero() -> Self {
+        Mod256(0u8)
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(a.add_logic(Self::zero()) == a && Self::zero().add_logic(a) == a)]
+    fn add_identity(a: Self) {}
+}
+
+/// The maximum of two numbers.
+#[derive(Clone, Copy)]
+pub struct Max(pub u32);
+
+impl Mul for Max {
+    type Output = Max;
+
+    #[ensures(result == self.mul_logic(rhs))]
+    fn mul(self, rhs: Self) -> Self {
+        if self.0 >= rhs.0 { self } else { rhs }
+    }
+}
+
+impl MulSpec for Max {
+    #[logic]
+    #[open]
+    fn mul_logic(self, rhs: Self) -> Self {
+        pearlite! { if self.0@ >= rhs.0@ { self } else { rhs } }
+    }
+}
+
+impl MulCommutative for Max {
+    #[law]
+    #[open(self)]
+    #[ensures(a.mul_logic(b) == b.mul_logic(a))]
+    fn mul_commutative(a: Self, b: Self) {}
+}
+
+#[ensures(result == a.add_logic(b).add_logic(c))]
+pub fn add3(a: Mod256, b: Mod256, c: Mod256) -> Mod256 {
+    a + b + c
+}
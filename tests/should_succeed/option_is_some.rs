@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// `is_some`, `is_none`, `is_ok` and `is_err` are logic functions, usable in specifications.
+
+#[ensures(result.is_some())]
+pub fn always_some(x: u32) -> Option<u32> {
+    Some(x)
+}
+
+#[ensures(result.is_none() == (x == 0u32))]
+pub fn nonzero(x: u32) -> Option<u32> {
+    if x == 0 { None } else { Some(x) }
+}
+
+#[ensures(result.is_ok() == (d != 0u32))]
+#[ensures(result.is_err() == (d == 0u32))]
+pub fn checked_div(n: u32, d: u32) -> Result<u32, ()> {
+    if d == 0 { Err(()) } else { Ok(n / d) }
+}
+
+// The same accessor in code and in the specification denotes the same value.
+#[ensures(result == o.is_some())]
+pub fn has_value(o: &Option<u32>) -> bool {
+    o.is_some()
+}
+
+#[requires(o.is_some())]
+#[ensures(result == o.unwrap_or_logic(0u32))]
+pub fn get(o: Option<u32>) -> u32 {
+    o.unwrap()
+}
@@ -0,0 +1,55 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Mapping, *};
+
+pub fn map_some() {
+    let x = Some(3i32).map(|x| x + 1);
+    proof_assert!(x == Some(4i32));
+}
+
+pub fn unwrap_or_logic(o: Option<u32>) -> u32 {
+    let r = o.unwrap_or(0);
+    proof_assert!(r == o.unwrap_or_logic(0u32));
+    r
+}
+
+#[logic]
+#[open]
+pub fn incr() -> Mapping<Int, Int> {
+    |x| x + 1
+}
+
+#[logic]
+#[open]
+#[ensures(Some(3).map_logic(incr()) == Some(4))]
+#[ensures(None.map_logic(incr()) == None)]
+#[ensures(Some(3).and_then_logic(|x| Some(x * 2)) == Some(6))]
+#[ensures(Some(3).and_then_logic(|_| None::<Int>) == None)]
+pub fn option_laws() {}
+
+#[logic]
+#[open]
+pub fn negate() -> Mapping<bool, bool> {
+    |b: bool| !b
+}
+
+#[ensures(result == o.map_logic(negate()))]
+pub fn map_negate(o: Option<bool>) -> Option<bool> {
+    o.map(
+        #[ensures(result == !b)]
+        |b: bool| !b,
+    )
+}
+
+#[logic]
+#[open]
+pub fn some_if() -> Mapping<bool, Option<bool>> {
+    |b: bool| if b { Some(b) } else { None }
+}
+
+#[ensures(result == o.and_then_logic(some_if()))]
+pub fn and_then_some_if(o: Option<bool>) -> Option<bool> {
+    o.and_then(
+        #[ensures(result == if b { Some(b) } else { None })]
+        |b: bool| if b { Some(b) } else { None },
+    )
+}
@@ -0,0 +1,62 @@
+// WHY3PROVE
+#![feature(custom_inner_attributes)]
+extern crate creusot_contracts;
+
+// The same function is proved in each mode, with the specification allowed by the mode.
+
+// Plain arithmetic wraps around, as in release builds.
+pub mod wrap {
+    #![creusot::backend(overflow = "wrap")]
+    use creusot_contracts::*;
+
+    #[ensures(x@ < 255 ==> result@ == x@ + 1)]
+    #[ensures(x == 255u8 ==> result == 0u8)]
+    pub fn incr(x: u8) -> u8 {
+        x + 1
+    }
+
+    // The operators agree with the wrapping methods.
+    #[ensures(result == a.wrapping_mul(b).wrapping_sub(c))]
+    pub fn mul_sub(a: i32, b: i32, c: i32) -> i32 {
+        a * b - c
+    }
+
+    #[ensures(x == i64::MIN ==> result == i64::MIN)]
+    pub fn neg(x: i64) -> i64 {
+        -x
+    }
+}
+
+// Overflows are trusted to never happen.
+pub mod assume {
+    #![creusot::backend(overflow = "assume")]
+    use creusot_contracts::*;
+
+    #[ensures(result@ == x@ + 1)]
+    pub fn incr(x: u8) -> u8 {
+        x + 1
+    }
+
+    // Each operation is assumed to give the mathematical result of its operands.
+    #[ensures(result@ == a@ * b@ - c@)]
+    pub fn mul_sub(a: i32, b: i32, c: i32) -> i32 {
+        a * b - c
+    }
+
+    #[ensures(result@ == -x@)]
+    pub fn neg(x: i64) -> i64 {
+        -x
+    }
+
+    // Nested modules inherit the mode of their parent, unless they override it.
+    pub mod check {
+        #![creusot::backend(overflow = "check")]
+        use creusot_contracts::*;
+
+        #[requires(x@ < 255)]
+        #[ensures(result@ == x@ + 1)]
+        pub fn incr(x: u8) -> u8 {
+            x + 1
+        }
+    }
+}
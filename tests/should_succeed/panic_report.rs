@@ -0,0 +1,26 @@
+// CREUSOT_ARG=--panic-report
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[pure]
+#[ensures(result == x)]
+pub fn id(x: u32) -> u32 {
+    x
+}
+
+// Panic-free: it only calls `#[pure]` functions, and its overflow check is proved.
+#[requires(x@ < 100)]
+pub fn add_one(x: u32) -> u32 {
+    id(x) + 1
+}
+
+// May panic through `Vec::push`, without declaring it: reported with a warning.
+pub fn push(v: &mut Vec<u32>) {
+    v.push(id(0))
+}
+
+// May panic through `Vec::push`, as declared.
+#[panics_when(v@.len() == usize::MAX@)]
+pub fn push_declared(v: &mut Vec<u32>) {
+    v.push(0)
+}
@@ -0,0 +1,35 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    pcell::{PCell, PCellOwn},
+    *,
+};
+
+// Interior mutability through shared references, with the value tracked by the ghost permission.
+
+#[requires(c.id() == own.id())]
+#[ensures((^own.inner_logic())@ == v)]
+#[ensures((^own.inner_logic()).id() == c.id())]
+pub fn set_shared(c: &PCell<u32>, own: Ghost<&mut PCellOwn<u32>>, v: u32) {
+    unsafe { c.set(own, v) }
+}
+
+pub fn get_after_set() {
+    let (c, mut own) = PCell::new(1u32);
+    let r = &c;
+    set_shared(r, own.borrow_mut(), 5);
+    let x = unsafe { r.get(own.borrow()) };
+    proof_assert!(x@ == 5);
+    set_shared(r, own.borrow_mut(), x + 1);
+    assert!(unsafe { r.get(own.borrow()) } == 6);
+}
+
+// There is a single permission for each cell, and two permissions which are both owned are for
+// different cells: a cell cannot be mutably borrowed twice at the same time.
+pub fn distinct_cells() {
+    let (c1, mut own1) = PCell::new(1u32);
+    let (c2, own2) = PCell::new(2u32);
+    ghost! {
+        let _ = PCellOwn::disjoint_lemma(&mut own1, &own2);
+    };
+    proof_assert!(c1.id() != c2.id());
+}
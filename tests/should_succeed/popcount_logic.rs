@@ -0,0 +1,17 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Popcount, *};
+
+pub fn count_ones_zero() {
+    assert!(0u32.count_ones() == 0);
+}
+
+#[ensures(result.popcount() <= x.popcount())]
+pub fn halve(x: u32) -> u32 {
+    x / 2
+}
+
+#[requires(x@ != 0)]
+#[ensures(result@ >= 1)]
+pub fn nonzero_has_bits(x: u64) -> u32 {
+    x.count_ones()
+}
@@ -0,0 +1,19 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+#[ensures(2.pow(10) == 1024)]
+pub fn two_pow_ten() {}
+
+#[requires(x@ < 1000)]
+#[ensures(result@ == x@ * x@)]
+pub fn square(x: u64) -> u64 {
+    proof_assert!(x@.pow(2) == x@ * x@);
+    x.pow(2)
+}
+
+#[ensures(result == None)]
+pub fn overflow() -> Option<u64> {
+    proof_assert!(2.pow(64) > u64::MAX@);
+    2u64.checked_pow(64)
+}
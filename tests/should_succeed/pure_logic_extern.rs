@@ -0,0 +1,33 @@
+// CREUSOT_ARG=--report-assumptions
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+extern_spec! {
+    impl u32 {
+        // `isqrt` is modelled by a logic function, characterized by its postcondition
+        #[pure(logic)]
+        #[ensures(result@ * result@ <= self@ && self@ < (result@ + 1) * (result@ + 1))]
+        fn isqrt(self) -> u32;
+    }
+}
+
+/// The distance from `n` to the greatest square below it.
+#[logic]
+#[open]
+pub fn dist_to_square(n: u32) -> Int {
+    pearlite! { n@ - n.isqrt()@ * n.isqrt()@ }
+}
+
+#[ensures(result == (n.isqrt()@ * n.isqrt()@ == n@))]
+pub fn is_square(n: u32) -> bool {
+    let r = n.isqrt() as u64;
+    r * r == n as u64
+}
+
+// The same call in code and in the specification denotes the same value
+#[ensures(result@ == dist_to_square(n))]
+#[ensures(result@ <= 2 * n.isqrt()@)]
+pub fn remainder(n: u32) -> u32 {
+    let r = n.isqrt();
+    n - r * r
+}
@@ -0,0 +1,63 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Grid {
+    pub cells: Vec<Vec<u32>>,
+    pub width: usize,
+}
+
+impl Invariant for Grid {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! {
+            forall<i: Int> 0 <= i && i < self.cells@.len() ==> self.cells[i]@.len() == self.width@
+        }
+    }
+}
+
+impl Grid {
+    #[predicate]
+    #[open]
+    pub fn in_bounds(self, i: Int, j: Int) -> bool {
+        pearlite! { 0 <= i && i < self.cells@.len() && 0 <= j && j < self.width@ }
+    }
+
+    #[predicate]
+    #[open]
+    pub fn bounded(self, max: u32) -> bool {
+        pearlite! {
+            forall<(i, j): (Int, Int)> self.in_bounds(i, j) ==> self.cells[i][j]@ <= max@
+        }
+    }
+
+    #[requires(self.in_bounds(i@, j@))]
+    #[ensures(self.bounded(max) ==> result@ <= max@)]
+    pub fn get(&self, i: usize, j: usize, max: u32) -> u32 {
+        self.cells[i][j]
+    }
+
+    #[requires(self.in_bounds(i@, j@))]
+    #[ensures((^self).width == self.width)]
+    #[ensures((^self).cells@.len() == self.cells@.len())]
+    #[ensures((^self).cells[i@][j@] == v)]
+    #[ensures(forall<(k, l): (Int, Int)> #![trigger (^self).cells[k][l]]
+        self.in_bounds(k, l) && !(k == i@ && l == j@) ==> (^self).cells[k][l] == self.cells[k][l])]
+    pub fn set(&mut self, i: usize, j: usize, v: u32) {
+        self.cells[i][j] = v;
+    }
+}
+
+#[requires(g.bounded(max))]
+#[requires(v <= max)]
+#[requires(g.in_bounds(i@, j@))]
+#[ensures((^g).bounded(max))]
+pub fn set_bounded(g: &mut Grid, i: usize, j: usize, v: u32, max: u32) {
+    g.set(i, j, v)
+}
+
+#[logic]
+#[ensures(forall<((i, j), _): ((Int, Int), Int)> i + j == result + j + i)]
+pub fn nested() -> Int {
+    0
+}
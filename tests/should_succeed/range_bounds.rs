@@ -0,0 +1,37 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Int, *};
+
+// The bound `0 <= i < v@.len()` of a loop over a literal range is known in the body, so that
+// indexing needs no invariant about `i`.
+#[ensures(forall<j: Int> 0 <= j && j < (^v)@.len() ==> (^v)[j]@ == j)]
+#[ensures(v@.len() == (^v)@.len())]
+pub fn fill_indices(v: &mut Vec<usize>) {
+    let old_v = snapshot! { v };
+    #[invariant(v@.len() == old_v@.len())]
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> v[j]@ == j)]
+    for i in 0..v.len() {
+        v[i] = i;
+    }
+}
+
+#[requires(n@ <= 1000)]
+#[ensures(result@ <= n@ * 1000)]
+pub fn sum_below(n: u32) -> u32 {
+    let mut sum = 0;
+    #[invariant(sum@ <= produced.len() * 1000)]
+    for i in 0..n {
+        proof_assert! { 0 <= i@ && i@ < n@ };
+        sum += if i < 1000 { i } else { 1000 };
+    }
+    sum
+}
+
+#[ensures(result == (a@ <= x@ && x@ < b@))]
+pub fn in_range(a: u64, b: u64, x: u64) -> bool {
+    (a..b).contains(&x)
+}
+
+#[ensures(result == (a@ <= b@ && a@ <= x@ && x@ <= b@))]
+pub fn in_range_inclusive(a: u64, b: u64, x: u64) -> bool {
+    (a..=b).contains(&x)
+}
@@ -0,0 +1,24 @@
+extern crate creusot_contracts;
+
+use ::std::{rc::Rc, sync::Arc};
+use creusot_contracts::*;
+
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+pub fn rc_field() {
+    let rc = Rc::new(Point { x: 1, y: 2 });
+    let shared = rc.clone();
+    proof_assert!(shared@ == rc@);
+    let x = (*shared).x;
+    proof_assert!(x@ == 1);
+    proof_assert!(rc@.y@ == 2);
+}
+
+#[ensures(result@ == a@@.len())]
+pub fn arc_len(a: &Arc<Vec<u32>>) -> usize {
+    let b = a.clone();
+    b.len()
+}
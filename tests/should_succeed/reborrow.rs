@@ -0,0 +1,39 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires((*x)@ < 1000)]
+#[ensures((^x)@ == (*x)@ + 1)]
+pub fn incr(x: &mut u32) {
+    *x += 1;
+}
+
+// The reborrow is final: its final value is the one of `x`
+#[requires((*x)@ < 1000)]
+#[ensures((^x)@ == (*x)@ + 1)]
+pub fn incr_reborrow(x: &mut u32) {
+    incr(&mut *x);
+}
+
+// The first reborrow is not final, since `x` is used again
+#[requires((*x)@ < 1000)]
+#[ensures((^x)@ == (*x)@ + 2)]
+pub fn incr_twice(x: &mut u32) {
+    incr(&mut *x);
+    incr(&mut *x);
+}
+
+// Reborrow through two levels of references
+#[requires((**x)@ < 1000)]
+#[ensures((^*x)@ == (**x)@ + 1)]
+pub fn incr_nested(x: &mut &mut u32) {
+    incr(&mut **x);
+}
+
+// Reborrow of a field, followed by a read of another field
+#[requires((*p).0@ < 1000)]
+#[ensures((^p).0@ == (*p).0@ + 1 && (^p).1 == (*p).1)]
+pub fn incr_field(p: &mut (u32, u32)) -> u32 {
+    incr(&mut p.0);
+    p.1
+}
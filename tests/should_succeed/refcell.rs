@@ -0,0 +1,80 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::cell::*, *};
+
+pub struct Five;
+
+impl CellInv<u32> for Five {
+    #[predicate]
+    #[open]
+    fn cell_inv(x: u32) -> bool {
+        pearlite! { x@ == 5 }
+    }
+}
+
+// The only value satisfying the predicate of the cell is known after it is set.
+pub fn get_after_set(c: &PredCell<u32, Five>) {
+    c.set(5);
+    let x = c.get();
+    proof_assert!(x@ == 5);
+}
+
+pub struct Bounded;
+
+impl CellInv<u32> for Bounded {
+    #[predicate]
+    #[open]
+    fn cell_inv(x: u32) -> bool {
+        pearlite! { x@ < 100 }
+    }
+}
+
+pub fn bump(c: &PredCell<u32, Bounded>) {
+    let x = c.get();
+    if x < 99 {
+        c.set(x + 1);
+    }
+}
+
+pub fn shared_bumps() {
+    let c: PredCell<u32, Bounded> = PredCell::new(0);
+    let (r1, r2) = (&c, &c);
+    bump(r1);
+    bump(r2);
+    let x = c.into_inner();
+    proof_assert!(x@ < 100);
+}
+
+// A standard cell can be used, but its contents are not known.
+pub fn std_cell() -> u32 {
+    let mut c = Cell::new(1u32);
+    c.set(2);
+    *c.get_mut() = 3;
+    c.into_inner()
+}
+
+// The contents of a `RefCell` are known through a guard while it is alive.
+pub fn refcell_get_after_set(c: &RefCell<u32>) {
+    let mut g = c.borrow_mut();
+    *g = 5;
+    let x = *g;
+    proof_assert!(x@ == 5);
+}
+
+// The borrows of the guards are released before the cell is borrowed again.
+pub fn sequential_borrows() -> u32 {
+    let c = RefCell::new(1u32);
+    let mut g = c.borrow_mut();
+    *g = 2;
+    drop(g);
+    let r = c.borrow();
+    *r
+}
+
+// The second borrow panics, since the first guard is still alive.
+#[panics_when(true)]
+pub fn double_borrow(c: &RefCell<u32>) {
+    let g = c.borrow_mut();
+    let h = c.borrow_mut();
+    drop(g);
+    drop(h);
+}
@@ -0,0 +1,31 @@
+// The output of this file is also compared when it is translated from two different directories:
+// the names of impls must not depend on the location of the crate.
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Size {
+    #[logic]
+    fn size(self) -> Int;
+}
+
+impl<const N: usize> Size for [u8; N] {
+    #[logic]
+    #[open]
+    fn size(self) -> Int {
+        pearlite! { N@ }
+    }
+}
+
+impl<T> Size for Option<T> {
+    #[logic]
+    #[open]
+    fn size(self) -> Int {
+        match self {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+}
+
+#[ensures(x.size() == 4)]
+pub fn four(x: [u8; 4]) {}
@@ -0,0 +1,72 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::{std::convert::TryFromSpec, *};
+
+#[ensures(match result { Some(r) => r@ == x@, None => x@ >= 256 })]
+pub fn narrow(x: u16) -> Option<u8> {
+    match u8::try_from(x) {
+        Ok(r) => Some(r),
+        Err(_) => None,
+    }
+}
+
+pub fn narrow_const() {
+    assert!(u8::try_from(255u16).is_ok());
+    assert!(u8::try_from(256u16).is_err());
+}
+
+pub struct Percent(u8);
+
+impl TryFrom<u8> for Percent {
+    type Error = ();
+
+    #[ensures(match result {
+        Ok(p) => p.0 == value,
+        Err(_) => value@ > 100,
+    })]
+    fn try_from(value: u8) -> Result<Self, ()> {
+        if value <= 100 { Ok(Percent(value)) } else { Err(()) }
+    }
+}
+
+impl TryFromSpec<u8> for Percent {
+    #[predicate]
+    #[open]
+    fn fails(value: u8) -> bool {
+        pearlite! { value@ > 100 }
+    }
+
+    #[predicate]
+    #[open]
+    fn converts(value: u8, result: Self) -> bool {
+        result.0 == value
+    }
+}
+
+#[requires(x@ <= 100)]
+pub fn percent(x: u8) -> Percent {
+    match Percent::try_from(x) {
+        Ok(p) => p,
+        Err(_) => unreachable!(),
+    }
+}
+
+#[ensures(match result { Some(c) => c@ == x@, None => x@ >= 0xD800 })]
+pub fn to_char(x: u32) -> Option<char> {
+    if x >= 0xD800 {
+        return None;
+    }
+    match char::try_from(x) {
+        Ok(c) => Some(c),
+        Err(_) => unreachable!(),
+    }
+}
+
+#[requires(c@ < 128)]
+#[ensures(result@ == c@)]
+pub fn ascii_byte(c: char) -> u8 {
+    match u8::try_from(c) {
+        Ok(b) => b,
+        Err(_) => unreachable!(),
+    }
+}
@@ -0,0 +1,56 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[derive(PartialEq, Eq)]
+pub enum DigitError {
+    TooLarge,
+}
+
+#[derive(PartialEq, Eq)]
+pub enum AppError {
+    Digit(DigitError),
+    Odd,
+}
+
+impl From<DigitError> for AppError {
+    #[ensures(result == AppError::Digit(e))]
+    fn from(e: DigitError) -> Self {
+        AppError::Digit(e)
+    }
+}
+
+#[ensures(match result {
+    Ok(d) => x@ < 10 && d == x,
+    Err(e) => x@ >= 10 && e == DigitError::TooLarge,
+})]
+pub fn digit(x: u32) -> Result<u32, DigitError> {
+    if x < 10 { Ok(x) } else { Err(DigitError::TooLarge) }
+}
+
+// The error of `digit` is converted with `From`, and must satisfy the contract too.
+#[ensures(match result {
+    Ok(d) => x@ < 10 && d@ == 2 * x@,
+    Err(e) => x@ >= 10 && e == AppError::Digit(DigitError::TooLarge),
+})]
+pub fn double_digit(x: u32) -> Result<u32, AppError> {
+    let d = digit(x)?;
+    Ok(d * 2)
+}
+
+#[ensures(match result {
+    Ok(d) => x@ < 10 && x@ % 2 == 0 && d == x,
+    Err(e) => x@ >= 10 && e == AppError::Digit(DigitError::TooLarge)
+        || x@ < 10 && x@ % 2 == 1 && e == AppError::Odd,
+})]
+pub fn even_digit(x: u32) -> Result<u32, AppError> {
+    let d = digit(x)?;
+    if d % 2 == 0 { Ok(d) } else { Err(AppError::Odd) }
+}
+
+#[ensures(match result {
+    Some(r) => exists<a: u32, b: u32> x == Some(a) && y == Some(b) && r@ == a@ + b@,
+    None => x == None || y == None,
+})]
+pub fn add_options(x: Option<u32>, y: Option<u32>) -> Option<u64> {
+    Some(x? as u64 + y? as u64)
+}
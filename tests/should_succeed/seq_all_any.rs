@@ -0,0 +1,22 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+#[open]
+pub fn is_even(x: u32) -> bool {
+    pearlite! { x@ % 2 == 0 }
+}
+
+pub fn all_even() {
+    let v = vec![2u32, 4, 6];
+    proof_assert!(v@.all(|x: u32| is_even(x)));
+    proof_assert!(!v@.any(|x: u32| !is_even(x)));
+}
+
+#[requires(v@.all(|x: u32| is_even(x)))]
+#[requires(is_even(x))]
+#[ensures((^v)@.all(|y: u32| is_even(y)))]
+pub fn push_even(v: &mut Vec<u32>, x: u32) {
+    v.push(x);
+    proof_assert! { Seq::<u32>::all_laws(); true };
+}
@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(v@.len() == 2)]
+#[ensures((^v)@.permutation_of(v@))]
+#[ensures(v@.permutation_of((^v)@))]
+#[ensures(forall<x: u32> (^v)@.count(x) == v@.count(x))]
+pub fn swap_two(v: &mut Vec<u32>) {
+    v.swap(0, 1);
+}
+
+#[requires(v@.len() == 2)]
+#[ensures((^v)@.permutation_of(v@))]
+pub fn swap_twice(v: &mut Vec<u32>) {
+    let old_v = snapshot! { v@ };
+    v.swap(0, 1);
+    let mid = snapshot! { v@ };
+    v.swap(0, 1);
+    proof_assert! { v@.permutation_of(*mid) && mid.permutation_of(*old_v) };
+}
+
+#[requires(i@ < v@.len() && j@ < v@.len())]
+#[ensures(forall<x: T> (^v)@.count(x) == v@.count(x))]
+pub fn swap_counts<T>(v: &mut Vec<T>, i: usize, j: usize) {
+    v.swap(i, j);
+}
+
+#[ensures((^v)@.count(x) == v@.count(x) + 1)]
+#[ensures(forall<y: u32> y != x ==> (^v)@.count(y) == v@.count(y))]
+pub fn push_counts(v: &mut Vec<u32>, x: u32) {
+    v.push(x);
+}
@@ -0,0 +1,27 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(v@.len() <= 1000)]
+#[requires(forall<j: Int> 0 <= j && j < v@.len() ==> v@[j]@ <= 1000)]
+#[ensures(result@ == v@.sum())]
+pub fn sum(v: &[u32]) -> u64 {
+    let mut acc = 0u64;
+    let mut i = 0;
+    #[invariant(i@ <= v@.len())]
+    #[invariant(acc@ <= i@ * 1000)]
+    #[invariant(acc@ == v@.subsequence(0, i@).sum())]
+    while i < v.len() {
+        proof_assert! { Seq::<u32>::sum_push_back();
+            v@.subsequence(0, i@ + 1) == v@.subsequence(0, i@).push_back(v@[i@]) };
+        acc += v[i] as u64;
+        i += 1;
+    }
+    proof_assert! { v@.subsequence(0, i@) == v@ };
+    acc
+}
+
+#[ensures(result == s.push_back(x).product())]
+pub fn product_push_back(s: Snapshot<Seq<Int>>, x: Snapshot<Int>) -> Snapshot<Int> {
+    proof_assert! { Seq::<Int>::product_push_back(); true };
+    snapshot!(s.product() * *x)
+}
@@ -0,0 +1,33 @@
+// CREUSOT_ARG=--simplify=const-fold CREUSOT_ARG=--simplify=trivial-logic
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[allow(creusot::vacuous_postcondition)]
+#[requires(1 + 2 == 3 && x@ < 100)]
+#[requires(true ==> x@ > 0)]
+#[ensures(result@ == x@ + 2 * 3 - 5)]
+#[ensures(forall<i: Int> true)]
+#[ensures(result@ > 0 || false)]
+pub fn folded(x: u32) -> u32 {
+    x + 1
+}
+
+#[ensures(!(2 < 1) ==> result)]
+#[ensures(-(-4) == 4)]
+pub fn negations() -> bool {
+    true
+}
+
+pub trait Tr {
+    #[requires(x@ >= 1 + 1)]
+    #[ensures(result@ >= 2 - 1)]
+    fn f(&self, x: u32) -> u32;
+}
+
+impl Tr for () {
+    #[requires(true && x@ >= 2)]
+    #[ensures(result@ >= 1 || 0 > 1)]
+    fn f(&self, x: u32) -> u32 {
+        x - 1
+    }
+}
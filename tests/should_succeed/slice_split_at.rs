@@ -0,0 +1,25 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub fn split_four() {
+    let a = [1, 2, 3, 4];
+    let (l, r) = a.split_at(2);
+    proof_assert! { l@ == seq![1i32, 2i32] && r@ == seq![3i32, 4i32] };
+    proof_assert! { l@.concat(r@) == a@ };
+}
+
+#[requires(mid@ <= s@.len())]
+#[ensures(result@ == s@.len())]
+pub fn split_len<T>(s: &[T], mid: usize) -> usize {
+    let (l, r) = s.split_at(mid);
+    l.len() + r.len()
+}
+
+// The two halves of `split_at_mut` are independent: writing to one leaves the other unchanged.
+#[requires(s@.len() == 4)]
+#[ensures((^s)@ == seq![0u32, (*s)[1], (*s)[2], 1u32])]
+pub fn write_halves(s: &mut [u32]) {
+    let (l, r) = s.split_at_mut(2);
+    l[0] = 0;
+    r[1] = 1;
+}
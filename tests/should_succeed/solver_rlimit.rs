@@ -0,0 +1,31 @@
+// CREUSOT_ARG=--rlimit=20000 WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The obligations of `sum_squares` need more solver steps than the default limit given above:
+// the attribute raises it for this function only.
+#[creusot::solver(rlimit = 2000000)]
+#[requires(a@ < 1000 && b@ < 1000 && c@ < 1000)]
+#[ensures(result@ == a@ * a@ + b@ * b@ + c@ * c@)]
+pub fn sum_squares(a: u64, b: u64, c: u64) -> u64 {
+    a * a + b * b + c * c
+}
+
+#[ensures(result@ == x@ + 1)]
+pub fn incr(x: u32) -> u64 {
+    x as u64 + 1
+}
+
+pub trait Double {
+    #[ensures(result@ == 2 * self@)]
+    fn double(self) -> u64;
+}
+
+// The limit of an impl applies to the refinement obligations of its items.
+#[creusot::solver(rlimit = 100000)]
+impl Double for u32 {
+    #[ensures(result@ == self@ + self@)]
+    fn double(self) -> u64 {
+        self as u64 + self as u64
+    }
+}
@@ -2,6 +2,7 @@ extern crate creusot_contracts;
 
 use creusot_contracts::{logic::Int, *};
 
+#[allow(creusot::vacuous_postcondition)]
 #[ensures(forall<_x:u32> true && true && true && true && true && true && true && true && true)]
 pub fn f() {}
 
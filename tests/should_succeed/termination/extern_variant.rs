@@ -0,0 +1,21 @@
+// CREUSOT_ARG=--report-assumptions
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+extern_spec! {
+    impl u64 {
+        // Exponentiation by squaring recurses on the exponent. The variant is trusted, since the
+        // body of `pow` is not verified.
+        #[requires(self@.pow(exp@) <= u64::MAX@)]
+        #[ensures(result@ == self@.pow(exp@))]
+        #[variant(exp@)]
+        fn pow(self, exp: u32) -> u64;
+    }
+}
+
+#[terminates]
+#[requires(x@.pow(3) <= u64::MAX@)]
+#[ensures(result@ == x@.pow(3))]
+pub fn cube(x: u64) -> u64 {
+    x.pow(3)
+}
@@ -0,0 +1,55 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The variant of a loop over a range is inferred.
+#[terminates]
+#[ensures(result@ == n@)]
+pub fn count_range(n: u32) -> u32 {
+    let mut count = 0;
+    #[invariant(count@ == produced.len())]
+    for _ in 0..n {
+        count += 1;
+    }
+    count
+}
+
+// The variant of a loop incrementing its counter up to a bound is inferred.
+#[terminates]
+#[requires(n@ <= 1000)]
+#[ensures(result@ == 2 * n@)]
+pub fn count_while(n: u32) -> u32 {
+    let mut i = 0;
+    let mut r = 0;
+    #[invariant(i@ <= n@ && r@ == 2 * i@)]
+    while i < n {
+        r += 2;
+        i += 1;
+    }
+    r
+}
+
+// Also when decrementing the counter down to a literal bound.
+#[terminates]
+#[ensures(result@ == 0)]
+pub fn count_down(mut i: u64) -> u64 {
+    #[invariant(true)]
+    while i > 0 {
+        i -= 1;
+    }
+    i
+}
+
+// Other loops need an explicit variant.
+#[terminates]
+#[ensures(result@ == n@ / 2)]
+pub fn halve(n: u32) -> u32 {
+    let mut i = 0;
+    let mut j = n;
+    #[invariant(i@ <= n@ / 2 && j@ == n@ - 2 * i@)]
+    #[variant(j@)]
+    while j >= 2 {
+        i += 1;
+        j -= 2;
+    }
+    i
+}
@@ -0,0 +1,26 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Parity {
+    #[logic]
+    #[open(self)]
+    #[variant(n)]
+    #[requires(n >= 0)]
+    fn even(n: Int) -> bool {
+        if n == 0 { true } else { Self::odd(n - 1) }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[variant(n)]
+    #[requires(n >= 0)]
+    fn odd(n: Int) -> bool {
+        if n == 0 { false } else { Self::even(n - 1) }
+    }
+}
+
+impl Parity for () {}
+
+#[logic]
+#[ensures(<() as Parity>::even(2) && <() as Parity>::odd(3))]
+pub fn parity_of_small_numbers() {}
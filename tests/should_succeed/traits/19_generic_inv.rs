@@ -0,0 +1,47 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::*, *};
+
+pub trait Consume<T> {
+    fn consume(self, x: T);
+}
+
+pub struct Wrap<T>(pub T);
+
+// The invariant of `x` is a hypothesis of the trait signature, so the impl may
+// require it even though the trait item has no contract.
+impl<T: Invariant> Consume<T> for Wrap<T> {
+    #[requires(inv(x))]
+    fn consume(self, x: T) {}
+}
+
+pub trait Produce {
+    type Item;
+
+    fn produce(self) -> Self::Item;
+}
+
+impl<T> Produce for Wrap<T> {
+    type Item = T;
+
+    #[ensures(inv(result))]
+    fn produce(self) -> T {
+        self.0
+    }
+}
+
+pub struct NonZero(pub u32);
+
+impl Invariant for NonZero {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ != 0 }
+    }
+}
+
+// At a concrete type, the invariant of the argument resolves to the user invariant of this type:
+// the impl may require what this invariant states.
+impl Consume<NonZero> for Wrap<u32> {
+    #[requires(x.0@ != 0)]
+    fn consume(self, x: NonZero) {}
+}
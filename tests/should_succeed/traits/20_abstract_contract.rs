@@ -0,0 +1,85 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The contract of a trait method without a default body is refined by every impl.
+
+pub trait Counter {
+    #[requires(n@ < 100)]
+    #[ensures(result@ > n@)]
+    fn next(&self, n: u32) -> u32;
+
+    #[ensures(result@ > 0)]
+    fn positive(&self) -> u32;
+}
+
+pub struct One;
+
+impl Counter for One {
+    #[ensures(result@ == n@ + 1)]
+    fn next(&self, n: u32) -> u32 {
+        n + 1
+    }
+
+    #[ensures(result@ == 1)]
+    fn positive(&self) -> u32 {
+        1
+    }
+}
+
+pub struct Two;
+
+// Weaker precondition and stronger postcondition than the trait
+impl Counter for Two {
+    #[requires(n@ < 1000)]
+    #[ensures(result@ == n@ + 2)]
+    fn next(&self, n: u32) -> u32 {
+        n + 2
+    }
+
+    #[ensures(result@ == 2)]
+    fn positive(&self) -> u32 {
+        2
+    }
+}
+
+#[requires(n@ < 100)]
+#[ensures(result@ > 0)]
+pub fn use_counter<C: Counter>(c: &C, n: u32) -> u32 {
+    let x = c.next(n);
+    proof_assert!(x@ > n@);
+    c.positive()
+}
+
+// Declarations taking or returning `Self` by value, in a trait whose implementors may be unsized.
+pub trait Make {
+    #[ensures(result.value()@ == n@)]
+    fn make(n: u32) -> Self;
+
+    #[requires(self.value()@ > 0)]
+    #[ensures(result@ < self.value()@)]
+    fn consume(self) -> u32;
+
+    #[logic]
+    fn value(self) -> u32;
+}
+
+pub struct Num(pub u32);
+
+impl Make for Num {
+    #[ensures(result.0 == n)]
+    fn make(n: u32) -> Self {
+        Num(n)
+    }
+
+    #[requires(self.0@ > 0)]
+    #[ensures(result@ == self.0@ - 1)]
+    fn consume(self) -> u32 {
+        self.0 - 1
+    }
+
+    #[logic]
+    #[open]
+    fn value(self) -> u32 {
+        self.0
+    }
+}
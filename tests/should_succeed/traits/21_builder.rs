@@ -0,0 +1,56 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Refinement of trait methods returning `Self`
+
+pub trait Builder: Sized {
+    #[logic]
+    fn count(self) -> Int;
+
+    #[requires(self.count() < 100)]
+    #[ensures(result.count() == self.count() + 1)]
+    fn with(self, x: u32) -> Self;
+}
+
+pub struct Counter {
+    pub n: u32,
+}
+
+impl Builder for Counter {
+    #[logic]
+    #[open]
+    fn count(self) -> Int {
+        pearlite! { self.n@ }
+    }
+
+    #[requires(self.n@ < 100)]
+    #[ensures(result.n@ == self.n@ + 1)]
+    fn with(self, _x: u32) -> Self {
+        Counter { n: self.n + 1 }
+    }
+}
+
+pub struct Items {
+    pub v: Vec<u32>,
+}
+
+impl Builder for Items {
+    #[logic]
+    #[open]
+    fn count(self) -> Int {
+        pearlite! { self.v@.len() }
+    }
+
+    #[requires(self.v@.len() < 100)]
+    #[ensures(result.v@ == self.v@.push_back(x))]
+    fn with(mut self, x: u32) -> Items {
+        self.v.push(x);
+        self
+    }
+}
+
+#[requires(b.count() < 98)]
+#[ensures(result.count() == b.count() + 2)]
+pub fn twice<B: Builder>(b: B) -> B {
+    b.with(0).with(1)
+}
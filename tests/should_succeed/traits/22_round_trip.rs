@@ -0,0 +1,30 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::convert::RoundTrip, *};
+
+pub struct Wide(pub u16);
+
+impl From<u8> for Wide {
+    #[ensures(result.0@ == value@)]
+    fn from(value: u8) -> Self {
+        Wide(value as u16)
+    }
+}
+
+impl RoundTrip<u8> for Wide {
+    #[logic]
+    #[open]
+    fn from_logic(value: u8) -> Self {
+        Wide(value as u16)
+    }
+
+    #[logic]
+    #[open]
+    fn back(self) -> u8 {
+        self.0 as u8
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(Self::from_logic(value).back() == value)]
+    fn round_trip(value: u8) {}
+}
@@ -0,0 +1,47 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Make: Sized {
+    #[logic]
+    fn size(self) -> Int;
+
+    #[ensures(result.size() == 0)]
+    fn new() -> Self;
+
+    #[requires(n@ <= 100)]
+    #[ensures(result.size() == n@)]
+    fn with_size<U>(n: u32, filler: U) -> Self;
+}
+
+pub struct Block(pub u32);
+
+impl Make for Block {
+    #[logic]
+    #[open]
+    fn size(self) -> Int {
+        pearlite! { self.0@ }
+    }
+
+    #[ensures(result.0 == 0u32)]
+    fn new() -> Self {
+        Block(0)
+    }
+
+    // Weaker requirement and stronger guarantee than in the trait
+    #[ensures(result.0 == n)]
+    fn with_size<U>(n: u32, _filler: U) -> Self {
+        Block(n)
+    }
+}
+
+pub fn make_pair<T: Make>() -> (T, T) {
+    let a = T::new();
+    let b = T::with_size(3, ());
+    proof_assert!(a.size() == 0 && b.size() == 3);
+    (a, b)
+}
+
+pub fn make_block() {
+    let b: Block = Make::with_size(5, true);
+    proof_assert!(b.0@ == 5);
+}
@@ -0,0 +1,64 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Scale {
+    /// The default scaling factor is 1.
+    #[logic]
+    #[open]
+    fn factor(self) -> Int {
+        1
+    }
+
+    #[logic]
+    #[open]
+    fn spec_fn(self, x: Int) -> Int {
+        pearlite! { self.factor() * x }
+    }
+
+    #[requires(0 <= x@ && x@ <= 100)]
+    #[ensures(result@ == self.spec_fn(x@))]
+    fn scale(&self, x: u32) -> u32;
+}
+
+pub struct Id;
+
+impl Scale for Id {
+    #[requires(0 <= x@ && x@ <= 100)]
+    #[ensures(result == x)]
+    fn scale(&self, x: u32) -> u32 {
+        x
+    }
+}
+
+pub struct Double;
+
+impl Scale for Double {
+    // Differs from the default definition: the refinement of `scale` only holds with this one.
+    #[logic]
+    #[open]
+    fn factor(self) -> Int {
+        2
+    }
+
+    #[requires(0 <= x@ && x@ <= 100)]
+    #[ensures(result@ == self.spec_fn(x@))]
+    fn scale(&self, x: u32) -> u32 {
+        x * 2
+    }
+}
+
+pub struct Offset(u8);
+
+impl Scale for Offset {
+    #[logic]
+    #[open]
+    fn spec_fn(self, x: Int) -> Int {
+        pearlite! { x + self.0@ }
+    }
+
+    #[requires(0 <= x@ && x@ <= 100)]
+    #[ensures(result@ == x@ + self.0@)]
+    fn scale(&self, x: u32) -> u32 {
+        x + self.0 as u32
+    }
+}
@@ -0,0 +1,89 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Parse {
+    #[ensures(match result {
+        Ok(v) => v@ <= s@,
+        Err(()) => s@ > 100,
+    })]
+    fn parse(s: u32) -> Result<u32, ()>;
+}
+
+// The impl never fails: its postcondition only describes `Ok`.
+pub struct Total;
+
+impl Parse for Total {
+    #[ensures(match result {
+        Ok(v) => v@ == s@ / 2,
+        Err(_) => false,
+    })]
+    fn parse(s: u32) -> Result<u32, ()> {
+        Ok(s / 2)
+    }
+}
+
+pub struct Strict;
+
+impl Parse for Strict {
+    #[ensures(s@ <= 100 ==> result == Ok(s))]
+    #[ensures(s@ > 100 ==> result == Err(()))]
+    fn parse(s: u32) -> Result<u32, ()> {
+        if s <= 100 { Ok(s) } else { Err(()) }
+    }
+}
+
+pub enum Shape {
+    Empty,
+    Square(u32),
+    Rect(u32, u32),
+}
+
+pub trait Build {
+    #[ensures(match result {
+        Shape::Empty => true,
+        Shape::Square(c) => c@ > 0,
+        Shape::Rect(w, h) => w@ > 0 && h@ > 0,
+    })]
+    fn build(&self) -> Shape;
+}
+
+impl Build for u32 {
+    #[ensures(*self == 0u32 ==> result == Shape::Empty)]
+    #[ensures(*self != 0u32 ==> result == Shape::Square(*self))]
+    fn build(&self) -> Shape {
+        if *self == 0 { Shape::Empty } else { Shape::Square(*self) }
+    }
+}
+
+// Too many variants to be case-split: the refinement is proved as a single goal.
+pub enum Digit {
+    Zero,
+    One,
+    Two,
+    Three,
+    Other(u8),
+}
+
+pub trait Classify {
+    #[ensures(match result {
+        Digit::Other(d) => d@ > 3,
+        _ => true,
+    })]
+    fn classify(d: u8) -> Digit;
+}
+
+impl Classify for u8 {
+    #[ensures(match result {
+        Digit::Other(e) => e == d && d@ > 3,
+        _ => d@ <= 3,
+    })]
+    fn classify(d: u8) -> Digit {
+        match d {
+            0 => Digit::Zero,
+            1 => Digit::One,
+            2 => Digit::Two,
+            3 => Digit::Three,
+            _ => Digit::Other(d),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Named {
+    #[ensures(result@ > 0)]
+    fn name(&self) -> u32;
+}
+
+pub trait Animal: Named {
+    #[ensures(result@ <= 4)]
+    fn legs(&self) -> u32;
+}
+
+pub struct Dog;
+
+impl Named for Dog {
+    #[ensures(result@ == 1)]
+    fn name(&self) -> u32 {
+        1
+    }
+}
+
+impl Animal for Dog {
+    #[ensures(result@ == 4)]
+    fn legs(&self) -> u32 {
+        4
+    }
+}
+
+// `name` is a method of the supertrait: the call uses the contract of `Named::name`.
+#[ensures(result@ > 0)]
+pub fn name_of(animal: &dyn Animal) -> u32 {
+    animal.name()
+}
+
+#[ensures(result@ <= 5)]
+pub fn describe(animal: &dyn Animal) -> u32 {
+    let legs = animal.legs();
+    if animal.name() > 1 { legs + 1 } else { legs }
+}
+
+pub fn call_with_dog() {
+    let d = Dog;
+    let n = name_of(&d);
+    proof_assert!(n@ > 0);
+}
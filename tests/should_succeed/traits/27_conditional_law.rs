@@ -0,0 +1,47 @@
+// CREUSOT_ARG=--closed-crate=27_conditional_law
+extern crate creusot_contracts;
+use creusot_contracts::{logic::OrdLogic, *};
+
+// `weight_mono` only holds for the types with an order: it is available and proved only for them.
+
+pub trait Weight {
+    #[logic]
+    fn weight(self) -> Int;
+
+    #[law]
+    #[forall_impls]
+    #[ensures(a.le_log(b) ==> a.weight() <= b.weight())]
+    fn weight_mono(a: Self, b: Self)
+    where
+        Self: OrdLogic,
+    {
+    }
+}
+
+impl Weight for u32 {
+    #[open]
+    #[logic]
+    fn weight(self) -> Int {
+        pearlite! { self@ * 2 }
+    }
+}
+
+pub struct Unordered(pub u32);
+
+// `Unordered` does not implement `OrdLogic`: no law to prove here, although `weight` is not
+// monotonic for any order.
+impl Weight for Unordered {
+    #[open]
+    #[logic]
+    fn weight(self) -> Int {
+        pearlite! { -self.0@ }
+    }
+}
+
+#[open]
+#[logic]
+#[requires(a.le_log(b))]
+#[ensures(result)]
+pub fn uses_law<T: Weight + OrdLogic>(a: T, b: T) -> bool {
+    pearlite! { a.weight() <= b.weight() }
+}
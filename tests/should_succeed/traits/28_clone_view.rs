@@ -0,0 +1,55 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The spec of `Clone::clone` is `result == *self`, so every clone preserves the view.
+
+#[derive(Clone)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl View for Point {
+    type ViewTy = (Int, Int);
+
+    #[logic]
+    #[open]
+    fn view(self) -> (Int, Int) {
+        pearlite! { (self.x@, self.y@) }
+    }
+}
+
+pub struct Pair(pub u32, pub u32);
+
+impl Clone for Pair {
+    #[ensures(result == *self)]
+    fn clone(&self) -> Self {
+        Pair(self.0, self.1)
+    }
+}
+
+impl View for Pair {
+    type ViewTy = Int;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Int {
+        pearlite! { self.0@ + self.1@ }
+    }
+}
+
+pub fn clone_point(p: &Point) -> Point {
+    let q = p.clone();
+    proof_assert!(q@ == p@);
+    q
+}
+
+#[ensures(result@ == p@)]
+pub fn clone_generic<T: Clone + View>(p: &T) -> T {
+    p.clone()
+}
+
+pub fn clone_pair(p: &Pair) {
+    let q = clone_generic(p);
+    proof_assert!(q@ == p@);
+}
@@ -0,0 +1,43 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Arity {
+    const N: u32;
+
+    const TWICE: u32 = 2;
+}
+
+pub struct Pair;
+
+impl Arity for Pair {
+    const N: u32 = 2;
+}
+
+pub struct Triple;
+
+impl Arity for Triple {
+    const N: u32 = 3;
+
+    const TWICE: u32 = 6;
+}
+
+#[ensures(result == 2u32)]
+pub fn pair_arity() -> u32 {
+    <Pair as Arity>::N
+}
+
+#[ensures(<Pair as Arity>::N@ + <Triple as Arity>::N@ == 5)]
+#[ensures(<Pair as Arity>::TWICE == 2u32 && <Triple as Arity>::TWICE == 6u32)]
+pub fn arities() {}
+
+// In generic code, the constant is abstract.
+#[requires(T::N@ > 0)]
+#[ensures(result@ == T::N@ - 1)]
+pub fn pred_arity<T: Arity>() -> u32 {
+    T::N - 1
+}
+
+#[ensures(result == 2u32)]
+pub fn pred_arity_triple() -> u32 {
+    pred_arity::<Triple>()
+}
@@ -0,0 +1,43 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::ops::{Deref, DerefMut, DerefSpec},
+    *,
+};
+
+pub struct Wrapper<T>(pub T);
+
+impl<T> Deref for Wrapper<T> {
+    type Target = T;
+
+    #[ensures(*result == self.0)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Wrapper<T> {
+    #[ensures(*result == (*self).0 && ^result == (^self).0)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> DerefSpec for Wrapper<T> {
+    #[logic]
+    #[open]
+    fn target(self) -> T {
+        self.0
+    }
+}
+
+#[requires(w.target()@ < 100)]
+#[ensures(result@ == w.target()@ + 1)]
+pub fn through_deref(w: &Wrapper<u32>) -> u32 {
+    **w + 1
+}
+
+#[requires((*w).target()@ < 100)]
+#[ensures((^w).target()@ == (*w).target()@ + 1)]
+pub fn through_deref_mut(w: &mut Wrapper<u32>) {
+    **w += 1;
+}
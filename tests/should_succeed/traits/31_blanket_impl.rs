@@ -0,0 +1,66 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Size {
+    #[logic]
+    fn size_log(self) -> Int;
+
+    #[ensures(result@ == self.size_log())]
+    fn size(&self) -> u64;
+}
+
+pub trait Weight: Size {
+    #[requires(self.size_log() <= 1000)]
+    #[ensures(result@ == 2 * self.size_log())]
+    fn weight(&self) -> u64;
+}
+
+// The refinement of `Weight::weight` is proved once, for any `T: Size`.
+impl<T: Size> Weight for T {
+    #[requires(self.size_log() <= 1000)]
+    #[ensures(result@ == 2 * self.size_log())]
+    fn weight(&self) -> u64 {
+        2 * self.size()
+    }
+}
+
+impl Size for u8 {
+    #[logic]
+    #[open]
+    fn size_log(self) -> Int {
+        1
+    }
+
+    #[ensures(result@ == self.size_log())]
+    fn size(&self) -> u64 {
+        1
+    }
+}
+
+impl Size for (u8, u8) {
+    #[logic]
+    #[open]
+    fn size_log(self) -> Int {
+        2
+    }
+
+    #[ensures(result@ == self.size_log())]
+    fn size(&self) -> u64 {
+        2
+    }
+}
+
+#[ensures(result@ == 2)]
+pub fn weight_u8(x: u8) -> u64 {
+    x.weight()
+}
+
+#[ensures(result@ == 4)]
+pub fn weight_pair(x: (u8, u8)) -> u64 {
+    x.weight()
+}
+
+#[ensures(result@ == 6)]
+pub fn weight_both(x: u8, y: (u8, u8)) -> u64 {
+    x.weight() + y.weight()
+}
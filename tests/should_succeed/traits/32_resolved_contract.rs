@@ -0,0 +1,36 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Size {
+    #[ensures(result@ >= 1)]
+    fn size(&self) -> u32;
+}
+
+pub struct Small;
+
+impl Size for Small {
+    #[ensures(result@ == 4)]
+    fn size(&self) -> u32 {
+        4
+    }
+}
+
+// The call resolves to the impl for `Small`: its contract is used, rather than the one of the
+// trait, which does not give the exact value.
+pub fn small_size(s: &Small) -> u32 {
+    let n = s.size();
+    proof_assert! { n@ == 4 };
+    n * 2
+}
+
+// Through a generic function, only the contract of the trait is known.
+#[ensures(result@ >= 1)]
+pub fn any_size<T: Size>(t: &T) -> u32 {
+    t.size()
+}
+
+// A generic function instantiated with `Small` still only has its own contract.
+pub fn small_through_generic(s: &Small) {
+    let n = any_size(s);
+    proof_assert! { n@ >= 1 };
+}
@@ -0,0 +1,50 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// Rust has no `where Self::N > 0` bounds: the condition on the constant is a precondition of the
+// method. In the refinement obligation of each impl, `Self::N` is resolved to the constant of the
+// impl, so that the condition is evaluated for this impl.
+pub trait Buckets {
+    const N: u32;
+
+    #[requires(Self::N@ > 0)]
+    #[ensures(result@ < Self::N@)]
+    fn bucket(&self, key: u32) -> u32;
+}
+
+// `N = 0` violates the condition: the method is not applicable, and its
+// body is verified vacuously.
+pub struct NoBuckets;
+
+impl Buckets for NoBuckets {
+    const N: u32 = 0;
+
+    #[requires(Self::N@ > 0)]
+    #[ensures(result@ < Self::N@)]
+    fn bucket(&self, _: u32) -> u32 {
+        0
+    }
+}
+
+// With `N = 5`, the condition holds and is assumed by the implementation.
+pub struct Five;
+
+impl Buckets for Five {
+    const N: u32 = 5;
+
+    #[ensures(result@ < 5)]
+    fn bucket(&self, key: u32) -> u32 {
+        key % 5
+    }
+}
+
+#[ensures(result@ < 5)]
+pub fn five_bucket(key: u32) -> u32 {
+    Five.bucket(key)
+}
+
+#[requires(T::N@ > 0)]
+#[ensures(result@ < T::N@)]
+pub fn generic_bucket<T: Buckets>(t: &T, key: u32) -> u32 {
+    t.bucket(key)
+}
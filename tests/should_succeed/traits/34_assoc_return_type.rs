@@ -0,0 +1,61 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The trait method returns an associated type, which each impl instantiates with its own return
+// type: the `result` of the refinement has the normalized type on both sides.
+pub trait First {
+    type Item;
+
+    #[logic]
+    fn first_log(self) -> Self::Item;
+
+    #[ensures(result == self.first_log())]
+    fn first(self) -> Self::Item;
+}
+
+pub struct Pair<T>(T, T);
+
+impl<'a, T> First for &'a Pair<T> {
+    type Item = &'a T;
+
+    #[logic]
+    #[open]
+    fn first_log(self) -> &'a T {
+        &self.0
+    }
+
+    #[ensures(*result == self.0)]
+    fn first(self) -> &'a T {
+        &self.0
+    }
+}
+
+pub struct Range {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl First for Range {
+    type Item = u32;
+
+    #[logic]
+    #[open]
+    fn first_log(self) -> u32 {
+        self.start
+    }
+
+    #[ensures(result == self.start)]
+    fn first(self) -> u32 {
+        self.start
+    }
+}
+
+#[ensures(*result == p.0)]
+pub fn first_of_pair<T>(p: &Pair<T>) -> &T {
+    p.first()
+}
+
+#[ensures(result == 3u32)]
+pub fn first_of_range() -> u32 {
+    Range { start: 3, end: 5 }.first()
+}
@@ -0,0 +1,63 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Total {
+    #[logic]
+    fn total(self) -> Int;
+
+    #[logic]
+    fn elem(self, i: Int) -> Int;
+
+    #[logic]
+    fn width() -> Int;
+
+    #[law]
+    #[requires(Self::width() == 4)]
+    #[ensures(self.total() == self.elem(0) + self.elem(1) + self.elem(2) + self.elem(3))]
+    fn total4(self);
+}
+
+// The law of the impl is generic over `N`: it is instantiated with the size of the arrays of the
+// functions using `total`.
+impl<const N: usize> Total for [u32; N] {
+    #[logic]
+    #[open]
+    fn total(self) -> Int {
+        pearlite! { self@.sum() }
+    }
+
+    #[logic]
+    #[open]
+    fn elem(self, i: Int) -> Int {
+        pearlite! { self[i]@ }
+    }
+
+    #[logic]
+    #[open]
+    fn width() -> Int {
+        pearlite! { N@ }
+    }
+
+    #[law]
+    #[open(self)]
+    #[requires(Self::width() == 4)]
+    #[ensures(self.total() == self.elem(0) + self.elem(1) + self.elem(2) + self.elem(3))]
+    fn total4(self) {
+        proof_assert! { self@.subsequence(0, 3).subsequence(0, 2) == self@.subsequence(0, 2) };
+        proof_assert! { self@.subsequence(0, 2).subsequence(0, 1) == self@.subsequence(0, 1) };
+        proof_assert! { self@.subsequence(0, 1).subsequence(0, 0) == Seq::EMPTY }
+    }
+}
+
+#[ensures(result@ == a.total())]
+pub fn sum4(a: [u32; 4]) -> u64 {
+    a[0] as u64 + a[1] as u64 + a[2] as u64 + a[3] as u64
+}
+
+#[ensures(result@ == 10)]
+pub fn sum_kernel() -> u64 {
+    let a = [1, 2, 3, 4];
+    let s = sum4(a);
+    proof_assert! { a.total() == 10 };
+    s
+}
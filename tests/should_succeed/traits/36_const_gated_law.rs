@@ -0,0 +1,58 @@
+// WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Len {
+    #[logic]
+    fn len_log(self) -> Int;
+
+    // Only available for the types with a `Default` impl: for arrays, those with at most 32
+    // elements.
+    #[law]
+    #[ensures(x.len_log() <= 32)]
+    fn small(x: Self)
+    where
+        Self: Default;
+
+    #[law]
+    #[ensures(x.len_log() >= 0)]
+    fn nonneg(x: Self);
+}
+
+pub mod arrays {
+    use super::*;
+
+    impl<const N: usize> Len for [u32; N] {
+        // Opaque outside of this module: only the law tells the bound on the length.
+        #[logic]
+        fn len_log(self) -> Int {
+            pearlite! { N@ }
+        }
+
+        // The law is trusted: the bound follows from the impls of `Default` for arrays, which
+        // are not visible in the logic.
+        #[law]
+        #[trusted]
+        #[ensures(x.len_log() <= 32)]
+        fn small(x: Self)
+        where
+            Self: Default,
+        {
+        }
+
+        #[law]
+        #[ensures(x.len_log() >= 0)]
+        fn nonneg(x: Self) {}
+    }
+}
+
+// `[u32; 2 * 2]: Default` holds once the size is evaluated: the law is emitted.
+pub fn small_array(a: [u32; 2 * 2]) {
+    proof_assert! { a.len_log() <= 32 };
+}
+
+// `[u32; 40]: Default` does not hold: `small` is not emitted, but the other laws of the impl are.
+// should_fail/traits/const_gated_law.rs checks that `small` cannot be used here.
+pub fn large_array(a: [u32; 40]) {
+    proof_assert! { a.len_log() >= 0 };
+}
@@ -0,0 +1,28 @@
+#![feature(min_specialization)]
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Size {
+    fn size(&self) -> usize;
+}
+
+impl<T> Size for Vec<T> {
+    #[ensures(result@ == self@.len())]
+    default fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+// The specialized impl may strengthen the postcondition of the base impl, but not change it.
+impl Size for Vec<()> {
+    #[ensures(result@ == self@.len())]
+    #[ensures(result@ <= usize::MAX@)]
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+#[ensures(result@ == v@.len())]
+pub fn size_of_units(v: &Vec<()>) -> usize {
+    v.size()
+}
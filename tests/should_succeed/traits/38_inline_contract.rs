@@ -0,0 +1,33 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Size {
+    #[ensures(result@ >= 1)]
+    fn size(&self) -> u32;
+}
+
+pub struct Small;
+
+impl Size for Small {
+    #[ensures(result@ == 4)]
+    fn size(&self) -> u32 {
+        4
+    }
+}
+
+// The call resolves to the impl for `Small`: its contract is assumed at the call site, and gives
+// the exact value that the contract of the trait does not.
+#[creusot::inline_contract]
+pub fn small_size(s: &Small) -> u32 {
+    let n = s.size();
+    proof_assert! { n@ == 4 };
+    n * 2
+}
+
+// Through a bound of a generic parameter, the call is not inlined: only the contract of the trait
+// is known.
+#[creusot::inline_contract]
+#[ensures(result@ >= 1)]
+pub fn any_size<T: Size>(t: &T) -> u32 {
+    t.size()
+}
@@ -0,0 +1,55 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The refinements of this file are also dumped with `--dump-refinements-all`, and compared to
+// `dump_refinements.json` by the test suite.
+
+pub trait Counter {
+    #[logic]
+    fn count(self) -> Int;
+
+    #[requires(self.count() < 100)]
+    #[ensures(result.count() == self.count() + 1)]
+    fn next(self) -> Self;
+
+    #[ensures(result ==> self.count() > 0)]
+    fn positive(&self) -> bool;
+}
+
+pub struct Small(pub u8);
+
+impl Counter for Small {
+    #[logic]
+    #[open]
+    fn count(self) -> Int {
+        pearlite! { self.0@ }
+    }
+
+    #[requires(self.count() < 200)]
+    #[ensures(result.count() == self.count() + 1)]
+    fn next(self) -> Self {
+        Small(self.0 + 1)
+    }
+
+    #[ensures(result == (self.count() > 0))]
+    fn positive(&self) -> bool {
+        self.0 > 0
+    }
+}
+
+pub enum Choice {
+    Left,
+    Right(u8),
+}
+
+pub trait Pick {
+    #[ensures(true)]
+    fn pick(&self) -> Choice;
+}
+
+impl Pick for Small {
+    #[ensures(match result { Choice::Left => self.0@ == 0, Choice::Right(x) => x == self.0 })]
+    fn pick(&self) -> Choice {
+        if self.0 == 0 { Choice::Left } else { Choice::Right(self.0) }
+    }
+}
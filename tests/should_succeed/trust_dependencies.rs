@@ -0,0 +1,31 @@
+// CREUSOT_ARG=--report-trust-dependencies WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Measure {
+    #[logic]
+    fn measure(self) -> Int;
+
+    #[law]
+    #[ensures(x.measure() >= 0)]
+    fn measure_nonneg(x: Self);
+}
+
+pub struct Opaque(pub u32);
+
+impl Measure for Opaque {
+    #[logic]
+    #[trusted]
+    fn measure(self) -> Int {
+        dead
+    }
+
+    // The measure is opaque: the law cannot be proved, and `positive` may rely on it.
+    #[law]
+    #[trusted]
+    #[ensures(x.measure() >= 0)]
+    fn measure_nonneg(x: Self) {}
+}
+
+#[ensures(x.measure() + 1 > 0)]
+pub fn positive(x: Opaque) {}
@@ -0,0 +1,18 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct NonZero(pub u32);
+
+impl Invariant for NonZero {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ != 0 }
+    }
+}
+
+#[requires(n@ > 0)]
+pub fn local_literal(n: u32) -> u32 {
+    let x = NonZero(n);
+    x.0
+}
@@ -0,0 +1,57 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, std::default::DefaultSpec, *};
+
+pub struct NonZero(pub u32);
+
+impl Invariant for NonZero {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ != 0 }
+    }
+}
+
+impl Default for NonZero {
+    #[ensures(result.0@ == 1)]
+    fn default() -> Self {
+        NonZero(1)
+    }
+}
+
+impl DefaultSpec for NonZero {
+    #[predicate]
+    #[open]
+    fn is_default(self) -> bool {
+        pearlite! { self.0@ == 1 }
+    }
+}
+
+// The type invariant alone is enough to prove `is_default`.
+pub struct Positive(pub i32);
+
+impl Invariant for Positive {
+    #[predicate]
+    #[open(self)]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ > 0 }
+    }
+}
+
+impl Default for Positive {
+    fn default() -> Self {
+        Positive(7)
+    }
+}
+
+impl DefaultSpec for Positive {
+    #[predicate]
+    #[open]
+    fn is_default(self) -> bool {
+        pearlite! { self.0@ >= 1 }
+    }
+}
+
+pub fn use_default() {
+    let x = NonZero::default();
+    proof_assert!(x.0@ == 1);
+}
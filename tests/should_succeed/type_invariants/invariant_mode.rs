@@ -0,0 +1,62 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+pub struct Even(pub u32);
+
+impl Invariant for Even {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ % 2 == 0 }
+    }
+}
+
+// A defensive function: callers may pass values breaking the invariant.
+#[creusot::invariant_mode(open_args)]
+#[ensures(result == (x.0@ % 2 == 0))]
+pub fn is_valid(x: Even) -> bool {
+    x.0 % 2 == 0
+}
+
+pub fn check_broken() {
+    let mut x = Even(0);
+    x.0 = 1;
+    let b = is_valid(x);
+    proof_assert! { !b };
+}
+
+// The result invariant is not proved, and callers do not get it.
+#[creusot::invariant_mode(open_result)]
+#[ensures(result.0 == x)]
+pub fn raw(x: u32) -> Even {
+    Even(x)
+}
+
+#[creusot::invariant_mode(open_args, open_result)]
+#[requires(x.0@ < u32::MAX@)]
+#[ensures(result.0@ == x.0@ + 1)]
+pub fn bump(x: Even) -> Even {
+    Even(x.0 + 1)
+}
+
+pub trait Check {
+    #[creusot::invariant_mode(open_args)]
+    fn check(x: Even) -> bool;
+
+    fn make() -> Even;
+}
+
+pub struct Impl;
+
+impl Check for Impl {
+    #[creusot::invariant_mode(open_args)]
+    fn check(x: Even) -> bool {
+        x.0 % 2 == 0
+    }
+
+    // The trait does not open the result invariant: the implementation may not either, but may
+    // open its arguments.
+    fn make() -> Even {
+        Even(0)
+    }
+}
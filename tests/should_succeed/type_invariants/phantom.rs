@@ -0,0 +1,47 @@
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+use std::marker::PhantomData;
+
+pub struct Tagged<T>(u32, PhantomData<T>);
+
+impl<T> Invariant for Tagged<T> {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ < 100 }
+    }
+}
+
+impl<T> View for Tagged<T> {
+    type ViewTy = Int;
+
+    #[logic]
+    #[open]
+    fn view(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+impl<T> Tagged<T> {
+    #[requires(x@ < 100)]
+    #[ensures(result@ == x@)]
+    pub fn new(x: u32) -> Self {
+        Tagged(x, PhantomData)
+    }
+
+    #[ensures(result@ == self@ && result@ < 100)]
+    pub fn get(&self) -> u32 {
+        self.0
+    }
+
+    /// Changing the tag does not change the view.
+    #[ensures(result@ == self@)]
+    pub fn retag<U>(self) -> Tagged<U> {
+        Tagged(self.0, PhantomData)
+    }
+}
+
+pub fn phantom_model<T>(p: PhantomData<T>) {
+    proof_assert!(p.deep_model() == ());
+    proof_assert!(p@ == ());
+}
@@ -0,0 +1,61 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Int, *};
+
+pub enum Tree {
+    Leaf,
+    Node(Box<Tree>, u32, Box<Tree>),
+}
+use Tree::*;
+
+impl Tree {
+    #[logic]
+    #[open]
+    #[ensures(result >= 0)]
+    pub fn size(self) -> Int {
+        pearlite! {
+            match self {
+                Leaf => 0,
+                Node(l, _, r) => 1 + l.size() + r.size(),
+            }
+        }
+    }
+
+    /// The maximum value of the tree, or `-1` if it is empty.
+    #[logic]
+    #[open]
+    pub fn max(self) -> Int {
+        pearlite! {
+            match self {
+                Leaf => -1,
+                Node(l, v, r) => v@.max(l.max()).max(r.max()),
+            }
+        }
+    }
+}
+
+// The invariant is recursive: it is well-defined thanks to the measure.
+impl Invariant for Tree {
+    #[predicate]
+    #[open]
+    #[variant(self.size())]
+    fn invariant(self) -> bool {
+        pearlite! {
+            match self {
+                Leaf => true,
+                Node(l, v, r) => l.max() < v@ && (*l).invariant() && (*r).invariant(),
+            }
+        }
+    }
+}
+
+#[requires(l.max() < v@)]
+pub fn node(l: Tree, v: u32) -> Tree {
+    Node(Box::new(l), v, Box::new(Leaf))
+}
+
+pub fn singleton(v: u32) -> Tree {
+    node(Leaf, v)
+}
+
+#[ensures(match *t { Node(l, v, _) => l.max() < v@, Leaf => true })]
+pub fn left_below(t: &Tree) {}
@@ -0,0 +1,12 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(v@.len() > 0)]
+#[ensures(result == v[v@.len() - 1])]
+pub fn last(v: &[u32]) -> u32 {
+    let i = v.len() - 1;
+    unsafe {
+        unsafe_requires!(i@ < v@.len());
+        *v.get_unchecked(i)
+    }
+}
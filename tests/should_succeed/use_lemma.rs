@@ -0,0 +1,33 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+#[variant(m - n)]
+#[requires(1 <= x && 0 <= n && n <= m)]
+#[ensures(x.pow(n) <= x.pow(m))]
+pub fn pow_mono(x: Int, n: Int, m: Int) {
+    if n < m {
+        pow_mono(x, n, m - 1)
+    }
+}
+
+#[requires(1 <= x@ && n <= m && x@.pow(m@) <= u64::MAX@)]
+#[ensures(result@ == x@.pow(n@))]
+pub fn pow_below(x: u64, n: u32, m: u32) -> u64 {
+    let mut acc = 1u64;
+    let mut i = 0u32;
+    #[invariant(i <= n)]
+    #[invariant(acc@ == x@.pow(i@))]
+    while i < n {
+        use_lemma!(pow_mono, x@, i@ + 1, m@);
+        acc *= x;
+        i += 1;
+    }
+    acc
+}
+
+#[requires(1 <= x@ && n <= m && x@.pow(m@) <= u64::MAX@)]
+#[ensures(x@.pow(n@) <= u64::MAX@)]
+pub fn smaller_fits(x: u64, n: u32, m: u32) {
+    use_lemma!(pow_mono, x@, n@, m@);
+}
@@ -0,0 +1,17 @@
+// CREUSOT_ARG=--check-precondition-satisfiability WHY3PROVE
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+// The goal `satisfiable` of this function is proved, with `x == 1`.
+#[requires(x@ > 0)]
+#[requires(x@ < 2)]
+#[ensures(result == x)]
+pub fn satisfiable(x: i32) -> i32 {
+    x
+}
+
+// No goal is generated for functions without precondition.
+#[ensures(result == x)]
+pub fn no_precondition(x: i32) -> i32 {
+    x
+}
@@ -0,0 +1,32 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(v@.len() < usize::MAX@)]
+#[ensures((^v)@.len() == v@.len() + 1)]
+#[ensures((^v).capacity_log() >= (^v)@.len())]
+#[ensures((^v).capacity_log() >= v.capacity_log())]
+pub fn push_one(v: &mut Vec<u32>) {
+    v.push(0);
+}
+
+pub fn with_capacity() {
+    let mut v: Vec<u32> = Vec::with_capacity(10);
+    proof_assert!(v.capacity_log() >= 10);
+    let before = snapshot!(v.capacity_log());
+    v.push(1);
+    v.push(2);
+    proof_assert!(v@.len() == 2);
+    proof_assert!(v.capacity_log() >= *before);
+    assert!(v.capacity() >= v.len());
+    v.pop();
+    v.clear();
+    proof_assert!(v.capacity_log() >= 10);
+}
+
+#[requires(v@.len() + 8 <= usize::MAX@)]
+pub fn reserve_then_push(v: &mut Vec<u8>) {
+    v.reserve(8);
+    let cap = v.capacity();
+    v.push(0);
+    proof_assert!(cap@ >= v@.len());
+}
@@ -0,0 +1,17 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures((^v)@ == v@.concat(w@))]
+#[ensures((^w)@.len() == 0)]
+pub fn append(v: &mut Vec<u32>, w: &mut Vec<u32>) {
+    v.append(w)
+}
+
+pub fn append_concrete() {
+    let mut v = vec![1, 2];
+    let mut w = vec![3];
+    v.append(&mut w);
+    proof_assert!(v@.len() == 3);
+    proof_assert!(v[2]@ == 3);
+    proof_assert!(w@.len() == 0);
+}
@@ -0,0 +1,23 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result@ == v@.reverse())]
+pub fn reversed(v: &Vec<u32>) -> Vec<u32> {
+    let mut out = Vec::new();
+    let mut i = v.len();
+    #[invariant(i@ <= v@.len())]
+    #[invariant(out@.len() == v@.len() - i@)]
+    #[invariant(forall<j: Int> 0 <= j && j < out@.len() ==> out@[j] == v@[v@.len() - 1 - j])]
+    while i > 0 {
+        i -= 1;
+        out.push(v[i]);
+    }
+    proof_assert! { out@.ext_eq(v@.reverse()) };
+    out
+}
+
+#[ensures((^v)@ == v@)]
+pub fn reverse_twice(v: &mut Vec<u32>) {
+    v.reverse();
+    v.reverse();
+}
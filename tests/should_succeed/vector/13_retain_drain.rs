@@ -0,0 +1,42 @@
+extern crate creusot_contracts;
+
+use creusot_contracts::{
+    logic::{Int, Mapping},
+    *,
+};
+
+// `r` is made of the even elements of `s`, in order: this determines `r`.
+#[predicate]
+#[open]
+pub fn even_filtered(s: Seq<u32>, r: Seq<u32>) -> bool {
+    pearlite! {
+        exists<m: Mapping<Int, Int>>
+            (forall<i: Int> 0 <= i && i < r.len() ==> 0 <= m.get(i) && m.get(i) < s.len() && r[i] == s[m.get(i)]) &&
+            (forall<i: Int, j: Int> 0 <= i && i < j && j < r.len() ==> m.get(i) < m.get(j)) &&
+            (forall<k: Int> 0 <= k && k < s.len() ==>
+                (exists<i: Int> 0 <= i && i < r.len() && m.get(i) == k) == (s[k]@ % 2 == 0))
+    }
+}
+
+#[ensures(even_filtered(v@, (^v)@))]
+pub fn keep_even(v: &mut Vec<u32>) {
+    v.retain(
+        #[ensures(result == (x@ % 2 == 0))]
+        |x: &u32| *x % 2 == 0,
+    );
+}
+
+#[requires(v@.len() >= 3)]
+#[ensures((^v)@ == v@.subsequence(0, 1).concat(v@.subsequence(3, v@.len())))]
+#[ensures((^v)@.len() == v@.len() - 2)]
+pub fn drain_middle(v: &mut Vec<u32>) {
+    v.drain(1..3);
+}
+
+#[requires(v@.len() >= 2)]
+#[ensures((^v)@ == v@.subsequence(0, 2))]
+pub fn truncate_two(v: &mut Vec<u32>) {
+    let old_v = snapshot! { v };
+    let tail = v.drain(2..);
+    proof_assert!(tail@.len() == old_v@.len() - 2);
+}
@@ -0,0 +1,43 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Value {
+    fn value(&self) -> u32;
+}
+
+impl Value for u8 {
+    #[ensures(result@ == self@)]
+    fn value(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl Value for bool {
+    #[ensures(result@ <= 1)]
+    fn value(&self) -> u32 {
+        if *self { 1 } else { 0 }
+    }
+}
+
+// The trait says nothing about `value`: this is only provable for `u8` and `bool`.
+#[creusot::verify_monomorphized]
+#[ensures(result@ < 1000)]
+fn doubled<T: Value>(x: &T) -> u32 {
+    x.value() * 2
+}
+
+pub fn callers() {
+    let a = doubled(&200u8);
+    let b = doubled(&true);
+    proof_assert!(a@ < 1000 && b@ < 1000);
+}
+
+#[requires(f.precondition((x,)))]
+fn apply<T, F: Fn(&T) -> u32>(f: F, x: &T) -> u32 {
+    f(x)
+}
+
+// `doubled::<u8>` is verified, although it is only used as a value.
+pub fn as_value() -> u32 {
+    apply(doubled::<u8>, &100u8)
+}
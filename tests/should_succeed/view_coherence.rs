@@ -0,0 +1,65 @@
+// CREUSOT_ARG=--closed-crate=view_coherence
+extern crate creusot_contracts;
+use creusot_contracts::{logic::FSet, *};
+
+// A type with two views: the coherence law relating them is not assumed, but proved at each impl
+// of `Views`, with the definitions of both views of this impl.
+
+pub trait Views {
+    #[logic]
+    fn seq_view(self) -> Seq<Int>;
+
+    #[logic]
+    fn set_view(self) -> FSet<Int>;
+
+    #[law]
+    #[forall_impls]
+    #[ensures(x.set_view() == x.seq_view().to_set())]
+    fn coherence(x: Self) {}
+}
+
+pub enum Tree {
+    Leaf,
+    Node(Box<Tree>, i32, Box<Tree>),
+}
+
+impl Tree {
+    #[logic]
+    #[open]
+    #[variant(self)]
+    pub fn elems(self) -> Seq<Int> {
+        pearlite! {
+            match self {
+                Tree::Leaf => Seq::empty(),
+                Tree::Node(l, v, r) => l.elems().push_back(v@).concat(r.elems()),
+            }
+        }
+    }
+
+    #[logic]
+    #[open]
+    #[ensures(result == self.elems().to_set())]
+    pub fn elem_set(self) -> FSet<Int> {
+        pearlite! { self.elems().to_set() }
+    }
+}
+
+impl Views for Tree {
+    #[logic]
+    #[open]
+    fn seq_view(self) -> Seq<Int> {
+        self.elems()
+    }
+
+    #[logic]
+    #[open]
+    fn set_view(self) -> FSet<Int> {
+        self.elem_set()
+    }
+}
+
+#[requires(t.seq_view().contains(x))]
+#[ensures(t.set_view().contains(x))]
+pub fn member(t: &Tree, x: Int) {
+    let _ = snapshot!(x);
+}
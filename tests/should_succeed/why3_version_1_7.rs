@@ -0,0 +1,57 @@
+// CREUSOT_ARG=--why3-version=1.7
+extern crate creusot_contracts;
+use creusot_contracts::{invariant::Invariant, *};
+
+// Why3 1.7 has no named spans: they are written in full at each use, and the invariant axiom is
+// made a rewrite rule by a `meta` declaration.
+
+pub struct Even(pub u32);
+
+impl Invariant for Even {
+    #[predicate]
+    #[open]
+    fn invariant(self) -> bool {
+        pearlite! { self.0@ % 2 == 0 }
+    }
+}
+
+#[requires(x.0@ < 1000)]
+#[ensures(result.0@ == x.0@ + 2)]
+pub fn add_two(x: Even) -> Even {
+    Even(x.0 + 2)
+}
+
+pub trait Double {
+    #[logic]
+    fn double(self) -> Int;
+
+    #[law]
+    #[ensures(self.double() >= 0)]
+    fn double_nonneg(self);
+
+    #[ensures(result@ == self.double())]
+    fn double_it(self) -> u64;
+}
+
+impl Double for u32 {
+    #[logic]
+    #[open]
+    fn double(self) -> Int {
+        pearlite! { self@ * 2 }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.double() >= 0)]
+    fn double_nonneg(self) {}
+
+    #[ensures(result@ == self.double())]
+    fn double_it(self) -> u64 {
+        self as u64 * 2
+    }
+}
+
+#[ensures(result@ >= 0)]
+pub fn use_double(x: u32) -> u64 {
+    x.double_it()
+}
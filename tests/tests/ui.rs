@@ -90,6 +90,15 @@ fn main() {
 
     total += total1 + total2;
     failed += fail1 + fail2;
+
+    for check in [check_reproducible, check_refinements, check_incremental] {
+        if let Some(success) = check(&args, creusot_rustc, &temp_file) {
+            total += 1;
+            if !success {
+                failed += 1;
+            }
+        }
+    }
     if failed > 0 {
         let mut out =
             StandardStream::stdout(if args.force_color || std::io::stdout().is_terminal() {
@@ -221,10 +230,159 @@ fn translate_creusot_contracts(
     succeeded
 }
 
+/// The outcome of one of the tests run on a single file with specific options.
+#[derive(PartialEq, Eq)]
+enum Outcome {
+    Ok,
+    Blessed,
+    Failure,
+}
+
+/// Run the test `test` on `source`, reporting its outcome as `Checking {what}... ok`.
+///
+/// Returns `None` if the test is filtered out, and otherwise whether it succeeded.
+fn check_file(
+    args: &Args,
+    source: &str,
+    what: &str,
+    test: impl FnOnce(&Path) -> Outcome,
+) -> Option<bool> {
+    if let Some(ref filter) = args.filter {
+        if !source.contains(filter) {
+            return None;
+        }
+    }
+    print!("Checking {what}... ");
+    std::io::stdout().flush().unwrap();
+
+    let outcome = test(Path::new(source));
+    let mut out = StandardStream::stdout(if args.force_color || std::io::stdout().is_terminal() {
+        ColorChoice::Always
+    } else {
+        ColorChoice::Never
+    });
+    let (color, msg) = match outcome {
+        Outcome::Ok => (Color::Green, "ok"),
+        Outcome::Blessed => (Color::Blue, "blessed"),
+        Outcome::Failure => (Color::Red, "failure"),
+    };
+    out.set_color(ColorSpec::new().set_fg(Some(color))).unwrap();
+    writeln!(&mut out, "{msg}").unwrap();
+    out.reset().unwrap();
+    Some(outcome != Outcome::Failure)
+}
+
+fn outcome(success: bool) -> Outcome {
+    if success { Outcome::Ok } else { Outcome::Failure }
+}
+
+/// Translate the same file from two different directories, and check that the outputs are the same.
+fn check_reproducible(args: &Args, creusot_rustc: &Path, contracts: &str) -> Option<bool> {
+    check_file(args, "tests/should_succeed/reproducible.rs", "reproducibility", |source| {
+        let root = env::temp_dir().join(format!("creusot-reproducible-{}", std::process::id()));
+        let outputs: Vec<_> = ["first", "second/nested"]
+            .iter()
+            .map(|dir| {
+                let dir = root.join(dir);
+                std::fs::create_dir_all(&dir).unwrap();
+                let file = dir.join(source.file_name().unwrap());
+                std::fs::copy(source, &file).unwrap();
+                run_creusot(creusot_rustc, &file, contracts).unwrap().output().unwrap()
+            })
+            .collect();
+        let _ = std::fs::remove_dir_all(&root);
+
+        outcome(
+            outputs.iter().all(|o| o.status.success()) && outputs[0].stdout == outputs[1].stdout,
+        )
+    })
+}
+
+/// Dump the refinements generated for a file with `--dump-refinements-all`, and compare them to
+/// the expected ones.
+fn check_refinements(args: &Args, creusot_rustc: &Path, contracts: &str) -> Option<bool> {
+    check_file(args, "tests/should_succeed/traits/dump_refinements.rs", "refinements", |source| {
+        let expect = source.with_extension("json");
+        let dump = env::temp_dir().join(format!("creusot-refinements-{}.json", std::process::id()));
+        let mut cmd = run_creusot(creusot_rustc, source, contracts).unwrap();
+        cmd.arg("--dump-refinements-all").arg(&dump);
+        let status = cmd.output().unwrap().status;
+        let actual = std::fs::read(&dump).unwrap_or_default();
+        let _ = std::fs::remove_file(&dump);
+        let expected = std::fs::read(&expect).unwrap_or_default();
+
+        if status.success() && actual == expected {
+            Outcome::Ok
+        } else if args.bless && status.success() {
+            std::fs::write(&expect, &actual).unwrap();
+            Outcome::Blessed
+        } else {
+            Outcome::Failure
+        }
+    })
+}
+
+/// Translate a file to a directory twice, changing one item in between, and check that only the
+/// file of this item was rewritten.
+fn check_incremental(args: &Args, creusot_rustc: &Path, contracts: &str) -> Option<bool> {
+    check_file(args, "tests/should_succeed/incremental.rs", "incremental output", |source| {
+        let root = env::temp_dir().join(format!("creusot-incremental-{}", std::process::id()));
+        let out = root.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+        let file = root.join(source.file_name().unwrap());
+        let original = std::fs::read_to_string(source).unwrap();
+        let run = |contents: &str| {
+            std::fs::write(&file, contents).unwrap();
+            let mut cmd = creusot_command(creusot_rustc, &file, contracts, &[
+                "--output-dir",
+                out.to_str().unwrap(),
+            ])
+            .unwrap();
+            let success = cmd.output().unwrap().status.success();
+            let files: Vec<_> = glob::glob(&format!("{}/**/*.coma", out.display()))
+                .unwrap()
+                .map(|path| {
+                    let path = path.unwrap();
+                    let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+                    let contents = std::fs::read(&path).unwrap();
+                    (path, modified, contents)
+                })
+                .collect();
+            (success, files)
+        };
+        let (success1, before) = run(&original);
+        // Wait, so that a rewritten file gets a different modification time.
+        thread::sleep(std::time::Duration::from_millis(50));
+        let (success2, after) = run(&original.replace("changed_item = 1", "changed_item = 2"));
+        let _ = std::fs::remove_dir_all(&root);
+
+        // Exactly one file changed, and the others were left untouched.
+        let changed = before.iter().zip(&after).filter(|(b, a)| b != a).count();
+        outcome(
+            success1
+                && success2
+                && !before.is_empty()
+                && before.len() == after.len()
+                && before.iter().zip(&after).all(|(b, a)| b.0 == a.0)
+                && changed == 1,
+        )
+    })
+}
+
 fn run_creusot(
     creusot_rustc: &Path,
     file: &Path,
     contracts: &str,
+) -> Option<std::process::Command> {
+    creusot_command(creusot_rustc, file, contracts, &["--stdout"])
+}
+
+/// The command running creusot on `file`, with the options `output` selecting its output.
+fn creusot_command(
+    creusot_rustc: &Path,
+    file: &Path,
+    contracts: &str,
+    output: &[&str],
 ) -> Option<std::process::Command> {
     let header_line = BufReader::new(File::open(&file).unwrap()).lines().nth(0).unwrap().unwrap();
     if header_line.contains("UISKIP") {
@@ -268,9 +426,9 @@ fn run_creusot(
     if header_line.contains("SHORT_ERROR") {
         cmd.arg("--error-format=short");
     }
+    cmd.arg("--");
+    cmd.args(output);
     cmd.args(&[
-        "--",
-        "--stdout",
         "--export-metadata=false",
         "--span-mode=relative",
         // we will write the coma output next to the .rs file
@@ -60,19 +60,19 @@ impl std::fmt::Debug for Symbol {
     }
 }
 
-// Adding these placeholders was less hassle than removing all `derive(Serialize)` from the why3 crate
-// and removing their use in `creusot/src/run_why3.rs`.
+// A symbol is serialized as the string it represents, which is interned again on deserialization.
 #[cfg(feature = "serialize")]
 impl Serialize for Symbol {
-    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
-        todo! {}
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
     }
 }
 
 #[cfg(feature = "serialize")]
 impl<'d> Deserialize<'d> for Symbol {
-    fn deserialize<D: serde::Deserializer<'d>>(_deserializer: D) -> Result<Self, D::Error> {
-        todo! {}
+    fn deserialize<D: serde::Deserializer<'d>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Symbol::intern(&s))
     }
 }
 
@@ -17,6 +17,8 @@ use crate::{
 };
 use num::{Float, Zero};
 use pretty::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
 
 pub struct PrintDisplay<'a, A: Print>(&'a A);
 
@@ -32,18 +34,65 @@ pub const ALLOC: BoxAllocator = BoxAllocator;
 
 pub fn render_decls<'a, W: Write>(
     decls: impl IntoIterator<Item = &'a Decl>,
+    version: Why3Version,
     out: &mut W,
 ) -> std::io::Result<()> {
-    let mut scope = Why3Scope::new();
+    let mut scope = Why3Scope::for_version(version);
     scope.open();
+    let decls = scope.bind_inline_spans(decls);
     pretty_blocks(decls, &ALLOC, &mut scope).1.render(120, out)
 }
 
-pub fn render_module<W: Write>(module: &Module, out: &mut W) -> std::io::Result<()> {
-    let mut scope = Why3Scope::new();
+pub fn render_module<W: Write>(
+    module: &Module,
+    version: Why3Version,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let mut scope = Why3Scope::for_version(version);
     module.pretty(&ALLOC, &mut scope).1.render(120, out)
 }
 
+/// The release of Why3 that the printed code is meant for, when their syntaxes differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum Why3Version {
+    V1_7,
+    #[default]
+    V1_8,
+}
+
+impl Why3Version {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Why3Version::V1_7 => "1.7",
+            Why3Version::V1_8 => "1.8",
+        }
+    }
+
+    /// Spans bound once with `let%span` and referred to as `[%#name]`. Older releases only
+    /// accept spans written in full at each use.
+    pub fn named_spans(self) -> bool {
+        self >= Why3Version::V1_8
+    }
+
+    /// Rewrite rules given by the `[@rewrite]` attribute on their axiom, rather than by a
+    /// separate `meta "rewrite" prop` declaration.
+    pub fn rewrite_attribute(self) -> bool {
+        self >= Why3Version::V1_8
+    }
+}
+
+impl std::str::FromStr for Why3Version {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        [Why3Version::V1_7, Why3Version::V1_8]
+            .into_iter()
+            .find(|version| version.as_str() == s)
+            .ok_or_else(|| format!("unsupported Why3 version `{s}`, expected `1.7` or `1.8`"))
+    }
+}
+
 /// To pretty print Why3 code, we keep track of what `Ident` are in scope in order to
 /// assign them the simplest identifier based on their `name`: `name`, `name'0`, `name'1`...
 struct Scope {
@@ -146,11 +195,43 @@ pub struct Why3Scope {
     value_scope: Scope,
     type_scope: Scope,
     span_scope: Scope,
+    version: Why3Version,
+    /// The spans printed in full at each use, when the targeted version has no named spans.
+    inline_spans: HashMap<Ident, Span>,
 }
 
 impl Why3Scope {
     pub fn new() -> Self {
-        Why3Scope { value_scope: Scope::new(), type_scope: Scope::new(), span_scope: Scope::new() }
+        Self::for_version(Why3Version::default())
+    }
+
+    pub fn for_version(version: Why3Version) -> Self {
+        Why3Scope {
+            value_scope: Scope::new(),
+            type_scope: Scope::new(),
+            span_scope: Scope::new(),
+            version,
+            inline_spans: HashMap::new(),
+        }
+    }
+
+    /// If the targeted version has no named spans, remember the spans of the `let%span`
+    /// declarations to print them at each use instead, and leave these declarations out.
+    fn bind_inline_spans<'a>(
+        &mut self,
+        decls: impl IntoIterator<Item = &'a Decl>,
+    ) -> Vec<&'a Decl> {
+        if self.version.named_spans() {
+            return decls.into_iter().collect();
+        }
+        decls
+            .into_iter()
+            .filter(|decl| {
+                let Decl::LetSpans(spans) = decl else { return true };
+                self.inline_spans.extend(spans.iter().map(|span| (span.name, span.clone())));
+                false
+            })
+            .collect()
     }
 
     pub fn open(&mut self) {
@@ -365,7 +446,10 @@ impl Print for Module {
                 None => alloc.nil(),
             })
             .append(alloc.hardline())
-            .append(pretty_blocks(&self.decls, alloc, scope).indent(2))
+            .append({
+                let decls = scope.bind_inline_spans(&self.decls);
+                pretty_blocks(decls, alloc, scope).indent(2)
+            })
             .append(alloc.hardline())
             .append("end");
         scope.close();
@@ -398,11 +482,19 @@ impl Print for Axiom {
         A::Doc: Clone,
     {
         scope.bind_value(self.name);
-        alloc
+        let rewrite_attribute = self.rewrite && scope.version.rewrite_attribute();
+        let doc = alloc
             .text("axiom ")
             .append(self.name.pretty_value_name(alloc, scope))
-            .append(if self.rewrite { " [@rewrite] : " } else { " : " })
-            .append(self.axiom.pretty(alloc, scope))
+            .append(if rewrite_attribute { " [@rewrite] : " } else { " : " })
+            .append(self.axiom.pretty(alloc, scope));
+        if self.rewrite && !rewrite_attribute {
+            doc.append(alloc.hardline())
+                .append("meta \"rewrite\" prop ")
+                .append(self.name.pretty_value_name(alloc, scope))
+        } else {
+            doc
+        }
     }
 }
 
@@ -462,7 +554,20 @@ impl Print for Attribute {
     {
         match &self {
             Attribute::Attr(s) => alloc.text("@").append(s),
-            Attribute::NamedSpan(s) => alloc.text("%#").append(s.pretty_span_name(alloc, scope)),
+            Attribute::NamedSpan(s) => match scope.inline_spans.get(s) {
+                Some(span) => alloc
+                    .text("#")
+                    .append(alloc.text(span.path.clone()).double_quotes())
+                    .append(alloc.space())
+                    .append(alloc.as_string(span.start_line))
+                    .append(alloc.space())
+                    .append(alloc.as_string(span.start_column))
+                    .append(alloc.space())
+                    .append(alloc.as_string(span.end_line))
+                    .append(alloc.space())
+                    .append(alloc.as_string(span.end_column)),
+                None => alloc.text("%#").append(s.pretty_span_name(alloc, scope)),
+            },
             Attribute::Span(f, ls, cs, le, ce) => alloc
                 .text("#")
                 .append(alloc.text(f).double_quotes())